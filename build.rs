@@ -0,0 +1,8 @@
+fn main() -> std::io::Result<()> {
+    // 使用内置 protoc，无需在构建环境中安装
+    std::env::set_var("PROTOC", protoc_bin_vendored::protoc_bin_path().unwrap());
+    println!("cargo:rerun-if-changed=src/protos/events.proto");
+    prost_build::Config::new()
+        .type_attribute("Event.event", "#[allow(clippy::large_enum_variant)]")
+        .compile_protos(&["src/protos/events.proto"], &["src/protos"])
+}
@@ -0,0 +1,86 @@
+use std::sync::Arc;
+
+use solana_streamer_sdk::streaming::{
+    event_parser::{
+        core::anchor_idl::{IdlAccountEvent, IdlCpiEvent, IdlInstructionEvent},
+        AnchorIdl, ProtocolRegistry, UnifiedEvent,
+    },
+    grpc::ClientConfig,
+    yellowstone_grpc::{AccountFilter, TransactionFilter},
+    YellowstoneGrpc,
+};
+
+#[tokio::main]
+async fn main() -> Result<(), Box<dyn std::error::Error>> {
+    println!("Starting Yellowstone gRPC Streamer with an Anchor IDL...");
+    test_grpc().await?;
+    Ok(())
+}
+
+async fn test_grpc() -> Result<(), Box<dyn std::error::Error>> {
+    // 用法: cargo run --example anchor_idl_example -- <idl.json> [program_id]
+    let mut args = std::env::args().skip(1);
+    let idl_path = args.next().ok_or("usage: anchor_idl_example <idl.json> [program_id]")?;
+    let program_id = args.next().map(|id| id.parse()).transpose()?;
+
+    let idl = AnchorIdl::from_file(&idl_path)?;
+    println!(
+        "Loaded IDL {}: {} instructions, {} events, {} accounts",
+        idl.name,
+        idl.instructions.len(),
+        idl.events.len(),
+        idl.accounts.len()
+    );
+    let protocol = idl.into_protocol(program_id)?;
+    let program_id = protocol.program_id;
+    let mut registry = ProtocolRegistry::new();
+    registry.register(protocol);
+
+    let mut config: ClientConfig = ClientConfig::low_latency();
+    config.protocol_registry = Some(Arc::new(registry));
+    let grpc = YellowstoneGrpc::new_with_config(
+        "https://solana-yellowstone-grpc.publicnode.com:443".to_string(),
+        None,
+        config,
+    )?;
+
+    let transaction_filter = TransactionFilter {
+        account_include: vec![program_id.to_string()],
+        account_exclude: vec![],
+        account_required: vec![],
+    };
+    let account_filter =
+        AccountFilter { account: vec![], owner: vec![program_id.to_string()], filters: vec![] };
+
+    println!("Starting to listen for events, press Ctrl+C to stop...");
+    grpc.subscribe_events_immediate(
+        vec![],
+        None,
+        vec![transaction_filter],
+        vec![account_filter],
+        None,
+        None,
+        |event: Box<dyn UnifiedEvent>| {
+            if let Some(e) = event.as_any().downcast_ref::<IdlInstructionEvent>() {
+                println!(
+                    "Instruction {}: {}",
+                    e.instruction,
+                    serde_json::to_string(&e.args).unwrap()
+                );
+            } else if let Some(e) = event.as_any().downcast_ref::<IdlCpiEvent>() {
+                println!("Event {}: {}", e.event, serde_json::to_string(&e.data).unwrap());
+            } else if let Some(e) = event.as_any().downcast_ref::<IdlAccountEvent>() {
+                println!(
+                    "Account {} {}: {}",
+                    e.account,
+                    e.pubkey,
+                    serde_json::to_string(&e.data).unwrap()
+                );
+            }
+        },
+    )
+    .await?;
+
+    tokio::signal::ctrl_c().await?;
+    Ok(())
+}
@@ -0,0 +1,85 @@
+use std::{str::FromStr, sync::Arc};
+
+use serde::Serialize;
+use solana_sdk::pubkey::Pubkey;
+use solana_streamer_sdk::{
+    impl_unified_event,
+    streaming::{
+        event_parser::{
+            common::{EventMetadata, EventType},
+            CustomProtocol, ProtocolRegistry, UnifiedEvent,
+        },
+        grpc::ClientConfig,
+        yellowstone_grpc::{AccountFilter, TransactionFilter},
+        YellowstoneGrpc,
+    },
+};
+
+const MEMO_PROGRAM_ID: &str = "MemoSq4gqABAXKb96qnH8TysNcWxMyWCqXgDLGmfcHr";
+
+/// SPL Memo 事件（非内置协议）
+#[derive(Clone, Debug, Default, Serialize)]
+pub struct MemoEvent {
+    pub metadata: EventMetadata,
+    pub memo: String,
+    pub signers: Vec<Pubkey>,
+}
+
+impl_unified_event!(custom MemoEvent,);
+
+#[tokio::main]
+async fn main() -> Result<(), Box<dyn std::error::Error>> {
+    println!("Starting Yellowstone gRPC Streamer with a custom protocol...");
+    test_grpc().await?;
+    Ok(())
+}
+
+async fn test_grpc() -> Result<(), Box<dyn std::error::Error>> {
+    let memo_program_id = Pubkey::from_str(MEMO_PROGRAM_ID)?;
+
+    // Memo 指令没有 discriminator，整段指令数据即为 memo 文本
+    let memo_protocol = CustomProtocol::new("SplMemo", memo_program_id).with_instruction_parser(
+        EventType::Custom("SplMemo".to_string()),
+        &[],
+        |data, accounts, metadata| {
+            let memo = std::str::from_utf8(data).ok()?.to_string();
+            Some(Box::new(MemoEvent { metadata, memo, signers: accounts.to_vec() }))
+        },
+    );
+    let mut registry = ProtocolRegistry::new();
+    registry.register(memo_protocol);
+
+    let mut config: ClientConfig = ClientConfig::low_latency();
+    config.protocol_registry = Some(Arc::new(registry));
+    let grpc = YellowstoneGrpc::new_with_config(
+        "https://solana-yellowstone-grpc.publicnode.com:443".to_string(),
+        None,
+        config,
+    )?;
+
+    let transaction_filter = TransactionFilter {
+        account_include: vec![MEMO_PROGRAM_ID.to_string()],
+        account_exclude: vec![],
+        account_required: vec![],
+    };
+    let account_filter = AccountFilter { account: vec![], owner: vec![], filters: vec![] };
+
+    println!("Starting to listen for events, press Ctrl+C to stop...");
+    grpc.subscribe_events_immediate(
+        vec![],
+        None,
+        vec![transaction_filter],
+        vec![account_filter],
+        None,
+        None,
+        |event: Box<dyn UnifiedEvent>| {
+            if let Some(memo) = event.as_any().downcast_ref::<MemoEvent>() {
+                println!("Memo {}: {}", memo.metadata.signature, memo.memo);
+            }
+        },
+    )
+    .await?;
+
+    tokio::signal::ctrl_c().await?;
+    Ok(())
+}
@@ -0,0 +1,2 @@
+// 由 build.rs 根据 events.proto 使用 prost-build 生成
+include!(concat!(env!("OUT_DIR"), "/solana_streamer.events.v1.rs"));
@@ -1,4 +1,8 @@
+use std::sync::Arc;
+
 use super::constants::*;
+use super::reporter::MetricsReportConfig;
+use crate::streaming::event_parser::core::protocol_registry::ProtocolRegistry;
 
 /// Backpressure handling strategy
 #[derive(Debug, Clone, Copy)]
@@ -15,6 +19,29 @@ impl Default for BackpressureStrategy {
     }
 }
 
+/// Failed transaction handling for gRPC subscriptions
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum FailedTransactionMode {
+    /// Only successful transactions (default)
+    #[default]
+    Exclude,
+    /// Successful and failed transactions, use `UnifiedEvent::is_failed` to route them
+    Include,
+    /// Only failed transactions
+    Only,
+}
+
+impl FailedTransactionMode {
+    /// Value of the `failed` field in the transaction subscribe filter
+    pub fn subscribe_filter(&self) -> Option<bool> {
+        match self {
+            Self::Exclude => Some(false),
+            Self::Include => None,
+            Self::Only => Some(true),
+        }
+    }
+}
+
 /// Backpressure configuration
 #[derive(Debug, Clone)]
 pub struct BackpressureConfig {
@@ -60,6 +87,14 @@ pub struct StreamClientConfig {
     pub backpressure: BackpressureConfig,
     /// Whether performance monitoring is enabled (default: false)
     pub enable_metrics: bool,
+    /// Custom protocols parsed alongside the built-in ones (default: None)
+    pub protocol_registry: Option<Arc<ProtocolRegistry>>,
+    /// Failed transaction handling (default: Exclude)
+    ///
+    /// Events from failed transactions carry `tx_context.err` and only instruction-level fields
+    pub failed_transactions: FailedTransactionMode,
+    /// Periodic metrics reporting when `enable_metrics` is set (default: table every 10s)
+    pub metrics_report: MetricsReportConfig,
 }
 
 impl Default for StreamClientConfig {
@@ -68,6 +103,9 @@ impl Default for StreamClientConfig {
             connection: ConnectionConfig::default(),
             backpressure: BackpressureConfig::default(),
             enable_metrics: false,
+            protocol_registry: None,
+            failed_transactions: FailedTransactionMode::default(),
+            metrics_report: MetricsReportConfig::default(),
         }
     }
 }
@@ -89,6 +127,9 @@ impl StreamClientConfig {
                 strategy: BackpressureStrategy::Drop,
             },
             enable_metrics: false,
+            protocol_registry: None,
+            failed_transactions: FailedTransactionMode::default(),
+            metrics_report: MetricsReportConfig::default(),
         }
     }
 
@@ -106,6 +147,9 @@ impl StreamClientConfig {
             connection: ConnectionConfig::default(),
             backpressure: BackpressureConfig { permits: 4000, strategy: BackpressureStrategy::Block },
             enable_metrics: false,
+            protocol_registry: None,
+            failed_transactions: FailedTransactionMode::default(),
+            metrics_report: MetricsReportConfig::default(),
         }
     }
 
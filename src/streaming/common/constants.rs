@@ -10,3 +10,5 @@ pub const DEFAULT_MAX_DECODING_MESSAGE_SIZE: usize = 1024 * 1024 * 10;
 pub const DEFAULT_METRICS_WINDOW_SECONDS: u64 = 5;
 pub const DEFAULT_METRICS_PRINT_INTERVAL_SECONDS: u64 = 10;
 pub const SLOW_PROCESSING_THRESHOLD_US: f64 = 3000.0;
+// 打印指标时每个程序显示的未匹配指令与解码失败条目数
+pub const PRINT_DIAGNOSTICS_TOP_N: usize = 3;
@@ -1,14 +1,16 @@
-use std::sync::atomic::{AtomicBool, AtomicUsize, Ordering};
+use std::sync::atomic::{AtomicBool, AtomicI64, AtomicUsize, Ordering};
 use std::sync::Arc;
 
 use crossbeam_queue::SegQueue;
 use solana_sdk::pubkey::Pubkey;
+use tracing::{Instrument, Span};
 
 use crate::common::AnyResult;
 use crate::streaming::common::BackpressureStrategy;
 use crate::streaming::common::{
-    MetricsEventType, MetricsManager, StreamClientConfig as ClientConfig,
+    LatencyStage, MetricsEventType, MetricsManager, StreamClientConfig as ClientConfig,
 };
+use crate::streaming::event_parser::common::high_performance_clock::get_high_perf_clock;
 use crate::streaming::event_parser::common::filter::EventTypeFilter;
 use crate::streaming::event_parser::core::account_event_parser::AccountEventParser;
 use crate::streaming::event_parser::core::common_event_parser::CommonEventParser;
@@ -19,6 +21,19 @@ use crate::streaming::grpc::{BackpressureConfig, EventPretty};
 use crate::streaming::shred::TransactionWithSlot;
 use once_cell::sync::OnceCell;
 
+/// 处理链路 tracing span 的 target
+pub const PIPELINE_TRACING_TARGET: &str = "solana_streamer::pipeline";
+
+/// 队列中等待处理的消息
+pub(crate) struct QueuedMessage<T> {
+    pub(crate) message: T,
+    pub(crate) bot_wallet: Option<Pubkey>,
+    /// 入队时间（微秒），用于统计排队延迟
+    pub(crate) enqueue_us: i64,
+    /// 消息级 span，贯穿入队、解析与回调
+    pub(crate) span: Span,
+}
+
 /// High-performance Event processor using SegQueue for all strategies
 pub struct EventProcessor {
     pub(crate) metrics_manager: MetricsManager,
@@ -29,8 +44,8 @@ pub struct EventProcessor {
     pub(crate) callback: Option<Arc<dyn Fn(Box<dyn UnifiedEvent>) + Send + Sync>>,
     pub(crate) tx_pack_callback: Option<Arc<dyn Fn(Vec<Box<dyn UnifiedEvent>>) + Send + Sync>>,
     pub(crate) backpressure_config: BackpressureConfig,
-    pub(crate) grpc_queue: Arc<SegQueue<(EventPretty, Option<Pubkey>)>>,
-    pub(crate) shred_queue: Arc<SegQueue<(TransactionWithSlot, Option<Pubkey>)>>,
+    pub(crate) grpc_queue: Arc<SegQueue<QueuedMessage<EventPretty>>>,
+    pub(crate) shred_queue: Arc<SegQueue<QueuedMessage<TransactionWithSlot>>>,
     pub(crate) grpc_pending_count: Arc<AtomicUsize>,
     pub(crate) shred_pending_count: Arc<AtomicUsize>,
     pub(crate) processing_shutdown: Arc<AtomicBool>,
@@ -44,6 +59,7 @@ impl EventProcessor {
         let grpc_pending_count = Arc::new(AtomicUsize::new(0));
         let shred_pending_count = Arc::new(AtomicUsize::new(0));
         let processing_shutdown = Arc::new(AtomicBool::new(false));
+        metrics_manager.register_queue_depths(grpc_pending_count.clone(), shred_pending_count.clone());
 
         Self {
             metrics_manager,
@@ -78,9 +94,14 @@ impl EventProcessor {
         self.tx_pack_callback = tx_pack_callback;
         let protocols_ref = &self.protocols;
         let event_type_filter_ref = self.event_type_filter.as_ref();
-        self.parser_cache.get_or_init(|| {
-            Arc::new(EventParser::new(protocols_ref.clone(), event_type_filter_ref.cloned()))
+        let parser = self.parser_cache.get_or_init(|| {
+            Arc::new(EventParser::new_with_registry(
+                protocols_ref.clone(),
+                event_type_filter_ref.cloned(),
+                self.config.protocol_registry.as_deref(),
+            ))
         });
+        self.metrics_manager.register_parse_diagnostics(parser.diagnostics.clone());
 
         if matches!(self.backpressure_config.strategy, BackpressureStrategy::Block) {
             self.start_block_processing_thread();
@@ -91,20 +112,44 @@ impl EventProcessor {
         self.parser_cache.get().unwrap().clone()
     }
 
-    fn create_adapter_callback(&self) -> Arc<dyn Fn(Box<dyn UnifiedEvent>) + Send + Sync> {
+    /// `parse_start_us` 为开始处理本条消息的时间，用于统计解析与回调耗时
+    fn create_adapter_callback(
+        &self,
+        parse_start_us: i64,
+    ) -> Arc<dyn Fn(Box<dyn UnifiedEvent>) + Send + Sync> {
         let callback = self.callback.clone().unwrap();
         let metrics_manager = self.metrics_manager.clone();
+        // 上一次回调返回的时间，之后的耗时计入下一个事件的解析阶段
+        let parse_mark_us = AtomicI64::new(parse_start_us);
+        let message_span = Span::current();
 
         Arc::new(move |event: Box<dyn UnifiedEvent>| {
             let processing_time_us = event.handle_us() as f64;
-            callback(event);
+            metrics_manager.record_parsed_event(&*event);
+            let _event_span = event_span(&message_span, &*event).entered();
+            if metrics_manager.is_enabled() {
+                let callback_start_us = get_high_perf_clock();
+                let parse_us = callback_start_us - parse_mark_us.load(Ordering::Relaxed);
+                metrics_manager.record_latency(LatencyStage::Parse, parse_us);
+                callback(event);
+                let callback_end_us = get_high_perf_clock();
+                metrics_manager
+                    .record_latency(LatencyStage::Callback, callback_end_us - callback_start_us);
+                parse_mark_us.store(callback_end_us, Ordering::Relaxed);
+            } else {
+                callback(event);
+            }
             metrics_manager.update_metrics(MetricsEventType::Transaction, 1, processing_time_us);
         })
     }
 
-    fn create_tx_pack_adapter_callback(&self) -> Arc<dyn Fn(Vec<Box<dyn UnifiedEvent>>) + Send + Sync> {
+    fn create_tx_pack_adapter_callback(
+        &self,
+        parse_start_us: i64,
+    ) -> Arc<dyn Fn(Vec<Box<dyn UnifiedEvent>>) + Send + Sync> {
         let callback = self.tx_pack_callback.clone().unwrap();
         let metrics_manager = self.metrics_manager.clone();
+        let message_span = Span::current();
 
         Arc::new(move |events: Vec<Box<dyn UnifiedEvent>>| {
             if (events.is_empty()) {
@@ -113,7 +158,26 @@ impl EventProcessor {
             let event = &events[0];
             let events_count = events.len() as u64;
             let processing_time_us = event.handle_us() as f64;
-            callback(events);
+            for event in &events {
+                metrics_manager.record_parsed_event(&**event);
+            }
+            let _events_span = tracing::debug_span!(
+                target: PIPELINE_TRACING_TARGET,
+                parent: &message_span,
+                "events",
+                count = events.len(),
+            )
+            .entered();
+            if metrics_manager.is_enabled() {
+                let callback_start_us = get_high_perf_clock();
+                let parse_us = callback_start_us - parse_start_us;
+                metrics_manager.record_latency(LatencyStage::Parse, parse_us);
+                callback(events);
+                let callback_us = get_high_perf_clock() - callback_start_us;
+                metrics_manager.record_latency(LatencyStage::Callback, callback_us);
+            } else {
+                callback(events);
+            }
             metrics_manager.update_metrics(MetricsEventType::Transaction, events_count, processing_time_us);
         })
     }
@@ -131,12 +195,19 @@ impl EventProcessor {
         event_pretty: EventPretty,
         bot_wallet: Option<Pubkey>,
     ) -> AnyResult<()> {
+        let span = grpc_message_span(&event_pretty);
         match self.backpressure_config.strategy {
             BackpressureStrategy::Block => {
                 loop {
                     let current_pending = self.grpc_pending_count.load(Ordering::Relaxed);
                     if current_pending < self.backpressure_config.permits {
-                        self.grpc_queue.push((event_pretty, bot_wallet));
+                        tracing::trace!(target: PIPELINE_TRACING_TARGET, parent: &span, "enqueued");
+                        self.grpc_queue.push(QueuedMessage {
+                            message: event_pretty,
+                            bot_wallet,
+                            enqueue_us: get_high_perf_clock(),
+                            span,
+                        });
                         self.grpc_pending_count.fetch_add(1, Ordering::Relaxed);
                         break;
                     }
@@ -149,23 +220,29 @@ impl EventProcessor {
                 let current_pending = self.grpc_pending_count.load(Ordering::Relaxed);
                 if current_pending >= self.backpressure_config.permits {
                     self.metrics_manager.increment_dropped_events();
+                    tracing::debug!(target: PIPELINE_TRACING_TARGET, parent: &span, "dropped");
                     Ok(())
                 } else {
                     self.grpc_pending_count.fetch_add(1, Ordering::Relaxed);
                     let processor = self.clone();
-                    tokio::spawn(async move {
-                        match processor
-                            .process_grpc_event_transaction(event_pretty, bot_wallet)
-                            .await
-                        {
-                            Ok(_) => {
-                                processor.grpc_pending_count.fetch_sub(1, Ordering::Relaxed);
-                            }
-                            Err(e) => {
-                                log::error!("Error in async gRPC processing: {}", e);
+                    let enqueue_us = get_high_perf_clock();
+                    tokio::spawn(
+                        async move {
+                            processor.record_queue_wait(enqueue_us);
+                            match processor
+                                .process_grpc_event_transaction(event_pretty, bot_wallet)
+                                .await
+                            {
+                                Ok(_) => {
+                                    processor.grpc_pending_count.fetch_sub(1, Ordering::Relaxed);
+                                }
+                                Err(e) => {
+                                    log::error!("Error in async gRPC processing: {}", e);
+                                }
                             }
                         }
-                    });
+                        .instrument(span),
+                    );
                     Ok(())
                 }
             }
@@ -180,17 +257,20 @@ impl EventProcessor {
         if self.callback.is_none() {
             return Ok(());
         }
+        let parse_start_us = get_high_perf_clock();
         match event_pretty {
             EventPretty::Account(account_pretty) => {
                 self.metrics_manager.add_account_process_count();
-                let account_event = AccountEventParser::parse_account_event(
+                let account_event = AccountEventParser::parse_account_event_with_registry(
                     &self.protocols,
                     account_pretty,
                     self.event_type_filter.as_ref(),
+                    self.config.protocol_registry.as_deref(),
                 );
                 if let Some(event) = account_event {
                     let processing_time_us = event.handle_us() as f64;
-                    self.invoke_callback(event);
+                    self.metrics_manager.record_parsed_event(&*event);
+                    self.invoke_callback_with_latency(event, parse_start_us);
                     self.update_metrics(MetricsEventType::Account, 1, processing_time_us);
                 }
             }
@@ -202,10 +282,13 @@ impl EventProcessor {
                 let recv_us = transaction_pretty.recv_us;
                 let transaction_index = transaction_pretty.transaction_index;
                 let grpc_tx = transaction_pretty.grpc_tx;
+                self.record_network_delay(block_time.as_ref(), recv_us);
 
                 let parser = self.get_parser();
-                let adapter_callback = self.create_adapter_callback();
-                parser
+                let matched = self.metrics_manager.is_enabled()
+                    && parser.matches_grpc_transaction(&grpc_tx);
+                let adapter_callback = self.create_adapter_callback(parse_start_us);
+                let parsed_events = parser
                     .parse_grpc_transaction_owned(
                         grpc_tx,
                         signature,
@@ -216,10 +299,18 @@ impl EventProcessor {
                         transaction_index,
                         adapter_callback,
                     )
+                    .instrument(parse_span())
                     .await?;
+                if matched {
+                    self.metrics_manager.record_matched_transaction(parsed_events);
+                }
             }
             EventPretty::BlockMeta(block_meta_pretty) => {
                 self.metrics_manager.add_block_meta_process_count();
+                self.record_network_delay(
+                    block_meta_pretty.block_time.as_ref(),
+                    block_meta_pretty.recv_us,
+                );
                 let block_time_ms = block_meta_pretty
                     .block_time
                     .map(|ts| ts.seconds * 1000 + ts.nanos as i64 / 1_000_000)
@@ -231,7 +322,7 @@ impl EventProcessor {
                     block_meta_pretty.recv_us,
                 );
                 let processing_time_us = block_meta_event.handle_us() as f64;
-                self.invoke_callback(block_meta_event);
+                self.invoke_callback_with_latency(block_meta_event, parse_start_us);
                 self.update_metrics(MetricsEventType::BlockMeta, 1, processing_time_us);
             }
         }
@@ -245,6 +336,41 @@ impl EventProcessor {
         }
     }
 
+    /// 调用回调并记录解析与回调耗时
+    fn invoke_callback_with_latency(&self, event: Box<dyn UnifiedEvent>, parse_start_us: i64) {
+        let _event_span = event_span(&Span::current(), &*event).entered();
+        if !self.metrics_manager.is_enabled() {
+            self.invoke_callback(event);
+            return;
+        }
+        let callback_start_us = get_high_perf_clock();
+        let parse_us = callback_start_us - parse_start_us;
+        self.metrics_manager.record_latency(LatencyStage::Parse, parse_us);
+        self.invoke_callback(event);
+        let callback_us = get_high_perf_clock() - callback_start_us;
+        self.metrics_manager.record_latency(LatencyStage::Callback, callback_us);
+    }
+
+    /// 记录从入队到开始处理的排队延迟，并写入当前消息 span
+    #[inline]
+    fn record_queue_wait(&self, enqueue_us: i64) {
+        let span = Span::current();
+        if self.metrics_manager.is_enabled() || !span.is_disabled() {
+            let queue_wait_us = get_high_perf_clock() - enqueue_us;
+            self.metrics_manager.record_latency(LatencyStage::QueueWait, queue_wait_us);
+            span.record("queue_wait_us", queue_wait_us);
+        }
+    }
+
+    /// 记录 gRPC 消息 `created_at` 到本地接收的网络延迟
+    #[inline]
+    fn record_network_delay(&self, created_at: Option<&prost_types::Timestamp>, recv_us: i64) {
+        if let Some(created_at) = created_at {
+            let created_us = created_at.seconds * 1_000_000 + created_at.nanos as i64 / 1_000;
+            self.metrics_manager.record_latency(LatencyStage::NetworkDelay, recv_us - created_us);
+        }
+    }
+
     pub async fn process_shred_transaction_immediate(
         &self,
         transaction_with_slot: TransactionWithSlot,
@@ -266,12 +392,19 @@ impl EventProcessor {
         transaction_with_slot: TransactionWithSlot,
         bot_wallet: Option<Pubkey>,
     ) -> AnyResult<()> {
+        let span = shred_message_span(&transaction_with_slot);
         match self.backpressure_config.strategy {
             BackpressureStrategy::Block => {
                 loop {
                     let current_pending = self.shred_pending_count.load(Ordering::Relaxed);
                     if current_pending < self.backpressure_config.permits {
-                        self.shred_queue.push((transaction_with_slot, bot_wallet));
+                        tracing::trace!(target: PIPELINE_TRACING_TARGET, parent: &span, "enqueued");
+                        self.shred_queue.push(QueuedMessage {
+                            message: transaction_with_slot,
+                            bot_wallet,
+                            enqueue_us: get_high_perf_clock(),
+                            span,
+                        });
                         self.shred_pending_count.fetch_add(1, Ordering::Relaxed);
                         break;
                     }
@@ -284,23 +417,29 @@ impl EventProcessor {
                 let current_pending = self.shred_pending_count.load(Ordering::Relaxed);
                 if current_pending >= self.backpressure_config.permits {
                     self.metrics_manager.increment_dropped_events();
+                    tracing::debug!(target: PIPELINE_TRACING_TARGET, parent: &span, "dropped");
                     Ok(())
                 } else {
                     self.shred_pending_count.fetch_add(1, Ordering::Relaxed);
                     let processor = self.clone();
-                    tokio::spawn(async move {
-                        match processor
-                            .process_shred_transaction(transaction_with_slot, bot_wallet)
-                            .await
-                        {
-                            Ok(_) => {
-                                processor.shred_pending_count.fetch_sub(1, Ordering::Relaxed);
-                            }
-                            Err(e) => {
-                                log::error!("Error in async shred processing: {}", e);
+                    let enqueue_us = get_high_perf_clock();
+                    tokio::spawn(
+                        async move {
+                            processor.record_queue_wait(enqueue_us);
+                            match processor
+                                .process_shred_transaction(transaction_with_slot, bot_wallet)
+                                .await
+                            {
+                                Ok(_) => {
+                                    processor.shred_pending_count.fetch_sub(1, Ordering::Relaxed);
+                                }
+                                Err(e) => {
+                                    log::error!("Error in async shred processing: {}", e);
+                                }
                             }
                         }
-                    });
+                        .instrument(span),
+                    );
                     Ok(())
                 }
             }
@@ -315,6 +454,7 @@ impl EventProcessor {
         if self.callback.is_none() {
             return Ok(());
         }
+        let parse_start_us = get_high_perf_clock();
         self.metrics_manager.add_tx_process_count();
         let tx = transaction_with_slot.transaction;
 
@@ -326,13 +466,15 @@ impl EventProcessor {
         let recv_us = transaction_with_slot.recv_us;
 
         let parser = self.get_parser();
-        let adapter_callback = self.create_adapter_callback();
+        let matched =
+            self.metrics_manager.is_enabled() && parser.matches_versioned_transaction(&tx);
+        let adapter_callback = self.create_adapter_callback(parse_start_us);
         let tx_pack_adapter_callback = if let Some(_) = self.tx_pack_callback {
-            Some(self.create_tx_pack_adapter_callback())
+            Some(self.create_tx_pack_adapter_callback(parse_start_us))
         } else {
             None
         };
-        parser
+        let parsed_events = parser
             .parse_versioned_transaction_owned(
                 tx,
                 signature,
@@ -345,7 +487,11 @@ impl EventProcessor {
                 adapter_callback,
                 tx_pack_adapter_callback,
             )
+            .instrument(parse_span())
             .await?;
+        if matched {
+            self.metrics_manager.record_matched_transaction(parsed_events);
+        }
 
         Ok(())
     }
@@ -376,11 +522,12 @@ impl EventProcessor {
                 .unwrap();
 
             while !shutdown_flag.load(Ordering::Relaxed) {
-                if let Some((event_pretty, bot_wallet)) = grpc_queue.pop() {
+                if let Some(queued) = grpc_queue.pop() {
                     grpc_pending_count.fetch_sub(1, Ordering::Relaxed);
-                    if let Err(e) = rt.block_on(
-                        processor.process_grpc_event_transaction(event_pretty, bot_wallet),
-                    ) {
+                    let QueuedMessage { message, bot_wallet, enqueue_us, span } = queued;
+                    span.in_scope(|| processor.record_queue_wait(enqueue_us));
+                    let future = processor.process_grpc_event_transaction(message, bot_wallet);
+                    if let Err(e) = rt.block_on(future.instrument(span)) {
                         println!("Error processing gRPC event: {}", e);
                     }
                 } else {
@@ -401,12 +548,12 @@ impl EventProcessor {
                 .unwrap();
 
             while !shutdown_flag_clone.load(Ordering::Relaxed) {
-                if let Some((transaction_with_slot, bot_wallet)) = shred_queue.pop() {
+                if let Some(queued) = shred_queue.pop() {
                     shred_pending_count.fetch_sub(1, Ordering::Relaxed);
-                    if let Err(e) = rt.block_on(
-                        processor_clone
-                            .process_shred_transaction(transaction_with_slot, bot_wallet),
-                    ) {
+                    let QueuedMessage { message, bot_wallet, enqueue_us, span } = queued;
+                    span.in_scope(|| processor_clone.record_queue_wait(enqueue_us));
+                    let future = processor_clone.process_shred_transaction(message, bot_wallet);
+                    if let Err(e) = rt.block_on(future.instrument(span)) {
                         log::error!("Error processing shred transaction: {}", e);
                     }
                 } else {
@@ -441,3 +588,72 @@ impl Clone for EventProcessor {
         }
     }
 }
+
+/// gRPC 消息级 span，`queue_wait_us` 在开始处理时记录
+fn grpc_message_span(event_pretty: &EventPretty) -> Span {
+    match event_pretty {
+        EventPretty::Transaction(tx) => tracing::debug_span!(
+            target: PIPELINE_TRACING_TARGET,
+            "message",
+            source = "grpc",
+            kind = "transaction",
+            slot = tx.slot,
+            signature = %tx.signature,
+            recv_us = tx.recv_us,
+            queue_wait_us = tracing::field::Empty,
+        ),
+        EventPretty::Account(account) => tracing::debug_span!(
+            target: PIPELINE_TRACING_TARGET,
+            "message",
+            source = "grpc",
+            kind = "account",
+            slot = account.slot,
+            signature = %account.signature,
+            pubkey = %account.pubkey,
+            recv_us = account.recv_us,
+            queue_wait_us = tracing::field::Empty,
+        ),
+        EventPretty::BlockMeta(block_meta) => tracing::debug_span!(
+            target: PIPELINE_TRACING_TARGET,
+            "message",
+            source = "grpc",
+            kind = "block_meta",
+            slot = block_meta.slot,
+            recv_us = block_meta.recv_us,
+            queue_wait_us = tracing::field::Empty,
+        ),
+    }
+}
+
+/// ShredStream 消息级 span
+fn shred_message_span(transaction_with_slot: &TransactionWithSlot) -> Span {
+    let signature = transaction_with_slot.transaction.signatures.first();
+    tracing::debug_span!(
+        target: PIPELINE_TRACING_TARGET,
+        "message",
+        source = "shred",
+        kind = "transaction",
+        slot = transaction_with_slot.slot,
+        signature = signature.map(tracing::field::display),
+        recv_us = transaction_with_slot.recv_us,
+        queue_wait_us = tracing::field::Empty,
+    )
+}
+
+fn parse_span() -> Span {
+    tracing::debug_span!(target: PIPELINE_TRACING_TARGET, "parse")
+}
+
+/// 单个事件的 span，回调执行期间为 `Span::current()`
+fn event_span(parent: &Span, event: &dyn UnifiedEvent) -> Span {
+    tracing::debug_span!(
+        target: PIPELINE_TRACING_TARGET,
+        parent: parent,
+        "event",
+        protocol = %event.protocol(),
+        event_type = %event.event_type(),
+        slot = event.slot(),
+        signature = %event.signature(),
+        handle_us = event.handle_us(),
+    )
+}
@@ -0,0 +1,263 @@
+// 延迟直方图 - 按阶段统计端到端延迟的分位数
+use std::sync::atomic::{AtomicU64, Ordering};
+
+use serde::{Deserialize, Serialize};
+
+// 每个 2 的幂区间内的子桶数为 2^(SUB_BUCKET_BITS - 1)，相对误差不超过 1/32
+const SUB_BUCKET_BITS: u32 = 6;
+const SUB_BUCKET_COUNT: usize = 1 << SUB_BUCKET_BITS;
+const SUB_BUCKET_HALF: usize = SUB_BUCKET_COUNT / 2;
+// 可记录的最大值约为 2^36 微秒（19 小时），更大的值计入最后一个桶
+const MAX_VALUE_BITS: u32 = 36;
+const MAX_VALUE_US: u64 = (1 << MAX_VALUE_BITS) - 1;
+const BUCKET_COUNT: usize =
+    SUB_BUCKET_COUNT + (MAX_VALUE_BITS - SUB_BUCKET_BITS) as usize * SUB_BUCKET_HALF;
+
+/// 延迟统计阶段
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub enum LatencyStage {
+    /// 节点产生消息（gRPC `created_at`）到本地接收
+    NetworkDelay = 0,
+    /// 进入处理队列到开始处理
+    QueueWait = 1,
+    /// 开始处理到事件解析完成
+    Parse = 2,
+    /// 用户回调执行耗时
+    Callback = 3,
+}
+
+impl LatencyStage {
+    pub const ALL: [LatencyStage; 4] = [
+        LatencyStage::NetworkDelay,
+        LatencyStage::QueueWait,
+        LatencyStage::Parse,
+        LatencyStage::Callback,
+    ];
+
+    #[inline]
+    pub(crate) const fn as_index(self) -> usize {
+        self as usize
+    }
+
+    /// 导出指标时使用的标签值
+    pub const fn label(self) -> &'static str {
+        match self {
+            LatencyStage::NetworkDelay => "network_delay",
+            LatencyStage::QueueWait => "queue_wait",
+            LatencyStage::Parse => "parse",
+            LatencyStage::Callback => "callback",
+        }
+    }
+}
+
+/// 单个阶段的延迟分位数（微秒）
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct LatencyPercentiles {
+    pub count: u64,
+    pub mean_us: f64,
+    pub min_us: u64,
+    pub p50_us: u64,
+    pub p90_us: u64,
+    pub p99_us: u64,
+    pub p999_us: u64,
+    pub max_us: u64,
+}
+
+/// 各阶段的延迟分位数快照
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct LatencyBreakdown {
+    pub network_delay: LatencyPercentiles,
+    pub queue_wait: LatencyPercentiles,
+    pub parse: LatencyPercentiles,
+    pub callback: LatencyPercentiles,
+}
+
+/// HDR 风格的原子延迟直方图，桶宽随数值按 2 的幂增长
+#[derive(Debug)]
+pub(crate) struct AtomicLatencyHistogram {
+    buckets: Box<[AtomicU64]>,
+    sum_us: AtomicU64,
+    min_us: AtomicU64,
+    max_us: AtomicU64,
+}
+
+impl AtomicLatencyHistogram {
+    pub(crate) fn new() -> Self {
+        Self {
+            buckets: (0..BUCKET_COUNT).map(|_| AtomicU64::new(0)).collect(),
+            sum_us: AtomicU64::new(0),
+            min_us: AtomicU64::new(u64::MAX),
+            max_us: AtomicU64::new(0),
+        }
+    }
+
+    #[inline]
+    pub(crate) fn record(&self, value_us: u64) {
+        let value_us = value_us.min(MAX_VALUE_US);
+        self.buckets[bucket_index(value_us)].fetch_add(1, Ordering::Relaxed);
+        self.sum_us.fetch_add(value_us, Ordering::Relaxed);
+        self.min_us.fetch_min(value_us, Ordering::Relaxed);
+        self.max_us.fetch_max(value_us, Ordering::Relaxed);
+    }
+
+    pub(crate) fn reset(&self) {
+        for bucket in self.buckets.iter() {
+            bucket.store(0, Ordering::Relaxed);
+        }
+        self.sum_us.store(0, Ordering::Relaxed);
+        self.min_us.store(u64::MAX, Ordering::Relaxed);
+        self.max_us.store(0, Ordering::Relaxed);
+    }
+
+    /// 计算分位数，结果为所在桶的上界
+    pub(crate) fn percentiles(&self) -> LatencyPercentiles {
+        let counts: Vec<u64> =
+            self.buckets.iter().map(|bucket| bucket.load(Ordering::Relaxed)).collect();
+        let count: u64 = counts.iter().sum();
+        if count == 0 {
+            return LatencyPercentiles::default();
+        }
+        let max_us = self.max_us.load(Ordering::Relaxed);
+        let value_at = |quantile: f64| {
+            let rank = ((quantile * count as f64).ceil() as u64).max(1);
+            let mut cumulative = 0;
+            for (index, bucket_count) in counts.iter().enumerate() {
+                cumulative += bucket_count;
+                if cumulative >= rank {
+                    return bucket_upper_bound(index).min(max_us);
+                }
+            }
+            max_us
+        };
+        LatencyPercentiles {
+            count,
+            mean_us: self.sum_us.load(Ordering::Relaxed) as f64 / count as f64,
+            min_us: self.min_us.load(Ordering::Relaxed),
+            p50_us: value_at(0.5),
+            p90_us: value_at(0.9),
+            p99_us: value_at(0.99),
+            p999_us: value_at(0.999),
+            max_us,
+        }
+    }
+}
+
+#[inline]
+fn bucket_index(value_us: u64) -> usize {
+    if value_us < SUB_BUCKET_COUNT as u64 {
+        return value_us as usize;
+    }
+    let msb = 63 - value_us.leading_zeros();
+    let shift = msb + 1 - SUB_BUCKET_BITS;
+    let top = (value_us >> shift) as usize;
+    SUB_BUCKET_COUNT + (shift as usize - 1) * SUB_BUCKET_HALF + (top - SUB_BUCKET_HALF)
+}
+
+fn bucket_upper_bound(index: usize) -> u64 {
+    if index < SUB_BUCKET_COUNT {
+        return index as u64;
+    }
+    let offset = index - SUB_BUCKET_COUNT;
+    let shift = offset / SUB_BUCKET_HALF + 1;
+    let top = (offset % SUB_BUCKET_HALF + SUB_BUCKET_HALF) as u64;
+    ((top + 1) << shift) - 1
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn bucket_layout() {
+        assert_eq!(BUCKET_COUNT, 1024);
+        assert_eq!(bucket_index(0), 0);
+        assert_eq!(bucket_index(SUB_BUCKET_COUNT as u64 - 1), SUB_BUCKET_COUNT - 1);
+        // 第一个对数区间的桶宽为 2
+        assert_eq!(bucket_index(64), 64);
+        assert_eq!(bucket_index(65), 64);
+        assert_eq!(bucket_index(66), 65);
+        assert_eq!(bucket_upper_bound(64), 65);
+        assert_eq!(bucket_index(MAX_VALUE_US), BUCKET_COUNT - 1);
+        assert_eq!(bucket_upper_bound(BUCKET_COUNT - 1), MAX_VALUE_US);
+    }
+
+    #[test]
+    fn buckets_are_contiguous() {
+        let mut lower = 0;
+        for index in 0..BUCKET_COUNT {
+            let upper = bucket_upper_bound(index);
+            assert_eq!(bucket_index(lower), index, "lower edge of bucket {index}");
+            assert_eq!(bucket_index(upper), index, "upper edge of bucket {index}");
+            // 桶宽不超过下界的 1/32
+            assert!(upper - lower < lower / 32 + 1, "bucket {index} is too wide");
+            lower = upper + 1;
+        }
+        assert_eq!(lower, MAX_VALUE_US + 1);
+    }
+
+    #[test]
+    fn empty_and_single_value() {
+        let histogram = AtomicLatencyHistogram::new();
+        assert_eq!(histogram.percentiles(), LatencyPercentiles::default());
+
+        histogram.record(0);
+        let percentiles = histogram.percentiles();
+        assert_eq!(percentiles.count, 1);
+        assert_eq!((percentiles.min_us, percentiles.p50_us, percentiles.max_us), (0, 0, 0));
+        assert_eq!(percentiles.p999_us, 0);
+
+        histogram.reset();
+        assert_eq!(histogram.percentiles(), LatencyPercentiles::default());
+    }
+
+    #[test]
+    fn values_beyond_max_are_clamped() {
+        let histogram = AtomicLatencyHistogram::new();
+        histogram.record(u64::MAX);
+        histogram.record(MAX_VALUE_US + 1);
+        let percentiles = histogram.percentiles();
+        assert_eq!(percentiles.count, 2);
+        assert_eq!(percentiles.min_us, MAX_VALUE_US);
+        assert_eq!(percentiles.max_us, MAX_VALUE_US);
+        assert_eq!(percentiles.p99_us, MAX_VALUE_US);
+        assert_eq!(percentiles.mean_us, MAX_VALUE_US as f64);
+    }
+
+    #[test]
+    fn percentiles_match_sorted_reference() {
+        // 固定种子的 LCG，数值在 1us ~ 2^31us（约 36 分钟）间按对数分布
+        let mut state = 0x2545_f491_4f6c_dd1d_u64;
+        let mut values: Vec<u64> = (0..10_000)
+            .map(|_| {
+                state = state.wrapping_mul(6364136223846793005).wrapping_add(1442695040888963407);
+                let bits = (state >> 59) as u32;
+                1 + ((state >> 16) & ((1 << bits) - 1))
+            })
+            .collect();
+        let histogram = AtomicLatencyHistogram::new();
+        for value in &values {
+            histogram.record(*value);
+        }
+        values.sort_unstable();
+
+        let percentiles = histogram.percentiles();
+        let reference = |quantile: f64| {
+            let rank = ((quantile * values.len() as f64).ceil() as usize).max(1);
+            values[rank - 1]
+        };
+        for (quantile, actual) in [
+            (0.5, percentiles.p50_us),
+            (0.9, percentiles.p90_us),
+            (0.99, percentiles.p99_us),
+            (0.999, percentiles.p999_us),
+        ] {
+            let expected = reference(quantile);
+            // 结果为所在桶的上界：不小于真实值，误差不超过 1/32
+            assert!(actual >= expected, "p{quantile}: {actual} < {expected}");
+            assert!(actual - expected <= expected / 32, "p{quantile}: {actual} vs {expected}");
+        }
+        assert_eq!(percentiles.count, values.len() as u64);
+        assert_eq!(percentiles.min_us, values[0]);
+        assert_eq!(percentiles.max_us, *values.last().unwrap());
+    }
+}
@@ -1,7 +1,18 @@
-use std::sync::atomic::{AtomicBool, AtomicU64, Ordering};
+use std::sync::atomic::{AtomicBool, AtomicU64, AtomicUsize, Ordering};
 use std::sync::Arc;
 
+use dashmap::DashMap;
+use parking_lot::RwLock;
+use serde::{Deserialize, Serialize};
+
 use super::constants::*;
+use super::latency::{AtomicLatencyHistogram, LatencyBreakdown, LatencyPercentiles, LatencyStage};
+use super::reporter::MetricsReportConfig;
+use crate::streaming::event_parser::common::{EventType as ParserEventType, ProtocolType};
+use crate::streaming::event_parser::core::parse_diagnostics::{
+    ParseDiagnostics, ParseDiagnosticsSnapshot,
+};
+use crate::streaming::event_parser::UnifiedEvent;
 
 /// Event type enumeration
 #[derive(Debug, Clone, Copy)]
@@ -28,6 +39,18 @@ impl EventType {
         }
     }
 
+    /// 导出指标时使用的标签值
+    pub const fn label(self) -> &'static str {
+        match self {
+            EventType::Transaction => "transaction",
+            EventType::Account => "account",
+            EventType::BlockMeta => "block_meta",
+        }
+    }
+
+    pub const ALL: [EventType; 3] =
+        [EventType::Transaction, EventType::Account, EventType::BlockMeta];
+
     // Compatibility constants
     pub const TX: EventType = EventType::Transaction;
 }
@@ -41,6 +64,7 @@ struct AtomicEventMetrics {
     window_start_nanos: AtomicU64,
     // Processing time statistics per event type
     processing_stats: AtomicProcessingTimeStats,
+    processing_histogram: AtomicHistogram,
 }
 
 impl AtomicEventMetrics {
@@ -51,6 +75,7 @@ impl AtomicEventMetrics {
             events_in_window: AtomicU64::new(0),
             window_start_nanos: AtomicU64::new(now_nanos),
             processing_stats: AtomicProcessingTimeStats::new(),
+            processing_histogram: AtomicHistogram::new(),
         }
     }
 
@@ -99,9 +124,64 @@ impl AtomicEventMetrics {
     #[inline]
     fn update_processing_stats(&self, time_us: f64, event_count: u64) {
         self.processing_stats.update(time_us, event_count);
+        self.processing_histogram.observe(time_us, event_count);
     }
 }
 
+/// 处理耗时直方图的桶上界（微秒），与 Prometheus histogram 的 `le` 对应
+pub const PROCESSING_TIME_BUCKETS_US: [f64; 13] = [
+    10.0, 25.0, 50.0, 100.0, 250.0, 500.0, 1_000.0, 2_500.0, 5_000.0, 10_000.0, 25_000.0,
+    50_000.0, 100_000.0,
+];
+
+/// 固定桶的原子直方图
+#[derive(Debug)]
+struct AtomicHistogram {
+    // 最后一个桶为 +Inf
+    buckets: [AtomicU64; PROCESSING_TIME_BUCKETS_US.len() + 1],
+    sum_us: AtomicU64,
+    count: AtomicU64,
+}
+
+impl AtomicHistogram {
+    fn new() -> Self {
+        Self {
+            buckets: std::array::from_fn(|_| AtomicU64::new(0)),
+            sum_us: AtomicU64::new(0),
+            count: AtomicU64::new(0),
+        }
+    }
+
+    /// 记录 `count` 个耗时为 `value_us` 的观测值
+    #[inline]
+    fn observe(&self, value_us: f64, count: u64) {
+        let index = PROCESSING_TIME_BUCKETS_US
+            .iter()
+            .position(|bound| value_us <= *bound)
+            .unwrap_or(PROCESSING_TIME_BUCKETS_US.len());
+        self.buckets[index].fetch_add(count, Ordering::Relaxed);
+        self.sum_us.fetch_add((value_us * count as f64) as u64, Ordering::Relaxed);
+        self.count.fetch_add(count, Ordering::Relaxed);
+    }
+
+    fn snapshot(&self) -> HistogramSnapshot {
+        HistogramSnapshot {
+            bucket_counts: std::array::from_fn(|i| self.buckets[i].load(Ordering::Relaxed)),
+            sum_us: self.sum_us.load(Ordering::Relaxed),
+            count: self.count.load(Ordering::Relaxed),
+        }
+    }
+}
+
+/// 处理耗时直方图快照
+#[derive(Debug, Clone, Default)]
+pub struct HistogramSnapshot {
+    /// 各桶的观测数（非累积），下标与 `PROCESSING_TIME_BUCKETS_US` 对应，最后一个为 +Inf
+    pub bucket_counts: [u64; PROCESSING_TIME_BUCKETS_US.len() + 1],
+    pub sum_us: u64,
+    pub count: u64,
+}
+
 /// High-performance atomic processing time statistics
 #[derive(Debug)]
 struct AtomicProcessingTimeStats {
@@ -225,18 +305,70 @@ impl AtomicProcessingTimeStats {
 }
 
 /// Processing time statistics result
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
 pub struct ProcessingTimeStats {
     pub min_us: f64,
     pub max_us: f64,
     pub avg_us: f64,
 }
 
+/// 按协议或解析事件类型统计的指标
+#[derive(Debug)]
+struct AtomicBreakdownMetrics {
+    events: AtomicU64,
+    processing_stats: AtomicProcessingTimeStats,
+}
+
+impl AtomicBreakdownMetrics {
+    fn new() -> Self {
+        Self { events: AtomicU64::new(0), processing_stats: AtomicProcessingTimeStats::new() }
+    }
+
+    #[inline]
+    fn record(&self, processing_time_us: f64) {
+        self.events.fetch_add(1, Ordering::Relaxed);
+        self.processing_stats.update(processing_time_us, 1);
+    }
+
+    fn snapshot(&self) -> BreakdownMetricsSnapshot {
+        BreakdownMetricsSnapshot {
+            events: self.events.load(Ordering::Relaxed),
+            total_time_us: self.processing_stats.total_time_us.load(Ordering::Relaxed),
+            processing_stats: self.processing_stats.get_stats(),
+        }
+    }
+}
+
+/// 在 `DashMap` 中按键记录，已存在的键不需要克隆
+#[inline]
+fn record_breakdown<K: std::hash::Hash + Eq + Clone>(
+    map: &DashMap<K, AtomicBreakdownMetrics>,
+    key: &K,
+    processing_time_us: f64,
+) {
+    if let Some(metrics) = map.get(key) {
+        metrics.record(processing_time_us);
+        return;
+    }
+    map.entry(key.clone()).or_insert_with(AtomicBreakdownMetrics::new).record(processing_time_us);
+}
+
+/// 按协议或解析事件类型统计的指标快照
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct BreakdownMetricsSnapshot {
+    pub events: u64,
+    /// 累计处理耗时（微秒）
+    pub total_time_us: u64,
+    pub processing_stats: ProcessingTimeStats,
+}
+
 /// Event metrics snapshot
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
 pub struct EventMetricsSnapshot {
     pub process_count: u64,
     pub events_processed: u64,
+    /// 当前统计窗口内的事件速率
+    pub events_per_second: f64,
     pub processing_stats: ProcessingTimeStats,
 }
 
@@ -258,6 +390,7 @@ impl PerformanceMetrics {
         let default_metrics = EventMetricsSnapshot {
             process_count: 0,
             events_processed: 0,
+            events_per_second: 0.0,
             processing_stats: default_stats.clone(),
         };
 
@@ -280,6 +413,19 @@ pub struct HighPerformanceMetrics {
     processing_stats: AtomicProcessingTimeStats,
     // 丢弃事件指标
     dropped_events_count: AtomicU64,
+    // 按协议、解析事件类型统计的事件数与处理耗时
+    protocol_metrics: DashMap<ProtocolType, AtomicBreakdownMetrics>,
+    event_type_metrics: DashMap<ParserEventType, AtomicBreakdownMetrics>,
+    // 包含订阅程序的交易数，以及其中未解析出任何事件的交易数
+    matched_transactions: AtomicU64,
+    matched_transactions_without_events: AtomicU64,
+    // 事件处理器的待处理队列长度
+    grpc_pending_count: RwLock<Option<Arc<AtomicUsize>>>,
+    shred_pending_count: RwLock<Option<Arc<AtomicUsize>>>,
+    // 各阶段延迟直方图
+    latency: [AtomicLatencyHistogram; 4],
+    // 解析器的未匹配指令与解码失败统计
+    parse_diagnostics: RwLock<Option<Arc<ParseDiagnostics>>>,
 }
 
 impl HighPerformanceMetrics {
@@ -298,6 +444,14 @@ impl HighPerformanceMetrics {
             processing_stats: AtomicProcessingTimeStats::new(),
             // 初始化丢弃事件指标
             dropped_events_count: AtomicU64::new(0),
+            protocol_metrics: DashMap::new(),
+            event_type_metrics: DashMap::new(),
+            matched_transactions: AtomicU64::new(0),
+            matched_transactions_without_events: AtomicU64::new(0),
+            grpc_pending_count: RwLock::new(None),
+            shred_pending_count: RwLock::new(None),
+            latency: std::array::from_fn(|_| AtomicLatencyHistogram::new()),
+            parse_diagnostics: RwLock::new(None),
         }
     }
 
@@ -314,10 +468,20 @@ impl HighPerformanceMetrics {
     #[inline]
     pub fn get_event_metrics(&self, event_type: EventType) -> EventMetricsSnapshot {
         let index = event_type.as_index();
-        let (process_count, events_processed, _) = self.event_metrics[index].get_counts();
+        let (process_count, events_processed, events_in_window) =
+            self.event_metrics[index].get_counts();
         let processing_stats = self.event_metrics[index].get_processing_stats();
 
-        EventMetricsSnapshot { process_count, events_processed, processing_stats }
+        let now_nanos =
+            std::time::SystemTime::now().duration_since(std::time::UNIX_EPOCH).unwrap().as_nanos()
+                as u64;
+        let window_seconds =
+            now_nanos.saturating_sub(self.event_metrics[index].get_window_start()) as f64
+                / 1_000_000_000.0;
+        let events_per_second =
+            if window_seconds > 0.0 { events_in_window as f64 / window_seconds } else { 0.0 };
+
+        EventMetricsSnapshot { process_count, events_processed, events_per_second, processing_stats }
     }
 
     /// 获取处理时间统计
@@ -332,6 +496,68 @@ impl HighPerformanceMetrics {
         self.dropped_events_count.load(Ordering::Relaxed)
     }
 
+    /// 获取处理耗时直方图
+    #[inline]
+    pub fn get_processing_histogram(&self, event_type: EventType) -> HistogramSnapshot {
+        self.event_metrics[event_type.as_index()].processing_histogram.snapshot()
+    }
+
+    /// 获取按协议统计的指标
+    pub fn get_protocol_metrics(&self) -> Vec<(ProtocolType, BreakdownMetricsSnapshot)> {
+        self.protocol_metrics
+            .iter()
+            .map(|entry| (entry.key().clone(), entry.value().snapshot()))
+            .collect()
+    }
+
+    /// 获取按解析事件类型统计的指标
+    pub fn get_event_type_metrics(&self) -> Vec<(ParserEventType, BreakdownMetricsSnapshot)> {
+        self.event_type_metrics
+            .iter()
+            .map(|entry| (entry.key().clone(), entry.value().snapshot()))
+            .collect()
+    }
+
+    /// 获取包含订阅程序的交易数，以及其中未解析出任何事件的交易数
+    pub fn get_matched_transaction_counts(&self) -> (u64, u64) {
+        (
+            self.matched_transactions.load(Ordering::Relaxed),
+            self.matched_transactions_without_events.load(Ordering::Relaxed),
+        )
+    }
+
+    /// 获取 gRPC / ShredStream 待处理队列长度
+    pub fn get_queue_depths(&self) -> (usize, usize) {
+        let load = |count: &RwLock<Option<Arc<AtomicUsize>>>| {
+            count.read().as_ref().map_or(0, |count| count.load(Ordering::Relaxed))
+        };
+        (load(&self.grpc_pending_count), load(&self.shred_pending_count))
+    }
+
+    /// 获取解析诊断，每个程序保留次数最多的 `top_n` 条明细
+    pub fn get_parse_diagnostics(&self, top_n: usize) -> ParseDiagnosticsSnapshot {
+        self.parse_diagnostics
+            .read()
+            .as_ref()
+            .map(|diagnostics| diagnostics.snapshot(top_n))
+            .unwrap_or_default()
+    }
+
+    /// 获取单个阶段的延迟分位数
+    pub fn get_latency_percentiles(&self, stage: LatencyStage) -> LatencyPercentiles {
+        self.latency[stage.as_index()].percentiles()
+    }
+
+    /// 获取各阶段的延迟分位数
+    pub fn get_latency_breakdown(&self) -> LatencyBreakdown {
+        LatencyBreakdown {
+            network_delay: self.get_latency_percentiles(LatencyStage::NetworkDelay),
+            queue_wait: self.get_latency_percentiles(LatencyStage::QueueWait),
+            parse: self.get_latency_percentiles(LatencyStage::Parse),
+            callback: self.get_latency_percentiles(LatencyStage::Callback),
+        }
+    }
+
     /// 更新窗口指标（后台任务调用）
     fn update_window_metrics(&self, event_type: EventType, window_duration_nanos: u64) {
         let now_nanos =
@@ -354,6 +580,7 @@ pub struct MetricsManager {
     enable_metrics: bool,
     stream_name: String,
     background_task_running: AtomicBool,
+    report_config: MetricsReportConfig,
 }
 
 impl MetricsManager {
@@ -364,6 +591,7 @@ impl MetricsManager {
             enable_metrics,
             stream_name,
             background_task_running: AtomicBool::new(false),
+            report_config: MetricsReportConfig::default(),
         };
 
         // 启动后台任务
@@ -371,6 +599,12 @@ impl MetricsManager {
         manager
     }
 
+    /// 设置周期性指标上报方式
+    pub fn with_report_config(mut self, report_config: MetricsReportConfig) -> Self {
+        self.report_config = report_config;
+        self
+    }
+
     /// 启动后台任务
     fn start_background_tasks(&self) {
         if self
@@ -428,6 +662,54 @@ impl MetricsManager {
         self.metrics.processing_stats.update(processing_time_us, count);
     }
 
+    /// 按协议与解析事件类型记录解析出的事件（非阻塞），处理耗时取 `handle_us`
+    #[inline]
+    pub fn record_parsed_event(&self, event: &dyn UnifiedEvent) {
+        if !self.enable_metrics {
+            return;
+        }
+        let processing_time_us = event.handle_us() as f64;
+        record_breakdown(&self.metrics.protocol_metrics, &event.protocol(), processing_time_us);
+        record_breakdown(&self.metrics.event_type_metrics, &event.event_type(), processing_time_us);
+    }
+
+    /// 记录一笔包含订阅程序的交易及其解析出的事件数（非阻塞）
+    ///
+    /// `events` 为 EventTypeFilter 过滤前的指令事件数，为 0 通常意味着程序升级后解析失效。
+    #[inline]
+    pub fn record_matched_transaction(&self, events: usize) {
+        if !self.enable_metrics {
+            return;
+        }
+        self.metrics.matched_transactions.fetch_add(1, Ordering::Relaxed);
+        if events == 0 {
+            self.metrics.matched_transactions_without_events.fetch_add(1, Ordering::Relaxed);
+        }
+    }
+
+    /// 记录某个阶段的延迟（微秒，非阻塞），负值（时钟偏差）会被忽略
+    #[inline]
+    pub fn record_latency(&self, stage: LatencyStage, latency_us: i64) {
+        if self.enable_metrics && latency_us >= 0 {
+            self.metrics.latency[stage.as_index()].record(latency_us as u64);
+        }
+    }
+
+    /// 关联事件处理器的待处理队列计数，用于导出队列长度
+    pub fn register_queue_depths(
+        &self,
+        grpc_pending_count: Arc<AtomicUsize>,
+        shred_pending_count: Arc<AtomicUsize>,
+    ) {
+        *self.metrics.grpc_pending_count.write() = Some(grpc_pending_count);
+        *self.metrics.shred_pending_count.write() = Some(shred_pending_count);
+    }
+
+    /// 关联解析器的诊断统计，用于导出未匹配指令与解码失败
+    pub fn register_parse_diagnostics(&self, diagnostics: Arc<ParseDiagnostics>) {
+        *self.metrics.parse_diagnostics.write() = Some(diagnostics);
+    }
+
     /// 记录慢处理操作
     #[inline]
     pub fn log_slow_processing(&self, processing_time_us: f64, event_count: usize) {
@@ -461,6 +743,63 @@ impl MetricsManager {
         self.metrics.get_dropped_events_count()
     }
 
+    /// 获取处理耗时直方图
+    pub fn get_processing_histogram(&self, event_type: EventType) -> HistogramSnapshot {
+        self.metrics.get_processing_histogram(event_type)
+    }
+
+    /// 获取按协议统计的事件数与处理耗时
+    pub fn get_protocol_metrics(&self) -> Vec<(ProtocolType, BreakdownMetricsSnapshot)> {
+        self.metrics.get_protocol_metrics()
+    }
+
+    /// 获取按解析事件类型统计的事件数与处理耗时
+    pub fn get_event_type_metrics(&self) -> Vec<(ParserEventType, BreakdownMetricsSnapshot)> {
+        self.metrics.get_event_type_metrics()
+    }
+
+    /// 获取包含订阅程序的交易数，以及其中未解析出任何事件的交易数
+    pub fn get_matched_transaction_counts(&self) -> (u64, u64) {
+        self.metrics.get_matched_transaction_counts()
+    }
+
+    /// 获取 gRPC / ShredStream 待处理队列长度
+    pub fn get_queue_depths(&self) -> (usize, usize) {
+        self.metrics.get_queue_depths()
+    }
+
+    /// 获取未匹配指令与解码失败统计，每个程序保留次数最多的 `top_n` 条明细
+    pub fn get_parse_diagnostics(&self, top_n: usize) -> ParseDiagnosticsSnapshot {
+        self.metrics.get_parse_diagnostics(top_n)
+    }
+
+    /// 获取单个阶段的延迟分位数（自启动或上次重置以来）
+    pub fn get_latency_percentiles(&self, stage: LatencyStage) -> LatencyPercentiles {
+        self.metrics.get_latency_percentiles(stage)
+    }
+
+    /// 获取网络延迟、排队、解析与回调各阶段的延迟分位数
+    pub fn get_latency_breakdown(&self) -> LatencyBreakdown {
+        self.metrics.get_latency_breakdown()
+    }
+
+    /// 清空延迟直方图，用于按周期统计分位数
+    pub fn reset_latency(&self) {
+        for histogram in &self.metrics.latency {
+            histogram.reset();
+        }
+    }
+
+    /// 是否启用指标统计
+    pub fn is_enabled(&self) -> bool {
+        self.enable_metrics
+    }
+
+    /// 流名称，导出指标时作为 `stream` 标签
+    pub fn stream_name(&self) -> &str {
+        &self.stream_name
+    }
+
     /// 打印性能指标（非阻塞）
     pub fn print_metrics(&self) {
         println!("\n📊 {} Performance Metrics", self.stream_name);
@@ -491,28 +830,106 @@ impl MetricsManager {
         }
 
         println!("└─────────────┴──────────────┴──────────────────┴─────────────┴─────────────┴─────────────┘");
+
+        // 打印各阶段延迟分位数
+        println!("┌───────────────┬────────────┬───────────┬───────────┬───────────┬───────────┐");
+        println!("│ Latency Stage │ Count      │ p50(μs)   │ p90(μs)   │ p99(μs)   │ p999(μs)  │");
+        println!("├───────────────┼────────────┼───────────┼───────────┼───────────┼───────────┤");
+
+        for stage in LatencyStage::ALL {
+            let latency = self.get_latency_percentiles(stage);
+            println!(
+                "│ {:13} │ {:10} │ {:9} │ {:9} │ {:9} │ {:9} │",
+                stage.label(),
+                latency.count,
+                latency.p50_us,
+                latency.p90_us,
+                latency.p99_us,
+                latency.p999_us
+            );
+        }
+
+        println!("└───────────────┴────────────┴───────────┴───────────┴───────────┴───────────┘");
+
+        // 打印按协议统计的指标
+        let mut protocol_metrics = self.get_protocol_metrics();
+        if !protocol_metrics.is_empty() {
+            protocol_metrics.sort_by_cached_key(|(protocol, _)| protocol.to_string());
+            println!("┌─────────────────┬──────────────┬─────────────┬─────────────┬─────────────┐");
+            println!("│ Protocol        │ Events       │ Avg Time(μs)│ Min 10s(μs) │ Max 10s(μs) │");
+            println!("├─────────────────┼──────────────┼─────────────┼─────────────┼─────────────┤");
+            for (protocol, metrics) in protocol_metrics {
+                println!(
+                    "│ {:15} │ {:12} │ {:9.2}   │ {:9.2}   │ {:9.2}   │",
+                    protocol.to_string(),
+                    metrics.events,
+                    metrics.processing_stats.avg_us,
+                    metrics.processing_stats.min_us,
+                    metrics.processing_stats.max_us
+                );
+            }
+            println!("└─────────────────┴──────────────┴─────────────┴─────────────┴─────────────┘");
+        }
+
+        let (matched, without_events) = self.get_matched_transaction_counts();
+        if without_events > 0 {
+            println!("   Matched Transactions Without Events: {} / {}", without_events, matched);
+        }
+
+        // 打印未匹配指令与解码失败
+        let diagnostics = self.get_parse_diagnostics(PRINT_DIAGNOSTICS_TOP_N);
+        for (program_id, program) in &diagnostics.programs {
+            println!(
+                "   Unparsed Instructions [{}]: unmatched {}, decode failures {}",
+                program_id, program.unmatched_instructions, program.decode_failures
+            );
+            for unmatched in &program.top_unmatched {
+                println!(
+                    "     unknown discriminator {} x{} (len {}, e.g. {})",
+                    unmatched.discriminator,
+                    unmatched.count,
+                    unmatched.data_len,
+                    unmatched.sample_signature
+                );
+            }
+            for failure in &program.top_decode_failures {
+                println!(
+                    "     {} {} x{} (len {}, e.g. {})",
+                    failure.event_type,
+                    failure.reason.label(),
+                    failure.count,
+                    failure.data_len,
+                    failure.sample_signature
+                );
+            }
+        }
         println!();
     }
 
-    /// 启动自动性能监控任务
+    /// 启动自动性能监控任务，按 `MetricsReportConfig` 周期性上报
     pub async fn start_auto_monitoring(&self) -> Option<tokio::task::JoinHandle<()>> {
-        if !self.enable_metrics {
+        if !self.enable_metrics || self.report_config.reporter.is_disabled() {
             return None;
         }
 
         let manager = self.clone();
+        let interval_secs = self.report_config.interval_secs.max(1);
         let handle = tokio::spawn(async move {
-            let mut interval = tokio::time::interval(std::time::Duration::from_secs(
-                DEFAULT_METRICS_PRINT_INTERVAL_SECONDS,
-            ));
+            let mut interval =
+                tokio::time::interval(std::time::Duration::from_secs(interval_secs));
             loop {
                 interval.tick().await;
-                manager.print_metrics();
+                manager.report();
             }
         });
         Some(handle)
     }
 
+    /// 使用配置的上报方式立即上报一次
+    pub fn report(&self) {
+        self.report_config.reporter.report(self);
+    }
+
     // === 兼容性方法 ===
 
     /// 兼容性构造函数
@@ -617,6 +1034,7 @@ impl Clone for MetricsManager {
             enable_metrics: self.enable_metrics,
             stream_name: self.stream_name.clone(),
             background_task_running: AtomicBool::new(false), // 新实例不自动启动后台任务
+            report_config: self.report_config.clone(),
         }
     }
 }
@@ -1,6 +1,9 @@
 // 公用模块 - 包含流处理相关的通用功能
 pub mod config;
+pub mod latency;
 pub mod metrics;
+pub mod prometheus;
+pub mod reporter;
 pub mod constants;
 pub mod subscription;
 pub mod event_processor;
@@ -8,7 +11,10 @@ pub mod simd_utils;
 
 // 重新导出主要类型
 pub use config::*;
+pub use latency::*;
 pub use metrics::*;
+pub use prometheus::*;
+pub use reporter::*;
 pub use constants::*;
 pub use subscription::*;
 pub use event_processor::*;
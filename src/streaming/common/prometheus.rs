@@ -0,0 +1,465 @@
+// Prometheus 指标导出 - 文本格式渲染与内置 `/metrics` HTTP 端点
+use std::fmt::{Display, Write};
+use std::net::SocketAddr;
+use std::sync::Arc;
+use std::time::Duration;
+
+use tokio::io::{AsyncReadExt, AsyncWriteExt};
+use tokio::net::{TcpListener, TcpStream};
+use tokio::task::JoinHandle;
+
+use super::latency::LatencyStage;
+use super::metrics::{
+    BreakdownMetricsSnapshot, EventType, MetricsManager, PROCESSING_TIME_BUCKETS_US,
+};
+use crate::common::AnyResult;
+use crate::streaming::event_parser::core::parse_diagnostics::DEFAULT_DIAGNOSTICS_TOP_N;
+
+/// 指标名前缀
+pub const PROMETHEUS_METRIC_PREFIX: &str = "solana_streamer_";
+/// 内置 HTTP 端点的路径
+pub const PROMETHEUS_METRICS_PATH: &str = "/metrics";
+const PROMETHEUS_CONTENT_TYPE: &str = "text/plain; version=0.0.4; charset=utf-8";
+const REQUEST_READ_TIMEOUT: Duration = Duration::from_secs(5);
+
+impl MetricsManager {
+    /// 以 Prometheus 文本格式渲染当前指标，可挂到已有的 HTTP 服务或 registry 上
+    pub fn render_prometheus(&self) -> String {
+        render_prometheus(std::slice::from_ref(self))
+    }
+
+    /// 在 `addr` 上启动内置的 `/metrics` 端点
+    pub async fn serve_prometheus(&self, addr: SocketAddr) -> AnyResult<JoinHandle<()>> {
+        serve_prometheus(addr, vec![self.clone()]).await
+    }
+}
+
+/// 以 Prometheus 文本格式渲染多个流的指标，各流以 `stream` 标签区分
+pub fn render_prometheus(managers: &[MetricsManager]) -> String {
+    let mut out = String::new();
+
+    write_family(&mut out, "uptime_seconds", "gauge", "Time since the stream client was created");
+    for manager in managers {
+        write_sample(
+            &mut out,
+            "uptime_seconds",
+            &[("stream", manager.stream_name())],
+            manager.get_uptime().as_secs_f64(),
+        );
+    }
+
+    write_family(&mut out, "messages_total", "counter", "Stream messages handled by type");
+    for manager in managers {
+        for event_type in EventType::ALL {
+            let labels = [("stream", manager.stream_name()), ("type", event_type.label())];
+            let count = manager.get_event_metrics(event_type).process_count;
+            write_sample(&mut out, "messages_total", &labels, count);
+        }
+    }
+
+    write_family(&mut out, "events_total", "counter", "Events delivered to the callback by type");
+    for manager in managers {
+        for event_type in EventType::ALL {
+            let labels = [("stream", manager.stream_name()), ("type", event_type.label())];
+            let count = manager.get_event_metrics(event_type).events_processed;
+            write_sample(&mut out, "events_total", &labels, count);
+        }
+    }
+
+    write_family(
+        &mut out,
+        "processing_duration_seconds",
+        "histogram",
+        "Time from receiving a message to delivering its events",
+    );
+    for manager in managers {
+        for event_type in EventType::ALL {
+            let histogram = manager.get_processing_histogram(event_type);
+            let stream = manager.stream_name();
+            let mut cumulative = 0;
+            for (bound_us, count) in PROCESSING_TIME_BUCKETS_US.iter().zip(histogram.bucket_counts)
+            {
+                cumulative += count;
+                let le = (bound_us / 1_000_000.0).to_string();
+                let labels = [("stream", stream), ("type", event_type.label()), ("le", &le)];
+                write_sample(&mut out, "processing_duration_seconds_bucket", &labels, cumulative);
+            }
+            let labels = [("stream", stream), ("type", event_type.label()), ("le", "+Inf")];
+            write_sample(&mut out, "processing_duration_seconds_bucket", &labels, histogram.count);
+            let labels = [("stream", stream), ("type", event_type.label())];
+            let sum_seconds = histogram.sum_us as f64 / 1_000_000.0;
+            write_sample(&mut out, "processing_duration_seconds_sum", &labels, sum_seconds);
+            write_sample(&mut out, "processing_duration_seconds_count", &labels, histogram.count);
+        }
+    }
+
+    write_family(
+        &mut out,
+        "latency_seconds",
+        "summary",
+        "Latency by pipeline stage: network_delay, queue_wait, parse and callback",
+    );
+    for manager in managers {
+        for stage in LatencyStage::ALL {
+            let latency = manager.get_latency_percentiles(stage);
+            let stream = manager.stream_name();
+            let quantiles = [
+                ("0.5", latency.p50_us),
+                ("0.9", latency.p90_us),
+                ("0.99", latency.p99_us),
+                ("0.999", latency.p999_us),
+            ];
+            for (quantile, value_us) in quantiles {
+                let labels = [("stream", stream), ("stage", stage.label()), ("quantile", quantile)];
+                write_sample(&mut out, "latency_seconds", &labels, value_us as f64 / 1_000_000.0);
+            }
+            let labels = [("stream", stream), ("stage", stage.label())];
+            let sum_seconds = latency.mean_us * latency.count as f64 / 1_000_000.0;
+            write_sample(&mut out, "latency_seconds_sum", &labels, sum_seconds);
+            write_sample(&mut out, "latency_seconds_count", &labels, latency.count);
+        }
+    }
+
+    write_family(
+        &mut out,
+        "dropped_events_total",
+        "counter",
+        "Messages dropped by the backpressure Drop strategy",
+    );
+    for manager in managers {
+        let labels = [("stream", manager.stream_name())];
+        write_sample(&mut out, "dropped_events_total", &labels, manager.get_dropped_events_count());
+    }
+
+    write_family(&mut out, "queue_depth", "gauge", "Messages waiting in the event processor queue");
+    for manager in managers {
+        let (grpc_pending, shred_pending) = manager.get_queue_depths();
+        let stream = manager.stream_name();
+        let labels = [("stream", stream), ("queue", "grpc")];
+        write_sample(&mut out, "queue_depth", &labels, grpc_pending);
+        let labels = [("stream", stream), ("queue", "shred")];
+        write_sample(&mut out, "queue_depth", &labels, shred_pending);
+    }
+
+    write_family(&mut out, "protocol_events_total", "counter", "Parsed events by protocol");
+    for manager in managers {
+        for (protocol, metrics) in sorted_by_label(manager.get_protocol_metrics()) {
+            let labels = [("stream", manager.stream_name()), ("protocol", protocol.as_str())];
+            write_sample(&mut out, "protocol_events_total", &labels, metrics.events);
+        }
+    }
+
+    write_family(
+        &mut out,
+        "protocol_processing_seconds_total",
+        "counter",
+        "Total processing time of parsed events by protocol",
+    );
+    for manager in managers {
+        for (protocol, metrics) in sorted_by_label(manager.get_protocol_metrics()) {
+            let labels = [("stream", manager.stream_name()), ("protocol", protocol.as_str())];
+            let total_seconds = total_processing_seconds(&metrics);
+            write_sample(&mut out, "protocol_processing_seconds_total", &labels, total_seconds);
+        }
+    }
+
+    write_family(&mut out, "event_type_events_total", "counter", "Parsed events by event type");
+    for manager in managers {
+        for (event_type, metrics) in sorted_by_label(manager.get_event_type_metrics()) {
+            let labels = [("stream", manager.stream_name()), ("event_type", event_type.as_str())];
+            write_sample(&mut out, "event_type_events_total", &labels, metrics.events);
+        }
+    }
+
+    write_family(
+        &mut out,
+        "event_type_processing_seconds_total",
+        "counter",
+        "Total processing time of parsed events by event type",
+    );
+    for manager in managers {
+        for (event_type, metrics) in sorted_by_label(manager.get_event_type_metrics()) {
+            let labels = [("stream", manager.stream_name()), ("event_type", event_type.as_str())];
+            let total_seconds = total_processing_seconds(&metrics);
+            write_sample(&mut out, "event_type_processing_seconds_total", &labels, total_seconds);
+        }
+    }
+
+    write_family(
+        &mut out,
+        "matched_transactions_total",
+        "counter",
+        "Transactions that reference a subscribed program",
+    );
+    for manager in managers {
+        let (matched, _) = manager.get_matched_transaction_counts();
+        let labels = [("stream", manager.stream_name())];
+        write_sample(&mut out, "matched_transactions_total", &labels, matched);
+    }
+
+    write_family(
+        &mut out,
+        "matched_transactions_without_events_total",
+        "counter",
+        "Transactions that reference a subscribed program but yield no events",
+    );
+    for manager in managers {
+        let (_, without_events) = manager.get_matched_transaction_counts();
+        let labels = [("stream", manager.stream_name())];
+        write_sample(
+            &mut out,
+            "matched_transactions_without_events_total",
+            &labels,
+            without_events,
+        );
+    }
+
+    let diagnostics: Vec<_> = managers
+        .iter()
+        .map(|manager| (manager, manager.get_parse_diagnostics(DEFAULT_DIAGNOSTICS_TOP_N)))
+        .collect();
+    write_family(
+        &mut out,
+        "unmatched_instructions_total",
+        "counter",
+        "Instructions of subscribed programs that match no instruction config",
+    );
+    for (manager, snapshot) in &diagnostics {
+        for (program_id, program) in &snapshot.programs {
+            let labels = [("stream", manager.stream_name()), ("program", program_id.as_str())];
+            write_sample(
+                &mut out,
+                "unmatched_instructions_total",
+                &labels,
+                program.unmatched_instructions,
+            );
+        }
+    }
+    write_family(
+        &mut out,
+        "decode_failures_total",
+        "counter",
+        "Instruction or CPI event data that matched a discriminator but failed to decode",
+    );
+    for (manager, snapshot) in &diagnostics {
+        for (program_id, program) in &snapshot.programs {
+            let labels = [("stream", manager.stream_name()), ("program", program_id.as_str())];
+            write_sample(&mut out, "decode_failures_total", &labels, program.decode_failures);
+        }
+    }
+    write_family(
+        &mut out,
+        "unmatched_discriminator_instructions_total",
+        "counter",
+        "Unmatched instructions by discriminator prefix (most frequent per program)",
+    );
+    for (manager, snapshot) in &diagnostics {
+        for (program_id, program) in &snapshot.programs {
+            for unmatched in &program.top_unmatched {
+                let labels = [
+                    ("stream", manager.stream_name()),
+                    ("program", program_id.as_str()),
+                    ("discriminator", unmatched.discriminator.as_str()),
+                ];
+                write_sample(
+                    &mut out,
+                    "unmatched_discriminator_instructions_total",
+                    &labels,
+                    unmatched.count,
+                );
+            }
+        }
+    }
+    write_family(
+        &mut out,
+        "decode_failures_by_layout_total",
+        "counter",
+        "Decode failures by event type and data length (most frequent per program)",
+    );
+    for (manager, snapshot) in &diagnostics {
+        for (program_id, program) in &snapshot.programs {
+            for failure in &program.top_decode_failures {
+                let data_len = failure.data_len.to_string();
+                let labels = [
+                    ("stream", manager.stream_name()),
+                    ("program", program_id.as_str()),
+                    ("event_type", failure.event_type.as_str()),
+                    ("reason", failure.reason.label()),
+                    ("data_len", data_len.as_str()),
+                ];
+                write_sample(&mut out, "decode_failures_by_layout_total", &labels, failure.count);
+            }
+        }
+    }
+
+    out
+}
+
+/// 在 `addr` 上启动内置的 `/metrics` 端点，返回服务任务句柄
+///
+/// 多个流（如 gRPC 与 ShredStream）可共用一个端点。
+pub async fn serve_prometheus(
+    addr: SocketAddr,
+    managers: Vec<MetricsManager>,
+) -> AnyResult<JoinHandle<()>> {
+    let listener = TcpListener::bind(addr).await?;
+    let managers = Arc::new(managers);
+    log::info!(
+        "Prometheus metrics endpoint listening on http://{}{}",
+        addr,
+        PROMETHEUS_METRICS_PATH
+    );
+
+    Ok(tokio::spawn(async move {
+        loop {
+            let (stream, _) = match listener.accept().await {
+                Ok(conn) => conn,
+                Err(e) => {
+                    log::warn!("Prometheus metrics endpoint accept error: {}", e);
+                    continue;
+                }
+            };
+            let managers = managers.clone();
+            tokio::spawn(async move {
+                if let Err(e) = handle_connection(stream, &managers).await {
+                    log::debug!("Prometheus metrics request error: {}", e);
+                }
+            });
+        }
+    }))
+}
+
+async fn handle_connection(mut stream: TcpStream, managers: &[MetricsManager]) -> AnyResult<()> {
+    // 只需要请求行，读到头部结束或缓冲区满为止
+    let mut buf = [0u8; 4096];
+    let mut len = 0;
+    while len < buf.len() {
+        let n = tokio::time::timeout(REQUEST_READ_TIMEOUT, stream.read(&mut buf[len..])).await??;
+        if n == 0 {
+            break;
+        }
+        len += n;
+        if buf[..len].windows(4).any(|window| window == b"\r\n\r\n") {
+            break;
+        }
+    }
+
+    let request = String::from_utf8_lossy(&buf[..len]);
+    let mut request_line = request.split_whitespace();
+    let method = request_line.next();
+    let path = request_line.next().map(|path| path.split('?').next().unwrap_or(path));
+    let (status, body) = match (method, path) {
+        (Some("GET"), Some(PROMETHEUS_METRICS_PATH)) => ("200 OK", render_prometheus(managers)),
+        _ => ("404 Not Found", "Not Found\n".to_string()),
+    };
+
+    let header = format!(
+        "HTTP/1.1 {}\r\nContent-Type: {}\r\nContent-Length: {}\r\nConnection: close\r\n\r\n",
+        status,
+        PROMETHEUS_CONTENT_TYPE,
+        body.len()
+    );
+    stream.write_all(header.as_bytes()).await?;
+    stream.write_all(body.as_bytes()).await?;
+    stream.shutdown().await?;
+    Ok(())
+}
+
+/// 将键转换为标签值并排序，保证输出稳定
+fn sorted_by_label<K: Display>(
+    metrics: Vec<(K, BreakdownMetricsSnapshot)>,
+) -> Vec<(String, BreakdownMetricsSnapshot)> {
+    let mut metrics: Vec<_> = metrics.into_iter().map(|(key, m)| (key.to_string(), m)).collect();
+    metrics.sort_by(|(a, _), (b, _)| a.cmp(b));
+    metrics
+}
+
+fn total_processing_seconds(metrics: &BreakdownMetricsSnapshot) -> f64 {
+    metrics.total_time_us as f64 / 1_000_000.0
+}
+
+fn write_family(out: &mut String, name: &str, kind: &str, help: &str) {
+    let _ = writeln!(out, "# HELP {}{} {}", PROMETHEUS_METRIC_PREFIX, name, help);
+    let _ = writeln!(out, "# TYPE {}{} {}", PROMETHEUS_METRIC_PREFIX, name, kind);
+}
+
+fn write_sample(out: &mut String, name: &str, labels: &[(&str, &str)], value: impl Display) {
+    let _ = write!(out, "{}{}", PROMETHEUS_METRIC_PREFIX, name);
+    for (i, (key, value)) in labels.iter().enumerate() {
+        let separator = if i == 0 { '{' } else { ',' };
+        let _ = write!(out, "{}{}=\"{}\"", separator, key, escape_label_value(value));
+    }
+    if !labels.is_empty() {
+        out.push('}');
+    }
+    let _ = writeln!(out, " {}", value);
+}
+
+fn escape_label_value(value: &str) -> String {
+    value.replace('\\', "\\\\").replace('"', "\\\"").replace('\n', "\\n")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::streaming::event_parser::{
+        common::{EventType as ParserEventType, ProtocolType},
+        protocols::pumpfun::PumpFunTradeEvent,
+    };
+
+    /// 渲染结果中与 `name` 及标签完全匹配的样本值
+    fn sample<'a>(rendered: &'a str, name: &str, labels: &str) -> Option<&'a str> {
+        let prefix = format!("{PROMETHEUS_METRIC_PREFIX}{name}{{{labels}}} ");
+        rendered.lines().find_map(|line| line.strip_prefix(prefix.as_str()))
+    }
+
+    #[tokio::test]
+    async fn render_known_metrics() {
+        let manager = MetricsManager::new(true, "grpc \"main\"\\\n".to_string());
+        let stream = r#"stream="grpc \"main\"\\\n""#;
+
+        // 3 个 30us（落在 50us 桶）与 1 个 600us（落在 1ms 桶）
+        manager.record_events(EventType::Transaction, 3, 30.0);
+        manager.record_events(EventType::Transaction, 1, 600.0);
+        for handle_us in [1, 1, 2] {
+            let mut event = PumpFunTradeEvent::default();
+            event.metadata.protocol = ProtocolType::PumpFun;
+            event.metadata.event_type = ParserEventType::PumpFunBuy;
+            event.metadata.handle_us = handle_us;
+            manager.record_parsed_event(&event);
+        }
+        let rendered = manager.render_prometheus();
+
+        // 桶计数为累积值
+        let bucket = |le: &str| {
+            let labels = format!(r#"{stream},type="transaction",le="{le}""#);
+            sample(&rendered, "processing_duration_seconds_bucket", &labels)
+        };
+        assert_eq!(bucket("0.000025"), Some("0"));
+        assert_eq!(bucket("0.00005"), Some("3"));
+        assert_eq!(bucket("0.0005"), Some("3"));
+        assert_eq!(bucket("0.001"), Some("4"));
+        assert_eq!(bucket("0.1"), Some("4"));
+        assert_eq!(bucket("+Inf"), Some("4"));
+        let labels = format!(r#"{stream},type="transaction""#);
+        assert_eq!(sample(&rendered, "processing_duration_seconds_sum", &labels), Some("0.00069"));
+        assert_eq!(sample(&rendered, "processing_duration_seconds_count", &labels), Some("4"));
+
+        // 累计耗时直接取整数微秒总和，而非 avg * count
+        let labels = format!(r#"{stream},protocol="PumpFun""#);
+        assert_eq!(sample(&rendered, "protocol_events_total", &labels), Some("3"));
+        assert_eq!(
+            sample(&rendered, "protocol_processing_seconds_total", &labels),
+            Some("0.000004")
+        );
+        let labels = format!(r#"{stream},event_type="PumpFunBuy""#);
+        assert_eq!(
+            sample(&rendered, "event_type_processing_seconds_total", &labels),
+            Some("0.000004")
+        );
+
+        assert!(rendered.contains(&format!(
+            "# TYPE {PROMETHEUS_METRIC_PREFIX}processing_duration_seconds histogram\n"
+        )));
+    }
+}
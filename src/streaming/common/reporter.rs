@@ -0,0 +1,214 @@
+// 指标上报 - 结构化快照与周期性上报方式
+use std::collections::BTreeMap;
+use std::fmt;
+use std::io::Write;
+use std::sync::Arc;
+
+use serde::{Deserialize, Serialize};
+
+use super::constants::DEFAULT_METRICS_PRINT_INTERVAL_SECONDS;
+use super::latency::LatencyBreakdown;
+use super::metrics::{BreakdownMetricsSnapshot, EventMetricsSnapshot, EventType, MetricsManager};
+use crate::streaming::event_parser::core::parse_diagnostics::{
+    ParseDiagnosticsSnapshot, DEFAULT_DIAGNOSTICS_TOP_N,
+};
+
+/// 可序列化的完整指标快照
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct MetricsSnapshot {
+    pub stream: String,
+    /// 快照时间（Unix 毫秒）
+    pub timestamp_ms: i64,
+    pub uptime_secs: f64,
+    pub transactions: EventMetricsSnapshot,
+    pub accounts: EventMetricsSnapshot,
+    pub block_meta: EventMetricsSnapshot,
+    pub dropped_events: u64,
+    pub queue_depth: QueueDepthSnapshot,
+    pub latency: LatencyBreakdown,
+    /// 按协议统计，键为 `ProtocolType` 的显示名
+    pub protocols: BTreeMap<String, BreakdownMetricsSnapshot>,
+    /// 按解析事件类型统计，键为 `EventType` 的显示名
+    pub event_types: BTreeMap<String, BreakdownMetricsSnapshot>,
+    pub matched_transactions: u64,
+    pub matched_transactions_without_events: u64,
+    /// 未匹配指令与解码失败统计
+    pub parse_diagnostics: ParseDiagnosticsSnapshot,
+}
+
+/// 事件处理器待处理队列长度
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct QueueDepthSnapshot {
+    pub grpc: usize,
+    pub shred: usize,
+}
+
+impl MetricsSnapshot {
+    /// 单行 JSON，适合 JSON lines 日志
+    pub fn to_json_line(&self) -> String {
+        serde_json::to_string(self).unwrap_or_default()
+    }
+
+    /// 单行文本摘要
+    pub fn summary(&self) -> String {
+        format!(
+            "{} metrics: uptime={:.0}s tx={} ({:.1}/s) accounts={} block_meta={} dropped={} \
+             queue(grpc={}, shred={}) p99(network={}us, queue={}us, parse={}us, callback={}us)",
+            self.stream,
+            self.uptime_secs,
+            self.transactions.events_processed,
+            self.transactions.events_per_second,
+            self.accounts.events_processed,
+            self.block_meta.events_processed,
+            self.dropped_events,
+            self.queue_depth.grpc,
+            self.queue_depth.shred,
+            self.latency.network_delay.p99_us,
+            self.latency.queue_wait.p99_us,
+            self.latency.parse.p99_us,
+            self.latency.callback.p99_us,
+        )
+    }
+}
+
+impl MetricsManager {
+    /// 获取当前指标快照
+    pub fn snapshot(&self) -> MetricsSnapshot {
+        let (grpc, shred) = self.get_queue_depths();
+        let (matched_transactions, matched_transactions_without_events) =
+            self.get_matched_transaction_counts();
+        MetricsSnapshot {
+            stream: self.stream_name().to_string(),
+            timestamp_ms: chrono::Utc::now().timestamp_millis(),
+            uptime_secs: self.get_uptime().as_secs_f64(),
+            transactions: self.get_event_metrics(EventType::Transaction),
+            accounts: self.get_event_metrics(EventType::Account),
+            block_meta: self.get_event_metrics(EventType::BlockMeta),
+            dropped_events: self.get_dropped_events_count(),
+            queue_depth: QueueDepthSnapshot { grpc, shred },
+            latency: self.get_latency_breakdown(),
+            protocols: self
+                .get_protocol_metrics()
+                .into_iter()
+                .map(|(protocol, metrics)| (protocol.to_string(), metrics))
+                .collect(),
+            event_types: self
+                .get_event_type_metrics()
+                .into_iter()
+                .map(|(event_type, metrics)| (event_type.to_string(), metrics))
+                .collect(),
+            matched_transactions,
+            matched_transactions_without_events,
+            parse_diagnostics: self.get_parse_diagnostics(DEFAULT_DIAGNOSTICS_TOP_N),
+        }
+    }
+}
+
+/// 周期性指标上报方式
+#[derive(Clone, Default)]
+pub enum MetricsReporter {
+    /// 不上报，例如只通过 Prometheus 抓取
+    Disabled,
+    /// 在标准输出打印表格（默认）
+    #[default]
+    Table,
+    /// 通过 `log::info!` 输出单行摘要
+    Log,
+    /// 通过 `tracing::info!` 输出结构化字段
+    Tracing,
+    /// 在标准输出打印单行 JSON
+    JsonLines,
+    /// 自定义回调
+    Custom(Arc<dyn Fn(&MetricsSnapshot) + Send + Sync>),
+}
+
+impl MetricsReporter {
+    /// 使用自定义回调上报
+    pub fn custom<F>(callback: F) -> Self
+    where
+        F: Fn(&MetricsSnapshot) + Send + Sync + 'static,
+    {
+        Self::Custom(Arc::new(callback))
+    }
+
+    /// 以 JSON lines 格式写入 `writer`（如文件）
+    pub fn json_lines_to<W: Write + Send + 'static>(writer: W) -> Self {
+        let writer = parking_lot::Mutex::new(writer);
+        Self::custom(move |snapshot| {
+            let mut writer = writer.lock();
+            if let Err(e) = writeln!(writer, "{}", snapshot.to_json_line()) {
+                log::warn!("Failed to write metrics snapshot: {}", e);
+            }
+        })
+    }
+
+    pub fn is_disabled(&self) -> bool {
+        matches!(self, Self::Disabled)
+    }
+
+    /// 上报 `manager` 的当前指标
+    pub fn report(&self, manager: &MetricsManager) {
+        match self {
+            Self::Disabled => {}
+            Self::Table => manager.print_metrics(),
+            Self::Log => {
+                log::info!(target: "solana_streamer::metrics", "{}", manager.snapshot().summary())
+            }
+            Self::Tracing => {
+                let snapshot = manager.snapshot();
+                tracing::info!(
+                    target: "solana_streamer::metrics",
+                    stream = %snapshot.stream,
+                    uptime_secs = snapshot.uptime_secs,
+                    transactions = snapshot.transactions.events_processed,
+                    transactions_per_second = snapshot.transactions.events_per_second,
+                    accounts = snapshot.accounts.events_processed,
+                    block_meta = snapshot.block_meta.events_processed,
+                    dropped_events = snapshot.dropped_events,
+                    grpc_queue_depth = snapshot.queue_depth.grpc,
+                    shred_queue_depth = snapshot.queue_depth.shred,
+                    network_delay_p99_us = snapshot.latency.network_delay.p99_us,
+                    queue_wait_p99_us = snapshot.latency.queue_wait.p99_us,
+                    parse_p99_us = snapshot.latency.parse.p99_us,
+                    callback_p99_us = snapshot.latency.callback.p99_us,
+                    matched_transactions_without_events =
+                        snapshot.matched_transactions_without_events,
+                    "metrics snapshot"
+                );
+            }
+            Self::JsonLines => println!("{}", manager.snapshot().to_json_line()),
+            Self::Custom(callback) => callback(&manager.snapshot()),
+        }
+    }
+}
+
+impl fmt::Debug for MetricsReporter {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Disabled => write!(f, "Disabled"),
+            Self::Table => write!(f, "Table"),
+            Self::Log => write!(f, "Log"),
+            Self::Tracing => write!(f, "Tracing"),
+            Self::JsonLines => write!(f, "JsonLines"),
+            Self::Custom(_) => write!(f, "Custom(..)"),
+        }
+    }
+}
+
+/// 周期性指标上报配置
+#[derive(Debug, Clone)]
+pub struct MetricsReportConfig {
+    /// 上报方式（默认：Table）
+    pub reporter: MetricsReporter,
+    /// 上报间隔秒数（默认：10）
+    pub interval_secs: u64,
+}
+
+impl Default for MetricsReportConfig {
+    fn default() -> Self {
+        Self {
+            reporter: MetricsReporter::default(),
+            interval_secs: DEFAULT_METRICS_PRINT_INTERVAL_SECONDS,
+        }
+    }
+}
@@ -1,5 +1,6 @@
-use crate::streaming::event_parser::common::{
-    types::EventType, ACCOUNT_EVENT_TYPES, BLOCK_EVENT_TYPES,
+use crate::streaming::event_parser::{
+    common::{types::EventType, ACCOUNT_EVENT_TYPES, BLOCK_EVENT_TYPES},
+    core::protocol_registry::ProtocolRegistry,
 };
 
 #[derive(Debug, Clone, Default)]
@@ -8,17 +9,25 @@ pub struct EventTypeFilter {
 }
 
 impl EventTypeFilter {
-    pub fn include_transaction_event(&self) -> bool {
+    /// `registry` 中注册的账户事件类型按账户事件处理
+    pub fn include_transaction_event(&self, registry: Option<&ProtocolRegistry>) -> bool {
         self.include
             .iter()
-            .any(|event| !ACCOUNT_EVENT_TYPES.contains(event) && !BLOCK_EVENT_TYPES.contains(event))
+            .any(|event| !is_account_event(event, registry) && !BLOCK_EVENT_TYPES.contains(event))
     }
 
-    pub fn include_account_event(&self) -> bool {
-        self.include.iter().any(|event| ACCOUNT_EVENT_TYPES.contains(event))
+    /// `registry` 中注册的账户事件类型按账户事件处理
+    pub fn include_account_event(&self, registry: Option<&ProtocolRegistry>) -> bool {
+        self.include.iter().any(|event| is_account_event(event, registry))
     }
 
     pub fn include_block_event(&self) -> bool {
         self.include.iter().any(|event| BLOCK_EVENT_TYPES.contains(event))
     }
 }
+
+/// 内置账户事件，或 `registry` 中账户解析器输出的事件
+fn is_account_event(event: &EventType, registry: Option<&ProtocolRegistry>) -> bool {
+    ACCOUNT_EVENT_TYPES.contains(event)
+        || registry.is_some_and(|registry| registry.is_account_event_type(event))
+}
@@ -1,3 +1,4 @@
+pub mod swap_data;
 pub mod types;
 pub mod utils;
 pub mod filter;
@@ -6,13 +7,32 @@ pub mod high_performance_clock;
 /// 自动生成UnifiedEvent trait实现的宏
 #[macro_export]
 macro_rules! impl_unified_event {
+    // 外部自定义事件（如 ProtocolRegistry 注册的协议）：转换为 DexEvent::Custom，无 protobuf schema
+    (custom $struct_name:ident, $($field:ident),*) => {
+        $crate::impl_unified_event!(@impl $struct_name, [$($field),*], {});
+    };
     // 带有自定义ID表达式的版本
     ($struct_name:ident, $($field:ident),*) => {
+        $crate::impl_unified_event!(@impl $struct_name, [$($field),*], {
+            fn into_dex_event(self: Box<Self>) -> $crate::streaming::event_parser::core::dex_event::DexEvent {
+                $crate::streaming::event_parser::core::dex_event::DexEvent::$struct_name(self)
+            }
+
+            fn to_proto(&self) -> Option<$crate::protos::events::Event> {
+                Some(self.into())
+            }
+        });
+    };
+    (@impl $struct_name:ident, [$($field:ident),*], { $($extra:tt)* }) => {
         impl $crate::streaming::event_parser::core::traits::UnifiedEvent for $struct_name {
             fn event_type(&self) -> $crate::streaming::event_parser::common::types::EventType {
                 self.metadata.event_type.clone()
             }
 
+            fn protocol(&self) -> $crate::streaming::event_parser::common::types::ProtocolType {
+                self.metadata.protocol.clone()
+            }
+
             fn signature(&self) -> &solana_sdk::signature::Signature {
                 &self.metadata.signature
             }
@@ -61,6 +81,26 @@ macro_rules! impl_unified_event {
                 self.metadata.swap_data.is_some()
             }
 
+            fn set_tx_context(&mut self, tx_context: std::sync::Arc<$crate::streaming::event_parser::common::types::TransactionContext>) {
+                self.metadata.set_tx_context(tx_context);
+            }
+
+            fn set_invocation(&mut self, invocation: $crate::streaming::event_parser::common::types::InstructionInvocation) {
+                self.metadata.set_invocation(invocation);
+            }
+
+            fn invocation(&self) -> Option<&$crate::streaming::event_parser::common::types::InstructionInvocation> {
+                self.metadata.invocation.as_ref()
+            }
+
+            fn set_via_router(&mut self, via_router: $crate::streaming::event_parser::common::types::RouterInfo) {
+                self.metadata.set_via_router(via_router);
+            }
+
+            fn is_failed(&self) -> bool {
+                self.metadata.tx_context.as_ref().is_some_and(|tx_context| tx_context.is_failed())
+            }
+
             fn outer_index(&self) -> i64 {
                 self.metadata.outer_index
             }
@@ -71,9 +111,16 @@ macro_rules! impl_unified_event {
             fn transaction_index(&self) -> Option<u64> {
                 self.metadata.transaction_index
             }
+
+            fn to_json(&self) -> $crate::streaming::event_parser::core::json_schema::JsonResult {
+                $crate::streaming::event_parser::core::json_schema::event_to_json(stringify!($struct_name), self)
+            }
+
+            $($extra)*
         }
     };
 }
 
+pub use swap_data::*;
 pub use types::*;
 pub use utils::*;
@@ -0,0 +1,959 @@
+use std::{collections::HashMap, str::FromStr};
+
+use solana_sdk::pubkey::Pubkey;
+use solana_transaction_status::UiTransactionStatusMeta;
+use yellowstone_grpc_proto::prelude::TransactionStatusMeta;
+
+use crate::streaming::event_parser::{
+    common::types::SwapData,
+    core::balance_change::NATIVE_SOL_MINT,
+    protocols::{
+        axiom::AxiomPumpFunTradeEvent,
+        axiom2::AxiomPumpSwapBuyEvent,
+        bonk::{types::TradeDirection as BonkTradeDirection, BonkTradeEvent},
+        meteora_dammv2::MeteoraDAMMv2SwapEvent,
+        meteora_dbc::{types::TradeDirection, MeteoraDBCSwapEvent},
+        orca_whirlpool::{
+            OrcaWhirlpoolSwapEvent, OrcaWhirlpoolSwapV2Event, OrcaWhirlpoolTwoHopSwapEvent,
+        },
+        photon::{PhotonPumpFunTradeEvent, PhotonPumpSwapTradeEvent},
+        pumpfun::PumpFunTradeEvent,
+        pumpswap::{PumpSwapBuyEvent, PumpSwapSellEvent},
+        raydium_amm_v4::RaydiumAmmV4SwapEvent,
+        raydium_clmm::{RaydiumClmmSwapEvent, RaydiumClmmSwapV2Event},
+        raydium_cpmm::RaydiumCpmmSwapEvent,
+    },
+    UnifiedEvent,
+};
+
+const SYSTEM_PROGRAM_ID: Pubkey = solana_sdk::pubkey!("11111111111111111111111111111111");
+
+// SPL Token / Token-2022 指令序号
+const TOKEN_TRANSFER: u8 = 3;
+const TOKEN_TRANSFER_CHECKED: u8 = 12;
+const TOKEN_TRANSFER_FEE_EXTENSION: u8 = 26;
+const TRANSFER_CHECKED_WITH_FEE: u8 = 1;
+// System Program Transfer
+const SYSTEM_TRANSFER: u32 = 2;
+
+/// 内联指令的统一访问接口，屏蔽 gRPC 与 RPC 两种来源的类型差异
+pub trait InnerInstructionView {
+    fn program_id_index(&self) -> usize;
+    fn accounts(&self) -> &[u8];
+    fn data(&self) -> &[u8];
+    fn stack_height(&self) -> Option<u32>;
+}
+
+impl InnerInstructionView for solana_transaction_status::InnerInstruction {
+    fn program_id_index(&self) -> usize {
+        self.instruction.program_id_index as usize
+    }
+
+    fn accounts(&self) -> &[u8] {
+        &self.instruction.accounts
+    }
+
+    fn data(&self) -> &[u8] {
+        &self.instruction.data
+    }
+
+    fn stack_height(&self) -> Option<u32> {
+        self.stack_height
+    }
+}
+
+impl InnerInstructionView for yellowstone_grpc_proto::prelude::InnerInstruction {
+    fn program_id_index(&self) -> usize {
+        self.program_id_index as usize
+    }
+
+    fn accounts(&self) -> &[u8] {
+        &self.accounts
+    }
+
+    fn data(&self) -> &[u8] {
+        &self.data
+    }
+
+    fn stack_height(&self) -> Option<u32> {
+        self.stack_height
+    }
+}
+
+/// token 账户 -> mint、mint 精度及交易前后余额，来自交易 meta 的 `pre/post_token_balances`
+#[derive(Debug, Clone, Default)]
+pub struct TokenAccountMints {
+    mints: HashMap<Pubkey, Pubkey>,
+    decimals: HashMap<Pubkey, u8>,
+    /// token 账户 -> (交易前余额, 交易后余额)
+    balances: HashMap<Pubkey, (u64, u64)>,
+}
+
+impl TokenAccountMints {
+    /// `accounts` 为包含 ALT 地址的完整账户列表
+    pub fn from_grpc_meta(meta: &TransactionStatusMeta, accounts: &[Pubkey]) -> Self {
+        let mut token_mints = Self::default();
+        for (balances, is_post) in
+            [(&meta.pre_token_balances, false), (&meta.post_token_balances, true)]
+        {
+            for balance in balances {
+                let amount = balance.ui_token_amount.as_ref();
+                token_mints.insert(
+                    accounts.get(balance.account_index as usize),
+                    &balance.mint,
+                    amount.map(|amount| amount.decimals as u8),
+                    amount.map(|amount| amount.amount.as_str()),
+                    is_post,
+                );
+            }
+        }
+        token_mints
+    }
+
+    pub fn from_ui_meta(meta: &UiTransactionStatusMeta, accounts: &[Pubkey]) -> Self {
+        let mut token_mints = Self::default();
+        let pre: Option<&Vec<_>> = meta.pre_token_balances.as_ref().into();
+        let post: Option<&Vec<_>> = meta.post_token_balances.as_ref().into();
+        for (balances, is_post) in [(pre, false), (post, true)] {
+            for balance in balances.into_iter().flatten() {
+                token_mints.insert(
+                    accounts.get(balance.account_index as usize),
+                    &balance.mint,
+                    Some(balance.ui_token_amount.decimals),
+                    Some(&balance.ui_token_amount.amount),
+                    is_post,
+                );
+            }
+        }
+        token_mints
+    }
+
+    fn insert(
+        &mut self,
+        account: Option<&Pubkey>,
+        mint: &str,
+        decimals: Option<u8>,
+        amount: Option<&str>,
+        is_post: bool,
+    ) {
+        let (Some(account), Ok(mint)) = (account, Pubkey::from_str(mint)) else {
+            return;
+        };
+        self.mints.insert(*account, mint);
+        if let Some(decimals) = decimals {
+            self.decimals.insert(mint, decimals);
+        }
+        // 交易中新建或关闭的账户只出现在一侧，另一侧按 0 计
+        let amount = amount.and_then(|amount| amount.parse::<u64>().ok()).unwrap_or_default();
+        let balance = self.balances.entry(*account).or_default();
+        if is_post {
+            balance.1 = amount;
+        } else {
+            balance.0 = amount;
+        }
+    }
+
+    pub fn get(&self, token_account: &Pubkey) -> Option<Pubkey> {
+        self.mints.get(token_account).copied()
+    }
+
+    /// mint 精度，原生 SOL / WSOL 固定为 9
+    pub fn decimals(&self, mint: &Pubkey) -> Option<u8> {
+        if *mint == NATIVE_SOL_MINT {
+            return Some(9);
+        }
+        self.decimals.get(mint).copied()
+    }
+
+    /// token 账户在交易中减少的数量，余额未减少时为 0
+    pub fn outflow(&self, token_account: &Pubkey) -> u64 {
+        self.balances.get(token_account).map_or(0, |(pre, post)| pre.saturating_sub(*post))
+    }
+
+    /// token 账户在交易中增加的数量，余额未增加时为 0
+    pub fn inflow(&self, token_account: &Pubkey) -> u64 {
+        self.balances.get(token_account).map_or(0, |(pre, post)| post.saturating_sub(*pre))
+    }
+}
+
+/// 从 swap 事件中取出的账户：用户 token 账户与池子 vault，及其已知 mint
+#[derive(Default)]
+struct SwapAccounts {
+    from_mint: Option<Pubkey>,
+    to_mint: Option<Pubkey>,
+    user_accounts: [(Pubkey, Option<Pubkey>); 2],
+    vaults: [(Pubkey, Option<Pubkey>); 2],
+}
+
+impl SwapAccounts {
+    /// 与 SOL 交易的 bonding curve，数量由事件补全（见 [`complete_swap_data`]）
+    fn sol_pair(mint: Pubkey, is_buy: bool) -> Self {
+        let (from_mint, to_mint) =
+            if is_buy { (NATIVE_SOL_MINT, mint) } else { (mint, NATIVE_SOL_MINT) };
+        Self { from_mint: Some(from_mint), to_mint: Some(to_mint), ..Default::default() }
+    }
+
+    /// base/quote 池子，`is_buy` 为 quote -> base
+    #[allow(clippy::too_many_arguments)]
+    fn base_quote(
+        is_buy: bool,
+        base_mint: Pubkey,
+        quote_mint: Pubkey,
+        user_base: Pubkey,
+        user_quote: Pubkey,
+        base_vault: Pubkey,
+        quote_vault: Pubkey,
+    ) -> Self {
+        let (from_mint, to_mint) =
+            if is_buy { (quote_mint, base_mint) } else { (base_mint, quote_mint) };
+        Self {
+            from_mint: Some(from_mint),
+            to_mint: Some(to_mint),
+            user_accounts: [(user_base, Some(base_mint)), (user_quote, Some(quote_mint))],
+            vaults: [(base_vault, Some(base_mint)), (quote_vault, Some(quote_mint))],
+        }
+    }
+
+    /// input/output 池子，mint 未知时为 None
+    fn input_output(
+        input_mint: Option<Pubkey>,
+        output_mint: Option<Pubkey>,
+        input_account: Pubkey,
+        output_account: Pubkey,
+        input_vault: Pubkey,
+        output_vault: Pubkey,
+    ) -> Self {
+        Self {
+            from_mint: input_mint,
+            to_mint: output_mint,
+            user_accounts: [(input_account, input_mint), (output_account, output_mint)],
+            vaults: [(input_vault, input_mint), (output_vault, output_mint)],
+        }
+    }
+
+    fn from_event(event: &dyn UnifiedEvent) -> Option<Self> {
+        let any = event.as_any();
+        if let Some(e) = any.downcast_ref::<PumpFunTradeEvent>() {
+            Some(Self::sol_pair(e.mint, e.is_buy))
+        } else if let Some(e) = any.downcast_ref::<PhotonPumpFunTradeEvent>() {
+            Some(Self::sol_pair(e.mint, e.is_buy))
+        } else if let Some(e) = any.downcast_ref::<AxiomPumpFunTradeEvent>() {
+            Some(Self::sol_pair(e.mint, e.is_buy))
+        } else if let Some(e) = any.downcast_ref::<PumpSwapBuyEvent>() {
+            Some(Self::base_quote(
+                true,
+                e.base_mint,
+                e.quote_mint,
+                e.user_base_token_account,
+                e.user_quote_token_account,
+                e.pool_base_token_account,
+                e.pool_quote_token_account,
+            ))
+        } else if let Some(e) = any.downcast_ref::<PumpSwapSellEvent>() {
+            Some(Self::base_quote(
+                false,
+                e.base_mint,
+                e.quote_mint,
+                e.user_base_token_account,
+                e.user_quote_token_account,
+                e.pool_base_token_account,
+                e.pool_quote_token_account,
+            ))
+        } else if let Some(e) = any.downcast_ref::<PhotonPumpSwapTradeEvent>() {
+            Some(Self::base_quote(
+                e.is_buy,
+                e.base_mint,
+                e.quote_mint,
+                e.user_base_token_account,
+                e.user_quote_token_account,
+                e.pool_base_token_account,
+                e.pool_quote_token_account,
+            ))
+        } else if let Some(e) = any.downcast_ref::<AxiomPumpSwapBuyEvent>() {
+            Some(Self::base_quote(
+                true,
+                e.base_mint,
+                e.quote_mint,
+                e.user_base_token_account,
+                e.user_quote_token_account,
+                e.pool_base_token_account,
+                e.pool_quote_token_account,
+            ))
+        } else if let Some(e) = any.downcast_ref::<BonkTradeEvent>() {
+            Some(Self::base_quote(
+                e.trade_direction == BonkTradeDirection::Buy,
+                e.base_token_mint,
+                e.quote_token_mint,
+                e.user_base_token,
+                e.user_quote_token,
+                e.base_vault,
+                e.quote_vault,
+            ))
+        } else if let Some(e) = any.downcast_ref::<MeteoraDBCSwapEvent>() {
+            let is_buy = e.trade_direction == TradeDirection::Buy;
+            let mut accounts = Self::base_quote(
+                is_buy,
+                e.base_mint,
+                e.quote_mint,
+                Pubkey::default(),
+                Pubkey::default(),
+                e.base_vault,
+                e.quote_vault,
+            );
+            accounts.user_accounts = [
+                (e.input_token_account, accounts.from_mint),
+                (e.output_token_account, accounts.to_mint),
+            ];
+            Some(accounts)
+        } else if let Some(e) = any.downcast_ref::<MeteoraDAMMv2SwapEvent>() {
+            let is_buy = e.trade_direction == TradeDirection::Buy;
+            let mut accounts = Self::base_quote(
+                is_buy,
+                e.token_a_mint,
+                e.token_b_mint,
+                Pubkey::default(),
+                Pubkey::default(),
+                e.token_a_vault,
+                e.token_b_vault,
+            );
+            accounts.user_accounts = [
+                (e.input_token_account, accounts.from_mint),
+                (e.output_token_account, accounts.to_mint),
+            ];
+            Some(accounts)
+        } else if let Some(e) = any.downcast_ref::<RaydiumCpmmSwapEvent>() {
+            Some(Self::input_output(
+                Some(e.input_token_mint),
+                Some(e.output_token_mint),
+                e.input_token_account,
+                e.output_token_account,
+                e.input_vault,
+                e.output_vault,
+            ))
+        } else if let Some(e) = any.downcast_ref::<RaydiumClmmSwapEvent>() {
+            Some(Self::input_output(
+                None,
+                None,
+                e.input_token_account,
+                e.output_token_account,
+                e.input_vault,
+                e.output_vault,
+            ))
+        } else if let Some(e) = any.downcast_ref::<RaydiumClmmSwapV2Event>() {
+            Some(Self::input_output(
+                Some(e.input_vault_mint),
+                Some(e.output_vault_mint),
+                e.input_token_account,
+                e.output_token_account,
+                e.input_vault,
+                e.output_vault,
+            ))
+        } else if let Some(e) = any.downcast_ref::<OrcaWhirlpoolSwapEvent>() {
+            Some(Self::whirlpool(
+                e.a_to_b,
+                None,
+                None,
+                [e.token_owner_account_a, e.token_owner_account_b],
+                [e.token_vault_a, e.token_vault_b],
+            ))
+        } else if let Some(e) = any.downcast_ref::<OrcaWhirlpoolSwapV2Event>() {
+            Some(Self::whirlpool(
+                e.a_to_b,
+                Some(e.token_mint_a),
+                Some(e.token_mint_b),
+                [e.token_owner_account_a, e.token_owner_account_b],
+                [e.token_vault_a, e.token_vault_b],
+            ))
+        } else if let Some(e) = any.downcast_ref::<OrcaWhirlpoolTwoHopSwapEvent>() {
+            // 只记录用户转入第一个池子与第二个池子转给用户的转账，中间代币在两个池子之间转移
+            let (input_account, input_vault) = if e.a_to_b_one {
+                (e.token_owner_account_one_a, e.token_vault_one_a)
+            } else {
+                (e.token_owner_account_one_b, e.token_vault_one_b)
+            };
+            let (output_account, output_vault) = if e.a_to_b_two {
+                (e.token_owner_account_two_b, e.token_vault_two_b)
+            } else {
+                (e.token_owner_account_two_a, e.token_vault_two_a)
+            };
+            Some(Self::input_output(
+                None,
+                None,
+                input_account,
+                output_account,
+                input_vault,
+                output_vault,
+            ))
+        } else {
+            // coin / pc vault 与输入输出的对应关系未知，由转账方向判断
+            let e = any.downcast_ref::<RaydiumAmmV4SwapEvent>()?;
+            Some(Self::input_output(
+                None,
+                None,
+                e.user_source_token_account,
+                e.user_destination_token_account,
+                e.pool_pc_token_account,
+                e.pool_coin_token_account,
+            ))
+        }
+    }
+
+    /// token A/B 池子，`a_to_b` 为 A -> B
+    fn whirlpool(
+        a_to_b: bool,
+        mint_a: Option<Pubkey>,
+        mint_b: Option<Pubkey>,
+        [owner_a, owner_b]: [Pubkey; 2],
+        [vault_a, vault_b]: [Pubkey; 2],
+    ) -> Self {
+        if a_to_b {
+            Self::input_output(mint_a, mint_b, owner_a, owner_b, vault_a, vault_b)
+        } else {
+            Self::input_output(mint_b, mint_a, owner_b, owner_a, vault_b, vault_a)
+        }
+    }
+
+    fn user_mint(&self, account: &Pubkey) -> Option<Option<Pubkey>> {
+        Self::lookup(&self.user_accounts, account)
+    }
+
+    fn vault_mint(&self, account: &Pubkey) -> Option<Option<Pubkey>> {
+        Self::lookup(&self.vaults, account)
+    }
+
+    fn lookup(
+        accounts: &[(Pubkey, Option<Pubkey>); 2],
+        account: &Pubkey,
+    ) -> Option<Option<Pubkey>> {
+        if *account == Pubkey::default() {
+            return None;
+        }
+        accounts.iter().find(|(a, _)| a == account).map(|(_, mint)| *mint)
+    }
+}
+
+/// SPL Token / Token-2022 / System 转账
+struct Transfer {
+    source: Pubkey,
+    destination: Pubkey,
+    amount: u64,
+    /// Token-2022 TransferCheckedWithFee 的转账费
+    fee: u64,
+    /// TransferChecked 指令中的 mint
+    mint: Option<Pubkey>,
+}
+
+fn read_u64(data: &[u8], offset: usize) -> Option<u64> {
+    data.get(offset..offset + 8)?.try_into().ok().map(u64::from_le_bytes)
+}
+
+fn decode_transfer(
+    program_id: &Pubkey,
+    instruction: &impl InnerInstructionView,
+    accounts: &[Pubkey],
+) -> Option<Transfer> {
+    let data = instruction.data();
+    let account = |i: usize| -> Option<Pubkey> {
+        accounts.get(*instruction.accounts().get(i)? as usize).copied()
+    };
+    if *program_id == SYSTEM_PROGRAM_ID {
+        let tag = u32::from_le_bytes(data.get(..4)?.try_into().ok()?);
+        if tag != SYSTEM_TRANSFER {
+            return None;
+        }
+        return Some(Transfer {
+            source: account(0)?,
+            destination: account(1)?,
+            amount: read_u64(data, 4)?,
+            fee: 0,
+            mint: None,
+        });
+    }
+    if *program_id != spl_token::ID && *program_id != spl_token_2022::ID {
+        return None;
+    }
+    match *data.first()? {
+        TOKEN_TRANSFER => Some(Transfer {
+            source: account(0)?,
+            destination: account(1)?,
+            amount: read_u64(data, 1)?,
+            fee: 0,
+            mint: None,
+        }),
+        TOKEN_TRANSFER_CHECKED => Some(Transfer {
+            source: account(0)?,
+            destination: account(2)?,
+            amount: read_u64(data, 1)?,
+            fee: 0,
+            mint: account(1),
+        }),
+        // data: [26, 1, amount(8), decimals(1), fee(8)]
+        TOKEN_TRANSFER_FEE_EXTENSION if data.get(1) == Some(&TRANSFER_CHECKED_WITH_FEE) => {
+            Some(Transfer {
+                source: account(0)?,
+                destination: account(2)?,
+                amount: read_u64(data, 2)?,
+                fee: read_u64(data, 11)?,
+                mint: account(1),
+            })
+        }
+        _ => None,
+    }
+}
+
+fn is_transfer_program(program_id: &Pubkey) -> bool {
+    *program_id == spl_token::ID
+        || *program_id == spl_token_2022::ID
+        || *program_id == SYSTEM_PROGRAM_ID
+}
+
+/// 事件所在指令的 CPI 子树中的转账
+///
+/// `inner_index` 为事件所在内联指令的下标，外层指令为 None；只扫描该指令的 CPI 子树
+/// （依据 `stack_height`，缺失时遇到非转账指令即停止）。
+fn cpi_transfers<I: InnerInstructionView>(
+    inner_instructions: &[I],
+    inner_index: Option<i64>,
+    accounts: &[Pubkey],
+) -> Vec<Transfer> {
+    let mut transfers = Vec::new();
+    let (start, parent_height) = match inner_index {
+        None => (0, Some(1)),
+        Some(index) => (
+            index as usize + 1,
+            inner_instructions.get(index as usize).and_then(InnerInstructionView::stack_height),
+        ),
+    };
+    for instruction in inner_instructions.iter().skip(start) {
+        if let (Some(parent), Some(height)) = (parent_height, instruction.stack_height()) {
+            if height <= parent {
+                break;
+            }
+        }
+        let Some(program_id) = accounts.get(instruction.program_id_index()) else {
+            break;
+        };
+        if !is_transfer_program(program_id) {
+            if parent_height.is_none() {
+                break;
+            }
+            continue;
+        }
+        transfers.extend(decode_transfer(program_id, instruction, accounts));
+    }
+    transfers
+}
+
+/// 事件所在指令的 CPI 子树中，转入（`is_deposit`）或转出各 vault 的数量之和
+///
+/// 转出数量扣除 Token-2022 转账费，即接收方实际到账的数量。
+pub fn sum_vault_transfers<I: InnerInstructionView, const N: usize>(
+    inner_instructions: &[I],
+    inner_index: Option<i64>,
+    accounts: &[Pubkey],
+    vaults: &[Pubkey; N],
+    is_deposit: bool,
+) -> [u64; N] {
+    let mut amounts = [0_u64; N];
+    for transfer in cpi_transfers(inner_instructions, inner_index, accounts) {
+        let (vault, amount) = if is_deposit {
+            (transfer.destination, transfer.amount)
+        } else {
+            (transfer.source, transfer.amount.saturating_sub(transfer.fee))
+        };
+        if let Some(index) = vaults.iter().position(|v| *v == vault) {
+            amounts[index] = amounts[index].saturating_add(amount);
+        }
+    }
+    amounts
+}
+
+/// 外层指令直接发起（`stack_height` 为 2）的 SOL 转账，返回 (接收方, lamports)
+///
+/// 缺少 `stack_height` 时无法区分调用层级，返回空。
+pub fn direct_sol_transfers<I: InnerInstructionView>(
+    inner_instructions: &[I],
+    accounts: &[Pubkey],
+) -> Vec<(Pubkey, u64)> {
+    inner_instructions
+        .iter()
+        .filter(|instruction| instruction.stack_height() == Some(2))
+        .filter(|instruction| {
+            accounts.get(instruction.program_id_index()) == Some(&SYSTEM_PROGRAM_ID)
+        })
+        .filter_map(|instruction| decode_transfer(&SYSTEM_PROGRAM_ID, instruction, accounts))
+        .map(|transfer| (transfer.destination, transfer.amount))
+        .collect()
+}
+
+/// 从事件所在指令的 CPI 转账中解析 swap 数据
+///
+/// 用户 token 账户转入 vault 记为输入，vault 转出到用户 token 账户记为输出；
+/// mint 依次取自事件、`TransferChecked` 指令与 `token_mints`。
+pub fn parse_swap_data<I: InnerInstructionView>(
+    event: &dyn UnifiedEvent,
+    inner_instructions: &[I],
+    inner_index: Option<i64>,
+    accounts: &[Pubkey],
+    token_mints: &TokenAccountMints,
+) -> Option<SwapData> {
+    let swap_accounts = SwapAccounts::from_event(event)?;
+    let mut swap_data = SwapData::default();
+    let mut from_mint = None;
+    let mut to_mint = None;
+
+    for transfer in cpi_transfers(inner_instructions, inner_index, accounts) {
+        let resolve = |user: &Pubkey, user_mint: Option<Pubkey>, vault: &Pubkey, vault_mint| {
+            user_mint
+                .or(vault_mint)
+                .or(transfer.mint)
+                .or_else(|| token_mints.get(user))
+                .or_else(|| token_mints.get(vault))
+        };
+        if swap_data.from_amount == 0 {
+            if let (Some(user_mint), Some(vault_mint)) = (
+                swap_accounts.user_mint(&transfer.source),
+                swap_accounts.vault_mint(&transfer.destination),
+            ) {
+                swap_data.from_amount = transfer.amount;
+                from_mint = resolve(&transfer.source, user_mint, &transfer.destination, vault_mint);
+                continue;
+            }
+        }
+        if swap_data.to_amount == 0 {
+            if let (Some(vault_mint), Some(user_mint)) = (
+                swap_accounts.vault_mint(&transfer.source),
+                swap_accounts.user_mint(&transfer.destination),
+            ) {
+                swap_data.to_amount = transfer.amount.saturating_sub(transfer.fee);
+                to_mint = resolve(&transfer.destination, user_mint, &transfer.source, vault_mint);
+            }
+        }
+        if swap_data.from_amount != 0 && swap_data.to_amount != 0 {
+            break;
+        }
+    }
+
+    swap_data.from_mint = from_mint.or(swap_accounts.from_mint).unwrap_or_default();
+    swap_data.to_mint = to_mint.or(swap_accounts.to_mint).unwrap_or_default();
+    if swap_data.from_mint != Pubkey::default()
+        || swap_data.to_mint != Pubkey::default()
+        || swap_data.from_amount != 0
+        || swap_data.to_amount != 0
+    {
+        Some(swap_data)
+    } else {
+        None
+    }
+}
+
+/// 用事件自身的数量补全 swap 数据，需在合并 CPI 事件之后调用
+///
+/// PumpFun 类事件的 SOL 不经过 token 转账，PumpSwap 事件的数量包含手续费，均以事件为准。
+pub fn complete_swap_data(event: &mut dyn UnifiedEvent) {
+    let any = event.as_any_mut();
+    let (swap_data, from_amount, to_amount) = if let Some(e) =
+        any.downcast_mut::<PumpFunTradeEvent>()
+    {
+        let (from, to) =
+            if e.is_buy { (e.sol_amount, e.token_amount) } else { (e.token_amount, e.sol_amount) };
+        (&mut e.metadata.swap_data, from, to)
+    } else if let Some(e) = any.downcast_mut::<PhotonPumpFunTradeEvent>() {
+        let (from, to) =
+            if e.is_buy { (e.sol_amount, e.token_amount) } else { (e.token_amount, e.sol_amount) };
+        (&mut e.metadata.swap_data, from, to)
+    } else if let Some(e) = any.downcast_mut::<AxiomPumpFunTradeEvent>() {
+        let (from, to) =
+            if e.is_buy { (e.sol_amount, e.token_amount) } else { (e.token_amount, e.sol_amount) };
+        (&mut e.metadata.swap_data, from, to)
+    } else if let Some(e) = any.downcast_mut::<PumpSwapBuyEvent>() {
+        (&mut e.metadata.swap_data, e.user_quote_amount_in, e.base_amount_out)
+    } else if let Some(e) = any.downcast_mut::<PumpSwapSellEvent>() {
+        (&mut e.metadata.swap_data, e.base_amount_in, e.user_quote_amount_out)
+    } else {
+        return;
+    };
+    if let Some(swap_data) = swap_data {
+        swap_data.from_amount = from_amount;
+        swap_data.to_amount = to_amount;
+    }
+}
+
+/// Parse token transfer data from next instructions
+pub fn parse_swap_data_from_next_instructions(
+    event: &dyn UnifiedEvent,
+    inner_instruction: &solana_transaction_status::InnerInstructions,
+    current_index: i8,
+    accounts: &[Pubkey],
+) -> Option<SwapData> {
+    parse_swap_data(
+        event,
+        &inner_instruction.instructions,
+        (current_index >= 0).then_some(current_index as i64),
+        accounts,
+        &TokenAccountMints::default(),
+    )
+}
+
+/// Parse token transfer data from next instructions
+pub fn parse_swap_data_from_next_grpc_instructions(
+    event: &dyn UnifiedEvent,
+    inner_instruction: &yellowstone_grpc_proto::prelude::InnerInstructions,
+    current_index: i8,
+    accounts: &[Pubkey],
+) -> Option<SwapData> {
+    parse_swap_data(
+        event,
+        &inner_instruction.instructions,
+        (current_index >= 0).then_some(current_index as i64),
+        accounts,
+        &TokenAccountMints::default(),
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use yellowstone_grpc_proto::prelude::{InnerInstruction, TokenBalance, UiTokenAmount};
+
+    use super::*;
+
+    // 账户表下标
+    const USER: u8 = 0;
+    const USER_IN: u8 = 1;
+    const USER_OUT: u8 = 2;
+    const VAULT_IN: u8 = 3;
+    const VAULT_OUT: u8 = 4;
+    const MINT_IN: u8 = 5;
+    const MINT_OUT: u8 = 6;
+    const TOKEN: u8 = 7;
+    const TOKEN_2022: u8 = 8;
+    const SYSTEM: u8 = 9;
+    const AMM: u8 = 10;
+
+    fn accounts() -> Vec<Pubkey> {
+        let mut accounts: Vec<_> =
+            (0..TOKEN).map(|i| Pubkey::new_from_array([i + 1; 32])).collect();
+        accounts.extend([spl_token::ID, spl_token_2022::ID, SYSTEM_PROGRAM_ID]);
+        accounts.push(Pubkey::new_from_array([AMM + 1; 32]));
+        accounts
+    }
+
+    fn key(index: u8) -> Pubkey {
+        accounts()[index as usize]
+    }
+
+    fn ix(
+        program: u8,
+        accounts: &[u8],
+        data: Vec<u8>,
+        stack_height: Option<u32>,
+    ) -> InnerInstruction {
+        InnerInstruction {
+            program_id_index: program as u32,
+            accounts: accounts.to_vec(),
+            data,
+            stack_height,
+        }
+    }
+
+    fn transfer(source: u8, destination: u8, amount: u64, height: u32) -> InnerInstruction {
+        let data = [&[TOKEN_TRANSFER][..], &amount.to_le_bytes()].concat();
+        ix(TOKEN, &[source, destination, USER], data, Some(height))
+    }
+
+    fn transfer_checked(source: u8, mint: u8, destination: u8, amount: u64) -> InnerInstruction {
+        let data = [&[TOKEN_TRANSFER_CHECKED][..], &amount.to_le_bytes(), &[6]].concat();
+        ix(TOKEN, &[source, mint, destination, USER], data, Some(2))
+    }
+
+    fn transfer_checked_with_fee(
+        source: u8,
+        mint: u8,
+        destination: u8,
+        amount: u64,
+        fee: u64,
+    ) -> InnerInstruction {
+        let data = [
+            &[TOKEN_TRANSFER_FEE_EXTENSION, TRANSFER_CHECKED_WITH_FEE][..],
+            &amount.to_le_bytes(),
+            &[6],
+            &fee.to_le_bytes(),
+        ]
+        .concat();
+        ix(TOKEN_2022, &[source, mint, destination, USER], data, Some(2))
+    }
+
+    fn system_transfer(
+        source: u8,
+        destination: u8,
+        lamports: u64,
+        height: Option<u32>,
+    ) -> InnerInstruction {
+        let data = [&SYSTEM_TRANSFER.to_le_bytes()[..], &lamports.to_le_bytes()].concat();
+        ix(SYSTEM, &[source, destination], data, height)
+    }
+
+    fn cpmm_swap() -> RaydiumCpmmSwapEvent {
+        RaydiumCpmmSwapEvent {
+            input_token_account: key(USER_IN),
+            output_token_account: key(USER_OUT),
+            input_vault: key(VAULT_IN),
+            output_vault: key(VAULT_OUT),
+            input_token_mint: key(MINT_IN),
+            output_token_mint: key(MINT_OUT),
+            ..Default::default()
+        }
+    }
+
+    /// 不含 mint 的事件，mint 需从转账指令或 token balance 解析
+    fn clmm_swap() -> RaydiumClmmSwapEvent {
+        RaydiumClmmSwapEvent {
+            input_token_account: key(USER_IN),
+            output_token_account: key(USER_OUT),
+            input_vault: key(VAULT_IN),
+            output_vault: key(VAULT_OUT),
+            ..Default::default()
+        }
+    }
+
+    fn token_balance(account: u8, mint: u8, decimals: u32, amount: u64) -> TokenBalance {
+        TokenBalance {
+            account_index: account as u32,
+            mint: key(mint).to_string(),
+            ui_token_amount: Some(UiTokenAmount {
+                decimals,
+                amount: amount.to_string(),
+                ..Default::default()
+            }),
+            ..Default::default()
+        }
+    }
+
+    fn amounts(swap_data: &SwapData) -> (Pubkey, u64, Pubkey, u64) {
+        (swap_data.from_mint, swap_data.from_amount, swap_data.to_mint, swap_data.to_amount)
+    }
+
+    #[test]
+    fn spl_transfer_and_transfer_checked() {
+        let inner = [
+            transfer(USER_IN, VAULT_IN, 1_000, 2),
+            transfer_checked(VAULT_OUT, MINT_OUT, USER_OUT, 990),
+        ];
+        let swap_data =
+            parse_swap_data(&cpmm_swap(), &inner, None, &accounts(), &TokenAccountMints::default())
+                .unwrap();
+        assert_eq!(amounts(&swap_data), (key(MINT_IN), 1_000, key(MINT_OUT), 990));
+    }
+
+    #[test]
+    fn transfer_checked_with_fee_nets_fee_and_resolves_mint() {
+        let inner = [
+            transfer_checked(USER_IN, MINT_IN, VAULT_IN, 1_000),
+            transfer_checked_with_fee(VAULT_OUT, MINT_OUT, USER_OUT, 500, 5),
+        ];
+        let swap_data =
+            parse_swap_data(&clmm_swap(), &inner, None, &accounts(), &TokenAccountMints::default())
+                .unwrap();
+        // mint 取自 TransferChecked 指令，输出扣除 Token-2022 转账费
+        assert_eq!(amounts(&swap_data), (key(MINT_IN), 1_000, key(MINT_OUT), 495));
+
+        let received = sum_vault_transfers(&inner, None, &accounts(), &[key(VAULT_OUT)], false);
+        assert_eq!(received, [495]);
+    }
+
+    #[test]
+    fn system_transfers() {
+        let inner = [
+            system_transfer(USER, VAULT_IN, 7_000, Some(2)),
+            ix(AMM, &[], vec![], Some(2)),
+            system_transfer(USER, VAULT_OUT, 3_000, Some(3)),
+        ];
+        assert_eq!(
+            sum_vault_transfers(&inner, None, &accounts(), &[key(VAULT_IN), key(VAULT_OUT)], true),
+            [7_000, 3_000]
+        );
+        // 只保留外层指令直接发起的转账
+        assert_eq!(direct_sol_transfers(&inner, &accounts()), vec![(key(VAULT_IN), 7_000)]);
+
+        let without_height = [system_transfer(USER, VAULT_IN, 7_000, None)];
+        assert!(direct_sol_transfers(&without_height, &accounts()).is_empty());
+    }
+
+    #[test]
+    fn transfers_are_scoped_to_event_subtree() {
+        let inner = [
+            // 事件所在的内联指令及其子树
+            ix(AMM, &[], vec![], Some(2)),
+            transfer(USER_IN, VAULT_IN, 1_000, 3),
+            ix(AMM, &[], vec![], Some(3)),
+            transfer(VAULT_OUT, USER_OUT, 900, 4),
+            // 兄弟指令中的转账不属于该事件
+            ix(AMM, &[], vec![], Some(2)),
+            transfer(USER_IN, VAULT_IN, 5, 3),
+        ];
+        let swap_data = parse_swap_data(
+            &cpmm_swap(),
+            &inner,
+            Some(0),
+            &accounts(),
+            &TokenAccountMints::default(),
+        )
+        .unwrap();
+        assert_eq!(amounts(&swap_data), (key(MINT_IN), 1_000, key(MINT_OUT), 900));
+
+        let deposits = sum_vault_transfers(&inner, Some(4), &accounts(), &[key(VAULT_IN)], true);
+        assert_eq!(deposits, [5]);
+    }
+
+    #[test]
+    fn missing_stack_height_stops_at_first_non_transfer() {
+        let without_height = |mut ix: InnerInstruction| {
+            ix.stack_height = None;
+            ix
+        };
+        let inner = [
+            without_height(ix(AMM, &[], vec![], Some(2))),
+            without_height(transfer(USER_IN, VAULT_IN, 1_000, 3)),
+            without_height(ix(AMM, &[], vec![], Some(2))),
+            without_height(transfer(VAULT_OUT, USER_OUT, 900, 3)),
+        ];
+        let swap_data = parse_swap_data(
+            &cpmm_swap(),
+            &inner,
+            Some(0),
+            &accounts(),
+            &TokenAccountMints::default(),
+        )
+        .unwrap();
+        assert_eq!(amounts(&swap_data), (key(MINT_IN), 1_000, key(MINT_OUT), 0));
+    }
+
+    #[test]
+    fn mints_resolved_from_token_balances() {
+        let meta = TransactionStatusMeta {
+            pre_token_balances: vec![
+                token_balance(USER_IN, MINT_IN, 6, 5_000),
+                token_balance(VAULT_OUT, MINT_OUT, 9, 800),
+            ],
+            post_token_balances: vec![
+                token_balance(USER_IN, MINT_IN, 6, 4_000),
+                token_balance(VAULT_OUT, MINT_OUT, 9, 0),
+            ],
+            ..Default::default()
+        };
+        let token_mints = TokenAccountMints::from_grpc_meta(&meta, &accounts());
+        assert_eq!(token_mints.decimals(&key(MINT_OUT)), Some(9));
+        assert_eq!(token_mints.decimals(&NATIVE_SOL_MINT), Some(9));
+        assert_eq!(
+            (token_mints.outflow(&key(USER_IN)), token_mints.inflow(&key(USER_IN))),
+            (1_000, 0)
+        );
+        // 交易中关闭的账户按 0 计
+        assert_eq!(token_mints.outflow(&key(VAULT_OUT)), 800);
+
+        let inner = [transfer(USER_IN, VAULT_IN, 1_000, 2), transfer(VAULT_OUT, USER_OUT, 800, 2)];
+        let swap_data =
+            parse_swap_data(&clmm_swap(), &inner, None, &accounts(), &token_mints).unwrap();
+        assert_eq!(amounts(&swap_data), (key(MINT_IN), 1_000, key(MINT_OUT), 800));
+    }
+}
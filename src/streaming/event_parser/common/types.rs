@@ -1,26 +1,10 @@
 use borsh::{BorshDeserialize, BorshSerialize};
 use crossbeam_queue::ArrayQueue;
 use serde::{Deserialize, Serialize};
-use solana_sdk::{pubkey::Pubkey, signature::Signature};
-use std::{borrow::Cow, fmt, str::FromStr, sync::Arc};
-
-use crate::{
-    match_event,
-    streaming::{
-        common::SimdUtils,
-        event_parser::{
-            protocols::{
-                bonk::BonkTradeEvent,
-                pumpfun::PumpFunTradeEvent,
-                pumpswap::{PumpSwapBuyEvent, PumpSwapSellEvent},
-                raydium_amm_v4::RaydiumAmmV4SwapEvent,
-                raydium_clmm::{RaydiumClmmSwapEvent, RaydiumClmmSwapV2Event},
-                raydium_cpmm::RaydiumCpmmSwapEvent,
-            },
-            UnifiedEvent,
-        },
-    },
+use solana_sdk::{
+    hash::Hash, pubkey::Pubkey, signature::Signature, transaction::TransactionError,
 };
+use std::{borrow::Cow, fmt, sync::Arc};
 
 // Object pool size configuration
 const EVENT_METADATA_POOL_SIZE: usize = 1000;
@@ -57,7 +41,16 @@ lazy_static::lazy_static! {
 }
 
 #[derive(
-    Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize, BorshSerialize, BorshDeserialize,
+    Debug,
+    Clone,
+    Default,
+    PartialEq,
+    Eq,
+    Hash,
+    Serialize,
+    Deserialize,
+    BorshSerialize,
+    BorshDeserialize,
 )]
 pub enum ProtocolType {
     #[default]
@@ -72,12 +65,25 @@ pub enum ProtocolType {
     AxiomTrading1,
     AxiomTrading2,
     RaydiumAmmV4,
+    JupiterV6,
+    OrcaWhirlpool,
     Common,
+    /// 通过 `ProtocolRegistry` 注册的自定义协议
+    Custom(String),
 }
 
 /// Event type enumeration
 #[derive(
-    Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize, BorshSerialize, BorshDeserialize,
+    Debug,
+    Clone,
+    Default,
+    PartialEq,
+    Eq,
+    Hash,
+    Serialize,
+    Deserialize,
+    BorshSerialize,
+    BorshDeserialize,
 )]
 pub enum EventType {
     // PumpSwap events
@@ -138,6 +144,9 @@ pub enum EventType {
     // Meteora DBC events
     MeteoraDBCSwap,
     MeteoraDBCSwap2,
+    MeteoraDBCInitializeVirtualPoolWithSplToken,
+    MeteoraDBCInitializeVirtualPoolWithToken2022,
+    MeteoraDBCMigrationDammV2,
 
     // Meteora DAMM v2 events
     MeteoraDAMMv2Swap,
@@ -146,6 +155,19 @@ pub enum EventType {
     AxiomPumpFunBuy,
     AxiomPumpSwapBuy,
 
+    // Jupiter v6 events
+    JupiterRoute,
+    JupiterSwap,
+
+    // Orca Whirlpool events
+    OrcaWhirlpoolSwap,
+    OrcaWhirlpoolSwapV2,
+    OrcaWhirlpoolTwoHopSwap,
+    OrcaWhirlpoolIncreaseLiquidity,
+    OrcaWhirlpoolDecreaseLiquidity,
+    OrcaWhirlpoolInitializePool,
+    OrcaWhirlpoolOpenPosition,
+
     // Account events
     AccountRaydiumAmmV4AmmInfo,
     AccountPumpSwapGlobalConfig,
@@ -161,13 +183,32 @@ pub enum EventType {
     AccountRaydiumClmmTickArrayState,
     AccountRaydiumCpmmAmmConfig,
     AccountRaydiumCpmmPoolState,
+    AccountOrcaWhirlpool,
+    AccountOrcaWhirlpoolTickArray,
 
     NonceAccount,
     TokenAccount,
 
     // Common events
     BlockMeta,
+    /// 交易前后余额变化，需在 `EventTypeFilter` 中显式包含
+    BalanceChange,
+    /// 跨 DEX 统一交易，由交易事件派生，需在 `EventTypeFilter` 中显式包含
+    NormalizedTrade,
+    /// 新代币发射，由各发射平台的创建事件派生，需在 `EventTypeFilter` 中显式包含
+    TokenLaunch,
+    /// 代币毕业迁移，由各发射平台的迁移事件派生，需在 `EventTypeFilter` 中显式包含
+    Graduation,
+    /// 流动性增减，由各 AMM 的存取款事件派生，需在 `EventTypeFilter` 中显式包含
+    LiquidityChange,
+    /// 订阅程序中未能解析的指令，需在 `EventTypeFilter` 中显式包含
+    UnparsedInstruction,
+    /// 订阅协议中未建模指令的原始数据，需在 `EventTypeFilter` 中显式包含
+    RawInstruction,
     Unknown,
+
+    /// 自定义协议事件
+    Custom(String),
 }
 
 pub const ACCOUNT_EVENT_TYPES: &[EventType] = &[
@@ -185,6 +226,8 @@ pub const ACCOUNT_EVENT_TYPES: &[EventType] = &[
     EventType::AccountRaydiumClmmTickArrayState,
     EventType::AccountRaydiumCpmmAmmConfig,
     EventType::AccountRaydiumCpmmPoolState,
+    EventType::AccountOrcaWhirlpool,
+    EventType::AccountOrcaWhirlpoolTickArray,
     EventType::TokenAccount,
     EventType::NonceAccount,
 ];
@@ -252,18 +295,53 @@ impl fmt::Display for EventType {
             }
             EventType::AccountRaydiumCpmmAmmConfig => write!(f, "AccountRaydiumCpmmAmmConfig"),
             EventType::AccountRaydiumCpmmPoolState => write!(f, "AccountRaydiumCpmmPoolState"),
+            EventType::AccountOrcaWhirlpool => write!(f, "AccountOrcaWhirlpool"),
+            EventType::AccountOrcaWhirlpoolTickArray => write!(f, "AccountOrcaWhirlpoolTickArray"),
             EventType::TokenAccount => write!(f, "TokenAccount"),
             EventType::NonceAccount => write!(f, "NonceAccount"),
             EventType::BlockMeta => write!(f, "BlockMeta"),
+            EventType::BalanceChange => write!(f, "BalanceChange"),
+            EventType::NormalizedTrade => write!(f, "NormalizedTrade"),
+            EventType::TokenLaunch => write!(f, "TokenLaunch"),
+            EventType::Graduation => write!(f, "Graduation"),
+            EventType::LiquidityChange => write!(f, "LiquidityChange"),
+            EventType::UnparsedInstruction => write!(f, "UnparsedInstruction"),
+            EventType::RawInstruction => write!(f, "RawInstruction"),
             EventType::PhotonPumpFunBuy => write!(f, "PhotonPumpFunBuy"),
             EventType::PhotonPumpFunSell => write!(f, "PhotonPumpFunSell"),
             EventType::PhotonPumpSwapTrade => write!(f, "PhotonPumpSwapTrade"),
             EventType::MeteoraDBCSwap => write!(f, "MeteoraDBCSwap"),
             EventType::MeteoraDBCSwap2 => write!(f, "MeteoraDBCSwap2"),
+            EventType::MeteoraDBCInitializeVirtualPoolWithSplToken => {
+                write!(f, "MeteoraDBCInitializeVirtualPoolWithSplToken")
+            }
+            EventType::MeteoraDBCInitializeVirtualPoolWithToken2022 => {
+                write!(f, "MeteoraDBCInitializeVirtualPoolWithToken2022")
+            }
+            EventType::MeteoraDBCMigrationDammV2 => write!(f, "MeteoraDBCMigrationDammV2"),
             EventType::MeteoraDAMMv2Swap => write!(f, "MeteoraDAMMv2Swap"),
             EventType::AxiomPumpFunBuy => write!(f, "AxiomPumpFunBuy"),
             EventType::AxiomPumpSwapBuy => write!(f, "AxiomPumpSwapBuy"),
+            EventType::JupiterRoute => write!(f, "JupiterRoute"),
+            EventType::JupiterSwap => write!(f, "JupiterSwap"),
+            EventType::OrcaWhirlpoolSwap => write!(f, "OrcaWhirlpoolSwap"),
+            EventType::OrcaWhirlpoolSwapV2 => write!(f, "OrcaWhirlpoolSwapV2"),
+            EventType::OrcaWhirlpoolTwoHopSwap => write!(f, "OrcaWhirlpoolTwoHopSwap"),
+            EventType::OrcaWhirlpoolIncreaseLiquidity => write!(f, "OrcaWhirlpoolIncreaseLiquidity"),
+            EventType::OrcaWhirlpoolDecreaseLiquidity => write!(f, "OrcaWhirlpoolDecreaseLiquidity"),
+            EventType::OrcaWhirlpoolInitializePool => write!(f, "OrcaWhirlpoolInitializePool"),
+            EventType::OrcaWhirlpoolOpenPosition => write!(f, "OrcaWhirlpoolOpenPosition"),
             EventType::Unknown => write!(f, "Unknown"),
+            EventType::Custom(name) => write!(f, "{}", name),
+        }
+    }
+}
+
+impl fmt::Display for ProtocolType {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            ProtocolType::Custom(name) => write!(f, "{}", name),
+            other => write!(f, "{:?}", other),
         }
     }
 }
@@ -322,6 +400,70 @@ pub struct SwapData {
     pub description: Option<Cow<'static, str>>,
 }
 
+/// 指令在 CPI 调用树中的位置
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct InstructionInvocation {
+    /// 调用栈高度，外层指令为 1；旧版本节点未提供时为 None
+    pub stack_height: Option<u32>,
+    /// 直接调用该指令的程序，外层指令为 None
+    pub parent_program_id: Option<Pubkey>,
+    /// 所属外层指令的程序，即整条调用链的发起者（如 Axiom、Photon、Jupiter 等路由）
+    pub invoking_program_id: Pubkey,
+}
+
+impl InstructionInvocation {
+    /// 是否为外层指令
+    pub fn is_top_level(&self) -> bool {
+        self.parent_program_id.is_none()
+    }
+}
+
+/// 指令在交易中的位置
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct InstructionIndex {
+    pub outer_index: i64,
+    pub inner_index: Option<i64>,
+}
+
+/// 发起该事件的路由（Photon、Axiom 等）
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct RouterInfo {
+    pub router: ProtocolType,
+    pub router_program: Pubkey,
+    /// 路由指令直接发起的 SOL 转账之和（lamports），包含推荐人分成；
+    /// 不含转入 token 账户或签名者自身账户的转账；Jupiter 路由为 0
+    pub router_fee: u64,
+    /// 第一笔转账的接收方，即路由的手续费账户
+    pub fee_recipient: Option<Pubkey>,
+    /// 其余转账的接收方，没有推荐人时为 None
+    pub referral: Option<Pubkey>,
+}
+
+/// 交易级上下文，来自交易 message 与 meta
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct TransactionContext {
+    /// 交易是否成功；ShredStream 等没有 meta 的来源为 None
+    pub success: Option<bool>,
+    pub err: Option<TransactionError>,
+    /// 交易手续费（lamports）
+    pub fee: Option<u64>,
+    pub compute_units_consumed: Option<u64>,
+    /// ComputeBudget SetComputeUnitLimit
+    pub compute_unit_limit: Option<u32>,
+    /// ComputeBudget SetComputeUnitPrice（micro-lamports）
+    pub compute_unit_price: Option<u64>,
+    pub fee_payer: Pubkey,
+    pub signers: Vec<Pubkey>,
+    pub recent_blockhash: Hash,
+}
+
+impl TransactionContext {
+    /// 交易已上链但执行失败
+    pub fn is_failed(&self) -> bool {
+        self.success == Some(false)
+    }
+}
+
 /// Event metadata
 #[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
 pub struct EventMetadata {
@@ -338,6 +480,12 @@ pub struct EventMetadata {
     pub swap_data: Option<SwapData>,
     pub outer_index: i64,
     pub inner_index: Option<i64>,
+    /// 交易级上下文，同一交易的事件共享；账户事件为 None
+    pub tx_context: Option<Arc<TransactionContext>>,
+    /// CPI 调用链信息，账户事件为 None
+    pub invocation: Option<InstructionInvocation>,
+    /// 经路由发起时的路由信息，直接调用或路由自身的事件为 None
+    pub via_router: Option<RouterInfo>,
 }
 
 impl EventMetadata {
@@ -369,6 +517,9 @@ impl EventMetadata {
             outer_index,
             inner_index,
             transaction_index,
+            tx_context: None,
+            invocation: None,
+            via_router: None,
         }
     }
 
@@ -376,350 +527,20 @@ impl EventMetadata {
         self.swap_data = Some(swap_data);
     }
 
-    /// Recycle EventMetadata to object pool
-    pub fn recycle(self) {
-        EVENT_METADATA_POOL.release(self);
+    pub fn set_tx_context(&mut self, tx_context: Arc<TransactionContext>) {
+        self.tx_context = Some(tx_context);
     }
-}
 
-lazy_static::lazy_static! {
-    static ref SOL_MINT: Pubkey = Pubkey::from_str("So11111111111111111111111111111111111111111").unwrap();
-    static ref SYSTEM_PROGRAMS: [Pubkey; 3] = [
-        Pubkey::from_str("TokenkegQfeZyiNwAJbNbGKPFXCWuBvf9Ss623VQ5DA").unwrap(),
-        Pubkey::from_str("TokenzQdBNbLqP5VEhdkAS6EPFLC1PHnBqCXEpPxuEb").unwrap(),
-        Pubkey::from_str("11111111111111111111111111111111").unwrap(),
-    ];
-}
-
-/// Parse token transfer data from next instructions
-pub fn parse_swap_data_from_next_instructions(
-    event: &dyn UnifiedEvent,
-    inner_instruction: &solana_transaction_status::InnerInstructions,
-    current_index: i8,
-    accounts: &[Pubkey],
-) -> Option<SwapData> {
-    let mut swap_data = SwapData {
-        from_mint: Pubkey::default(),
-        to_mint: Pubkey::default(),
-        from_amount: 0,
-        to_amount: 0,
-        description: None,
-    };
-
-    // 先根据 event 取出关键信息
-    let mut user: Option<Pubkey> = None;
-    let mut from_mint: Option<Pubkey> = None;
-    let mut to_mint: Option<Pubkey> = None;
-    let mut user_from_token: Option<Pubkey> = None;
-    let mut user_to_token: Option<Pubkey> = None;
-    let mut from_vault: Option<Pubkey> = None;
-    let mut to_vault: Option<Pubkey> = None;
-
-    match_event!(&*event, {
-        BonkTradeEvent => |e: BonkTradeEvent| {
-            user = Some(e.payer);
-            from_mint = Some(e.base_token_mint);
-            to_mint = Some(e.quote_token_mint);
-            user_from_token = Some(e.user_base_token);
-            user_to_token = Some(e.user_quote_token);
-            from_vault = Some(e.base_vault);
-            to_vault = Some(e.quote_vault);
-        },
-        PumpFunTradeEvent => |e: PumpFunTradeEvent| {
-            swap_data.from_mint = if e.is_buy { *SOL_MINT } else { e.mint };
-            swap_data.to_mint   = if e.is_buy { e.mint } else { *SOL_MINT };
-        },
-        PumpSwapBuyEvent => |e: PumpSwapBuyEvent| {
-            swap_data.from_mint = e.quote_mint;
-            swap_data.to_mint   = e.base_mint;
-        },
-        PumpSwapSellEvent => |e: PumpSwapSellEvent| {
-            swap_data.from_mint = e.base_mint;
-            swap_data.to_mint   = e.quote_mint;
-        },
-        RaydiumCpmmSwapEvent => |e: RaydiumCpmmSwapEvent| {
-            user = Some(e.payer);
-            from_mint = Some(e.input_token_mint);
-            to_mint   = Some(e.output_token_mint);
-            user_from_token = Some(e.input_token_account);
-            user_to_token   = Some(e.output_token_account);
-            from_vault = Some(e.input_vault);
-            to_vault   = Some(e.output_vault);
-        },
-        RaydiumClmmSwapEvent => |e: RaydiumClmmSwapEvent| {
-            user = Some(e.payer);
-            swap_data.description = Some("Unable to get from_mint and to_mint from RaydiumClmmSwapEvent".into());
-            user_from_token = Some(e.input_token_account);
-            user_to_token   = Some(e.output_token_account);
-            from_vault = Some(e.input_vault);
-            to_vault   = Some(e.output_vault);
-        },
-        RaydiumClmmSwapV2Event => |e: RaydiumClmmSwapV2Event| {
-            user = Some(e.payer);
-            from_mint = Some(e.input_vault_mint);
-            to_mint   = Some(e.output_vault_mint);
-            user_from_token = Some(e.input_token_account);
-            user_to_token   = Some(e.output_token_account);
-            from_vault = Some(e.input_vault);
-            to_vault   = Some(e.output_vault);
-        },
-        RaydiumAmmV4SwapEvent => |e: RaydiumAmmV4SwapEvent| {
-            user = Some(e.user_source_owner);
-            swap_data.description = Some("Unable to get from_mint and to_mint from RaydiumAmmV4SwapEvent".into());
-            user_from_token = Some(e.user_source_token_account);
-            user_to_token   = Some(e.user_destination_token_account);
-            from_vault = Some(e.pool_pc_token_account);
-            to_vault   = Some(e.pool_coin_token_account);
-        },
-    });
-
-    let user_to_token = user_to_token.unwrap_or_default();
-    let user_from_token = user_from_token.unwrap_or_default();
-    let to_vault = to_vault.unwrap_or_default();
-    let from_vault = from_vault.unwrap_or_default();
-    let to_mint = to_mint.unwrap_or_default();
-    let from_mint = from_mint.unwrap_or_default();
-
-    // 单次循环完成提取和判断
-    for instruction in inner_instruction.instructions.iter().skip((current_index + 1) as usize) {
-        let compiled = &instruction.instruction;
-        let program_id = accounts[compiled.program_id_index as usize];
-        if !SYSTEM_PROGRAMS.contains(&program_id) {
-            break;
-        }
-        let data = &compiled.data;
-
-        // 使用 SIMD 验证数据格式
-        if !SimdUtils::validate_data_format(data, 8) {
-            continue;
-        }
-
-        let get_pubkey = |i: usize| accounts[compiled.accounts[i] as usize];
-        let (source, destination, amount) = match data[0] {
-            12 if compiled.accounts.len() >= 4 => {
-                let amt = u64::from_le_bytes(data[1..9].try_into().unwrap());
-                (get_pubkey(0), get_pubkey(2), amt)
-            }
-            3 if compiled.accounts.len() >= 3 => {
-                let amt = u64::from_le_bytes(data[1..9].try_into().unwrap());
-                (get_pubkey(0), get_pubkey(1), amt)
-            }
-            2 if compiled.accounts.len() >= 2 => {
-                let amt = u64::from_le_bytes(data[4..12].try_into().unwrap());
-                (get_pubkey(0), get_pubkey(1), amt)
-            }
-            _ => continue,
-        };
-
-        match (source, destination) {
-            (s, d) if s == user_to_token && d == to_vault => {
-                swap_data.from_mint = to_mint;
-                swap_data.from_amount = amount;
-            }
-            (s, d) if s == from_vault && d == user_from_token => {
-                swap_data.to_mint = from_mint;
-                swap_data.to_amount = amount;
-            }
-            (s, d) if s == user_from_token && d == from_vault => {
-                swap_data.from_mint = from_mint;
-                swap_data.from_amount = amount;
-            }
-            (s, d) if s == to_vault && d == user_to_token => {
-                swap_data.to_mint = to_mint;
-                swap_data.to_amount = amount;
-            }
-            (s, d) if s == user_from_token && d == to_vault => {
-                swap_data.from_mint = from_mint;
-                swap_data.from_amount = amount;
-            }
-            (s, d) if s == from_vault && d == user_to_token => {
-                swap_data.to_mint = to_mint;
-                swap_data.to_amount = amount;
-            }
-            _ => {}
-        }
-        if swap_data.from_mint != Pubkey::default() && swap_data.to_mint != Pubkey::default() {
-            break;
-        }
-        if swap_data.from_amount != 0 && swap_data.to_amount != 0 {
-            break;
-        }
+    pub fn set_invocation(&mut self, invocation: InstructionInvocation) {
+        self.invocation = Some(invocation);
     }
 
-    if swap_data.from_mint != Pubkey::default()
-        || swap_data.to_mint != Pubkey::default()
-        || swap_data.from_amount != 0
-        || swap_data.to_amount != 0
-    {
-        Some(swap_data)
-    } else {
-        None
+    pub fn set_via_router(&mut self, via_router: RouterInfo) {
+        self.via_router = Some(via_router);
     }
-}
 
-/// Parse token transfer data from next instructions
-/// TODO: - wait refactor
-pub fn parse_swap_data_from_next_grpc_instructions(
-    event: &dyn UnifiedEvent,
-    inner_instruction: &yellowstone_grpc_proto::prelude::InnerInstructions,
-    current_index: i8,
-    accounts: &[Pubkey],
-) -> Option<SwapData> {
-    let mut swap_data = SwapData {
-        from_mint: Pubkey::default(),
-        to_mint: Pubkey::default(),
-        from_amount: 0,
-        to_amount: 0,
-        description: None,
-    };
-
-    // 先根据 event 取出关键信息
-    let mut user: Option<Pubkey> = None;
-    let mut from_mint: Option<Pubkey> = None;
-    let mut to_mint: Option<Pubkey> = None;
-    let mut user_from_token: Option<Pubkey> = None;
-    let mut user_to_token: Option<Pubkey> = None;
-    let mut from_vault: Option<Pubkey> = None;
-    let mut to_vault: Option<Pubkey> = None;
-
-    match_event!(&*event, {
-        BonkTradeEvent => |e: BonkTradeEvent| {
-            user = Some(e.payer);
-            from_mint = Some(e.base_token_mint);
-            to_mint = Some(e.quote_token_mint);
-            user_from_token = Some(e.user_base_token);
-            user_to_token = Some(e.user_quote_token);
-            from_vault = Some(e.base_vault);
-            to_vault = Some(e.quote_vault);
-        },
-        PumpFunTradeEvent => |e: PumpFunTradeEvent| {
-            swap_data.from_mint = if e.is_buy { *SOL_MINT } else { e.mint };
-            swap_data.to_mint   = if e.is_buy { e.mint } else { *SOL_MINT };
-        },
-        PumpSwapBuyEvent => |e: PumpSwapBuyEvent| {
-            swap_data.from_mint = e.quote_mint;
-            swap_data.to_mint   = e.base_mint;
-        },
-        PumpSwapSellEvent => |e: PumpSwapSellEvent| {
-            swap_data.from_mint = e.base_mint;
-            swap_data.to_mint   = e.quote_mint;
-        },
-        RaydiumCpmmSwapEvent => |e: RaydiumCpmmSwapEvent| {
-            user = Some(e.payer);
-            from_mint = Some(e.input_token_mint);
-            to_mint   = Some(e.output_token_mint);
-            user_from_token = Some(e.input_token_account);
-            user_to_token   = Some(e.output_token_account);
-            from_vault = Some(e.input_vault);
-            to_vault   = Some(e.output_vault);
-        },
-        RaydiumClmmSwapEvent => |e: RaydiumClmmSwapEvent| {
-            user = Some(e.payer);
-            swap_data.description = Some("Unable to get from_mint and to_mint from RaydiumClmmSwapEvent".into());
-            user_from_token = Some(e.input_token_account);
-            user_to_token   = Some(e.output_token_account);
-            from_vault = Some(e.input_vault);
-            to_vault   = Some(e.output_vault);
-        },
-        RaydiumClmmSwapV2Event => |e: RaydiumClmmSwapV2Event| {
-            user = Some(e.payer);
-            from_mint = Some(e.input_vault_mint);
-            to_mint   = Some(e.output_vault_mint);
-            user_from_token = Some(e.input_token_account);
-            user_to_token   = Some(e.output_token_account);
-            from_vault = Some(e.input_vault);
-            to_vault   = Some(e.output_vault);
-        },
-        RaydiumAmmV4SwapEvent => |e: RaydiumAmmV4SwapEvent| {
-            user = Some(e.user_source_owner);
-            swap_data.description = Some("Unable to get from_mint and to_mint from RaydiumAmmV4SwapEvent".into());
-            user_from_token = Some(e.user_source_token_account);
-            user_to_token   = Some(e.user_destination_token_account);
-            from_vault = Some(e.pool_pc_token_account);
-            to_vault   = Some(e.pool_coin_token_account);
-        },
-    });
-
-    let user_to_token = user_to_token.unwrap_or_default();
-    let user_from_token = user_from_token.unwrap_or_default();
-    let to_vault = to_vault.unwrap_or_default();
-    let from_vault = from_vault.unwrap_or_default();
-    let to_mint = to_mint.unwrap_or_default();
-    let from_mint = from_mint.unwrap_or_default();
-
-    // 单次循环完成提取和判断
-    for instruction in inner_instruction.instructions.iter().skip((current_index + 1) as usize) {
-        let compiled = &instruction;
-        let program_id = accounts[compiled.program_id_index as usize];
-        if !SYSTEM_PROGRAMS.contains(&program_id) {
-            break;
-        }
-        let data = &compiled.data;
-
-        // 使用 SIMD 验证数据格式
-        if !SimdUtils::validate_data_format(data, 8) {
-            continue;
-        }
-
-        let get_pubkey = |i: usize| accounts[compiled.accounts[i] as usize];
-        let (source, destination, amount) = match data[0] {
-            12 if compiled.accounts.len() >= 4 => {
-                let amt = u64::from_le_bytes(data[1..9].try_into().unwrap());
-                (get_pubkey(0), get_pubkey(2), amt)
-            }
-            3 if compiled.accounts.len() >= 3 => {
-                let amt = u64::from_le_bytes(data[1..9].try_into().unwrap());
-                (get_pubkey(0), get_pubkey(1), amt)
-            }
-            2 if compiled.accounts.len() >= 2 => {
-                let amt = u64::from_le_bytes(data[4..12].try_into().unwrap());
-                (get_pubkey(0), get_pubkey(1), amt)
-            }
-            _ => continue,
-        };
-
-        match (source, destination) {
-            (s, d) if s == user_to_token && d == to_vault => {
-                swap_data.from_mint = to_mint;
-                swap_data.from_amount = amount;
-            }
-            (s, d) if s == from_vault && d == user_from_token => {
-                swap_data.to_mint = from_mint;
-                swap_data.to_amount = amount;
-            }
-            (s, d) if s == user_from_token && d == from_vault => {
-                swap_data.from_mint = from_mint;
-                swap_data.from_amount = amount;
-            }
-            (s, d) if s == to_vault && d == user_to_token => {
-                swap_data.to_mint = to_mint;
-                swap_data.to_amount = amount;
-            }
-            (s, d) if s == user_from_token && d == to_vault => {
-                swap_data.from_mint = from_mint;
-                swap_data.from_amount = amount;
-            }
-            (s, d) if s == from_vault && d == user_to_token => {
-                swap_data.to_mint = to_mint;
-                swap_data.to_amount = amount;
-            }
-            _ => {}
-        }
-        if swap_data.from_mint != Pubkey::default() && swap_data.to_mint != Pubkey::default() {
-            break;
-        }
-        if swap_data.from_amount != 0 && swap_data.to_amount != 0 {
-            break;
-        }
-    }
-
-    if swap_data.from_mint != Pubkey::default()
-        || swap_data.to_mint != Pubkey::default()
-        || swap_data.from_amount != 0
-        || swap_data.to_amount != 0
-    {
-        Some(swap_data)
-    } else {
-        None
+    /// Recycle EventMetadata to object pool
+    pub fn recycle(self) {
+        EVENT_METADATA_POOL.release(self);
     }
 }
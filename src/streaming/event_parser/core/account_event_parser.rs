@@ -3,8 +3,10 @@ use crate::streaming::common::SimdUtils;
 use crate::streaming::event_parser::common::filter::EventTypeFilter;
 use crate::streaming::event_parser::common::high_performance_clock::elapsed_micros_since;
 use crate::streaming::event_parser::common::{EventMetadata, EventType, ProtocolType};
+use crate::streaming::event_parser::core::protocol_registry::ProtocolRegistry;
 use crate::streaming::event_parser::core::traits::UnifiedEvent;
 use crate::streaming::event_parser::protocols::bonk::parser::BONK_PROGRAM_ID;
+use crate::streaming::event_parser::protocols::orca_whirlpool::parser::ORCA_WHIRLPOOL_PROGRAM_ID;
 use crate::streaming::event_parser::protocols::pumpfun::parser::PUMPFUN_PROGRAM_ID;
 use crate::streaming::event_parser::protocols::pumpswap::parser::PUMPSWAP_PROGRAM_ID;
 use crate::streaming::event_parser::protocols::raydium_amm_v4::parser::RAYDIUM_AMM_V4_PROGRAM_ID;
@@ -200,6 +202,22 @@ impl AccountEventParser {
                     account_parser: crate::streaming::event_parser::protocols::raydium_amm_v4::types::amm_info_parser,
                 },
             ]);
+            map.insert(Protocol::OrcaWhirlpool, vec![
+                AccountEventParseConfig {
+                    program_id: ORCA_WHIRLPOOL_PROGRAM_ID,
+                    protocol_type: ProtocolType::OrcaWhirlpool,
+                    event_type: EventType::AccountOrcaWhirlpool,
+                    account_discriminator: crate::streaming::event_parser::protocols::orca_whirlpool::discriminators::WHIRLPOOL,
+                    account_parser: crate::streaming::event_parser::protocols::orca_whirlpool::types::whirlpool_parser,
+                },
+                AccountEventParseConfig {
+                    program_id: ORCA_WHIRLPOOL_PROGRAM_ID,
+                    protocol_type: ProtocolType::OrcaWhirlpool,
+                    event_type: EventType::AccountOrcaWhirlpoolTickArray,
+                    account_discriminator: crate::streaming::event_parser::protocols::orca_whirlpool::discriminators::TICK_ARRAY,
+                    account_parser: crate::streaming::event_parser::protocols::orca_whirlpool::types::tick_array_parser,
+                },
+            ]);
             map
         });
 
@@ -257,6 +275,43 @@ impl AccountEventParser {
         account: AccountPretty,
         event_type_filter: Option<&EventTypeFilter>,
     ) -> Option<Box<dyn UnifiedEvent>> {
+        Self::parse_account_event_with_registry(protocols, account, event_type_filter, None)
+    }
+
+    /// 解析账户事件，优先匹配 `registry` 中注册的自定义账户解析器
+    pub fn parse_account_event_with_registry(
+        protocols: &[Protocol],
+        account: AccountPretty,
+        event_type_filter: Option<&EventTypeFilter>,
+        registry: Option<&ProtocolRegistry>,
+    ) -> Option<Box<dyn UnifiedEvent>> {
+        // 自定义协议需在通用 TokenAccount 解析之前匹配
+        for config in registry.into_iter().flat_map(|r| r.account_configs()) {
+            if event_type_filter.is_some_and(|filter| !filter.include.contains(&config.event_type))
+            {
+                continue;
+            }
+            if account.owner == config.program_id
+                && SimdUtils::fast_discriminator_match(&account.data, &config.account_discriminator)
+            {
+                let event = (config.account_parser)(
+                    &account,
+                    EventMetadata {
+                        slot: account.slot,
+                        signature: account.signature,
+                        protocol: config.protocol_type.clone(),
+                        event_type: config.event_type.clone(),
+                        program_id: config.program_id,
+                        recv_us: account.recv_us,
+                        ..Default::default()
+                    },
+                );
+                if let Some(mut event) = event {
+                    event.set_handle_us(elapsed_micros_since(account.recv_us));
+                    return Some(event);
+                }
+            }
+        }
         let configs = Self::configs(protocols, event_type_filter);
         for config in configs {
             if config.program_id == Pubkey::default()
@@ -0,0 +1,1034 @@
+use std::{collections::HashMap, path::Path, str::FromStr, sync::Arc};
+
+use anyhow::{anyhow, Context};
+use serde::{ser::SerializeMap, Serialize, Serializer};
+use serde_json::Value;
+use solana_sdk::{hash::hash, pubkey::Pubkey};
+
+use crate::impl_unified_event;
+use crate::streaming::event_parser::{
+    common::{EventMetadata, EventType},
+    core::protocol_registry::CustomProtocol,
+};
+
+/// Anchor `emit_cpi!` 事件指令前缀：sha256("anchor:event")[..8]
+pub const ANCHOR_EVENT_IX_TAG: [u8; 8] = [0xe4, 0x45, 0xa5, 0x2e, 0x51, 0xcb, 0x9a, 0x1d];
+
+/// 解码嵌套类型的最大深度，防止递归类型耗尽栈
+const MAX_DECODE_DEPTH: usize = 64;
+
+/// 计算 Anchor discriminator：sha256("{namespace}:{name}")[..8]
+pub fn anchor_discriminator(namespace: &str, name: &str) -> [u8; 8] {
+    let digest = hash(format!("{}:{}", namespace, name).as_bytes()).to_bytes();
+    let mut discriminator = [0u8; 8];
+    discriminator.copy_from_slice(&digest[..8]);
+    discriminator
+}
+
+/// IDL 字段类型
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum IdlType {
+    Bool,
+    U8,
+    I8,
+    U16,
+    I16,
+    U32,
+    I32,
+    F32,
+    U64,
+    I64,
+    F64,
+    U128,
+    I128,
+    Bytes,
+    String,
+    Pubkey,
+    Option(Box<IdlType>),
+    COption(Box<IdlType>),
+    Vec(Box<IdlType>),
+    Array(Box<IdlType>, usize),
+    Defined(String),
+    /// 暂不支持的类型（泛型、u256 等），解码时返回 None
+    Unsupported(String),
+}
+
+/// 结构体 / 枚举变体的字段
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum IdlFields {
+    Named(Vec<(String, IdlType)>),
+    Tuple(Vec<IdlType>),
+}
+
+/// IDL `types` 中的自定义类型
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum IdlTypeDef {
+    Struct(IdlFields),
+    Enum(Vec<(String, Option<IdlFields>)>),
+    Alias(IdlType),
+}
+
+/// IDL 指令
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct IdlInstruction {
+    pub name: String,
+    pub discriminator: Vec<u8>,
+    /// 展开后的账户名称，嵌套账户以 `parent.child` 表示
+    pub accounts: Vec<String>,
+    pub args: Vec<(String, IdlType)>,
+}
+
+/// IDL 事件或账户，数据布局为 `types` 中的同名类型
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct IdlItem {
+    pub name: String,
+    pub discriminator: Vec<u8>,
+}
+
+/// 动态解码结果
+#[derive(Debug, Clone, Default, PartialEq)]
+pub enum IdlValue {
+    #[default]
+    Null,
+    Bool(bool),
+    U64(u64),
+    I64(i64),
+    U128(u128),
+    I128(i128),
+    F64(f64),
+    String(String),
+    Bytes(Vec<u8>),
+    Pubkey(Pubkey),
+    Array(Vec<IdlValue>),
+    Struct(Vec<(String, IdlValue)>),
+    Enum {
+        variant: String,
+        fields: Option<Box<IdlValue>>,
+    },
+}
+
+impl IdlValue {
+    /// 按字段名取结构体字段
+    pub fn get(&self, name: &str) -> Option<&IdlValue> {
+        match self {
+            IdlValue::Struct(fields) => fields.iter().find(|(n, _)| n == name).map(|(_, v)| v),
+            _ => None,
+        }
+    }
+
+    pub fn as_u64(&self) -> Option<u64> {
+        match self {
+            IdlValue::U64(v) => Some(*v),
+            IdlValue::I64(v) => u64::try_from(*v).ok(),
+            IdlValue::U128(v) => u64::try_from(*v).ok(),
+            IdlValue::I128(v) => u64::try_from(*v).ok(),
+            _ => None,
+        }
+    }
+
+    pub fn as_i64(&self) -> Option<i64> {
+        match self {
+            IdlValue::I64(v) => Some(*v),
+            IdlValue::U64(v) => i64::try_from(*v).ok(),
+            IdlValue::U128(v) => i64::try_from(*v).ok(),
+            IdlValue::I128(v) => i64::try_from(*v).ok(),
+            _ => None,
+        }
+    }
+
+    pub fn as_bool(&self) -> Option<bool> {
+        match self {
+            IdlValue::Bool(v) => Some(*v),
+            _ => None,
+        }
+    }
+
+    pub fn as_str(&self) -> Option<&str> {
+        match self {
+            IdlValue::String(v) => Some(v),
+            _ => None,
+        }
+    }
+
+    pub fn as_pubkey(&self) -> Option<Pubkey> {
+        match self {
+            IdlValue::Pubkey(v) => Some(*v),
+            _ => None,
+        }
+    }
+}
+
+impl Serialize for IdlValue {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        match self {
+            IdlValue::Null => serializer.serialize_none(),
+            IdlValue::Bool(v) => serializer.serialize_bool(*v),
+            IdlValue::U64(v) => serializer.serialize_u64(*v),
+            IdlValue::I64(v) => serializer.serialize_i64(*v),
+            IdlValue::U128(v) => serializer.serialize_u128(*v),
+            IdlValue::I128(v) => serializer.serialize_i128(*v),
+            IdlValue::F64(v) => serializer.serialize_f64(*v),
+            IdlValue::String(v) => serializer.serialize_str(v),
+            IdlValue::Bytes(v) => v.serialize(serializer),
+            IdlValue::Pubkey(v) => v.serialize(serializer),
+            IdlValue::Array(v) => v.serialize(serializer),
+            IdlValue::Struct(fields) => {
+                let mut map = serializer.serialize_map(Some(fields.len()))?;
+                for (name, value) in fields {
+                    map.serialize_entry(name, value)?;
+                }
+                map.end()
+            }
+            IdlValue::Enum { variant, fields: None } => serializer.serialize_str(variant),
+            IdlValue::Enum { variant, fields: Some(fields) } => {
+                let mut map = serializer.serialize_map(Some(1))?;
+                map.serialize_entry(variant, fields)?;
+                map.end()
+            }
+        }
+    }
+}
+
+/// 指令账户
+#[derive(Clone, Debug, Default, PartialEq, Eq, Serialize)]
+pub struct IdlAccountMeta {
+    pub name: String,
+    pub pubkey: Pubkey,
+}
+
+/// IDL 解码的指令事件
+#[derive(Clone, Debug, Default, Serialize)]
+pub struct IdlInstructionEvent {
+    pub metadata: EventMetadata,
+    pub program: String,
+    pub instruction: String,
+    pub args: IdlValue,
+    /// 超出 IDL 定义的账户以 `remaining` 命名
+    pub accounts: Vec<IdlAccountMeta>,
+}
+impl_unified_event!(custom IdlInstructionEvent,);
+
+/// IDL 解码的 CPI 事件（`emit_cpi!`）
+#[derive(Clone, Debug, Default, Serialize)]
+pub struct IdlCpiEvent {
+    pub metadata: EventMetadata,
+    pub program: String,
+    pub event: String,
+    pub data: IdlValue,
+}
+impl_unified_event!(custom IdlCpiEvent,);
+
+/// IDL 解码的账户事件
+#[derive(Clone, Debug, Default, Serialize)]
+pub struct IdlAccountEvent {
+    pub metadata: EventMetadata,
+    pub program: String,
+    pub account: String,
+    pub pubkey: Pubkey,
+    pub executable: bool,
+    pub lamports: u64,
+    pub owner: Pubkey,
+    pub rent_epoch: u64,
+    pub data: IdlValue,
+}
+impl_unified_event!(custom IdlAccountEvent,);
+
+/// 运行时加载的 Anchor IDL
+///
+/// 同时支持 Anchor 0.30+（带 `discriminator` 字段）与旧版 IDL（由名称计算 discriminator）。
+/// 仅支持 borsh 序列化，zero-copy 账户的对齐填充不会被处理。
+#[derive(Debug, Clone)]
+pub struct AnchorIdl {
+    pub name: String,
+    pub address: Option<Pubkey>,
+    pub instructions: Vec<IdlInstruction>,
+    pub events: Vec<IdlItem>,
+    pub accounts: Vec<IdlItem>,
+    pub types: HashMap<String, IdlTypeDef>,
+}
+
+impl AnchorIdl {
+    pub fn from_file(path: impl AsRef<Path>) -> anyhow::Result<Self> {
+        let path = path.as_ref();
+        let json = std::fs::read_to_string(path)
+            .with_context(|| format!("failed to read IDL file {}", path.display()))?;
+        Self::from_json(&json)
+    }
+
+    pub fn from_json(json: &str) -> anyhow::Result<Self> {
+        let root: Value = serde_json::from_str(json).context("invalid IDL json")?;
+        let name = root
+            .get("metadata")
+            .and_then(|m| m.get("name"))
+            .or_else(|| root.get("name"))
+            .and_then(Value::as_str)
+            .ok_or_else(|| anyhow!("IDL is missing a program name"))?
+            .to_string();
+        let address = root
+            .get("address")
+            .or_else(|| root.get("metadata").and_then(|m| m.get("address")))
+            .and_then(Value::as_str)
+            .map(Pubkey::from_str)
+            .transpose()
+            .context("invalid IDL address")?;
+
+        let mut types = HashMap::new();
+        for ty in array(&root, "types") {
+            types.insert(required_name(ty)?, parse_type_def(ty.get("type"))?);
+        }
+
+        let mut instructions = Vec::new();
+        for ix in array(&root, "instructions") {
+            let name = required_name(ix)?;
+            let discriminator = discriminator_or(ix, || {
+                anchor_discriminator("global", &to_snake_case(&name)).to_vec()
+            })?;
+            let mut accounts = Vec::new();
+            flatten_accounts(array(ix, "accounts"), "", &mut accounts)?;
+            let args = parse_named_fields(array(ix, "args"))?;
+            instructions.push(IdlInstruction { name, discriminator, accounts, args });
+        }
+
+        // 旧版 IDL 的账户与事件布局内联定义，统一放入 types
+        let mut accounts = Vec::new();
+        for account in array(&root, "accounts") {
+            let name = required_name(account)?;
+            if let Some(ty) = account.get("type") {
+                types.insert(name.clone(), parse_type_def(Some(ty))?);
+            }
+            let discriminator =
+                discriminator_or(account, || anchor_discriminator("account", &name).to_vec())?;
+            accounts.push(IdlItem { name, discriminator });
+        }
+
+        let mut events = Vec::new();
+        for event in array(&root, "events") {
+            let name = required_name(event)?;
+            if let Some(fields) = event.get("fields").and_then(Value::as_array) {
+                types.insert(
+                    name.clone(),
+                    IdlTypeDef::Struct(IdlFields::Named(parse_named_fields(fields)?)),
+                );
+            }
+            let discriminator =
+                discriminator_or(event, || anchor_discriminator("event", &name).to_vec())?;
+            events.push(IdlItem { name, discriminator });
+        }
+
+        Ok(Self { name, address, instructions, events, accounts, types })
+    }
+
+    /// 解码指令数据（包含 discriminator）
+    pub fn decode_instruction(&self, data: &[u8]) -> Option<(&IdlInstruction, IdlValue)> {
+        let ix = self.instructions.iter().find(|ix| data.starts_with(&ix.discriminator))?;
+        let args = self.decode_args(ix, &data[ix.discriminator.len()..])?;
+        Some((ix, args))
+    }
+
+    /// 解码事件数据（以事件 discriminator 开头，不含 `ANCHOR_EVENT_IX_TAG`）
+    pub fn decode_event(&self, data: &[u8]) -> Option<(&IdlItem, IdlValue)> {
+        let event = self.events.iter().find(|e| data.starts_with(&e.discriminator))?;
+        let value = self.decode_defined(&event.name, &data[event.discriminator.len()..])?;
+        Some((event, value))
+    }
+
+    /// 解码账户数据（包含 discriminator）
+    pub fn decode_account(&self, data: &[u8]) -> Option<(&IdlItem, IdlValue)> {
+        let account = self.accounts.iter().find(|a| data.starts_with(&a.discriminator))?;
+        let value = self.decode_defined(&account.name, &data[account.discriminator.len()..])?;
+        Some((account, value))
+    }
+
+    /// 按 `types` 中的类型名解码数据
+    pub fn decode_defined(&self, type_name: &str, data: &[u8]) -> Option<IdlValue> {
+        let mut reader = BorshReader::new(data);
+        self.decode_type(&IdlType::Defined(type_name.to_string()), &mut reader, 0)
+    }
+
+    /// 转换为可注册到 `ProtocolRegistry` 的协议
+    ///
+    /// `program_id` 为空时使用 IDL 中的地址。每个指令、CPI 事件和账户类型分别生成
+    /// `EventType::Custom(name)` 的事件。
+    pub fn into_protocol(self, program_id: Option<Pubkey>) -> anyhow::Result<CustomProtocol> {
+        let program_id = program_id
+            .or(self.address)
+            .ok_or_else(|| anyhow!("IDL {} has no address, program_id is required", self.name))?;
+        let idl = Arc::new(self);
+        let mut protocol = CustomProtocol::new(idl.name.clone(), program_id);
+
+        for (index, ix) in idl.instructions.iter().enumerate() {
+            let idl = Arc::clone(&idl);
+            protocol = protocol.with_instruction_parser(
+                EventType::Custom(ix.name.clone()),
+                &ix.discriminator,
+                move |data, accounts, metadata| {
+                    let ix = &idl.instructions[index];
+                    let args = idl.decode_args(ix, data)?;
+                    Some(Box::new(IdlInstructionEvent {
+                        metadata,
+                        program: idl.name.clone(),
+                        instruction: ix.name.clone(),
+                        args,
+                        accounts: name_accounts(&ix.accounts, accounts),
+                    }))
+                },
+            );
+        }
+
+        // CPI 事件是程序对自身的内联调用，数据为 tag + 事件 discriminator + 事件内容
+        for (index, event) in idl.events.iter().enumerate() {
+            let idl = Arc::clone(&idl);
+            let discriminator = [ANCHOR_EVENT_IX_TAG.as_slice(), &event.discriminator].concat();
+            protocol = protocol.with_instruction_parser(
+                EventType::Custom(event.name.clone()),
+                &discriminator,
+                move |data, _accounts, metadata| {
+                    let event = &idl.events[index];
+                    Some(Box::new(IdlCpiEvent {
+                        metadata,
+                        program: idl.name.clone(),
+                        event: event.name.clone(),
+                        data: idl.decode_defined(&event.name, data)?,
+                    }))
+                },
+            );
+        }
+
+        for (index, account) in idl.accounts.iter().enumerate() {
+            let idl = Arc::clone(&idl);
+            protocol = protocol.with_account_parser(
+                EventType::Custom(account.name.clone()),
+                &account.discriminator,
+                move |account, metadata| {
+                    let item = &idl.accounts[index];
+                    let data = idl.decode_defined(
+                        &item.name,
+                        account.data.get(item.discriminator.len()..)?,
+                    )?;
+                    Some(Box::new(IdlAccountEvent {
+                        metadata,
+                        program: idl.name.clone(),
+                        account: item.name.clone(),
+                        pubkey: account.pubkey,
+                        executable: account.executable,
+                        lamports: account.lamports,
+                        owner: account.owner,
+                        rent_epoch: account.rent_epoch,
+                        data,
+                    }))
+                },
+            );
+        }
+
+        Ok(protocol)
+    }
+
+    fn decode_args(&self, ix: &IdlInstruction, data: &[u8]) -> Option<IdlValue> {
+        let mut reader = BorshReader::new(data);
+        let mut values = Vec::with_capacity(ix.args.len());
+        for (name, ty) in &ix.args {
+            values.push((name.clone(), self.decode_type(ty, &mut reader, 0)?));
+        }
+        Some(IdlValue::Struct(values))
+    }
+
+    fn decode_type(
+        &self,
+        ty: &IdlType,
+        reader: &mut BorshReader,
+        depth: usize,
+    ) -> Option<IdlValue> {
+        if depth > MAX_DECODE_DEPTH {
+            return None;
+        }
+        let value = match ty {
+            IdlType::Bool => IdlValue::Bool(reader.read::<1>()?[0] != 0),
+            IdlType::U8 => IdlValue::U64(reader.read::<1>()?[0] as u64),
+            IdlType::I8 => IdlValue::I64(reader.read::<1>()?[0] as i8 as i64),
+            IdlType::U16 => IdlValue::U64(u16::from_le_bytes(reader.read()?) as u64),
+            IdlType::I16 => IdlValue::I64(i16::from_le_bytes(reader.read()?) as i64),
+            IdlType::U32 => IdlValue::U64(u32::from_le_bytes(reader.read()?) as u64),
+            IdlType::I32 => IdlValue::I64(i32::from_le_bytes(reader.read()?) as i64),
+            IdlType::F32 => IdlValue::F64(f32::from_le_bytes(reader.read()?) as f64),
+            IdlType::U64 => IdlValue::U64(u64::from_le_bytes(reader.read()?)),
+            IdlType::I64 => IdlValue::I64(i64::from_le_bytes(reader.read()?)),
+            IdlType::F64 => IdlValue::F64(f64::from_le_bytes(reader.read()?)),
+            IdlType::U128 => IdlValue::U128(u128::from_le_bytes(reader.read()?)),
+            IdlType::I128 => IdlValue::I128(i128::from_le_bytes(reader.read()?)),
+            IdlType::Bytes => {
+                let len = reader.read_len()?;
+                IdlValue::Bytes(reader.take(len)?.to_vec())
+            }
+            IdlType::String => {
+                let len = reader.read_len()?;
+                IdlValue::String(String::from_utf8(reader.take(len)?.to_vec()).ok()?)
+            }
+            IdlType::Pubkey => IdlValue::Pubkey(Pubkey::new_from_array(reader.read()?)),
+            IdlType::Option(inner) => match reader.read::<1>()?[0] {
+                0 => IdlValue::Null,
+                _ => self.decode_type(inner, reader, depth + 1)?,
+            },
+            IdlType::COption(inner) => match u32::from_le_bytes(reader.read()?) {
+                0 => IdlValue::Null,
+                _ => self.decode_type(inner, reader, depth + 1)?,
+            },
+            IdlType::Vec(inner) => {
+                let len = reader.read_len()?;
+                self.decode_array(inner, len, reader, depth)?
+            }
+            IdlType::Array(inner, len) => {
+                if **inner == IdlType::U8 {
+                    IdlValue::Bytes(reader.take(*len)?.to_vec())
+                } else {
+                    self.decode_array(inner, *len, reader, depth)?
+                }
+            }
+            IdlType::Defined(name) => match self.types.get(name)? {
+                IdlTypeDef::Struct(fields) => self.decode_fields(fields, reader, depth)?,
+                IdlTypeDef::Enum(variants) => {
+                    let (variant, fields) = variants.get(reader.read::<1>()?[0] as usize)?;
+                    let fields = match fields {
+                        Some(fields) => Some(Box::new(self.decode_fields(fields, reader, depth)?)),
+                        None => None,
+                    };
+                    IdlValue::Enum { variant: variant.clone(), fields }
+                }
+                IdlTypeDef::Alias(inner) => self.decode_type(inner, reader, depth + 1)?,
+            },
+            IdlType::Unsupported(_) => return None,
+        };
+        Some(value)
+    }
+
+    fn decode_array(
+        &self,
+        inner: &IdlType,
+        len: usize,
+        reader: &mut BorshReader,
+        depth: usize,
+    ) -> Option<IdlValue> {
+        // 每个元素至少占 1 字节，避免恶意长度导致大量分配
+        if len > reader.remaining() {
+            return None;
+        }
+        let mut values = Vec::with_capacity(len);
+        for _ in 0..len {
+            values.push(self.decode_type(inner, reader, depth + 1)?);
+        }
+        Some(IdlValue::Array(values))
+    }
+
+    fn decode_fields(
+        &self,
+        fields: &IdlFields,
+        reader: &mut BorshReader,
+        depth: usize,
+    ) -> Option<IdlValue> {
+        match fields {
+            IdlFields::Named(fields) => {
+                let mut values = Vec::with_capacity(fields.len());
+                for (name, ty) in fields {
+                    values.push((name.clone(), self.decode_type(ty, reader, depth + 1)?));
+                }
+                Some(IdlValue::Struct(values))
+            }
+            IdlFields::Tuple(types) => {
+                let mut values = Vec::with_capacity(types.len());
+                for ty in types {
+                    values.push(self.decode_type(ty, reader, depth + 1)?);
+                }
+                Some(IdlValue::Array(values))
+            }
+        }
+    }
+}
+
+/// borsh 顺序读取器
+struct BorshReader<'a> {
+    data: &'a [u8],
+    offset: usize,
+}
+
+impl<'a> BorshReader<'a> {
+    fn new(data: &'a [u8]) -> Self {
+        Self { data, offset: 0 }
+    }
+
+    fn remaining(&self) -> usize {
+        self.data.len() - self.offset
+    }
+
+    fn take(&mut self, len: usize) -> Option<&'a [u8]> {
+        let end = self.offset.checked_add(len)?;
+        let bytes = self.data.get(self.offset..end)?;
+        self.offset = end;
+        Some(bytes)
+    }
+
+    fn read<const N: usize>(&mut self) -> Option<[u8; N]> {
+        self.take(N)?.try_into().ok()
+    }
+
+    fn read_len(&mut self) -> Option<usize> {
+        Some(u32::from_le_bytes(self.read()?) as usize)
+    }
+}
+
+fn array<'a>(value: &'a Value, key: &str) -> &'a [Value] {
+    value.get(key).and_then(Value::as_array).map(Vec::as_slice).unwrap_or(&[])
+}
+
+fn required_name(value: &Value) -> anyhow::Result<String> {
+    value
+        .get("name")
+        .and_then(Value::as_str)
+        .map(str::to_string)
+        .ok_or_else(|| anyhow!("IDL item is missing a name: {}", value))
+}
+
+fn discriminator_or(value: &Value, default: impl FnOnce() -> Vec<u8>) -> anyhow::Result<Vec<u8>> {
+    match value.get("discriminator") {
+        Some(discriminator) => serde_json::from_value(discriminator.clone())
+            .with_context(|| format!("invalid discriminator: {}", discriminator)),
+        None => Ok(default()),
+    }
+}
+
+fn flatten_accounts(accounts: &[Value], prefix: &str, out: &mut Vec<String>) -> anyhow::Result<()> {
+    for account in accounts {
+        let name = format!("{}{}", prefix, required_name(account)?);
+        match account.get("accounts").and_then(Value::as_array) {
+            Some(nested) => flatten_accounts(nested, &format!("{}.", name), out)?,
+            None => out.push(name),
+        }
+    }
+    Ok(())
+}
+
+fn name_accounts(names: &[String], accounts: &[Pubkey]) -> Vec<IdlAccountMeta> {
+    accounts
+        .iter()
+        .enumerate()
+        .map(|(i, pubkey)| IdlAccountMeta {
+            name: names.get(i).cloned().unwrap_or_else(|| "remaining".to_string()),
+            pubkey: *pubkey,
+        })
+        .collect()
+}
+
+fn parse_named_fields(fields: &[Value]) -> anyhow::Result<Vec<(String, IdlType)>> {
+    fields
+        .iter()
+        .map(|field| -> anyhow::Result<(String, IdlType)> {
+            let ty = field.get("type").ok_or_else(|| anyhow!("IDL field is missing a type"))?;
+            Ok((required_name(field)?, parse_type(ty)))
+        })
+        .collect()
+}
+
+/// 字段列表可能是命名字段 `{name, type}` 或元组类型
+fn parse_fields(fields: &[Value]) -> anyhow::Result<IdlFields> {
+    if fields.iter().all(|field| field.get("name").is_some() && field.get("type").is_some()) {
+        Ok(IdlFields::Named(parse_named_fields(fields)?))
+    } else {
+        Ok(IdlFields::Tuple(fields.iter().map(parse_type).collect()))
+    }
+}
+
+fn parse_type_def(ty: Option<&Value>) -> anyhow::Result<IdlTypeDef> {
+    let ty = ty.ok_or_else(|| anyhow!("IDL type definition is missing a type"))?;
+    match ty.get("kind").and_then(Value::as_str) {
+        Some("struct") => Ok(IdlTypeDef::Struct(parse_fields(array(ty, "fields"))?)),
+        Some("enum") => {
+            let variants = array(ty, "variants")
+                .iter()
+                .map(|variant| -> anyhow::Result<(String, Option<IdlFields>)> {
+                    let fields = match variant.get("fields").and_then(Value::as_array) {
+                        Some(fields) => Some(parse_fields(fields)?),
+                        None => None,
+                    };
+                    Ok((required_name(variant)?, fields))
+                })
+                .collect::<anyhow::Result<_>>()?;
+            Ok(IdlTypeDef::Enum(variants))
+        }
+        Some("type") | Some("alias") => Ok(IdlTypeDef::Alias(parse_type(
+            ty.get("alias").or_else(|| ty.get("value")).unwrap_or(&Value::Null),
+        ))),
+        other => Err(anyhow!("unsupported IDL type kind: {:?}", other)),
+    }
+}
+
+fn parse_type(ty: &Value) -> IdlType {
+    if let Some(name) = ty.as_str() {
+        return match name {
+            "bool" => IdlType::Bool,
+            "u8" => IdlType::U8,
+            "i8" => IdlType::I8,
+            "u16" => IdlType::U16,
+            "i16" => IdlType::I16,
+            "u32" => IdlType::U32,
+            "i32" => IdlType::I32,
+            "f32" => IdlType::F32,
+            "u64" => IdlType::U64,
+            "i64" => IdlType::I64,
+            "f64" => IdlType::F64,
+            "u128" => IdlType::U128,
+            "i128" => IdlType::I128,
+            "bytes" => IdlType::Bytes,
+            "string" => IdlType::String,
+            "pubkey" | "publicKey" => IdlType::Pubkey,
+            other => IdlType::Unsupported(other.to_string()),
+        };
+    }
+    if let Some(inner) = ty.get("option") {
+        return IdlType::Option(Box::new(parse_type(inner)));
+    }
+    if let Some(inner) = ty.get("coption") {
+        return IdlType::COption(Box::new(parse_type(inner)));
+    }
+    if let Some(inner) = ty.get("vec") {
+        return IdlType::Vec(Box::new(parse_type(inner)));
+    }
+    if let Some([inner, len]) = ty.get("array").and_then(Value::as_array).map(Vec::as_slice) {
+        return match len.as_u64() {
+            Some(len) => IdlType::Array(Box::new(parse_type(inner)), len as usize),
+            None => IdlType::Unsupported(ty.to_string()),
+        };
+    }
+    if let Some(defined) = ty.get("defined") {
+        let name = defined.as_str().or_else(|| defined.get("name").and_then(Value::as_str));
+        if let Some(name) = name {
+            return IdlType::Defined(name.to_string());
+        }
+    }
+    IdlType::Unsupported(ty.to_string())
+}
+
+/// 旧版 IDL 指令名为 camelCase，discriminator 按 Rust 函数名（snake_case）计算
+///
+/// 分词规则与 Anchor 使用的 heck 一致：连续大写视为一个词（`setNFTOwner` -> `set_nft_owner`），
+/// 数字沿用前一个字母的大小写。
+fn to_snake_case(name: &str) -> String {
+    let mut words: Vec<String> = Vec::new();
+    for part in name.split(|c: char| !c.is_alphanumeric()).filter(|part| !part.is_empty()) {
+        let chars: Vec<char> = part.chars().collect();
+        let mut word = String::new();
+        // 上一个字母的大小写，词首两者均为 false
+        let (mut lower, mut upper) = (false, false);
+        for (i, &c) in chars.iter().enumerate() {
+            if c.is_uppercase() {
+                let next_lower = chars.get(i + 1).is_some_and(|next| next.is_lowercase());
+                if lower || (upper && next_lower) {
+                    words.push(std::mem::take(&mut word));
+                }
+                (lower, upper) = (false, true);
+            } else if c.is_lowercase() {
+                (lower, upper) = (true, false);
+            }
+            word.extend(c.to_lowercase());
+        }
+        words.push(word);
+    }
+    words.join("_")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// 旧版（Anchor < 0.30）IDL：无 discriminator，账户与事件布局内联
+    const LEGACY_IDL: &str = r#"{
+        "version": "0.1.0",
+        "name": "legacy",
+        "instructions": [
+            {
+                "name": "setNFTOwner",
+                "accounts": [
+                    { "name": "authority", "isMut": false, "isSigner": true },
+                    {
+                        "name": "pool",
+                        "accounts": [
+                            { "name": "state", "isMut": true, "isSigner": false },
+                            { "name": "vault", "isMut": true, "isSigner": false }
+                        ]
+                    }
+                ],
+                "args": [
+                    { "name": "amount", "type": "u64" },
+                    { "name": "owner", "type": "publicKey" },
+                    { "name": "memo", "type": { "option": "string" } }
+                ]
+            },
+            { "name": "initialize", "accounts": [], "args": [] }
+        ],
+        "accounts": [
+            {
+                "name": "BondingCurve",
+                "type": {
+                    "kind": "struct",
+                    "fields": [
+                        { "name": "complete", "type": "bool" },
+                        { "name": "ticks", "type": { "vec": "i16" } }
+                    ]
+                }
+            }
+        ],
+        "events": [
+            {
+                "name": "TradeEvent",
+                "fields": [
+                    { "name": "side", "type": { "defined": "Side" }, "index": false },
+                    { "name": "price", "type": "u128", "index": false }
+                ]
+            }
+        ],
+        "types": [
+            {
+                "name": "Side",
+                "type": {
+                    "kind": "enum",
+                    "variants": [
+                        { "name": "Buy" },
+                        { "name": "Sell", "fields": [{ "name": "min_out", "type": "u64" }] }
+                    ]
+                }
+            }
+        ]
+    }"#;
+
+    /// Anchor 0.30+ IDL：显式 discriminator，布局均在 types 中
+    const IDL: &str = r#"{
+        "address": "11111111111111111111111111111111",
+        "metadata": { "name": "modern", "version": "0.1.0", "spec": "0.1.0" },
+        "instructions": [
+            {
+                "name": "swap",
+                "discriminator": [1, 2, 3, 4, 5, 6, 7, 8],
+                "accounts": [{ "name": "user", "signer": true }],
+                "args": [{ "name": "params", "type": { "defined": { "name": "SwapParams" } } }]
+            }
+        ],
+        "accounts": [{ "name": "Node", "discriminator": [9, 9, 9, 9, 9, 9, 9, 9] }],
+        "events": [{ "name": "Swapped", "discriminator": [7, 7, 7, 7, 7, 7, 7, 7] }],
+        "types": [
+            {
+                "name": "SwapParams",
+                "type": {
+                    "kind": "struct",
+                    "fields": [
+                        { "name": "amounts", "type": { "array": ["u64", 2] } },
+                        { "name": "seed", "type": { "array": ["u8", 4] } },
+                        { "name": "hint", "type": { "option": { "vec": "u8" } } },
+                        { "name": "legs", "type": { "vec": { "defined": { "name": "Leg" } } } },
+                        { "name": "fee", "type": { "coption": "u16" } }
+                    ]
+                }
+            },
+            {
+                "name": "Leg",
+                "type": { "kind": "struct", "fields": ["i8", { "defined": { "name": "Bps" } }] }
+            },
+            { "name": "Bps", "type": { "kind": "type", "alias": "u16" } },
+            {
+                "name": "Node",
+                "type": {
+                    "kind": "struct",
+                    "fields": [{ "name": "next", "type": { "option": { "defined": { "name": "Node" } } } }]
+                }
+            },
+            {
+                "name": "Swapped",
+                "type": {
+                    "kind": "struct",
+                    "fields": [
+                        { "name": "name", "type": "string" },
+                        { "name": "amounts", "type": { "vec": "u64" } }
+                    ]
+                }
+            }
+        ]
+    }"#;
+
+    fn borsh_string(s: &str) -> Vec<u8> {
+        [(s.len() as u32).to_le_bytes().as_slice(), s.as_bytes()].concat()
+    }
+
+    #[test]
+    fn snake_case_matches_anchor() {
+        for (camel, snake) in [
+            ("initialize", "initialize"),
+            ("createPool", "create_pool"),
+            ("swapBaseIn", "swap_base_in"),
+            ("setNFTOwner", "set_nft_owner"),
+            ("HTTPServer", "http_server"),
+            ("migrateToDammV2", "migrate_to_damm_v2"),
+            ("withdrawV2Fees", "withdraw_v2_fees"),
+            ("already_snake", "already_snake"),
+        ] {
+            assert_eq!(to_snake_case(camel), snake, "{camel}");
+        }
+    }
+
+    #[test]
+    fn known_discriminators() {
+        assert_eq!(
+            anchor_discriminator("global", "initialize"),
+            [175, 175, 109, 31, 13, 152, 155, 237]
+        );
+        assert_eq!(anchor_discriminator("global", "buy"), [102, 6, 61, 18, 1, 218, 235, 234]);
+        assert_eq!(
+            anchor_discriminator("account", "BondingCurve"),
+            [23, 183, 248, 55, 96, 216, 172, 96]
+        );
+        assert_eq!(
+            anchor_discriminator("event", "TradeEvent"),
+            [189, 219, 127, 211, 78, 230, 97, 238]
+        );
+    }
+
+    #[test]
+    fn legacy_idl_derives_discriminators_and_decodes() {
+        let idl = AnchorIdl::from_json(LEGACY_IDL).expect("legacy idl");
+        assert_eq!(idl.name, "legacy");
+        assert_eq!(idl.address, None);
+
+        let ix = &idl.instructions[0];
+        assert_eq!(ix.discriminator, [182, 123, 201, 13, 213, 137, 82, 21]);
+        assert_eq!(ix.accounts, ["authority", "pool.state", "pool.vault"]);
+        assert_eq!(idl.instructions[1].discriminator, [175, 175, 109, 31, 13, 152, 155, 237]);
+        assert_eq!(idl.accounts[0].discriminator, [23, 183, 248, 55, 96, 216, 172, 96]);
+        assert_eq!(idl.events[0].discriminator, [189, 219, 127, 211, 78, 230, 97, 238]);
+
+        let owner = Pubkey::new_from_array([3; 32]);
+        let data = [
+            ix.discriminator.as_slice(),
+            &42u64.to_le_bytes(),
+            owner.as_ref(),
+            &[1],
+            &borsh_string("gm"),
+        ]
+        .concat();
+        let (decoded_ix, args) = idl.decode_instruction(&data).expect("instruction");
+        assert_eq!(decoded_ix.name, "setNFTOwner");
+        assert_eq!(args.get("amount").and_then(IdlValue::as_u64), Some(42));
+        assert_eq!(args.get("owner").and_then(IdlValue::as_pubkey), Some(owner));
+        assert_eq!(args.get("memo").and_then(IdlValue::as_str), Some("gm"));
+
+        let none_memo =
+            [ix.discriminator.as_slice(), &42u64.to_le_bytes(), owner.as_ref(), &[0]].concat();
+        let (_, args) = idl.decode_instruction(&none_memo).expect("instruction");
+        assert_eq!(args.get("memo"), Some(&IdlValue::Null));
+
+        let account = [
+            idl.accounts[0].discriminator.as_slice(),
+            &[1],
+            &2u32.to_le_bytes(),
+            &(-5i16).to_le_bytes(),
+            &7i16.to_le_bytes(),
+        ]
+        .concat();
+        let (_, value) = idl.decode_account(&account).expect("account");
+        assert_eq!(value.get("complete").and_then(IdlValue::as_bool), Some(true));
+        assert_eq!(
+            value.get("ticks"),
+            Some(&IdlValue::Array(vec![IdlValue::I64(-5), IdlValue::I64(7)]))
+        );
+
+        let event = [
+            idl.events[0].discriminator.as_slice(),
+            &[1],
+            &9u64.to_le_bytes(),
+            &u128::MAX.to_le_bytes(),
+        ]
+        .concat();
+        let (_, value) = idl.decode_event(&event).expect("event");
+        let sell = IdlValue::Struct(vec![("min_out".to_string(), IdlValue::U64(9))]);
+        assert_eq!(
+            value.get("side"),
+            Some(&IdlValue::Enum { variant: "Sell".to_string(), fields: Some(Box::new(sell)) })
+        );
+        assert_eq!(value.get("price"), Some(&IdlValue::U128(u128::MAX)));
+    }
+
+    #[test]
+    fn idl_decodes_nested_types() {
+        let idl = AnchorIdl::from_json(IDL).expect("idl");
+        assert_eq!(idl.name, "modern");
+        assert_eq!(idl.address, Some(Pubkey::default()));
+
+        let data = [
+            [1u8, 2, 3, 4, 5, 6, 7, 8].as_slice(),
+            &10u64.to_le_bytes(),
+            &20u64.to_le_bytes(),
+            &[0xde, 0xad, 0xbe, 0xef],
+            &[1],
+            &borsh_string("hi"),
+            &1u32.to_le_bytes(),
+            &[(-3i8) as u8],
+            &250u16.to_le_bytes(),
+            &1u32.to_le_bytes(),
+            &30u16.to_le_bytes(),
+        ]
+        .concat();
+        let (_, args) = idl.decode_instruction(&data).expect("instruction");
+        let params = args.get("params").expect("params");
+        assert_eq!(
+            params.get("amounts"),
+            Some(&IdlValue::Array(vec![IdlValue::U64(10), IdlValue::U64(20)]))
+        );
+        assert_eq!(params.get("seed"), Some(&IdlValue::Bytes(vec![0xde, 0xad, 0xbe, 0xef])));
+        assert_eq!(
+            params.get("hint"),
+            Some(&IdlValue::Array(vec![IdlValue::U64(b'h' as u64), IdlValue::U64(b'i' as u64)]))
+        );
+        assert_eq!(
+            params.get("legs"),
+            Some(&IdlValue::Array(vec![IdlValue::Array(vec![
+                IdlValue::I64(-3),
+                IdlValue::U64(250)
+            ])]))
+        );
+        assert_eq!(params.get("fee").and_then(IdlValue::as_u64), Some(30));
+
+        let event =
+            [[7u8; 8].as_slice(), &borsh_string("pool"), &1u32.to_le_bytes(), &5u64.to_le_bytes()]
+                .concat();
+        let (item, value) = idl.decode_event(&event).expect("event");
+        assert_eq!(item.name, "Swapped");
+        assert_eq!(value.get("name").and_then(IdlValue::as_str), Some("pool"));
+        assert_eq!(value.get("amounts"), Some(&IdlValue::Array(vec![IdlValue::U64(5)])));
+    }
+
+    #[test]
+    fn truncated_data_is_rejected() {
+        let idl = AnchorIdl::from_json(IDL).expect("idl");
+        let data = [[1u8, 2, 3, 4, 5, 6, 7, 8].as_slice(), &10u64.to_le_bytes()].concat();
+        assert!(idl.decode_instruction(&data).is_none());
+        assert!(idl.decode_event(&[7u8; 8]).is_none());
+    }
+
+    #[test]
+    fn recursion_depth_is_bounded() {
+        let idl = AnchorIdl::from_json(IDL).expect("idl");
+        let (_, shallow) =
+            idl.decode_account(&[[9u8; 8].as_slice(), &[1, 1, 0]].concat()).expect("account");
+        let inner = shallow.get("next").and_then(|next| next.get("next")).expect("two levels");
+        assert_eq!(inner.get("next"), Some(&IdlValue::Null));
+
+        // 数据完整的链表，仅因嵌套超过 MAX_DECODE_DEPTH 被拒绝
+        let deep = [[9u8; 8].as_slice(), &[1; MAX_DECODE_DEPTH], &[0]].concat();
+        assert!(idl.decode_account(&deep).is_none());
+    }
+
+    #[test]
+    fn oversized_lengths_do_not_allocate() {
+        let idl = AnchorIdl::from_json(IDL).expect("idl");
+        // vec / string 长度为 u32::MAX，但剩余数据只有几个字节
+        let vec_len =
+            [[7u8; 8].as_slice(), &borsh_string("a"), &u32::MAX.to_le_bytes(), &[0; 8]].concat();
+        assert!(idl.decode_event(&vec_len).is_none());
+        let string_len = [[7u8; 8].as_slice(), &u32::MAX.to_le_bytes(), b"abc"].concat();
+        assert!(idl.decode_event(&string_len).is_none());
+    }
+}
@@ -0,0 +1,274 @@
+use std::str::FromStr;
+
+use serde::{Deserialize, Serialize};
+use solana_sdk::pubkey::Pubkey;
+use solana_transaction_status::{option_serializer::OptionSerializer, UiTransactionStatusMeta};
+use yellowstone_grpc_proto::prelude::TransactionStatusMeta;
+
+use crate::{impl_unified_event, streaming::event_parser::common::EventMetadata};
+
+/// 原生 SOL 余额变化使用的 mint（与 WSOL mint 相同，以 `is_native` 区分）
+pub const NATIVE_SOL_MINT: Pubkey =
+    solana_sdk::pubkey!("So11111111111111111111111111111111111111112");
+const NATIVE_SOL_DECIMALS: u8 = 9;
+
+/// 交易前后余额变化事件，每个 (owner, mint) 一条
+///
+/// 来自交易 meta 的 `pre/post_token_balances` 与 `pre/post_balances`，与具体 DEX 或路由无关。
+/// 同一 owner 的多个 token 账户合并计算；原生 SOL 的 owner 为账户地址本身，变化包含手续费与租金。
+#[derive(Clone, Debug, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct BalanceChangeEvent {
+    pub metadata: EventMetadata,
+    pub owner: Pubkey,
+    pub mint: Pubkey,
+    pub decimals: u8,
+    /// 原生 SOL（lamports）余额变化
+    pub is_native: bool,
+    pub pre_amount: u64,
+    pub post_amount: u64,
+    /// post_amount - pre_amount
+    pub delta: i128,
+}
+impl_unified_event!(BalanceChangeEvent,);
+
+#[derive(Default)]
+struct BalanceChanges {
+    changes: Vec<BalanceChangeEvent>,
+}
+
+impl BalanceChanges {
+    fn entry(
+        &mut self,
+        owner: Pubkey,
+        mint: Pubkey,
+        decimals: u8,
+        is_native: bool,
+    ) -> &mut BalanceChangeEvent {
+        let position = self
+            .changes
+            .iter()
+            .position(|c| c.owner == owner && c.mint == mint && c.is_native == is_native);
+        let index = position.unwrap_or_else(|| {
+            self.changes.push(BalanceChangeEvent {
+                owner,
+                mint,
+                decimals,
+                is_native,
+                ..Default::default()
+            });
+            self.changes.len() - 1
+        });
+        &mut self.changes[index]
+    }
+
+    fn add_native(&mut self, accounts: &[Pubkey], pre_balances: &[u64], post_balances: &[u64]) {
+        for (index, (pre, post)) in pre_balances.iter().zip(post_balances).enumerate() {
+            let Some(owner) = accounts.get(index) else {
+                break;
+            };
+            if pre == post {
+                continue;
+            }
+            let change = self.entry(*owner, NATIVE_SOL_MINT, NATIVE_SOL_DECIMALS, true);
+            change.pre_amount = *pre;
+            change.post_amount = *post;
+        }
+    }
+
+    fn add_token(&mut self, owner: &str, mint: &str, decimals: u8, amount: &str, is_post: bool) {
+        // 旧交易的 token balance 可能没有 owner
+        let (Ok(owner), Ok(mint)) = (Pubkey::from_str(owner), Pubkey::from_str(mint)) else {
+            return;
+        };
+        let amount = amount.parse::<u64>().unwrap_or_default();
+        let change = self.entry(owner, mint, decimals, false);
+        if is_post {
+            change.post_amount = change.post_amount.saturating_add(amount);
+        } else {
+            change.pre_amount = change.pre_amount.saturating_add(amount);
+        }
+    }
+
+    fn into_events(self, metadata: &EventMetadata) -> Vec<BalanceChangeEvent> {
+        self.changes
+            .into_iter()
+            .filter(|c| c.pre_amount != c.post_amount)
+            .map(|mut c| {
+                c.delta = c.post_amount as i128 - c.pre_amount as i128;
+                c.metadata = metadata.clone();
+                c
+            })
+            .collect()
+    }
+}
+
+impl BalanceChangeEvent {
+    /// 从 gRPC 交易 meta 计算余额变化，`accounts` 为包含 ALT 地址的完整账户列表
+    ///
+    /// 每条事件的元数据复制自 `metadata`。
+    pub fn from_grpc_meta(
+        meta: &TransactionStatusMeta,
+        accounts: &[Pubkey],
+        metadata: &EventMetadata,
+    ) -> Vec<Self> {
+        let mut changes = BalanceChanges::default();
+        changes.add_native(accounts, &meta.pre_balances, &meta.post_balances);
+        for (balances, is_post) in
+            [(&meta.pre_token_balances, false), (&meta.post_token_balances, true)]
+        {
+            for balance in balances {
+                let Some(amount) = &balance.ui_token_amount else {
+                    continue;
+                };
+                changes.add_token(
+                    &balance.owner,
+                    &balance.mint,
+                    amount.decimals as u8,
+                    &amount.amount,
+                    is_post,
+                );
+            }
+        }
+        changes.into_events(metadata)
+    }
+
+    /// 从 RPC（UI 编码）交易 meta 计算余额变化
+    pub fn from_ui_meta(
+        meta: &UiTransactionStatusMeta,
+        accounts: &[Pubkey],
+        metadata: &EventMetadata,
+    ) -> Vec<Self> {
+        let mut changes = BalanceChanges::default();
+        changes.add_native(accounts, &meta.pre_balances, &meta.post_balances);
+        for (balances, is_post) in
+            [(&meta.pre_token_balances, false), (&meta.post_token_balances, true)]
+        {
+            let OptionSerializer::Some(balances) = balances else {
+                continue;
+            };
+            for balance in balances {
+                let OptionSerializer::Some(owner) = &balance.owner else {
+                    continue;
+                };
+                changes.add_token(
+                    owner,
+                    &balance.mint,
+                    balance.ui_token_amount.decimals,
+                    &balance.ui_token_amount.amount,
+                    is_post,
+                );
+            }
+        }
+        changes.into_events(metadata)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use yellowstone_grpc_proto::prelude::{TokenBalance, UiTokenAmount};
+
+    use super::*;
+
+    const USDC: Pubkey = solana_sdk::pubkey!("EPjFWdd5AufqSSqeM2qN1xzybapC8G4wEGGkZwyTDt1v");
+
+    fn token_balance(account_index: u32, owner: &Pubkey, amount: u64) -> TokenBalance {
+        TokenBalance {
+            account_index,
+            mint: USDC.to_string(),
+            owner: owner.to_string(),
+            ui_token_amount: Some(UiTokenAmount {
+                decimals: 6,
+                amount: amount.to_string(),
+                ..Default::default()
+            }),
+            ..Default::default()
+        }
+    }
+
+    fn changes(
+        meta: &TransactionStatusMeta,
+        accounts: &[Pubkey],
+    ) -> Vec<(Pubkey, bool, u64, u64, i128)> {
+        BalanceChangeEvent::from_grpc_meta(meta, accounts, &EventMetadata::default())
+            .into_iter()
+            .map(|c| (c.owner, c.is_native, c.pre_amount, c.post_amount, c.delta))
+            .collect()
+    }
+
+    #[test]
+    fn sol_and_token_deltas_are_separate() {
+        let user = Pubkey::new_unique();
+        let (user_ata, pool) = (Pubkey::new_unique(), Pubkey::new_unique());
+        let meta = TransactionStatusMeta {
+            // pool 余额不变，不输出
+            pre_balances: vec![10_000_000, 2_039_280, 5],
+            post_balances: vec![8_995_000, 2_039_280, 5],
+            pre_token_balances: vec![token_balance(1, &user, 100)],
+            post_token_balances: vec![token_balance(1, &user, 250)],
+            ..Default::default()
+        };
+        let events = BalanceChangeEvent::from_grpc_meta(
+            &meta,
+            &[user, user_ata, pool],
+            &EventMetadata::default(),
+        );
+        assert_eq!(events.len(), 2);
+        let sol = events.iter().find(|c| c.is_native).unwrap();
+        assert_eq!((sol.owner, sol.mint, sol.decimals), (user, NATIVE_SOL_MINT, 9));
+        assert_eq!(sol.delta, -1_005_000);
+        let token = events.iter().find(|c| !c.is_native).unwrap();
+        assert_eq!((token.owner, token.mint, token.decimals), (user, USDC, 6));
+        assert_eq!((token.pre_amount, token.post_amount, token.delta), (100, 250, 150));
+    }
+
+    #[test]
+    fn closed_accounts_go_to_zero() {
+        let user = Pubkey::new_unique();
+        let user_ata = Pubkey::new_unique();
+        // token 账户被关闭：post 中没有对应条目，租金退回给 user
+        let meta = TransactionStatusMeta {
+            pre_balances: vec![1_000_000, 2_039_280],
+            post_balances: vec![3_034_280, 0],
+            pre_token_balances: vec![token_balance(1, &user, 40)],
+            ..Default::default()
+        };
+        assert_eq!(
+            changes(&meta, &[user, user_ata]),
+            vec![
+                (user, true, 1_000_000, 3_034_280, 2_034_280),
+                (user_ata, true, 2_039_280, 0, -2_039_280),
+                (user, false, 40, 0, -40),
+            ]
+        );
+    }
+
+    #[test]
+    fn missing_pre_entries_and_merged_owner_accounts() {
+        let user = Pubkey::new_unique();
+        let (ata_a, ata_b) = (Pubkey::new_unique(), Pubkey::new_unique());
+        // ata_b 在本交易中创建，pre 中没有条目；同一 owner 的两个 token 账户合并
+        let meta = TransactionStatusMeta {
+            pre_token_balances: vec![token_balance(1, &user, 10)],
+            post_token_balances: vec![token_balance(1, &user, 5), token_balance(2, &user, 30)],
+            ..Default::default()
+        };
+        assert_eq!(changes(&meta, &[user, ata_a, ata_b]), vec![(user, false, 10, 35, 25)]);
+    }
+
+    #[test]
+    fn malformed_entries_are_skipped() {
+        let user = Pubkey::new_unique();
+        let mut without_owner = token_balance(1, &user, 10);
+        without_owner.owner.clear();
+        let mut without_amount = token_balance(1, &user, 10);
+        without_amount.ui_token_amount = None;
+        let meta = TransactionStatusMeta {
+            // 余额数组比账户列表长时，多出的部分忽略
+            pre_balances: vec![1, 1, 1],
+            post_balances: vec![2, 2, 2],
+            post_token_balances: vec![without_owner, without_amount],
+            ..Default::default()
+        };
+        assert_eq!(changes(&meta, &[user]), vec![(user, true, 1, 2, 1)]);
+    }
+}
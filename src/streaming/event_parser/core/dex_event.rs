@@ -0,0 +1,247 @@
+use crate::streaming::event_parser::{
+    common::EventType,
+    core::{
+        account_event_parser::{NonceAccountEvent, TokenAccountEvent, TokenInfoEvent},
+        balance_change::BalanceChangeEvent,
+        graduation::Graduation,
+        liquidity_change::LiquidityChange,
+        normalized_trade::NormalizedTrade,
+        parse_diagnostics::UnparsedInstructionEvent,
+        raw_instruction::RawInstructionEvent,
+        token_launch::TokenLaunch,
+        traits::UnifiedEvent,
+    },
+    protocols::{
+        axiom::AxiomPumpFunTradeEvent,
+        axiom2::AxiomPumpSwapBuyEvent,
+        block::block_meta_event::BlockMetaEvent,
+        bonk::{
+            BonkGlobalConfigAccountEvent, BonkMigrateToAmmEvent, BonkMigrateToCpswapEvent,
+            BonkPlatformConfigAccountEvent, BonkPoolCreateEvent, BonkPoolStateAccountEvent,
+            BonkTradeEvent,
+        },
+        jupiter_v6::{JupiterRouteEvent, JupiterSwapEvent},
+        meteora_dammv2::MeteoraDAMMv2SwapEvent,
+        meteora_dbc::{
+            MeteoraDBCInitializePoolEvent, MeteoraDBCMigrationDammV2Event, MeteoraDBCSwapEvent,
+        },
+        orca_whirlpool::{
+            OrcaWhirlpoolDecreaseLiquidityEvent, OrcaWhirlpoolIncreaseLiquidityEvent,
+            OrcaWhirlpoolInitializePoolEvent, OrcaWhirlpoolOpenPositionEvent,
+            OrcaWhirlpoolPoolAccountEvent, OrcaWhirlpoolSwapEvent, OrcaWhirlpoolSwapV2Event,
+            OrcaWhirlpoolTickArrayAccountEvent, OrcaWhirlpoolTwoHopSwapEvent,
+        },
+        photon::{PhotonPumpFunTradeEvent, PhotonPumpSwapTradeEvent},
+        pumpfun::{
+            PumpFunBondingCurveAccountEvent, PumpFunCreateTokenEvent, PumpFunGlobalAccountEvent,
+            PumpFunMigrateEvent, PumpFunTradeEvent,
+        },
+        pumpswap::{
+            PumpSwapBuyEvent, PumpSwapCreatePoolEvent, PumpSwapDepositEvent,
+            PumpSwapGlobalConfigAccountEvent, PumpSwapPoolAccountEvent, PumpSwapSellEvent,
+            PumpSwapWithdrawEvent,
+        },
+        raydium_amm_v4::{
+            RaydiumAmmV4AmmInfoAccountEvent, RaydiumAmmV4DepositEvent,
+            RaydiumAmmV4Initialize2Event, RaydiumAmmV4SwapEvent, RaydiumAmmV4WithdrawEvent,
+            RaydiumAmmV4WithdrawPnlEvent,
+        },
+        raydium_clmm::{
+            RaydiumClmmAmmConfigAccountEvent, RaydiumClmmClosePositionEvent,
+            RaydiumClmmCreatePoolEvent, RaydiumClmmDecreaseLiquidityV2Event,
+            RaydiumClmmIncreaseLiquidityV2Event, RaydiumClmmOpenPositionV2Event,
+            RaydiumClmmOpenPositionWithToken22NftEvent, RaydiumClmmPoolStateAccountEvent,
+            RaydiumClmmSwapEvent, RaydiumClmmSwapV2Event, RaydiumClmmTickArrayStateAccountEvent,
+        },
+        raydium_cpmm::{
+            RaydiumCpmmAmmConfigAccountEvent, RaydiumCpmmDepositEvent, RaydiumCpmmInitializeEvent,
+            RaydiumCpmmPoolStateAccountEvent, RaydiumCpmmSwapEvent, RaydiumCpmmWithdrawEvent,
+        },
+    },
+};
+use solana_sdk::signature::Signature;
+
+/// 生成 DexEvent 枚举：每个具体事件结构体对应一个同名变体
+macro_rules! define_dex_event {
+    ($($variant:ident),* $(,)?) => {
+        /// 强类型事件枚举
+        ///
+        /// 与 `Box<dyn UnifiedEvent>` 一一对应，每个具体事件结构体都有一个同名变体。
+        /// 新增事件类型时编译器会强制在 `match` 中处理，无需 `match_event!` 的 downcast 链。
+        /// 变体持有 `Box`，从 `Box<dyn UnifiedEvent>` 转换时不会复制事件数据。
+        #[derive(Debug, Clone)]
+        pub enum DexEvent {
+            $($variant(Box<$variant>),)*
+            /// 非内置事件（外部实现的 UnifiedEvent）
+            Custom(Box<dyn UnifiedEvent>),
+        }
+
+        impl DexEvent {
+            /// 以 UnifiedEvent 引用访问事件
+            pub fn as_unified(&self) -> &dyn UnifiedEvent {
+                match self {
+                    $(DexEvent::$variant(e) => e.as_ref(),)*
+                    DexEvent::Custom(e) => e.as_ref(),
+                }
+            }
+
+            /// 以 UnifiedEvent 可变引用访问事件
+            pub fn as_unified_mut(&mut self) -> &mut dyn UnifiedEvent {
+                match self {
+                    $(DexEvent::$variant(e) => e.as_mut(),)*
+                    DexEvent::Custom(e) => e.as_mut(),
+                }
+            }
+
+            /// 转换回 `Box<dyn UnifiedEvent>`
+            pub fn into_unified(self) -> Box<dyn UnifiedEvent> {
+                match self {
+                    $(DexEvent::$variant(e) => e,)*
+                    DexEvent::Custom(e) => e,
+                }
+            }
+
+            /// 变体名称（与事件结构体名相同）
+            pub fn name(&self) -> &'static str {
+                match self {
+                    $(DexEvent::$variant(_) => stringify!($variant),)*
+                    DexEvent::Custom(_) => "Custom",
+                }
+            }
+
+            /// 每个内置变体各一个默认值事件，供测试覆盖全部变体
+            #[cfg(test)]
+            pub(crate) fn all_defaults() -> Vec<DexEvent> {
+                vec![$(DexEvent::$variant(Box::default()),)*]
+            }
+        }
+
+        $(
+            impl From<$variant> for DexEvent {
+                fn from(event: $variant) -> Self {
+                    DexEvent::$variant(Box::new(event))
+                }
+            }
+        )*
+    };
+}
+
+define_dex_event!(
+    // PumpFun
+    PumpFunCreateTokenEvent,
+    PumpFunTradeEvent,
+    PumpFunMigrateEvent,
+    PumpFunBondingCurveAccountEvent,
+    PumpFunGlobalAccountEvent,
+    // PumpSwap
+    PumpSwapBuyEvent,
+    PumpSwapSellEvent,
+    PumpSwapCreatePoolEvent,
+    PumpSwapDepositEvent,
+    PumpSwapWithdrawEvent,
+    PumpSwapGlobalConfigAccountEvent,
+    PumpSwapPoolAccountEvent,
+    // Bonk
+    BonkTradeEvent,
+    BonkPoolCreateEvent,
+    BonkMigrateToAmmEvent,
+    BonkMigrateToCpswapEvent,
+    BonkPoolStateAccountEvent,
+    BonkGlobalConfigAccountEvent,
+    BonkPlatformConfigAccountEvent,
+    // Raydium CPMM
+    RaydiumCpmmSwapEvent,
+    RaydiumCpmmDepositEvent,
+    RaydiumCpmmInitializeEvent,
+    RaydiumCpmmWithdrawEvent,
+    RaydiumCpmmAmmConfigAccountEvent,
+    RaydiumCpmmPoolStateAccountEvent,
+    // Raydium CLMM
+    RaydiumClmmSwapEvent,
+    RaydiumClmmSwapV2Event,
+    RaydiumClmmClosePositionEvent,
+    RaydiumClmmDecreaseLiquidityV2Event,
+    RaydiumClmmCreatePoolEvent,
+    RaydiumClmmIncreaseLiquidityV2Event,
+    RaydiumClmmOpenPositionWithToken22NftEvent,
+    RaydiumClmmOpenPositionV2Event,
+    RaydiumClmmAmmConfigAccountEvent,
+    RaydiumClmmPoolStateAccountEvent,
+    RaydiumClmmTickArrayStateAccountEvent,
+    // Raydium AMM V4
+    RaydiumAmmV4SwapEvent,
+    RaydiumAmmV4DepositEvent,
+    RaydiumAmmV4Initialize2Event,
+    RaydiumAmmV4WithdrawEvent,
+    RaydiumAmmV4WithdrawPnlEvent,
+    RaydiumAmmV4AmmInfoAccountEvent,
+    // Photon
+    PhotonPumpFunTradeEvent,
+    PhotonPumpSwapTradeEvent,
+    // Axiom
+    AxiomPumpFunTradeEvent,
+    AxiomPumpSwapBuyEvent,
+    // Meteora
+    MeteoraDBCSwapEvent,
+    MeteoraDAMMv2SwapEvent,
+    // Jupiter
+    JupiterRouteEvent,
+    JupiterSwapEvent,
+    // Orca Whirlpool
+    OrcaWhirlpoolSwapEvent,
+    OrcaWhirlpoolSwapV2Event,
+    OrcaWhirlpoolTwoHopSwapEvent,
+    OrcaWhirlpoolIncreaseLiquidityEvent,
+    OrcaWhirlpoolDecreaseLiquidityEvent,
+    OrcaWhirlpoolInitializePoolEvent,
+    OrcaWhirlpoolOpenPositionEvent,
+    OrcaWhirlpoolPoolAccountEvent,
+    OrcaWhirlpoolTickArrayAccountEvent,
+    // Common
+    TokenAccountEvent,
+    NonceAccountEvent,
+    TokenInfoEvent,
+    BlockMetaEvent,
+    BalanceChangeEvent,
+    NormalizedTrade,
+    MeteoraDBCInitializePoolEvent,
+    TokenLaunch,
+    MeteoraDBCMigrationDammV2Event,
+    Graduation,
+    LiquidityChange,
+    UnparsedInstructionEvent,
+    RawInstructionEvent,
+);
+
+impl DexEvent {
+    /// Get event type
+    pub fn event_type(&self) -> EventType {
+        self.as_unified().event_type()
+    }
+
+    /// Get transaction signature
+    pub fn signature(&self) -> &Signature {
+        self.as_unified().signature()
+    }
+
+    /// Get slot number
+    pub fn slot(&self) -> u64 {
+        self.as_unified().slot()
+    }
+
+    /// Serialize into the stable JSON schema
+    pub fn to_json(&self) -> serde_json::Result<serde_json::Value> {
+        self.as_unified().to_json()
+    }
+
+    /// Convert into the protobuf message
+    pub fn to_proto(&self) -> Option<crate::protos::events::Event> {
+        self.as_unified().to_proto()
+    }
+}
+
+impl From<Box<dyn UnifiedEvent>> for DexEvent {
+    fn from(event: Box<dyn UnifiedEvent>) -> Self {
+        event.into_dex_event()
+    }
+}
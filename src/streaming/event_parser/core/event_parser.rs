@@ -4,9 +4,23 @@ use crate::streaming::{
         common::{
             filter::EventTypeFilter,
             high_performance_clock::{elapsed_micros_since, get_high_perf_clock},
-            parse_swap_data_from_next_grpc_instructions, parse_swap_data_from_next_instructions,
-            EventMetadata, EventType, ProtocolType,
+            complete_swap_data, parse_swap_data, EventMetadata, EventType, InnerInstructionView,
+            InstructionInvocation, ProtocolType, TokenAccountMints, TransactionContext,
         },
+        core::anchor_idl::ANCHOR_EVENT_IX_TAG,
+        core::balance_change::BalanceChangeEvent,
+        core::graduation::Graduation,
+        core::liquidity_change::LiquidityChange,
+        core::normalized_trade::NormalizedTrade,
+        core::parse_diagnostics::{
+            ParseDiagnostics, UnparsedInstructionEvent, UnparsedReason,
+            MAX_DIAGNOSTIC_DISCRIMINATOR_LEN,
+        },
+        core::raw_instruction::RawInstructionEvent,
+        core::router::attach_router,
+        core::token_launch::TokenLaunch,
+        core::program_logs::{parse_program_data_logs, ProgramDataLog},
+        core::protocol_registry::{EventParseConfig, ProtocolRegistry},
         core::global_state::{
             add_bonk_dev_address, add_dev_address, is_bonk_dev_address_in_signature,
             is_dev_address_in_signature,
@@ -14,7 +28,7 @@ use crate::streaming::{
         protocols::{
             bonk::{parser::BONK_PROGRAM_ID, BonkPoolCreateEvent, BonkTradeEvent},
             pumpfun::{parser::PUMPFUN_PROGRAM_ID, PumpFunCreateTokenEvent, PumpFunTradeEvent},
-            pumpswap::{parser::PUMPSWAP_PROGRAM_ID, PumpSwapBuyEvent, PumpSwapSellEvent},
+            pumpswap::parser::PUMPSWAP_PROGRAM_ID,
             raydium_amm_v4::parser::RAYDIUM_AMM_V4_PROGRAM_ID,
             raydium_clmm::parser::RAYDIUM_CLMM_PROGRAM_ID,
             raydium_cpmm::parser::RAYDIUM_CPMM_PROGRAM_ID,
@@ -32,7 +46,10 @@ use solana_transaction_status::{
 };
 use std::{
     collections::HashMap,
-    sync::{Arc, LazyLock},
+    sync::{
+        atomic::{AtomicUsize, Ordering},
+        Arc, LazyLock,
+    },
 };
 use yellowstone_grpc_proto::geyser::SubscribeUpdateTransactionInfo;
 use crate::streaming::event_parser::protocols::axiom2::parser::{AXIOM_2_PROGRAM_ID, AXIOM_2_PROGRAM_VAR_1_ID};
@@ -40,6 +57,175 @@ use crate::streaming::event_parser::protocols::axiom::parser::{AXIOM_1_PROGRAM_I
 use crate::streaming::event_parser::protocols::meteora_dammv2::parser::METEORA_DAMM_V2_PROGRAM_ID;
 use crate::streaming::event_parser::protocols::meteora_dbc::parser::METEORA_DBC_PROGRAM_ID;
 use crate::streaming::event_parser::protocols::photon::parser::PHOTON_PROGRAM_ID;
+use crate::streaming::event_parser::protocols::jupiter_v6::parser::{
+    complete_route as complete_jupiter_route, JUPITER_V6_PROGRAM_ID,
+};
+use crate::streaming::event_parser::protocols::orca_whirlpool::parser::ORCA_WHIRLPOOL_PROGRAM_ID;
+
+/// 外层指令的调用栈高度
+const TRANSACTION_LEVEL_STACK_HEIGHT: u32 = 1;
+
+/// 外层指令的调用链信息
+fn top_level_invocation(program_id: Pubkey) -> InstructionInvocation {
+    InstructionInvocation {
+        stack_height: Some(TRANSACTION_LEVEL_STACK_HEIGHT),
+        parent_program_id: None,
+        invoking_program_id: program_id,
+    }
+}
+
+/// 按 stack_height 还原外层指令下各内联指令的调用方
+struct CallStack {
+    invoking_program_id: Pubkey,
+    /// 当前调用链上的内联指令程序，下标 i 对应 stack_height i + 2
+    programs: Vec<Pubkey>,
+}
+
+impl CallStack {
+    fn new(invoking_program_id: Pubkey) -> Self {
+        Self { invoking_program_id, programs: Vec::new() }
+    }
+
+    /// 按顺序登记内联指令并返回其调用链信息，未提供 stack_height 时视为由外层指令直接调用
+    fn enter(&mut self, program_id: Pubkey, stack_height: Option<u32>) -> InstructionInvocation {
+        let parent_program_id = match stack_height {
+            Some(height) => {
+                self.programs.truncate(height.saturating_sub(2) as usize);
+                let parent = self.programs.last().copied().unwrap_or(self.invoking_program_id);
+                self.programs.push(program_id);
+                parent
+            }
+            None => self.invoking_program_id,
+        };
+        InstructionInvocation {
+            stack_height,
+            parent_program_id: Some(parent_program_id),
+            invoking_program_id: self.invoking_program_id,
+        }
+    }
+}
+
+/// 首次访问时才构建的交易级数据，未产生事件的交易不会构建
+type LazyValue<'a, T> = LazyLock<T, Box<dyn FnOnce() -> T + Send + 'a>>;
+
+fn lazy_value<'a, T>(init: impl FnOnce() -> T + Send + 'a) -> LazyValue<'a, T> {
+    LazyLock::new(Box::new(init))
+}
+
+/// 单笔交易内各指令共享的解析上下文
+#[derive(Debug)]
+struct TransactionParseContext<'a> {
+    /// `Program data:` 日志事件
+    program_data_logs: Vec<ProgramDataLog>,
+    /// 交易已上链但执行失败
+    failed: bool,
+    /// 交易级上下文，同一交易的事件共享
+    tx_context: LazyValue<'a, Arc<TransactionContext>>,
+    /// token 账户 -> mint，用于补全 swap_data
+    token_mints: LazyValue<'a, TokenAccountMints>,
+    /// 交易级余额变化事件，未启用时为空
+    balance_changes: Vec<BalanceChangeEvent>,
+    /// 本交易中的代币发射，指令事件解析完后输出
+    token_launches: parking_lot::Mutex<Vec<TokenLaunch>>,
+    /// 解析出的指令事件数（EventTypeFilter 过滤前）
+    parsed_events: AtomicUsize,
+}
+
+impl<'a> TransactionParseContext<'a> {
+    fn new(
+        program_data_logs: Vec<ProgramDataLog>,
+        failed: bool,
+        tx_context: impl FnOnce() -> TransactionContext + Send + 'a,
+        token_mints: impl FnOnce() -> TokenAccountMints + Send + 'a,
+    ) -> Self {
+        Self {
+            program_data_logs,
+            failed,
+            tx_context: lazy_value(|| Arc::new(tx_context())),
+            token_mints: lazy_value(token_mints),
+            balance_changes: Vec::new(),
+            token_launches: Default::default(),
+            parsed_events: AtomicUsize::new(0),
+        }
+    }
+
+    fn record_parsed_events(&self, count: usize) {
+        self.parsed_events.fetch_add(count, Ordering::Relaxed);
+    }
+
+    fn parsed_events(&self) -> usize {
+        self.parsed_events.load(Ordering::Relaxed)
+    }
+
+    fn is_failed(&self) -> bool {
+        self.failed
+    }
+
+    /// 交易签名者
+    fn signers(&self) -> &[Pubkey] {
+        &self.tx_context.signers
+    }
+
+    /// 余额变化事件，在所有指令事件之后输出
+    fn balance_change_events(
+        &self,
+        recv_us: i64,
+    ) -> impl Iterator<Item = Box<dyn UnifiedEvent>> + '_ {
+        self.balance_changes.iter().map(move |change| {
+            let mut event: Box<dyn UnifiedEvent> = Box::new(change.clone());
+            event.set_handle_us(elapsed_micros_since(recv_us));
+            event
+        })
+    }
+
+    /// 记录代币发射，或将创建者的买入关联到已记录的发射
+    fn track_token_launch(&self, event: &dyn UnifiedEvent) {
+        if let Some(launch) = TokenLaunch::from_event(event) {
+            self.token_launches.lock().push(launch);
+            return;
+        }
+        for launch in self.token_launches.lock().iter_mut() {
+            launch.link_dev_buy(event, &self.token_mints);
+        }
+    }
+
+    /// 代币发射事件，在指令事件之后、余额变化事件之前输出
+    fn token_launch_events(&self, recv_us: i64) -> impl Iterator<Item = Box<dyn UnifiedEvent>> {
+        std::mem::take(&mut *self.token_launches.lock()).into_iter().map(move |launch| {
+            let mut event: Box<dyn UnifiedEvent> = Box::new(launch);
+            event.set_handle_us(elapsed_micros_since(recv_us));
+            event
+        })
+    }
+}
+
+/// 交易级事件（不属于某条指令）的元数据，`outer_index` 为 -1
+fn balance_change_metadata(
+    signature: Signature,
+    slot: u64,
+    block_time: Option<Timestamp>,
+    recv_us: i64,
+    transaction_index: Option<u64>,
+    tx_context: &Arc<TransactionContext>,
+) -> EventMetadata {
+    let timestamp = block_time.unwrap_or(Timestamp { seconds: 0, nanos: 0 });
+    let block_time_ms = timestamp.seconds * 1000 + (timestamp.nanos as i64) / 1_000_000;
+    let mut metadata = EventMetadata::new(
+        signature,
+        slot,
+        timestamp.seconds,
+        block_time_ms,
+        ProtocolType::Common,
+        EventType::BalanceChange,
+        Pubkey::default(),
+        -1,
+        None,
+        recv_us,
+        transaction_index,
+    );
+    metadata.set_tx_context(Arc::clone(tx_context));
+    metadata
+}
 
 /// 高性能账户公钥缓存，避免重复Vec分配
 #[derive(Debug)]
@@ -112,7 +298,7 @@ pub static EVENT_PARSERS: LazyLock<HashMap<Protocol, (Pubkey, &[GenericEventPars
     LazyLock::new(|| {
         // 预分配容量，避免动态扩容
         let mut parsers: HashMap<Protocol, (Pubkey, &[GenericEventParseConfig])> =
-            HashMap::with_capacity(13);
+            HashMap::with_capacity(15);
         parsers.insert(
             Protocol::PumpSwap,
             (
@@ -201,6 +387,20 @@ pub static EVENT_PARSERS: LazyLock<HashMap<Protocol, (Pubkey, &[GenericEventPars
                 crate::streaming::event_parser::protocols::axiom2::parser::CONFIGSVAR1,
             ),
         );
+        parsers.insert(
+            Protocol::JupiterV6,
+            (
+                JUPITER_V6_PROGRAM_ID,
+                crate::streaming::event_parser::protocols::jupiter_v6::parser::CONFIGS,
+            ),
+        );
+        parsers.insert(
+            Protocol::OrcaWhirlpool,
+            (
+                ORCA_WHIRLPOOL_PROGRAM_ID,
+                crate::streaming::event_parser::protocols::orca_whirlpool::parser::CONFIGS,
+            ),
+        );
         parsers
     });
 
@@ -208,18 +408,70 @@ pub static EVENT_PARSERS: LazyLock<HashMap<Protocol, (Pubkey, &[GenericEventPars
 pub struct EventParser {
     pub program_ids: Vec<Pubkey>,
     // pub inner_instruction_configs: HashMap<Vec<u8>, Vec<GenericEventParseConfig>>,
-    pub instruction_configs: HashMap<Vec<u8>, Vec<GenericEventParseConfig>>,
+    pub instruction_configs: HashMap<Vec<u8>, Vec<EventParseConfig>>,
     /// 账户公钥缓存，避免重复分配
     pub account_cache: parking_lot::Mutex<AccountPubkeyCache>,
+    /// 是否输出 `BalanceChangeEvent`，仅当过滤器显式包含 `EventType::BalanceChange` 时启用
+    pub emit_balance_changes: bool,
+    /// 是否输出 `NormalizedTrade`，仅当过滤器显式包含 `EventType::NormalizedTrade` 时启用
+    pub emit_normalized_trades: bool,
+    /// 是否输出 `TokenLaunch`，仅当过滤器显式包含 `EventType::TokenLaunch` 时启用
+    pub emit_token_launches: bool,
+    /// 是否输出 `Graduation`，仅当过滤器显式包含 `EventType::Graduation` 时启用
+    pub emit_graduations: bool,
+    /// 是否输出 `LiquidityChange`，仅当过滤器显式包含 `EventType::LiquidityChange` 时启用
+    pub emit_liquidity_changes: bool,
+    /// 是否输出 `UnparsedInstructionEvent`，仅当过滤器显式包含 `EventType::UnparsedInstruction` 时启用
+    pub emit_unparsed_instructions: bool,
+    /// 是否输出 `RawInstructionEvent`，仅当过滤器显式包含 `EventType::RawInstruction` 时启用
+    pub emit_raw_instructions: bool,
+    /// 未匹配指令与解码失败统计
+    pub diagnostics: Arc<ParseDiagnostics>,
+    /// 各程序的全部指令 discriminator（不受过滤器影响），用于区分未知指令与被过滤的指令
+    known_instructions: HashMap<Pubkey, KnownInstructions>,
+}
+
+/// 程序已知的指令 discriminator
+struct KnownInstructions {
+    protocol_type: ProtocolType,
+    discriminator_len: usize,
+    discriminators: Vec<Vec<u8>>,
+}
+
+impl KnownInstructions {
+    fn new(protocol_type: ProtocolType) -> Self {
+        Self { protocol_type, discriminator_len: 0, discriminators: Vec::new() }
+    }
+
+    fn add(&mut self, discriminator: &[u8]) {
+        self.discriminator_len = self.discriminator_len.max(discriminator.len());
+        self.discriminators.push(discriminator.to_vec());
+    }
 }
 
 impl EventParser {
     pub fn new(protocols: Vec<Protocol>, event_type_filter: Option<EventTypeFilter>) -> Self {
+        Self::new_with_registry(protocols, event_type_filter, None)
+    }
+
+    /// 创建解析器，并同时启用 `registry` 中注册的自定义协议
+    pub fn new_with_registry(
+        protocols: Vec<Protocol>,
+        event_type_filter: Option<EventTypeFilter>,
+        registry: Option<&ProtocolRegistry>,
+    ) -> Self {
         let mut instruction_configs = HashMap::with_capacity(protocols.len());
         let mut program_ids = Vec::with_capacity(protocols.len());
+        let mut known_instructions: HashMap<Pubkey, KnownInstructions> = HashMap::new();
         // Configure all event types
         for protocol in protocols {
             let parse = EVENT_PARSERS.get(&protocol).unwrap();
+            for config in parse.1 {
+                known_instructions
+                    .entry(config.program_id)
+                    .or_insert_with(|| KnownInstructions::new(config.protocol_type.clone()))
+                    .add(config.instruction_discriminator);
+            }
             // Merge instruction_configs, append configurations to existing Vec
             parse
                 .1
@@ -234,15 +486,76 @@ impl EventParser {
                     instruction_configs
                         .entry(config.instruction_discriminator.to_vec())
                         .or_insert_with(Vec::new)
-                        .push(config.clone());
+                        .push(EventParseConfig::from(config));
                 });
 
             // Append program_ids (this is already appending)
             program_ids.push(parse.0);
         }
+        // 自定义协议
+        for protocol in registry.map(|r| r.protocols()).unwrap_or_default() {
+            for config in &protocol.event_configs {
+                known_instructions
+                    .entry(config.program_id)
+                    .or_insert_with(|| KnownInstructions::new(config.protocol_type.clone()))
+                    .add(&config.instruction_discriminator);
+            }
+            protocol
+                .event_configs
+                .iter()
+                .filter(|config| {
+                    event_type_filter
+                        .as_ref()
+                        .map(|filter| filter.include.contains(&config.event_type))
+                        .unwrap_or(true)
+                })
+                .for_each(|config| {
+                    instruction_configs
+                        .entry(config.instruction_discriminator.clone())
+                        .or_insert_with(Vec::new)
+                        .push(config.clone());
+                });
+            if !program_ids.contains(&protocol.program_id) {
+                program_ids.push(protocol.program_id);
+            }
+        }
         let account_cache = parking_lot::Mutex::new(AccountPubkeyCache::new());
+        let emit_balance_changes = event_type_filter
+            .as_ref()
+            .is_some_and(|filter| filter.include.contains(&EventType::BalanceChange));
+        let emit_normalized_trades = event_type_filter
+            .as_ref()
+            .is_some_and(|filter| filter.include.contains(&EventType::NormalizedTrade));
+        let emit_token_launches = event_type_filter
+            .as_ref()
+            .is_some_and(|filter| filter.include.contains(&EventType::TokenLaunch));
+        let emit_graduations = event_type_filter
+            .as_ref()
+            .is_some_and(|filter| filter.include.contains(&EventType::Graduation));
+        let emit_liquidity_changes = event_type_filter
+            .as_ref()
+            .is_some_and(|filter| filter.include.contains(&EventType::LiquidityChange));
+        let emit_unparsed_instructions = event_type_filter
+            .as_ref()
+            .is_some_and(|filter| filter.include.contains(&EventType::UnparsedInstruction));
+        let emit_raw_instructions = event_type_filter
+            .as_ref()
+            .is_some_and(|filter| filter.include.contains(&EventType::RawInstruction));
 
-        Self { program_ids, instruction_configs, account_cache }
+        Self {
+            program_ids,
+            instruction_configs,
+            account_cache,
+            emit_balance_changes,
+            emit_normalized_trades,
+            emit_token_launches,
+            emit_graduations,
+            emit_liquidity_changes,
+            emit_unparsed_instructions,
+            emit_raw_instructions,
+            diagnostics: Arc::new(ParseDiagnostics::new()),
+            known_instructions,
+        }
     }
 
     #[allow(clippy::too_many_arguments)]
@@ -255,6 +568,7 @@ impl EventParser {
         recv_us: i64,
         accounts: &[Pubkey],
         inner_instructions: &[yellowstone_grpc_proto::prelude::InnerInstructions],
+        tx_ctx: &TransactionParseContext<'_>,
         bot_wallet: Option<Pubkey>,
         transaction_index: Option<u64>,
         callback: Arc<dyn for<'a> Fn(&'a Box<dyn UnifiedEvent>) + Send + Sync>,
@@ -286,17 +600,27 @@ impl EventParser {
                             recv_us,
                             index as i64,
                             None,
+                            &top_level_invocation(program_id),
                             bot_wallet,
                             transaction_index,
                             inner_instructions,
+                            tx_ctx,
                             Arc::clone(&callback),
                         )?;
                     }
                     // Immediately process inner instructions for correct ordering
                     if let Some(inner_instructions) = inner_instructions {
+                        let mut call_stack = CallStack::new(program_id);
                         for (inner_index, inner_instruction) in
                             inner_instructions.instructions.iter().enumerate()
                         {
+                            let invocation = call_stack.enter(
+                                accounts
+                                    .get(inner_instruction.program_id_index as usize)
+                                    .copied()
+                                    .unwrap_or_default(),
+                                inner_instruction.stack_height,
+                            );
                             let inner_accounts = &inner_instruction.accounts;
                             let data = &inner_instruction.data;
                             let instruction =
@@ -314,9 +638,11 @@ impl EventParser {
                                 recv_us,
                                 inner_instructions.index as i64,
                                 Some(inner_index as i64),
+                                &invocation,
                                 bot_wallet,
                                 transaction_index,
                                 Some(&inner_instructions),
+                                tx_ctx,
                                 Arc::clone(&callback),
                             )?;
                         }
@@ -324,6 +650,10 @@ impl EventParser {
                 }
             }
         }
+        let tx_events = tx_ctx.token_launch_events(recv_us);
+        for event in tx_events.chain(tx_ctx.balance_change_events(recv_us)) {
+            callback(&event);
+        }
         Ok(())
     }
 
@@ -338,6 +668,7 @@ impl EventParser {
         recv_us: i64,
         accounts: &[Pubkey],
         inner_instructions: &[InnerInstructions],
+        tx_ctx: &TransactionParseContext<'_>,
         bot_wallet: Option<Pubkey>,
         transaction_index: Option<u64>,
         callback: Arc<dyn for<'a> Fn(&'a Box<dyn UnifiedEvent>) + Send + Sync>,
@@ -348,9 +679,9 @@ impl EventParser {
         let mut accounts: Vec<Pubkey> = accounts.to_vec();
         // 检查交易中是否包含程序
         let has_program = accounts.iter().any(|account| self.should_handle(account));
+        let mut events = vec![];
         if has_program {
             // 解析每个指令
-            let mut events = vec![];
             for (index, instruction) in compiled_instructions.iter().enumerate() {
                 if let Some(program_id) = accounts.get(instruction.program_id_index as usize) {
                     let program_id = *program_id; // 克隆程序ID，避免借用冲突
@@ -373,9 +704,11 @@ impl EventParser {
                                 recv_us,
                                 index as i64,
                                 None,
+                                &top_level_invocation(program_id),
                                 bot_wallet,
                                 transaction_index,
                                 inner_instructions,
+                                tx_ctx,
                             )?;
                             events.extend(parsed_events);
 
@@ -389,9 +722,11 @@ impl EventParser {
                                 recv_us,
                                 index as i64,
                                 None,
+                                &top_level_invocation(program_id),
                                 bot_wallet,
                                 transaction_index,
                                 inner_instructions,
+                                tx_ctx,
                                 Arc::clone(&callback),
                             )?;
                         }
@@ -399,9 +734,17 @@ impl EventParser {
                     }
                     // Immediately process inner instructions for correct ordering
                     if let Some(inner_instructions) = inner_instructions {
+                        let mut call_stack = CallStack::new(program_id);
                         for (inner_index, inner_instruction) in
                             inner_instructions.instructions.iter().enumerate()
                         {
+                            let invocation = call_stack.enter(
+                                accounts
+                                    .get(inner_instruction.instruction.program_id_index as usize)
+                                    .copied()
+                                    .unwrap_or_default(),
+                                inner_instruction.stack_height,
+                            );
                             if tx_pack_callback.is_some() {
                                 let parsed_events = self.parse_events_from_instruction_and_pack(
                                     &inner_instruction.instruction,
@@ -412,9 +755,11 @@ impl EventParser {
                                     recv_us,
                                     index as i64,
                                     Some(inner_index as i64),
+                                    &invocation,
                                     bot_wallet,
                                     transaction_index,
                                     Some(&inner_instructions),
+                                    tx_ctx,
                                 )?;
                                 events.extend(parsed_events);
 
@@ -428,9 +773,11 @@ impl EventParser {
                                     recv_us,
                                     index as i64,
                                     Some(inner_index as i64),
+                                    &invocation,
                                     bot_wallet,
                                     transaction_index,
                                     Some(&inner_instructions),
+                                    tx_ctx,
                                     Arc::clone(&callback),
                                 )?;
                             }
@@ -439,15 +786,24 @@ impl EventParser {
                 }
             }
 
-            if let Some(tx_pack_cb) = tx_pack_callback {
-                if !events.is_empty() {
-                    tx_pack_cb(&events);
-                }
+        }
+        let tx_events = tx_ctx.token_launch_events(recv_us);
+        for event in tx_events.chain(tx_ctx.balance_change_events(recv_us)) {
+            if tx_pack_callback.is_some() {
+                events.push(event);
+            } else {
+                callback(&event);
+            }
+        }
+        if let Some(tx_pack_cb) = tx_pack_callback {
+            if !events.is_empty() {
+                tx_pack_cb(&events);
             }
         }
         Ok(())
     }
 
+    /// 返回解析出的指令事件数（EventTypeFilter 过滤前），不含派生事件
     pub async fn parse_versioned_transaction_owned(
         &self,
         versioned_tx: VersionedTransaction,
@@ -460,7 +816,7 @@ impl EventParser {
         inner_instructions: &[InnerInstructions],
         callback: Arc<dyn Fn(Box<dyn UnifiedEvent>) + Send + Sync>,
         tx_pack_callback: Option<Arc<dyn Fn(Vec<Box<dyn UnifiedEvent>>) + Send + Sync>>,
-    ) -> anyhow::Result<()> {
+    ) -> anyhow::Result<usize> {
         // 创建适配器回调，将所有权回调转换为引用回调
         let adapter_callback = Arc::new(move |event: &Box<dyn UnifiedEvent>| {
             callback(event.clone_boxed());
@@ -482,8 +838,7 @@ impl EventParser {
             adapter_callback,
             tx_pack_adapter_callback
         )
-        .await?;
-        Ok(())
+        .await
     }
 
     async fn parse_versioned_transaction(
@@ -498,8 +853,14 @@ impl EventParser {
         inner_instructions: &[InnerInstructions],
         callback: Arc<dyn for<'a> Fn(&'a Box<dyn UnifiedEvent>) + Send + Sync>,
         tx_pack_callback: Option<Arc<dyn for<'a> Fn(&'a Vec<Box<dyn UnifiedEvent>>) + Send + Sync>>,
-    ) -> anyhow::Result<()> {
+    ) -> anyhow::Result<usize> {
         let accounts: Vec<Pubkey> = versioned_tx.message.static_account_keys().to_vec();
+        let tx_ctx = TransactionParseContext::new(
+            Vec::new(),
+            false,
+            || TransactionContext::from_versioned_transaction(versioned_tx, None),
+            TokenAccountMints::default,
+        );
         self.parse_instruction_events_from_versioned_transaction(
             versioned_tx,
             signature,
@@ -508,15 +869,17 @@ impl EventParser {
             recv_us,
             &accounts,
             inner_instructions,
+            &tx_ctx,
             bot_wallet,
             transaction_index,
             callback,
             tx_pack_callback
         )
         .await?;
-        Ok(())
+        Ok(tx_ctx.parsed_events())
     }
 
+    /// 返回解析出的指令事件数（EventTypeFilter 过滤前），不含派生事件
     pub async fn parse_grpc_transaction_owned(
         &self,
         grpc_tx: SubscribeUpdateTransactionInfo,
@@ -527,7 +890,7 @@ impl EventParser {
         bot_wallet: Option<Pubkey>,
         transaction_index: Option<u64>,
         callback: Arc<dyn Fn(Box<dyn UnifiedEvent>) + Send + Sync>,
-    ) -> anyhow::Result<()> {
+    ) -> anyhow::Result<usize> {
         // 创建适配器回调，将所有权回调转换为引用回调
         let adapter_callback = Arc::new(move |event: &Box<dyn UnifiedEvent>| {
             callback(event.clone_boxed());
@@ -556,7 +919,7 @@ impl EventParser {
         bot_wallet: Option<Pubkey>,
         transaction_index: Option<u64>,
         callback: Arc<dyn for<'a> Fn(&'a Box<dyn UnifiedEvent>) + Send + Sync>,
-    ) -> anyhow::Result<()> {
+    ) -> anyhow::Result<usize> {
         if let Some(transition) = grpc_tx.transaction {
             if let Some(message) = &transition.message {
                 let mut address_table_lookups: Vec<Vec<u8>> = vec![];
@@ -564,13 +927,33 @@ impl EventParser {
                     yellowstone_grpc_proto::solana::storage::confirmed_block::InnerInstructions,
                 > = vec![];
 
-                if let Some(meta) = grpc_tx.meta {
-                    inner_instructions = meta.inner_instructions;
+                let mut program_data_logs: Vec<ProgramDataLog> = vec![];
+
+                let mut meta = grpc_tx.meta;
+                if let Some(meta) = meta.as_mut() {
+                    inner_instructions = std::mem::take(&mut meta.inner_instructions);
+                    // 外层指令的程序 ID 只能来自静态账户
+                    let instruction_programs: Vec<Pubkey> = message
+                        .instructions
+                        .iter()
+                        .map(|ix| {
+                            message
+                                .account_keys
+                                .get(ix.program_id_index as usize)
+                                .and_then(|key| Pubkey::try_from(key.as_slice()).ok())
+                                .unwrap_or_default()
+                        })
+                        .collect();
+                    program_data_logs = parse_program_data_logs(
+                        &meta.log_messages,
+                        &instruction_programs,
+                        |id| self.should_handle(id),
+                    );
                     address_table_lookups.reserve(
                         meta.loaded_writable_addresses.len() + meta.loaded_writable_addresses.len(),
                     );
-                    let loaded_writable_addresses = meta.loaded_writable_addresses;
-                    let loaded_readonly_addresses = meta.loaded_readonly_addresses;
+                    let loaded_writable_addresses = std::mem::take(&mut meta.loaded_writable_addresses);
+                    let loaded_readonly_addresses = std::mem::take(&mut meta.loaded_readonly_addresses);
                     address_table_lookups.extend(
                         loaded_writable_addresses.into_iter().chain(loaded_readonly_addresses),
                     );
@@ -594,6 +977,28 @@ impl EventParser {
                 // 使用 Arc 包装共享数据，避免不必要的克隆
                 let accounts_arc = Arc::new(accounts);
                 let inner_instructions_arc = Arc::new(inner_instructions);
+                let meta = meta.as_ref();
+                let mut tx_ctx = TransactionParseContext::new(
+                    program_data_logs,
+                    meta.is_some_and(|meta| meta.err.is_some()),
+                    || TransactionContext::from_grpc_transaction(message, meta, &accounts_arc),
+                    || {
+                        meta.map(|meta| TokenAccountMints::from_grpc_meta(meta, &accounts_arc))
+                            .unwrap_or_default()
+                    },
+                );
+                if let Some(meta) = meta.filter(|_| self.emit_balance_changes) {
+                    let metadata = balance_change_metadata(
+                        signature,
+                        slot.unwrap_or(0),
+                        block_time,
+                        recv_us,
+                        transaction_index,
+                        &tx_ctx.tx_context,
+                    );
+                    tx_ctx.balance_changes =
+                        BalanceChangeEvent::from_grpc_meta(meta, &accounts_arc, &metadata);
+                }
                 // 解析指令事件
                 let instructions = &message.instructions;
                 self.parse_instruction_events_from_grpc_transaction(
@@ -604,15 +1009,17 @@ impl EventParser {
                     recv_us,
                     &accounts_arc,
                     &inner_instructions_arc,
+                    &tx_ctx,
                     bot_wallet,
                     transaction_index,
                     callback.clone(),
                 )
                 .await?;
+                return Ok(tx_ctx.parsed_events());
             }
         }
 
-        Ok(())
+        Ok(0)
     }
 
     pub async fn parse_encoded_confirmed_transaction_with_status_meta(
@@ -674,7 +1081,27 @@ impl EventParser {
 
         let meta = transaction.transaction.meta;
         let mut address_table_lookups: Vec<Pubkey> = vec![];
-        if let Some(meta) = meta {
+        let mut program_data_logs: Vec<ProgramDataLog> = vec![];
+        if let Some(meta) = &meta {
+            if let solana_transaction_status::option_serializer::OptionSerializer::Some(
+                log_messages,
+            ) = &meta.log_messages
+            {
+                let static_keys = versioned_tx.message.static_account_keys();
+                let instruction_programs: Vec<Pubkey> = versioned_tx
+                    .message
+                    .instructions()
+                    .iter()
+                    .map(|ix| {
+                        static_keys.get(ix.program_id_index as usize).copied().unwrap_or_default()
+                    })
+                    .collect();
+                program_data_logs = parse_program_data_logs(
+                    log_messages,
+                    &instruction_programs,
+                    |id| self.should_handle(id),
+                );
+            }
             if let solana_transaction_status::option_serializer::OptionSerializer::Some(
                 loaded_addresses,
             ) = &meta.loaded_addresses
@@ -700,15 +1127,37 @@ impl EventParser {
         );
         accounts.extend_from_slice(versioned_tx.message.static_account_keys());
         accounts.extend(address_table_lookups);
-        // 使用 Arc 包装共享数据，避免不必要的克隆
-        let accounts_arc = Arc::new(accounts);
-        let inner_instructions_arc = Arc::new(inner_instructions);
-
         let slot = transaction.slot;
         let block_time = transaction.block_time.map(|t| Timestamp { seconds: t as i64, nanos: 0 });
         let recv_us = get_high_perf_clock();
         let bot_wallet = None;
         let transaction_index = None;
+
+        // 使用 Arc 包装共享数据，避免不必要的克隆
+        let accounts_arc = Arc::new(accounts);
+        let inner_instructions_arc = Arc::new(inner_instructions);
+        let meta = meta.as_ref();
+        let mut tx_ctx = TransactionParseContext::new(
+            program_data_logs,
+            meta.is_some_and(|meta| meta.err.is_some()),
+            || TransactionContext::from_versioned_transaction(&versioned_tx, meta),
+            || {
+                meta.map(|meta| TokenAccountMints::from_ui_meta(meta, &accounts_arc))
+                    .unwrap_or_default()
+            },
+        );
+        if let Some(meta) = meta.filter(|_| self.emit_balance_changes) {
+            let metadata = balance_change_metadata(
+                signature,
+                slot,
+                block_time,
+                recv_us,
+                transaction_index,
+                &tx_ctx.tx_context,
+            );
+            tx_ctx.balance_changes =
+                BalanceChangeEvent::from_ui_meta(meta, &accounts_arc, &metadata);
+        }
         // 解析指令事件
         self.parse_instruction_events_from_versioned_transaction(
             &versioned_tx,
@@ -718,6 +1167,7 @@ impl EventParser {
             recv_us,
             &accounts_arc,
             &inner_instructions_arc,
+            &tx_ctx,
             bot_wallet,
             transaction_index,
             callback.clone(),
@@ -732,7 +1182,7 @@ impl EventParser {
     #[allow(clippy::too_many_arguments)]
     fn parse_inner_instruction_event(
         &self,
-        config: &GenericEventParseConfig,
+        config: &EventParseConfig,
         data: &[u8],
         signature: Signature,
         slot: u64,
@@ -742,7 +1192,7 @@ impl EventParser {
         inner_index: Option<i64>,
         transaction_index: Option<u64>,
     ) -> Option<Box<dyn UnifiedEvent>> {
-        if let Some(parser) = config.inner_instruction_parser {
+        if let Some(parser) = &config.inner_instruction_parser {
             let timestamp = block_time.unwrap_or(Timestamp { seconds: 0, nanos: 0 });
             let block_time_ms = timestamp.seconds * 1000 + (timestamp.nanos as i64) / 1_000_000;
             let metadata = EventMetadata::new(
@@ -758,7 +1208,17 @@ impl EventParser {
                 recv_us,
                 transaction_index,
             );
-            parser(data, metadata)
+            let event = parser(data, metadata);
+            if event.is_none() {
+                self.diagnostics.record_decode_failure(
+                    config.program_id,
+                    &config.event_type,
+                    UnparsedReason::InnerDecodeFailed,
+                    data.len(),
+                    signature,
+                );
+            }
+            event
         } else {
             None
         }
@@ -768,7 +1228,7 @@ impl EventParser {
     #[allow(clippy::too_many_arguments)]
     fn parse_instruction_event(
         &self,
-        config: &GenericEventParseConfig,
+        config: &EventParseConfig,
         data: &[u8],
         account_pubkeys: &[Pubkey],
         signature: Signature,
@@ -779,7 +1239,7 @@ impl EventParser {
         inner_index: Option<i64>,
         transaction_index: Option<u64>,
     ) -> Option<Box<dyn UnifiedEvent>> {
-        if let Some(parser) = config.instruction_parser {
+        if let Some(parser) = &config.instruction_parser {
             let timestamp = block_time.unwrap_or(Timestamp { seconds: 0, nanos: 0 });
             let block_time_ms = timestamp.seconds * 1000 + (timestamp.nanos as i64) / 1_000_000;
             let metadata = EventMetadata::new(
@@ -801,6 +1261,55 @@ impl EventParser {
         }
     }
 
+    /// 从当前指令输出的 `Program data:` 日志中解析事件数据
+    ///
+    /// 日志数据为事件 discriminator + 事件内容，对应内联指令数据去掉 event-cpi tag 后的部分，
+    /// 因此可直接复用 `inner_instruction_parser`。
+    #[allow(clippy::too_many_arguments)]
+    fn parse_event_from_program_data_logs(
+        &self,
+        program_data_logs: &[ProgramDataLog],
+        program_id: &Pubkey,
+        signature: Signature,
+        slot: u64,
+        block_time: Option<Timestamp>,
+        recv_us: i64,
+        outer_index: i64,
+        inner_index: Option<i64>,
+        transaction_index: Option<u64>,
+        config: &EventParseConfig,
+    ) -> Option<Box<dyn UnifiedEvent>> {
+        config.inner_instruction_parser.as_ref()?;
+        let discriminator = config
+            .inner_instruction_discriminator
+            .strip_prefix(ANCHOR_EVENT_IX_TAG.as_slice())
+            .unwrap_or(&config.inner_instruction_discriminator);
+        if discriminator.is_empty() {
+            return None;
+        }
+        program_data_logs
+            .iter()
+            .filter(|log| {
+                log.program_id == *program_id
+                    && log.outer_index == outer_index
+                    && log.inner_index == inner_index
+                    && log.data.starts_with(discriminator)
+            })
+            .find_map(|log| {
+                self.parse_inner_instruction_event(
+                    config,
+                    &log.data[discriminator.len()..],
+                    signature,
+                    slot,
+                    block_time,
+                    recv_us,
+                    outer_index,
+                    inner_index,
+                    transaction_index,
+                )
+            })
+    }
+
     /// 从内联指令中解析事件数据
     #[allow(clippy::too_many_arguments)]
     fn parse_events_from_inner_instruction(
@@ -813,7 +1322,7 @@ impl EventParser {
         outer_index: i64,
         inner_index: Option<i64>,
         transaction_index: Option<u64>,
-        config: &GenericEventParseConfig,
+        config: &EventParseConfig,
     ) -> Vec<Box<dyn UnifiedEvent>> {
         // Use SIMD-optimized data validation with correct discriminator length
         let discriminator_len = config.inner_instruction_discriminator.len();
@@ -828,7 +1337,7 @@ impl EventParser {
         // Use SIMD-optimized discriminator matching
         if !SimdUtils::fast_discriminator_match(
             &inner_instruction.data,
-            config.inner_instruction_discriminator,
+            &config.inner_instruction_discriminator,
         ) {
             return Vec::new();
         }
@@ -863,7 +1372,7 @@ impl EventParser {
         outer_index: i64,
         inner_index: Option<i64>,
         transaction_index: Option<u64>,
-        config: &GenericEventParseConfig,
+        config: &EventParseConfig,
     ) -> Vec<Box<dyn UnifiedEvent>> {
         // Use SIMD-optimized data validation with correct discriminator length
         let discriminator_len = config.inner_instruction_discriminator.len();
@@ -878,7 +1387,7 @@ impl EventParser {
         // Use SIMD-optimized discriminator matching
         if !SimdUtils::fast_discriminator_match(
             &inner_instruction.data,
-            config.inner_instruction_discriminator,
+            &config.inner_instruction_discriminator,
         ) {
             return Vec::new();
         }
@@ -913,9 +1422,11 @@ impl EventParser {
         recv_us: i64,
         outer_index: i64,
         inner_index: Option<i64>,
+        invocation: &InstructionInvocation,
         bot_wallet: Option<Pubkey>,
         transaction_index: Option<u64>,
         inner_instructions: Option<&InnerInstructions>,
+        tx_ctx: &TransactionParseContext<'_>,
         callback: Arc<dyn for<'a> Fn(&'a Box<dyn UnifiedEvent>) + Send + Sync>,
     ) -> anyhow::Result<()> {
         let program_id = accounts[instruction.program_id_index as usize];
@@ -939,6 +1450,28 @@ impl EventParser {
             })
             .collect();
 
+        if all_processing_params.is_empty() {
+            let unmatched = self.unmatched_instruction_events(
+                &program_id,
+                &instruction.accounts,
+                &instruction.data,
+                accounts,
+                invocation,
+                signature,
+                slot,
+                block_time,
+                recv_us,
+                outer_index,
+                inner_index,
+                transaction_index,
+                tx_ctx,
+            );
+            for event in &unmatched {
+                callback(event);
+            }
+            return Ok(());
+        }
+
         // Use SIMD-optimized account indices validation (只需检查一次)
         if !SimdUtils::validate_account_indices_simd(&instruction.accounts, accounts.len()) {
             return Ok(());
@@ -951,11 +1484,12 @@ impl EventParser {
         };
 
         // 并行处理所有 (discriminator, config) 组合
+        let mut unparsed_events = Vec::new();
         let all_results: Vec<_> = all_processing_params
             .iter()
             .filter_map(|(disc, config)| {
                 let data = &instruction.data[disc.len()..];
-                self.parse_instruction_event(
+                let event = self.parse_instruction_event(
                     config,
                     data,
                     &account_pubkeys,
@@ -966,15 +1500,37 @@ impl EventParser {
                     outer_index,
                     inner_index,
                     transaction_index,
-                )
-                .map(|event| ((*disc).clone(), (*config).clone(), event))
+                );
+                if event.is_none() {
+                    unparsed_events.extend(self.decode_failure_event(
+                        config,
+                        disc,
+                        data,
+                        invocation,
+                        signature,
+                        slot,
+                        block_time,
+                        recv_us,
+                        outer_index,
+                        inner_index,
+                        transaction_index,
+                    ));
+                }
+                event.map(|event| (*disc, *config, event))
             })
             .collect();
+        tx_ctx.record_parsed_events(all_results.len());
 
+        for event in &unparsed_events {
+            callback(event);
+        }
+
+        // 失败交易只保留指令层面的信息，不合并 CPI 事件与 swap_data
+        let failed = tx_ctx.is_failed();
         for (_disc, config, mut event) in all_results {
             // 阻塞处理：原有的同步逻辑
             let mut inner_instruction_event: Option<Box<dyn UnifiedEvent>> = None;
-            if inner_instructions.is_some() {
+            if !failed && inner_instructions.is_some() {
                 let inner_instructions_ref = inner_instructions.unwrap();
 
                 // 并行执行两个任务
@@ -990,7 +1546,7 @@ impl EventParser {
                                 outer_index,
                                 inner_index,
                                 transaction_index,
-                                &config,
+                                config,
                             );
                             if result.len() > 0 {
                                 return Some(result[0].clone());
@@ -1001,11 +1557,12 @@ impl EventParser {
 
                     let swap_data_handle = s.spawn(|| {
                         if !event.swap_data_is_parsed() {
-                            parse_swap_data_from_next_instructions(
+                            parse_swap_data(
                                 &*event,
-                                inner_instructions_ref,
-                                inner_index.unwrap_or(-1_i64) as i8,
-                                &accounts,
+                                &inner_instructions_ref.instructions,
+                                inner_index,
+                                accounts,
+                                &tx_ctx.token_mints,
                             )
                         } else {
                             None
@@ -1022,8 +1579,24 @@ impl EventParser {
                 }
             }
 
+            // 未找到 CPI 事件时，回退到 `Program data:` 日志
+            if !failed && inner_instruction_event.is_none() {
+                inner_instruction_event = self.parse_event_from_program_data_logs(
+                    &tx_ctx.program_data_logs,
+                    &program_id,
+                    signature,
+                    slot,
+                    block_time,
+                    recv_us,
+                    outer_index,
+                    inner_index,
+                    transaction_index,
+                    config,
+                );
+            }
+
             // Skip events that require inner instruction data but don't have it
-            if config.requires_inner_instruction && inner_instruction_event.is_none() {
+            if !failed && config.requires_inner_instruction && inner_instruction_event.is_none() {
                 continue;
             }
 
@@ -1032,13 +1605,37 @@ impl EventParser {
                 event.merge(&*inner_instruction_event);
             }
             // 设置处理时间（使用高性能时钟）
+            event.set_tx_context(Arc::clone(&tx_ctx.tx_context));
+            event.set_invocation(invocation.clone());
+            let inner = inner_instructions.map_or(&[][..], |ixs| ixs.instructions.as_slice());
+            attach_router(
+                &mut *event,
+                invocation,
+                outer_index,
+                inner,
+                accounts,
+                &tx_ctx.token_mints,
+                tx_ctx.signers(),
+            );
+            if !failed {
+                complete_jupiter_route(&mut *event, inner, inner_index, accounts);
+            }
             event.set_handle_us(elapsed_micros_since(recv_us));
             event = process_event(event, bot_wallet);
             callback(&event);
+            let derived =
+                self.derived_events(&*event, inner, inner_index, accounts, tx_ctx, failed);
+            for derived in derived {
+                callback(&derived);
+            }
+            if self.emit_token_launches && !failed {
+                tx_ctx.track_token_launch(&*event);
+            }
         }
         Ok(())
     }
 
+    #[allow(clippy::too_many_arguments)]
     fn parse_events_from_instruction_and_pack(
         &self,
         instruction: &CompiledInstruction,
@@ -1049,9 +1646,11 @@ impl EventParser {
         recv_us: i64,
         outer_index: i64,
         inner_index: Option<i64>,
+        invocation: &InstructionInvocation,
         bot_wallet: Option<Pubkey>,
         transaction_index: Option<u64>,
         inner_instructions: Option<&InnerInstructions>,
+        tx_ctx: &TransactionParseContext<'_>,
     ) -> anyhow::Result<Vec<Box<dyn UnifiedEvent>>> {
         let program_id = accounts[instruction.program_id_index as usize];
         if !self.should_handle(&program_id) {
@@ -1074,6 +1673,24 @@ impl EventParser {
             })
             .collect();
 
+        if all_processing_params.is_empty() {
+            return Ok(self.unmatched_instruction_events(
+                &program_id,
+                &instruction.accounts,
+                &instruction.data,
+                accounts,
+                invocation,
+                signature,
+                slot,
+                block_time,
+                recv_us,
+                outer_index,
+                inner_index,
+                transaction_index,
+                tx_ctx,
+            ));
+        }
+
         // Use SIMD-optimized account indices validation (只需检查一次)
         if !SimdUtils::validate_account_indices_simd(&instruction.accounts, accounts.len()) {
             return Ok(vec![]);
@@ -1086,11 +1703,12 @@ impl EventParser {
         };
 
         // 并行处理所有 (discriminator, config) 组合
+        let mut unparsed_events = Vec::new();
         let all_results: Vec<_> = all_processing_params
             .iter()
             .filter_map(|(disc, config)| {
                 let data = &instruction.data[disc.len()..];
-                self.parse_instruction_event(
+                let event = self.parse_instruction_event(
                     config,
                     data,
                     &account_pubkeys,
@@ -1101,17 +1719,35 @@ impl EventParser {
                     outer_index,
                     inner_index,
                     transaction_index,
-                )
-                    .map(|event| ((*disc).clone(), (*config).clone(), event))
+                );
+                if event.is_none() {
+                    unparsed_events.extend(self.decode_failure_event(
+                        config,
+                        disc,
+                        data,
+                        invocation,
+                        signature,
+                        slot,
+                        block_time,
+                        recv_us,
+                        outer_index,
+                        inner_index,
+                        transaction_index,
+                    ));
+                }
+                event.map(|event| (*disc, *config, event))
             })
             .collect();
+        tx_ctx.record_parsed_events(all_results.len());
 
-        let mut events = vec![];
+        let mut events = unparsed_events;
 
+        // 失败交易只保留指令层面的信息，不合并 CPI 事件与 swap_data
+        let failed = tx_ctx.is_failed();
         for (_disc, config, mut event) in all_results {
             // 阻塞处理：原有的同步逻辑
             let mut inner_instruction_event: Option<Box<dyn UnifiedEvent>> = None;
-            if inner_instructions.is_some() {
+            if !failed && inner_instructions.is_some() {
                 let inner_instructions_ref = inner_instructions.unwrap();
 
                 // 并行执行两个任务
@@ -1127,7 +1763,7 @@ impl EventParser {
                                 outer_index,
                                 inner_index,
                                 transaction_index,
-                                &config,
+                                config,
                             );
                             if result.len() > 0 {
                                 return Some(result[0].clone());
@@ -1138,11 +1774,12 @@ impl EventParser {
 
                     let swap_data_handle = s.spawn(|| {
                         if !event.swap_data_is_parsed() {
-                            parse_swap_data_from_next_instructions(
+                            parse_swap_data(
                                 &*event,
-                                inner_instructions_ref,
-                                inner_index.unwrap_or(-1_i64) as i8,
-                                &accounts,
+                                &inner_instructions_ref.instructions,
+                                inner_index,
+                                accounts,
+                                &tx_ctx.token_mints,
                             )
                         } else {
                             None
@@ -1159,8 +1796,24 @@ impl EventParser {
                 }
             }
 
+            // 未找到 CPI 事件时，回退到 `Program data:` 日志
+            if !failed && inner_instruction_event.is_none() {
+                inner_instruction_event = self.parse_event_from_program_data_logs(
+                    &tx_ctx.program_data_logs,
+                    &program_id,
+                    signature,
+                    slot,
+                    block_time,
+                    recv_us,
+                    outer_index,
+                    inner_index,
+                    transaction_index,
+                    config,
+                );
+            }
+
             // Skip events that require inner instruction data but don't have it
-            if config.requires_inner_instruction && inner_instruction_event.is_none() {
+            if !failed && config.requires_inner_instruction && inner_instruction_event.is_none() {
                 continue;
             }
 
@@ -1169,9 +1822,30 @@ impl EventParser {
                 event.merge(&*inner_instruction_event);
             }
             // 设置处理时间（使用高性能时钟）
+            event.set_tx_context(Arc::clone(&tx_ctx.tx_context));
+            event.set_invocation(invocation.clone());
+            let inner = inner_instructions.map_or(&[][..], |ixs| ixs.instructions.as_slice());
+            attach_router(
+                &mut *event,
+                invocation,
+                outer_index,
+                inner,
+                accounts,
+                &tx_ctx.token_mints,
+                tx_ctx.signers(),
+            );
+            if !failed {
+                complete_jupiter_route(&mut *event, inner, inner_index, accounts);
+            }
             event.set_handle_us(elapsed_micros_since(recv_us));
             event = process_event(event, bot_wallet);
+            let derived =
+                self.derived_events(&*event, inner, inner_index, accounts, tx_ctx, failed);
+            if self.emit_token_launches && !failed {
+                tx_ctx.track_token_launch(&*event);
+            }
             events.push(event);
+            events.extend(derived);
         }
         Ok(events)
     }
@@ -1189,9 +1863,11 @@ impl EventParser {
         recv_us: i64,
         outer_index: i64,
         inner_index: Option<i64>,
+        invocation: &InstructionInvocation,
         bot_wallet: Option<Pubkey>,
         transaction_index: Option<u64>,
         inner_instructions: Option<&yellowstone_grpc_proto::prelude::InnerInstructions>,
+        tx_ctx: &TransactionParseContext<'_>,
         callback: Arc<dyn for<'a> Fn(&'a Box<dyn UnifiedEvent>) + Send + Sync>,
     ) -> anyhow::Result<()> {
         let program_id = accounts[instruction.program_id_index as usize];
@@ -1215,6 +1891,28 @@ impl EventParser {
             })
             .collect();
 
+        if all_processing_params.is_empty() {
+            let unmatched = self.unmatched_instruction_events(
+                &program_id,
+                &instruction.accounts,
+                &instruction.data,
+                accounts,
+                invocation,
+                signature,
+                slot,
+                block_time,
+                recv_us,
+                outer_index,
+                inner_index,
+                transaction_index,
+                tx_ctx,
+            );
+            for event in &unmatched {
+                callback(event);
+            }
+            return Ok(());
+        }
+
         // Use SIMD-optimized account indices validation (只需检查一次)
         if !SimdUtils::validate_account_indices_simd(&instruction.accounts, accounts.len()) {
             return Ok(());
@@ -1227,11 +1925,12 @@ impl EventParser {
         };
 
         // 并行处理所有 (discriminator, config) 组合
+        let mut unparsed_events = Vec::new();
         let all_results: Vec<_> = all_processing_params
             .iter()
             .filter_map(|(disc, config)| {
                 let data = &instruction.data[disc.len()..];
-                self.parse_instruction_event(
+                let event = self.parse_instruction_event(
                     config,
                     data,
                     &account_pubkeys,
@@ -1242,15 +1941,37 @@ impl EventParser {
                     outer_index,
                     inner_index,
                     transaction_index,
-                )
-                .map(|event| ((*disc).clone(), (*config).clone(), event))
+                );
+                if event.is_none() {
+                    unparsed_events.extend(self.decode_failure_event(
+                        config,
+                        disc,
+                        data,
+                        invocation,
+                        signature,
+                        slot,
+                        block_time,
+                        recv_us,
+                        outer_index,
+                        inner_index,
+                        transaction_index,
+                    ));
+                }
+                event.map(|event| (*disc, *config, event))
             })
             .collect();
+        tx_ctx.record_parsed_events(all_results.len());
 
+        for event in &unparsed_events {
+            callback(event);
+        }
+
+        // 失败交易只保留指令层面的信息，不合并 CPI 事件与 swap_data
+        let failed = tx_ctx.is_failed();
         for (_disc, config, mut event) in all_results {
             // 阻塞处理：原有的同步逻辑
             let mut inner_instruction_event: Option<Box<dyn UnifiedEvent>> = None;
-            if inner_instructions.is_some() {
+            if !failed && inner_instructions.is_some() {
                 let inner_instructions_ref = inner_instructions.unwrap();
 
                 // 并行执行两个任务
@@ -1266,7 +1987,7 @@ impl EventParser {
                                 outer_index,
                                 inner_index,
                                 transaction_index,
-                                &config,
+                                config,
                             );
                             if result.len() > 0 {
                                 return Some(result[0].clone());
@@ -1277,11 +1998,12 @@ impl EventParser {
 
                     let swap_data_handle = s.spawn(|| {
                         if !event.swap_data_is_parsed() {
-                            parse_swap_data_from_next_grpc_instructions(
+                            parse_swap_data(
                                 &*event,
-                                inner_instructions_ref,
-                                inner_index.unwrap_or(-1_i64) as i8,
-                                &accounts,
+                                &inner_instructions_ref.instructions,
+                                inner_index,
+                                accounts,
+                                &tx_ctx.token_mints,
                             )
                         } else {
                             None
@@ -1298,8 +2020,24 @@ impl EventParser {
                 }
             }
 
+            // 未找到 CPI 事件时，回退到 `Program data:` 日志
+            if !failed && inner_instruction_event.is_none() {
+                inner_instruction_event = self.parse_event_from_program_data_logs(
+                    &tx_ctx.program_data_logs,
+                    &program_id,
+                    signature,
+                    slot,
+                    block_time,
+                    recv_us,
+                    outer_index,
+                    inner_index,
+                    transaction_index,
+                    config,
+                );
+            }
+
             // Skip events that require inner instruction data but don't have it
-            if config.requires_inner_instruction && inner_instruction_event.is_none() {
+            if !failed && config.requires_inner_instruction && inner_instruction_event.is_none() {
                 continue;
             }
 
@@ -1308,27 +2046,275 @@ impl EventParser {
                 event.merge(&*inner_instruction_event);
             }
             // 设置处理时间（使用高性能时钟）
+            event.set_tx_context(Arc::clone(&tx_ctx.tx_context));
+            event.set_invocation(invocation.clone());
+            let inner = inner_instructions.map_or(&[][..], |ixs| ixs.instructions.as_slice());
+            attach_router(
+                &mut *event,
+                invocation,
+                outer_index,
+                inner,
+                accounts,
+                &tx_ctx.token_mints,
+                tx_ctx.signers(),
+            );
+            if !failed {
+                complete_jupiter_route(&mut *event, inner, inner_index, accounts);
+            }
             event.set_handle_us(elapsed_micros_since(recv_us));
             event = process_event(event, bot_wallet);
             callback(&event);
+            let derived =
+                self.derived_events(&*event, inner, inner_index, accounts, tx_ctx, failed);
+            for derived in derived {
+                callback(&derived);
+            }
+            if self.emit_token_launches && !failed {
+                tx_ctx.track_token_launch(&*event);
+            }
         }
         Ok(())
     }
 
+    /// 处理没有匹配任何指令配置的指令：记录诊断统计，并按需输出
+    /// `UnparsedInstructionEvent` 与 `RawInstructionEvent`
+    #[allow(clippy::too_many_arguments)]
+    fn unmatched_instruction_events(
+        &self,
+        program_id: &Pubkey,
+        instruction_accounts: &[u8],
+        data: &[u8],
+        accounts: &[Pubkey],
+        invocation: &InstructionInvocation,
+        signature: Signature,
+        slot: u64,
+        block_time: Option<Timestamp>,
+        recv_us: i64,
+        outer_index: i64,
+        inner_index: Option<i64>,
+        transaction_index: Option<u64>,
+        tx_ctx: &TransactionParseContext<'_>,
+    ) -> Vec<Box<dyn UnifiedEvent>> {
+        // 程序自身的 Anchor event CPI 由 CPI 事件解析处理
+        if data.starts_with(&ANCHOR_EVENT_IX_TAG) {
+            return Vec::new();
+        }
+        let Some(known) = self.known_instructions.get(program_id) else {
+            return Vec::new();
+        };
+        // 被 EventTypeFilter 过滤掉的指令不计入诊断，但计入解析出的事件数
+        if known.discriminators.iter().any(|discriminator| data.starts_with(discriminator)) {
+            tx_ctx.record_parsed_events(1);
+            return Vec::new();
+        }
+        let discriminator_len = match known.discriminator_len {
+            0 => MAX_DIAGNOSTIC_DISCRIMINATOR_LEN,
+            len => len.min(MAX_DIAGNOSTIC_DISCRIMINATOR_LEN),
+        };
+        let discriminator = &data[..data.len().min(discriminator_len)];
+        self.diagnostics.record_unmatched(*program_id, discriminator, data.len(), signature);
+
+        let mut events: Vec<Box<dyn UnifiedEvent>> = Vec::new();
+        let metadata = |event_type: EventType| {
+            instruction_metadata(
+                known.protocol_type.clone(),
+                event_type,
+                *program_id,
+                invocation,
+                signature,
+                slot,
+                block_time,
+                recv_us,
+                outer_index,
+                inner_index,
+                transaction_index,
+            )
+        };
+        if self.emit_unparsed_instructions {
+            events.push(Box::new(UnparsedInstructionEvent {
+                metadata: metadata(EventType::UnparsedInstruction),
+                reason: UnparsedReason::UnknownDiscriminator,
+                attempted_event_type: EventType::Unknown,
+                discriminator: discriminator.to_vec(),
+                data_len: data.len() as u64,
+            }));
+        }
+        if self.emit_raw_instructions {
+            events.push(Box::new(RawInstructionEvent {
+                metadata: metadata(EventType::RawInstruction),
+                stack_height: invocation.stack_height,
+                accounts: instruction_accounts
+                    .iter()
+                    .map(|&index| accounts.get(index as usize).copied().unwrap_or_default())
+                    .collect(),
+                data: data.to_vec(),
+            }));
+        }
+        events
+    }
+
+    /// 记录 discriminator 匹配但解码失败的指令，启用时返回对应的 `UnparsedInstructionEvent`
+    #[allow(clippy::too_many_arguments)]
+    fn decode_failure_event(
+        &self,
+        config: &EventParseConfig,
+        discriminator: &[u8],
+        data: &[u8],
+        invocation: &InstructionInvocation,
+        signature: Signature,
+        slot: u64,
+        block_time: Option<Timestamp>,
+        recv_us: i64,
+        outer_index: i64,
+        inner_index: Option<i64>,
+        transaction_index: Option<u64>,
+    ) -> Option<Box<dyn UnifiedEvent>> {
+        config.instruction_parser.as_ref()?;
+        self.diagnostics.record_decode_failure(
+            config.program_id,
+            &config.event_type,
+            UnparsedReason::DecodeFailed,
+            data.len(),
+            signature,
+        );
+        if !self.emit_unparsed_instructions {
+            return None;
+        }
+        let metadata = instruction_metadata(
+            config.protocol_type.clone(),
+            EventType::UnparsedInstruction,
+            config.program_id,
+            invocation,
+            signature,
+            slot,
+            block_time,
+            recv_us,
+            outer_index,
+            inner_index,
+            transaction_index,
+        );
+        Some(Box::new(UnparsedInstructionEvent {
+            metadata,
+            reason: UnparsedReason::DecodeFailed,
+            attempted_event_type: config.event_type.clone(),
+            discriminator: discriminator[..discriminator.len().min(MAX_DIAGNOSTIC_DISCRIMINATOR_LEN)]
+                .to_vec(),
+            data_len: (discriminator.len() + data.len()) as u64,
+        }))
+    }
+
     fn should_handle(&self, program_id: &Pubkey) -> bool {
         self.program_ids.contains(program_id)
     }
 
+    /// gRPC 交易的账户（含地址查找表加载的账户）中是否包含订阅的程序
+    pub fn matches_grpc_transaction(&self, grpc_tx: &SubscribeUpdateTransactionInfo) -> bool {
+        let account_keys = grpc_tx
+            .transaction
+            .as_ref()
+            .and_then(|transaction| transaction.message.as_ref())
+            .map(|message| message.account_keys.as_slice())
+            .unwrap_or_default();
+        let loaded_addresses = grpc_tx.meta.as_ref().map_or(&[][..], |meta| {
+            meta.loaded_writable_addresses.as_slice()
+        });
+        let loaded_readonly_addresses = grpc_tx.meta.as_ref().map_or(&[][..], |meta| {
+            meta.loaded_readonly_addresses.as_slice()
+        });
+        account_keys.iter().chain(loaded_addresses).chain(loaded_readonly_addresses).any(|key| {
+            self.program_ids.iter().any(|program_id| program_id.as_ref() == key.as_slice())
+        })
+    }
+
+    /// 交易的静态账户中是否包含订阅的程序
+    pub fn matches_versioned_transaction(&self, transaction: &VersionedTransaction) -> bool {
+        transaction.message.static_account_keys().iter().any(|account| self.should_handle(account))
+    }
+
+    /// 由指令事件派生的 `NormalizedTrade` / `Graduation` / `LiquidityChange`，
+    /// 未启用或交易失败时为空
+    fn derived_events<I: InnerInstructionView>(
+        &self,
+        event: &dyn UnifiedEvent,
+        inner_instructions: &[I],
+        inner_index: Option<i64>,
+        accounts: &[Pubkey],
+        tx_ctx: &TransactionParseContext<'_>,
+        failed: bool,
+    ) -> Vec<Box<dyn UnifiedEvent>> {
+        let mut derived: Vec<Box<dyn UnifiedEvent>> = Vec::new();
+        if failed {
+            return derived;
+        }
+        if self.emit_normalized_trades {
+            if let Some(trade) = NormalizedTrade::from_event(event, &tx_ctx.token_mints) {
+                derived.push(Box::new(trade));
+            }
+        }
+        if self.emit_graduations {
+            if let Some(graduation) = Graduation::from_event(event, &tx_ctx.token_mints) {
+                derived.push(Box::new(graduation));
+            }
+        }
+        if self.emit_liquidity_changes {
+            if let Some(change) = LiquidityChange::from_event(
+                event,
+                inner_instructions,
+                inner_index,
+                accounts,
+                &tx_ctx.token_mints,
+            ) {
+                derived.push(Box::new(change));
+            }
+        }
+        derived
+    }
+
     // fn supported_program_ids(&self) -> Vec<Pubkey> {
     //     self.program_ids.clone()
     // }
 }
 
+#[allow(clippy::too_many_arguments)]
+fn instruction_metadata(
+    protocol: ProtocolType,
+    event_type: EventType,
+    program_id: Pubkey,
+    invocation: &InstructionInvocation,
+    signature: Signature,
+    slot: u64,
+    block_time: Option<Timestamp>,
+    recv_us: i64,
+    outer_index: i64,
+    inner_index: Option<i64>,
+    transaction_index: Option<u64>,
+) -> EventMetadata {
+    let timestamp = block_time.unwrap_or(Timestamp { seconds: 0, nanos: 0 });
+    let block_time_ms = timestamp.seconds * 1000 + (timestamp.nanos as i64) / 1_000_000;
+    let mut metadata = EventMetadata::new(
+        signature,
+        slot,
+        timestamp.seconds,
+        block_time_ms,
+        protocol,
+        event_type,
+        program_id,
+        outer_index,
+        inner_index,
+        recv_us,
+        transaction_index,
+    );
+    metadata.set_invocation(invocation.clone());
+    metadata.handle_us = elapsed_micros_since(recv_us);
+    metadata
+}
+
 fn process_event(
     mut event: Box<dyn UnifiedEvent>,
     bot_wallet: Option<Pubkey>,
 ) -> Box<dyn UnifiedEvent> {
     let signature = *event.signature(); // Copy the signature to avoid borrowing issues
+    complete_swap_data(&mut *event);
     if let Some(token_info) = event.as_any().downcast_ref::<PumpFunCreateTokenEvent>() {
         add_dev_address(&signature, token_info.user);
         if token_info.creator != Pubkey::default() && token_info.creator != token_info.user {
@@ -1344,24 +2330,6 @@ fn process_event(
         } else {
             trade_info.is_dev_create_token_trade = false;
         }
-        if trade_info.metadata.swap_data.is_some() {
-            trade_info.metadata.swap_data.as_mut().unwrap().from_amount =
-                if trade_info.is_buy { trade_info.sol_amount } else { trade_info.token_amount };
-            trade_info.metadata.swap_data.as_mut().unwrap().to_amount =
-                if trade_info.is_buy { trade_info.token_amount } else { trade_info.sol_amount };
-        }
-    } else if let Some(trade_info) = event.as_any_mut().downcast_mut::<PumpSwapBuyEvent>() {
-        if trade_info.metadata.swap_data.is_some() {
-            trade_info.metadata.swap_data.as_mut().unwrap().from_amount =
-                trade_info.user_quote_amount_in;
-            trade_info.metadata.swap_data.as_mut().unwrap().to_amount = trade_info.base_amount_out;
-        }
-    } else if let Some(trade_info) = event.as_any_mut().downcast_mut::<PumpSwapSellEvent>() {
-        if trade_info.metadata.swap_data.is_some() {
-            trade_info.metadata.swap_data.as_mut().unwrap().from_amount = trade_info.base_amount_in;
-            trade_info.metadata.swap_data.as_mut().unwrap().to_amount =
-                trade_info.user_quote_amount_out;
-        }
     } else if let Some(pool_info) = event.as_any().downcast_ref::<BonkPoolCreateEvent>() {
         add_bonk_dev_address(&signature, pool_info.creator);
     } else if let Some(trade_info) = event.as_any_mut().downcast_mut::<BonkTradeEvent>() {
@@ -1375,3 +2343,68 @@ fn process_event(
     }
     event
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn parents(stack: &mut CallStack, inner: &[(Pubkey, Option<u32>)]) -> Vec<Option<Pubkey>> {
+        inner
+            .iter()
+            .map(|&(program_id, height)| stack.enter(program_id, height).parent_program_id)
+            .collect()
+    }
+
+    #[test]
+    fn call_stack_nested_cpi() {
+        let [outer, amm, token, system] = [(); 4].map(|_| Pubkey::new_unique());
+        let mut stack = CallStack::new(outer);
+        // outer -> amm(2) -> token(3)，随后回到 outer 调用 system(2)
+        let parents = parents(
+            &mut stack,
+            &[(amm, Some(2)), (token, Some(3)), (system, Some(2)), (token, Some(3))],
+        );
+        assert_eq!(parents, [Some(outer), Some(amm), Some(outer), Some(system)]);
+
+        let invocation = stack.enter(token, Some(3));
+        assert_eq!(
+            invocation,
+            InstructionInvocation {
+                stack_height: Some(3),
+                parent_program_id: Some(system),
+                invoking_program_id: outer,
+            }
+        );
+    }
+
+    #[test]
+    fn call_stack_siblings_share_parent() {
+        let [outer, amm, token] = [(); 3].map(|_| Pubkey::new_unique());
+        let mut stack = CallStack::new(outer);
+        // 同一高度的相邻指令互为兄弟，不互相成为调用方
+        let parents = parents(
+            &mut stack,
+            &[
+                (token, Some(2)),
+                (token, Some(2)),
+                (amm, Some(2)),
+                (token, Some(3)),
+                (token, Some(3)),
+            ],
+        );
+        assert_eq!(parents, [Some(outer), Some(outer), Some(outer), Some(amm), Some(amm)]);
+    }
+
+    #[test]
+    fn call_stack_without_stack_height() {
+        let [outer, amm, token] = [(); 3].map(|_| Pubkey::new_unique());
+        let mut stack = CallStack::new(outer);
+        // 旧数据没有 stack_height 时都视为由外层指令直接调用
+        let invocation = stack.enter(amm, None);
+        assert_eq!(invocation.stack_height, None);
+        assert_eq!(invocation.parent_program_id, Some(outer));
+        assert_eq!(invocation.invoking_program_id, outer);
+        let parents = parents(&mut stack, &[(token, None), (token, None)]);
+        assert_eq!(parents, [Some(outer), Some(outer)]);
+    }
+}
@@ -0,0 +1,228 @@
+use serde::{Deserialize, Serialize};
+use solana_sdk::pubkey::Pubkey;
+
+use crate::{
+    impl_unified_event,
+    streaming::event_parser::{
+        common::{EventMetadata, EventType, ProtocolType, TokenAccountMints},
+        core::balance_change::NATIVE_SOL_MINT,
+        protocols::{
+            bonk::{BonkMigrateToAmmEvent, BonkMigrateToCpswapEvent},
+            meteora_dbc::MeteoraDBCMigrationDammV2Event,
+            pumpfun::PumpFunMigrateEvent,
+        },
+        UnifiedEvent,
+    },
+};
+
+/// 跨发射平台统一的毕业（迁移到 AMM）事件，由各平台的迁移事件派生
+///
+/// 迁移的流动性优先取自事件字段，否则按发射平台 vault 在交易中减少的余额计算。
+#[derive(Clone, Debug, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct Graduation {
+    pub metadata: EventMetadata,
+    /// 原始事件类型
+    pub source_event_type: EventType,
+    pub mint: Pubkey,
+    pub quote_mint: Pubkey,
+    /// 发射平台
+    pub launchpad: ProtocolType,
+    /// 发射平台上的 bonding curve / 池子
+    pub source_pool: Pubkey,
+    /// 迁移目标 AMM
+    pub destination: ProtocolType,
+    /// 迁移后的新池子
+    pub pool: Pubkey,
+    /// 新池子的 LP mint，目标 AMM 使用仓位 NFT 时为默认值
+    pub lp_mint: Pubkey,
+    /// 迁移的代币数量
+    pub base_amount: u64,
+    /// 迁移的计价币数量
+    pub quote_amount: u64,
+}
+impl_unified_event!(Graduation,);
+
+impl Graduation {
+    /// 由迁移事件派生，其他事件返回 None
+    ///
+    /// `token_mints` 提供交易前后的 vault 余额。
+    pub fn from_event(event: &dyn UnifiedEvent, token_mints: &TokenAccountMints) -> Option<Self> {
+        let any = event.as_any();
+        let (metadata, graduation) = if let Some(e) = any.downcast_ref::<PumpFunMigrateEvent>() {
+            let graduation = Self {
+                mint: e.mint,
+                quote_mint: NATIVE_SOL_MINT,
+                launchpad: ProtocolType::PumpFun,
+                source_pool: e.bonding_curve,
+                destination: ProtocolType::PumpSwap,
+                pool: e.pool,
+                lp_mint: e.lp_mint,
+                base_amount: e.mint_amount,
+                quote_amount: e.sol_amount,
+                ..Default::default()
+            };
+            (&e.metadata, graduation)
+        } else if let Some(e) = any.downcast_ref::<BonkMigrateToAmmEvent>() {
+            let graduation = Self {
+                mint: e.base_mint,
+                quote_mint: e.quote_mint,
+                launchpad: ProtocolType::Bonk,
+                source_pool: e.pool_state,
+                destination: ProtocolType::RaydiumAmmV4,
+                pool: e.amm_pool,
+                lp_mint: e.amm_lp_mint,
+                base_amount: token_mints.outflow(&e.base_vault),
+                quote_amount: token_mints.outflow(&e.quote_vault),
+                ..Default::default()
+            };
+            (&e.metadata, graduation)
+        } else if let Some(e) = any.downcast_ref::<BonkMigrateToCpswapEvent>() {
+            let graduation = Self {
+                mint: e.base_mint,
+                quote_mint: e.quote_mint,
+                launchpad: ProtocolType::Bonk,
+                source_pool: e.pool_state,
+                destination: ProtocolType::RaydiumCpmm,
+                pool: e.cpswap_pool,
+                lp_mint: e.cpswap_lp_mint,
+                base_amount: token_mints.outflow(&e.base_vault),
+                quote_amount: token_mints.outflow(&e.quote_vault),
+                ..Default::default()
+            };
+            (&e.metadata, graduation)
+        } else {
+            let e = any.downcast_ref::<MeteoraDBCMigrationDammV2Event>()?;
+            let graduation = Self {
+                mint: e.base_mint,
+                quote_mint: e.quote_mint,
+                launchpad: ProtocolType::MeteoraDBC,
+                source_pool: e.virtual_pool,
+                destination: ProtocolType::MeteoraDAMMv2,
+                pool: e.pool,
+                base_amount: token_mints.outflow(&e.base_vault),
+                quote_amount: token_mints.outflow(&e.quote_vault),
+                ..Default::default()
+            };
+            (&e.metadata, graduation)
+        };
+        let mut metadata = metadata.clone();
+        metadata.event_type = EventType::Graduation;
+        Some(Self { metadata, source_event_type: event.event_type(), ..graduation })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use yellowstone_grpc_proto::prelude::{TokenBalance, TransactionStatusMeta, UiTokenAmount};
+
+    use super::*;
+
+    fn token_balance(account_index: u32, mint: &Pubkey, amount: u64) -> TokenBalance {
+        TokenBalance {
+            account_index,
+            mint: mint.to_string(),
+            ui_token_amount: Some(UiTokenAmount {
+                decimals: 6,
+                amount: amount.to_string(),
+                ..Default::default()
+            }),
+            ..Default::default()
+        }
+    }
+
+    /// base vault 与 quote vault 在交易中转出全部余额，quote vault 被关闭（post 中没有条目）
+    fn drained_vaults(
+        base_mint: &Pubkey,
+        quote_mint: &Pubkey,
+    ) -> (Pubkey, Pubkey, TokenAccountMints) {
+        let (base_vault, quote_vault) = (Pubkey::new_unique(), Pubkey::new_unique());
+        let meta = TransactionStatusMeta {
+            pre_token_balances: vec![
+                token_balance(0, base_mint, 206_900_000_000_000),
+                token_balance(1, quote_mint, 85_000_000_000),
+            ],
+            post_token_balances: vec![token_balance(0, base_mint, 0)],
+            ..Default::default()
+        };
+        let token_mints = TokenAccountMints::from_grpc_meta(&meta, &[base_vault, quote_vault]);
+        (base_vault, quote_vault, token_mints)
+    }
+
+    #[test]
+    fn pumpfun_migration_uses_event_amounts() {
+        let event = PumpFunMigrateEvent {
+            mint: Pubkey::new_unique(),
+            bonding_curve: Pubkey::new_unique(),
+            pool: Pubkey::new_unique(),
+            mint_amount: 206_900_000_000_000,
+            sol_amount: 84_990_359_016,
+            ..Default::default()
+        };
+        let graduation = Graduation::from_event(&event, &TokenAccountMints::default()).unwrap();
+        assert_eq!(graduation.metadata.event_type, EventType::Graduation);
+        assert_eq!(
+            (graduation.launchpad, graduation.destination),
+            (ProtocolType::PumpFun, ProtocolType::PumpSwap)
+        );
+        assert_eq!((graduation.source_pool, graduation.pool), (event.bonding_curve, event.pool));
+        assert_eq!((graduation.mint, graduation.quote_mint), (event.mint, NATIVE_SOL_MINT));
+        assert_eq!(
+            (graduation.base_amount, graduation.quote_amount),
+            (206_900_000_000_000, 84_990_359_016)
+        );
+    }
+
+    #[test]
+    fn bonk_migration_resolves_vault_outflows() {
+        let (base_mint, quote_mint) = (Pubkey::new_unique(), NATIVE_SOL_MINT);
+        let (base_vault, quote_vault, token_mints) = drained_vaults(&base_mint, &quote_mint);
+        let event = BonkMigrateToAmmEvent {
+            base_mint,
+            quote_mint,
+            base_vault,
+            quote_vault,
+            amm_pool: Pubkey::new_unique(),
+            ..Default::default()
+        };
+        let graduation = Graduation::from_event(&event, &token_mints).unwrap();
+        assert_eq!(
+            (graduation.launchpad, graduation.destination),
+            (ProtocolType::Bonk, ProtocolType::RaydiumAmmV4)
+        );
+        assert_eq!(graduation.pool, event.amm_pool);
+        assert_eq!(
+            (graduation.base_amount, graduation.quote_amount),
+            (206_900_000_000_000, 85_000_000_000)
+        );
+
+        // 缺少 token balance 时数量为 0
+        let graduation = Graduation::from_event(&event, &TokenAccountMints::default()).unwrap();
+        assert_eq!((graduation.base_amount, graduation.quote_amount), (0, 0));
+    }
+
+    #[test]
+    fn meteora_dbc_migration_to_damm_v2_resolves_vault_outflows() {
+        let (base_mint, quote_mint) = (Pubkey::new_unique(), Pubkey::new_unique());
+        let (base_vault, quote_vault, token_mints) = drained_vaults(&base_mint, &quote_mint);
+        let event = MeteoraDBCMigrationDammV2Event {
+            base_mint,
+            quote_mint,
+            base_vault,
+            quote_vault,
+            virtual_pool: Pubkey::new_unique(),
+            pool: Pubkey::new_unique(),
+            ..Default::default()
+        };
+        let graduation = Graduation::from_event(&event, &token_mints).unwrap();
+        assert_eq!(
+            (graduation.launchpad, graduation.destination),
+            (ProtocolType::MeteoraDBC, ProtocolType::MeteoraDAMMv2)
+        );
+        assert_eq!((graduation.source_pool, graduation.pool), (event.virtual_pool, event.pool));
+        assert_eq!((graduation.mint, graduation.quote_mint), (base_mint, quote_mint));
+        assert_eq!(
+            (graduation.base_amount, graduation.quote_amount),
+            (206_900_000_000_000, 85_000_000_000)
+        );
+    }
+}
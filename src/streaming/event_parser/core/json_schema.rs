@@ -0,0 +1,589 @@
+//! 事件 JSON 序列化（稳定 schema）
+//!
+//! 所有事件通过 [`UnifiedEvent::to_json`] 输出如下结构（schema 版本见 [`EVENT_JSON_SCHEMA_VERSION`]）：
+//!
+//! ```json
+//! {
+//!   "schema_version": 1,
+//!   "event_type": "PumpFunBuy",
+//!   "event_name": "PumpFunTradeEvent",
+//!   "metadata": { "signature": "5h3…", "slot": "345678901", "protocol": "PumpFun", ... },
+//!   "data": { "mint": "So111…", "sol_amount": "1000000", "is_buy": true, ... }
+//! }
+//! ```
+//!
+//! 编码规则：
+//! - `Pubkey` / `Signature` / `Hash` 编码为 base58 字符串
+//! - `u64` / `i64` / `u128` / `i128` 编码为十进制字符串（避免 JS 等语言丢失精度）
+//! - 其余整数、布尔、字符串按 JSON 原生类型输出；`Option::None` 为 `null`
+//! - 无数据的枚举变体编码为变体名字符串，带数据的变体编码为 `{ "变体名": 值 }`
+//! - `metadata` 为 [`EventMetadata`](crate::streaming::event_parser::common::EventMetadata) 的全部字段，
+//!   `data` 为事件结构体除 `metadata` 以外的字段
+//!
+//! 字段只会新增不会删除或改名；不兼容的变更会提升 `schema_version`。
+
+use serde::ser::{self, Error as _, Serialize};
+use serde_json::{Map, Number, Value};
+use solana_sdk::{hash::Hash, pubkey::Pubkey, signature::Signature};
+
+use crate::streaming::event_parser::core::traits::UnifiedEvent;
+
+/// 当前 JSON schema 版本
+pub const EVENT_JSON_SCHEMA_VERSION: u32 = 1;
+
+/// `to_json` 的返回类型
+pub type JsonResult = serde_json::Result<Value>;
+
+/// 将任意可序列化值按 schema 编码规则转换为 JSON
+pub fn to_json_value<T: Serialize + ?Sized>(value: &T) -> serde_json::Result<Value> {
+    match Base58Type::of::<T>() {
+        Some(base58) => base58.encode(value),
+        None => value.serialize(JsonSerializer),
+    }
+}
+
+/// 将内置事件结构体编码为完整的 JSON envelope（由 `impl_unified_event!` 调用）
+pub fn event_to_json<T: Serialize>(event_name: &str, event: &T) -> serde_json::Result<Value> {
+    let mut data = match to_json_value(event)? {
+        Value::Object(map) => map,
+        other => {
+            return Err(serde_json::Error::custom(format!(
+                "event {} must serialize to an object, got {}",
+                event_name, other
+            )))
+        }
+    };
+    let metadata = data.remove("metadata").unwrap_or(Value::Null);
+    let event_type = metadata.get("event_type").cloned().unwrap_or(Value::Null);
+    Ok(envelope(event_type, event_name, metadata, Value::Object(data)))
+}
+
+/// 未使用 `impl_unified_event!` 的外部事件的 envelope：仅包含 trait 可访问的元数据，`data` 为 `null`
+pub fn unified_event_to_json<E: UnifiedEvent + ?Sized>(event: &E) -> serde_json::Result<Value> {
+    let event_type = to_json_value(&event.event_type())?;
+    let mut metadata = Map::new();
+    metadata.insert("signature".to_string(), Value::String(event.signature().to_string()));
+    metadata.insert("slot".to_string(), Value::String(event.slot().to_string()));
+    metadata.insert("transaction_index".to_string(), to_json_value(&event.transaction_index())?);
+    metadata.insert("recv_us".to_string(), Value::String(event.recv_us().to_string()));
+    metadata.insert("handle_us".to_string(), Value::String(event.handle_us().to_string()));
+    metadata.insert("event_type".to_string(), event_type.clone());
+    metadata.insert("outer_index".to_string(), Value::String(event.outer_index().to_string()));
+    metadata.insert("inner_index".to_string(), to_json_value(&event.inner_index())?);
+    let event_name = std::any::type_name::<E>().rsplit("::").next().unwrap_or("Custom");
+    Ok(envelope(event_type, event_name, Value::Object(metadata), Value::Null))
+}
+
+fn envelope(event_type: Value, event_name: &str, metadata: Value, data: Value) -> Value {
+    let mut map = Map::new();
+    map.insert("schema_version".to_string(), Value::from(EVENT_JSON_SCHEMA_VERSION));
+    map.insert("event_type".to_string(), event_type);
+    map.insert("event_name".to_string(), Value::String(event_name.to_string()));
+    map.insert("metadata".to_string(), metadata);
+    map.insert("data".to_string(), data);
+    Value::Object(map)
+}
+
+/// 编码为 base58 字符串的类型，按具体类型识别，同名的其他 newtype 不受影响
+#[derive(Clone, Copy, Debug)]
+enum Base58Type {
+    Pubkey,
+    Signature,
+    Hash,
+}
+
+impl Base58Type {
+    fn of<T: ?Sized>() -> Option<Self> {
+        // 序列元素等以引用形式传入，如 `&Pubkey`
+        let name = std::any::type_name::<T>().trim_start_matches('&');
+        if name == std::any::type_name::<Pubkey>() {
+            Some(Self::Pubkey)
+        } else if name == std::any::type_name::<Signature>() {
+            Some(Self::Signature)
+        } else if name == std::any::type_name::<Hash>() {
+            Some(Self::Hash)
+        } else {
+            None
+        }
+    }
+
+    /// 把 serde 输出的字节数组还原为 base58 字符串
+    fn encode<T: Serialize + ?Sized>(self, value: &T) -> serde_json::Result<Value> {
+        let bytes: Vec<u8> = serde_json::from_value(serde_json::to_value(value)?)?;
+        let encoded = match self {
+            Self::Pubkey => Pubkey::try_from(bytes.as_slice()).map(|k| k.to_string()).ok(),
+            Self::Signature => Signature::try_from(bytes.as_slice()).map(|s| s.to_string()).ok(),
+            Self::Hash => <[u8; 32]>::try_from(bytes.as_slice())
+                .map(|b| Hash::new_from_array(b).to_string())
+                .ok(),
+        };
+        encoded.map(Value::String).ok_or_else(|| {
+            serde_json::Error::custom(format!("invalid {:?} length {}", self, bytes.len()))
+        })
+    }
+}
+
+fn key_to_string(key: Value) -> serde_json::Result<String> {
+    match key {
+        Value::String(s) => Ok(s),
+        Value::Number(n) => Ok(n.to_string()),
+        Value::Bool(b) => Ok(b.to_string()),
+        other => Err(serde_json::Error::custom(format!("unsupported map key {}", other))),
+    }
+}
+
+struct JsonSerializer;
+
+impl ser::Serializer for JsonSerializer {
+    type Ok = Value;
+    type Error = serde_json::Error;
+    type SerializeSeq = SeqSerializer;
+    type SerializeTuple = SeqSerializer;
+    type SerializeTupleStruct = SeqSerializer;
+    type SerializeTupleVariant = TupleVariantSerializer;
+    type SerializeMap = MapSerializer;
+    type SerializeStruct = MapSerializer;
+    type SerializeStructVariant = StructVariantSerializer;
+
+    fn serialize_bool(self, v: bool) -> serde_json::Result<Value> {
+        Ok(Value::Bool(v))
+    }
+
+    fn serialize_i8(self, v: i8) -> serde_json::Result<Value> {
+        Ok(Value::from(v))
+    }
+
+    fn serialize_i16(self, v: i16) -> serde_json::Result<Value> {
+        Ok(Value::from(v))
+    }
+
+    fn serialize_i32(self, v: i32) -> serde_json::Result<Value> {
+        Ok(Value::from(v))
+    }
+
+    fn serialize_i64(self, v: i64) -> serde_json::Result<Value> {
+        Ok(Value::String(v.to_string()))
+    }
+
+    fn serialize_i128(self, v: i128) -> serde_json::Result<Value> {
+        Ok(Value::String(v.to_string()))
+    }
+
+    fn serialize_u8(self, v: u8) -> serde_json::Result<Value> {
+        Ok(Value::from(v))
+    }
+
+    fn serialize_u16(self, v: u16) -> serde_json::Result<Value> {
+        Ok(Value::from(v))
+    }
+
+    fn serialize_u32(self, v: u32) -> serde_json::Result<Value> {
+        Ok(Value::from(v))
+    }
+
+    fn serialize_u64(self, v: u64) -> serde_json::Result<Value> {
+        Ok(Value::String(v.to_string()))
+    }
+
+    fn serialize_u128(self, v: u128) -> serde_json::Result<Value> {
+        Ok(Value::String(v.to_string()))
+    }
+
+    fn serialize_f32(self, v: f32) -> serde_json::Result<Value> {
+        Ok(Number::from_f64(v as f64).map(Value::Number).unwrap_or(Value::Null))
+    }
+
+    fn serialize_f64(self, v: f64) -> serde_json::Result<Value> {
+        Ok(Number::from_f64(v).map(Value::Number).unwrap_or(Value::Null))
+    }
+
+    fn serialize_char(self, v: char) -> serde_json::Result<Value> {
+        Ok(Value::String(v.to_string()))
+    }
+
+    fn serialize_str(self, v: &str) -> serde_json::Result<Value> {
+        Ok(Value::String(v.to_string()))
+    }
+
+    fn serialize_bytes(self, v: &[u8]) -> serde_json::Result<Value> {
+        Ok(Value::Array(v.iter().map(|b| Value::from(*b)).collect()))
+    }
+
+    fn serialize_none(self) -> serde_json::Result<Value> {
+        Ok(Value::Null)
+    }
+
+    fn serialize_some<T: Serialize + ?Sized>(self, value: &T) -> serde_json::Result<Value> {
+        to_json_value(value)
+    }
+
+    fn serialize_unit(self) -> serde_json::Result<Value> {
+        Ok(Value::Null)
+    }
+
+    fn serialize_unit_struct(self, _name: &'static str) -> serde_json::Result<Value> {
+        Ok(Value::Null)
+    }
+
+    fn serialize_unit_variant(
+        self,
+        _name: &'static str,
+        _variant_index: u32,
+        variant: &'static str,
+    ) -> serde_json::Result<Value> {
+        Ok(Value::String(variant.to_string()))
+    }
+
+    fn serialize_newtype_struct<T: Serialize + ?Sized>(
+        self,
+        _name: &'static str,
+        value: &T,
+    ) -> serde_json::Result<Value> {
+        to_json_value(value)
+    }
+
+    fn serialize_newtype_variant<T: Serialize + ?Sized>(
+        self,
+        _name: &'static str,
+        _variant_index: u32,
+        variant: &'static str,
+        value: &T,
+    ) -> serde_json::Result<Value> {
+        let mut map = Map::new();
+        map.insert(variant.to_string(), to_json_value(value)?);
+        Ok(Value::Object(map))
+    }
+
+    fn serialize_seq(self, len: Option<usize>) -> serde_json::Result<SeqSerializer> {
+        Ok(SeqSerializer { items: Vec::with_capacity(len.unwrap_or(0)) })
+    }
+
+    fn serialize_tuple(self, len: usize) -> serde_json::Result<SeqSerializer> {
+        self.serialize_seq(Some(len))
+    }
+
+    fn serialize_tuple_struct(
+        self,
+        _name: &'static str,
+        len: usize,
+    ) -> serde_json::Result<SeqSerializer> {
+        self.serialize_seq(Some(len))
+    }
+
+    fn serialize_tuple_variant(
+        self,
+        _name: &'static str,
+        _variant_index: u32,
+        variant: &'static str,
+        len: usize,
+    ) -> serde_json::Result<TupleVariantSerializer> {
+        Ok(TupleVariantSerializer { variant, items: Vec::with_capacity(len) })
+    }
+
+    fn serialize_map(self, _len: Option<usize>) -> serde_json::Result<MapSerializer> {
+        Ok(MapSerializer { map: Map::new(), next_key: None })
+    }
+
+    fn serialize_struct(
+        self,
+        _name: &'static str,
+        len: usize,
+    ) -> serde_json::Result<MapSerializer> {
+        self.serialize_map(Some(len))
+    }
+
+    fn serialize_struct_variant(
+        self,
+        _name: &'static str,
+        _variant_index: u32,
+        variant: &'static str,
+        _len: usize,
+    ) -> serde_json::Result<StructVariantSerializer> {
+        Ok(StructVariantSerializer { variant, map: Map::new() })
+    }
+}
+
+struct SeqSerializer {
+    items: Vec<Value>,
+}
+
+impl ser::SerializeSeq for SeqSerializer {
+    type Ok = Value;
+    type Error = serde_json::Error;
+
+    fn serialize_element<T: Serialize + ?Sized>(&mut self, value: &T) -> serde_json::Result<()> {
+        self.items.push(to_json_value(value)?);
+        Ok(())
+    }
+
+    fn end(self) -> serde_json::Result<Value> {
+        Ok(Value::Array(self.items))
+    }
+}
+
+impl ser::SerializeTuple for SeqSerializer {
+    type Ok = Value;
+    type Error = serde_json::Error;
+
+    fn serialize_element<T: Serialize + ?Sized>(&mut self, value: &T) -> serde_json::Result<()> {
+        ser::SerializeSeq::serialize_element(self, value)
+    }
+
+    fn end(self) -> serde_json::Result<Value> {
+        ser::SerializeSeq::end(self)
+    }
+}
+
+impl ser::SerializeTupleStruct for SeqSerializer {
+    type Ok = Value;
+    type Error = serde_json::Error;
+
+    fn serialize_field<T: Serialize + ?Sized>(&mut self, value: &T) -> serde_json::Result<()> {
+        ser::SerializeSeq::serialize_element(self, value)
+    }
+
+    fn end(self) -> serde_json::Result<Value> {
+        ser::SerializeSeq::end(self)
+    }
+}
+
+struct TupleVariantSerializer {
+    variant: &'static str,
+    items: Vec<Value>,
+}
+
+impl ser::SerializeTupleVariant for TupleVariantSerializer {
+    type Ok = Value;
+    type Error = serde_json::Error;
+
+    fn serialize_field<T: Serialize + ?Sized>(&mut self, value: &T) -> serde_json::Result<()> {
+        self.items.push(to_json_value(value)?);
+        Ok(())
+    }
+
+    fn end(self) -> serde_json::Result<Value> {
+        let mut map = Map::new();
+        map.insert(self.variant.to_string(), Value::Array(self.items));
+        Ok(Value::Object(map))
+    }
+}
+
+struct MapSerializer {
+    map: Map<String, Value>,
+    next_key: Option<String>,
+}
+
+impl ser::SerializeMap for MapSerializer {
+    type Ok = Value;
+    type Error = serde_json::Error;
+
+    fn serialize_key<T: Serialize + ?Sized>(&mut self, key: &T) -> serde_json::Result<()> {
+        self.next_key = Some(key_to_string(to_json_value(key)?)?);
+        Ok(())
+    }
+
+    fn serialize_value<T: Serialize + ?Sized>(&mut self, value: &T) -> serde_json::Result<()> {
+        let key = self.next_key.take().ok_or_else(|| {
+            serde_json::Error::custom("serialize_value called before serialize_key")
+        })?;
+        self.map.insert(key, to_json_value(value)?);
+        Ok(())
+    }
+
+    fn end(self) -> serde_json::Result<Value> {
+        Ok(Value::Object(self.map))
+    }
+}
+
+impl ser::SerializeStruct for MapSerializer {
+    type Ok = Value;
+    type Error = serde_json::Error;
+
+    fn serialize_field<T: Serialize + ?Sized>(
+        &mut self,
+        key: &'static str,
+        value: &T,
+    ) -> serde_json::Result<()> {
+        self.map.insert(key.to_string(), to_json_value(value)?);
+        Ok(())
+    }
+
+    fn end(self) -> serde_json::Result<Value> {
+        Ok(Value::Object(self.map))
+    }
+}
+
+struct StructVariantSerializer {
+    variant: &'static str,
+    map: Map<String, Value>,
+}
+
+impl ser::SerializeStructVariant for StructVariantSerializer {
+    type Ok = Value;
+    type Error = serde_json::Error;
+
+    fn serialize_field<T: Serialize + ?Sized>(
+        &mut self,
+        key: &'static str,
+        value: &T,
+    ) -> serde_json::Result<()> {
+        self.map.insert(key.to_string(), to_json_value(value)?);
+        Ok(())
+    }
+
+    fn end(self) -> serde_json::Result<Value> {
+        let mut map = Map::new();
+        map.insert(self.variant.to_string(), Value::Object(self.map));
+        Ok(Value::Object(map))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use serde::Serialize;
+    use serde_json::json;
+    use solana_sdk::{hash::Hash, pubkey::Pubkey, signature::Signature};
+
+    use super::*;
+    use crate::streaming::event_parser::{
+        common::{EventMetadata, EventType, ProtocolType},
+        core::balance_change::BalanceChangeEvent,
+    };
+
+    #[derive(Serialize)]
+    enum Side {
+        Buy,
+        Limit { price: u64 },
+    }
+
+    #[derive(Serialize)]
+    struct Sample {
+        small: u8,
+        amount: u64,
+        delta: i64,
+        liquidity: u128,
+        signed_liquidity: i128,
+        key: Pubkey,
+        signature: Signature,
+        hash: Hash,
+        some_key: Option<Pubkey>,
+        none_key: Option<Pubkey>,
+        some_amount: Option<u64>,
+        keys: Vec<Pubkey>,
+        side: Side,
+        order: Side,
+    }
+
+    #[test]
+    fn encodes_scalars_keys_and_options() {
+        let key = Pubkey::new_from_array([1; 32]);
+        let sample = Sample {
+            small: 7,
+            amount: u64::MAX,
+            delta: -5,
+            liquidity: u128::MAX,
+            signed_liquidity: i128::MIN,
+            key,
+            signature: Signature::from([2; 64]),
+            hash: Hash::new_from_array([3; 32]),
+            some_key: Some(key),
+            none_key: None,
+            some_amount: Some(9),
+            keys: vec![key],
+            side: Side::Buy,
+            order: Side::Limit { price: 10 },
+        };
+        let key = key.to_string();
+        assert_eq!(
+            to_json_value(&sample).unwrap(),
+            json!({
+                "small": 7,
+                "amount": "18446744073709551615",
+                "delta": "-5",
+                "liquidity": "340282366920938463463374607431768211455",
+                "signed_liquidity": "-170141183460469231731687303715884105728",
+                "key": key,
+                "signature": Signature::from([2; 64]).to_string(),
+                "hash": Hash::new_from_array([3; 32]).to_string(),
+                "some_key": key,
+                "none_key": null,
+                "some_amount": "9",
+                "keys": [key],
+                "side": "Buy",
+                "order": { "Limit": { "price": "10" } },
+            })
+        );
+    }
+
+    #[test]
+    fn newtype_named_pubkey_is_not_base58() {
+        #[derive(Serialize)]
+        struct Pubkey([u8; 2]);
+
+        assert_eq!(to_json_value(&Pubkey([1, 2])).unwrap(), json!([1, 2]));
+    }
+
+    #[test]
+    fn event_envelope_golden() {
+        let signature = Signature::from([4; 64]);
+        let program_id = Pubkey::new_from_array([5; 32]);
+        let owner = Pubkey::new_from_array([6; 32]);
+        let event = BalanceChangeEvent {
+            metadata: EventMetadata::new(
+                signature,
+                345_678_901,
+                1_700_000_000,
+                1_700_000_000_123,
+                ProtocolType::Common,
+                EventType::BalanceChange,
+                program_id,
+                -1,
+                None,
+                42,
+                Some(3),
+            ),
+            owner,
+            mint: Pubkey::default(),
+            decimals: 9,
+            is_native: true,
+            pre_amount: 2_000,
+            post_amount: 500,
+            delta: -1_500,
+        };
+        assert_eq!(
+            event.to_json().unwrap(),
+            json!({
+                "schema_version": EVENT_JSON_SCHEMA_VERSION,
+                "event_type": "BalanceChange",
+                "event_name": "BalanceChangeEvent",
+                "metadata": {
+                    "signature": signature.to_string(),
+                    "slot": "345678901",
+                    "transaction_index": "3",
+                    "block_time": "1700000000",
+                    "block_time_ms": "1700000000123",
+                    "recv_us": "42",
+                    "handle_us": "0",
+                    "protocol": "Common",
+                    "event_type": "BalanceChange",
+                    "program_id": program_id.to_string(),
+                    "swap_data": null,
+                    "outer_index": "-1",
+                    "inner_index": null,
+                    "tx_context": null,
+                    "invocation": null,
+                    "via_router": null,
+                },
+                "data": {
+                    "owner": owner.to_string(),
+                    "mint": Pubkey::default().to_string(),
+                    "decimals": 9,
+                    "is_native": true,
+                    "pre_amount": "2000",
+                    "post_amount": "500",
+                    "delta": "-1500",
+                },
+            })
+        );
+    }
+}
@@ -0,0 +1,392 @@
+use serde::{Deserialize, Serialize};
+use solana_sdk::pubkey::Pubkey;
+
+use crate::{
+    impl_unified_event,
+    streaming::event_parser::{
+        common::{
+            sum_vault_transfers, EventMetadata, EventType, InnerInstructionView, TokenAccountMints,
+        },
+        protocols::{
+            orca_whirlpool::{
+                OrcaWhirlpoolDecreaseLiquidityEvent, OrcaWhirlpoolIncreaseLiquidityEvent,
+            },
+            pumpswap::{PumpSwapDepositEvent, PumpSwapWithdrawEvent},
+            raydium_amm_v4::{RaydiumAmmV4DepositEvent, RaydiumAmmV4WithdrawEvent},
+            raydium_clmm::{
+                RaydiumClmmDecreaseLiquidityV2Event, RaydiumClmmIncreaseLiquidityV2Event,
+            },
+            raydium_cpmm::{RaydiumCpmmDepositEvent, RaydiumCpmmWithdrawEvent},
+        },
+        UnifiedEvent,
+    },
+};
+
+/// 流动性变化方向
+#[derive(Copy, Clone, Debug, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub enum LiquidityDirection {
+    #[default]
+    Add,
+    Remove,
+}
+
+/// 跨 AMM 统一的流动性增减事件，由各协议的存取款事件派生
+///
+/// 代币数量取自指令 CPI 中转入或转出池子 vault 的转账，事件自带精确数量时以事件为准。
+/// 没有内联指令（如 ShredStream）或未找到 vault 转账时无法得到数量，不派生事件。
+#[derive(Clone, Debug, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct LiquidityChange {
+    pub metadata: EventMetadata,
+    /// 原始事件类型
+    pub source_event_type: EventType,
+    pub pool: Pubkey,
+    pub provider: Pubkey,
+    pub direction: LiquidityDirection,
+    pub mint_a: Pubkey,
+    pub mint_b: Pubkey,
+    pub amount_a: u64,
+    pub amount_b: u64,
+    /// LP mint，CLMM 为默认值
+    pub lp_mint: Pubkey,
+    /// 铸造或销毁的 LP 数量，CLMM 为仓位 liquidity；未知时为 0
+    pub lp_amount: u128,
+    /// 随移除流动性一并领取的手续费，不计入 amount_a / amount_b；仅 Raydium CLMM 非 0
+    pub fee_a: u64,
+    pub fee_b: u64,
+}
+impl_unified_event!(LiquidityChange,);
+
+impl LiquidityChange {
+    /// 由存取款事件派生，其他事件及无法得到数量的事件返回 None
+    ///
+    /// `inner_instructions` / `inner_index` / `accounts` 与 `parse_swap_data` 相同，
+    /// 用于统计与 vault 之间的转账；`token_mints` 用于补全 mint 与 LP 数量。
+    pub fn from_event<I: InnerInstructionView>(
+        event: &dyn UnifiedEvent,
+        inner_instructions: &[I],
+        inner_index: Option<i64>,
+        accounts: &[Pubkey],
+        token_mints: &TokenAccountMints,
+    ) -> Option<Self> {
+        let vault_transfers = |vaults: [Pubkey; 2], direction: LiquidityDirection| {
+            let is_deposit = direction == LiquidityDirection::Add;
+            let amounts =
+                sum_vault_transfers(inner_instructions, inner_index, accounts, &vaults, is_deposit);
+            (amounts != [0, 0]).then_some(amounts)
+        };
+        let any = event.as_any();
+        let (metadata, change) = if let Some(e) = any.downcast_ref::<PumpSwapDepositEvent>() {
+            let change = Self {
+                pool: e.pool,
+                provider: e.user,
+                direction: LiquidityDirection::Add,
+                mint_a: e.base_mint,
+                mint_b: e.quote_mint,
+                amount_a: e.base_amount_in,
+                amount_b: e.quote_amount_in,
+                lp_mint: token_mints.get(&e.user_pool_token_account).unwrap_or_default(),
+                lp_amount: e.lp_token_amount_out as u128,
+                ..Default::default()
+            };
+            (&e.metadata, change)
+        } else if let Some(e) = any.downcast_ref::<PumpSwapWithdrawEvent>() {
+            let change = Self {
+                pool: e.pool,
+                provider: e.user,
+                direction: LiquidityDirection::Remove,
+                mint_a: e.base_mint,
+                mint_b: e.quote_mint,
+                amount_a: e.base_amount_out,
+                amount_b: e.quote_amount_out,
+                lp_mint: token_mints.get(&e.user_pool_token_account).unwrap_or_default(),
+                lp_amount: e.lp_token_amount_in as u128,
+                ..Default::default()
+            };
+            (&e.metadata, change)
+        } else if let Some(e) = any.downcast_ref::<RaydiumCpmmDepositEvent>() {
+            let direction = LiquidityDirection::Add;
+            let [amount_a, amount_b] =
+                vault_transfers([e.token0_vault, e.token1_vault], direction)?;
+            let change = Self {
+                pool: e.pool_state,
+                provider: e.owner,
+                direction,
+                mint_a: e.vault0_mint,
+                mint_b: e.vault1_mint,
+                amount_a,
+                amount_b,
+                lp_mint: e.lp_mint,
+                lp_amount: e.lp_token_amount as u128,
+                ..Default::default()
+            };
+            (&e.metadata, change)
+        } else if let Some(e) = any.downcast_ref::<RaydiumCpmmWithdrawEvent>() {
+            let direction = LiquidityDirection::Remove;
+            let [amount_a, amount_b] =
+                vault_transfers([e.token0_vault, e.token1_vault], direction)?;
+            let change = Self {
+                pool: e.pool_state,
+                provider: e.owner,
+                direction,
+                mint_a: e.vault0_mint,
+                mint_b: e.vault1_mint,
+                amount_a,
+                amount_b,
+                lp_mint: e.lp_mint,
+                lp_amount: e.lp_token_amount as u128,
+                ..Default::default()
+            };
+            (&e.metadata, change)
+        } else if let Some(e) = any.downcast_ref::<RaydiumAmmV4DepositEvent>() {
+            let direction = LiquidityDirection::Add;
+            let vaults = [e.pool_coin_token_account, e.pool_pc_token_account];
+            let [amount_a, amount_b] = vault_transfers(vaults, direction)?;
+            let change = Self {
+                pool: e.amm,
+                provider: e.user_owner,
+                direction,
+                mint_a: token_mints.get(&e.pool_coin_token_account).unwrap_or_default(),
+                mint_b: token_mints.get(&e.pool_pc_token_account).unwrap_or_default(),
+                amount_a,
+                amount_b,
+                lp_mint: e.lp_mint_address,
+                // 指令中没有铸造的 LP 数量，按用户 LP 账户的余额变化计算
+                lp_amount: token_mints.inflow(&e.user_lp_token_account) as u128,
+                ..Default::default()
+            };
+            (&e.metadata, change)
+        } else if let Some(e) = any.downcast_ref::<RaydiumAmmV4WithdrawEvent>() {
+            let direction = LiquidityDirection::Remove;
+            let vaults = [e.pool_coin_token_account, e.pool_pc_token_account];
+            let [amount_a, amount_b] = vault_transfers(vaults, direction)?;
+            let change = Self {
+                pool: e.amm,
+                provider: e.user_owner,
+                direction,
+                mint_a: token_mints.get(&e.pool_coin_token_account).unwrap_or_default(),
+                mint_b: token_mints.get(&e.pool_pc_token_account).unwrap_or_default(),
+                amount_a,
+                amount_b,
+                lp_mint: e.lp_mint_address,
+                lp_amount: e.amount as u128,
+                ..Default::default()
+            };
+            (&e.metadata, change)
+        } else if let Some(e) = any.downcast_ref::<OrcaWhirlpoolIncreaseLiquidityEvent>() {
+            let direction = LiquidityDirection::Add;
+            let [amount_a, amount_b] =
+                vault_transfers([e.token_vault_a, e.token_vault_b], direction)?;
+            let change = Self {
+                pool: e.whirlpool,
+                provider: e.position_authority,
+                direction,
+                mint_a: token_mints.get(&e.token_vault_a).unwrap_or_default(),
+                mint_b: token_mints.get(&e.token_vault_b).unwrap_or_default(),
+                amount_a,
+                amount_b,
+                lp_amount: e.liquidity_amount,
+                ..Default::default()
+            };
+            (&e.metadata, change)
+        } else if let Some(e) = any.downcast_ref::<OrcaWhirlpoolDecreaseLiquidityEvent>() {
+            // 与 CLMM 不同，减少流动性不会领取手续费，转出数量即移除的流动性
+            let direction = LiquidityDirection::Remove;
+            let [amount_a, amount_b] =
+                vault_transfers([e.token_vault_a, e.token_vault_b], direction)?;
+            let change = Self {
+                pool: e.whirlpool,
+                provider: e.position_authority,
+                direction,
+                mint_a: token_mints.get(&e.token_vault_a).unwrap_or_default(),
+                mint_b: token_mints.get(&e.token_vault_b).unwrap_or_default(),
+                amount_a,
+                amount_b,
+                lp_amount: e.liquidity_amount,
+                ..Default::default()
+            };
+            (&e.metadata, change)
+        } else if let Some(e) = any.downcast_ref::<RaydiumClmmIncreaseLiquidityV2Event>() {
+            let direction = LiquidityDirection::Add;
+            let [amount_a, amount_b] =
+                vault_transfers([e.token_vault0, e.token_vault1], direction)?;
+            let change = Self {
+                pool: e.pool_state,
+                provider: e.nft_owner,
+                direction,
+                mint_a: e.vault0_mint,
+                mint_b: e.vault1_mint,
+                amount_a,
+                amount_b,
+                lp_amount: e.liquidity,
+                ..Default::default()
+            };
+            (&e.metadata, change)
+        } else {
+            // 减少流动性会同时领取仓位手续费，vault 转出数量包含手续费；
+            // 本金与手续费取自 DecreaseLiquidityEvent 日志，没有日志或只领取手续费时不派生
+            let e = any.downcast_ref::<RaydiumClmmDecreaseLiquidityV2Event>()?;
+            if e.decrease_amount0 == 0 && e.decrease_amount1 == 0 {
+                return None;
+            }
+            let change = Self {
+                pool: e.pool_state,
+                provider: e.nft_owner,
+                direction: LiquidityDirection::Remove,
+                mint_a: e.vault0_mint,
+                mint_b: e.vault1_mint,
+                amount_a: e.decrease_amount0,
+                amount_b: e.decrease_amount1,
+                lp_amount: e.liquidity,
+                fee_a: e.fee_amount0,
+                fee_b: e.fee_amount1,
+                ..Default::default()
+            };
+            (&e.metadata, change)
+        };
+        let mut metadata = metadata.clone();
+        metadata.event_type = EventType::LiquidityChange;
+        Some(Self { metadata, source_event_type: event.event_type(), ..change })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use yellowstone_grpc_proto::prelude::{
+        InnerInstruction, TokenBalance, TransactionStatusMeta, UiTokenAmount,
+    };
+
+    use super::*;
+
+    // 账户表下标
+    const USER_A: u8 = 0;
+    const USER_B: u8 = 1;
+    const VAULT_A: u8 = 2;
+    const VAULT_B: u8 = 3;
+    const TOKEN: u8 = 4;
+
+    fn accounts() -> Vec<Pubkey> {
+        let mut accounts: Vec<_> =
+            (0..TOKEN).map(|i| Pubkey::new_from_array([i + 1; 32])).collect();
+        accounts.push(spl_token::ID);
+        accounts
+    }
+
+    fn key(index: u8) -> Pubkey {
+        accounts()[index as usize]
+    }
+
+    fn transfer(source: u8, destination: u8, amount: u64) -> InnerInstruction {
+        InnerInstruction {
+            program_id_index: TOKEN as u32,
+            accounts: vec![source, destination, USER_A],
+            data: [&[3][..], &amount.to_le_bytes()].concat(),
+            stack_height: Some(2),
+        }
+    }
+
+    fn derive(
+        event: &dyn UnifiedEvent,
+        inner: &[InnerInstruction],
+        token_mints: &TokenAccountMints,
+    ) -> Option<LiquidityChange> {
+        LiquidityChange::from_event(event, inner, None, &accounts(), token_mints)
+    }
+
+    fn cpmm_deposit() -> RaydiumCpmmDepositEvent {
+        RaydiumCpmmDepositEvent {
+            pool_state: Pubkey::new_unique(),
+            owner: Pubkey::new_unique(),
+            token0_vault: key(VAULT_A),
+            token1_vault: key(VAULT_B),
+            vault0_mint: Pubkey::new_unique(),
+            vault1_mint: Pubkey::new_unique(),
+            lp_token_amount: 500,
+            ..Default::default()
+        }
+    }
+
+    #[test]
+    fn deposit_amounts_from_vault_transfers() {
+        let event = cpmm_deposit();
+        let inner = [transfer(USER_A, VAULT_A, 1_000), transfer(USER_B, VAULT_B, 2_000)];
+        let change = derive(&event, &inner, &TokenAccountMints::default()).unwrap();
+        assert_eq!(change.metadata.event_type, EventType::LiquidityChange);
+        assert_eq!(change.direction, LiquidityDirection::Add);
+        assert_eq!((change.mint_a, change.mint_b), (event.vault0_mint, event.vault1_mint));
+        assert_eq!((change.amount_a, change.amount_b, change.lp_amount), (1_000, 2_000, 500));
+    }
+
+    #[test]
+    fn missing_inner_instructions_skip_transfer_based_events() {
+        let token_mints = TokenAccountMints::default();
+        assert!(derive(&cpmm_deposit(), &[], &token_mints).is_none());
+
+        let whirlpool = OrcaWhirlpoolIncreaseLiquidityEvent {
+            token_vault_a: key(VAULT_A),
+            token_vault_b: key(VAULT_B),
+            ..Default::default()
+        };
+        assert!(derive(&whirlpool, &[], &token_mints).is_none());
+
+        // PumpSwap 事件自带数量，不依赖内联指令
+        let pumpswap = PumpSwapDepositEvent {
+            base_amount_in: 10,
+            quote_amount_in: 20,
+            lp_token_amount_out: 5,
+            ..Default::default()
+        };
+        let change = derive(&pumpswap, &[], &token_mints).unwrap();
+        assert_eq!((change.amount_a, change.amount_b, change.lp_amount), (10, 20, 5));
+    }
+
+    #[test]
+    fn amm_v4_withdraw_resolves_mints_from_token_balances() {
+        let (coin_mint, pc_mint) = (Pubkey::new_unique(), Pubkey::new_unique());
+        let balance = |account: u8, mint: &Pubkey| TokenBalance {
+            account_index: account as u32,
+            mint: mint.to_string(),
+            ui_token_amount: Some(UiTokenAmount { amount: "0".into(), ..Default::default() }),
+            ..Default::default()
+        };
+        let meta = TransactionStatusMeta {
+            post_token_balances: vec![balance(VAULT_A, &coin_mint), balance(VAULT_B, &pc_mint)],
+            ..Default::default()
+        };
+        let token_mints = TokenAccountMints::from_grpc_meta(&meta, &accounts());
+        let event = RaydiumAmmV4WithdrawEvent {
+            pool_coin_token_account: key(VAULT_A),
+            pool_pc_token_account: key(VAULT_B),
+            amount: 42,
+            ..Default::default()
+        };
+        let inner = [transfer(VAULT_A, USER_A, 300), transfer(VAULT_B, USER_B, 700)];
+        let change = derive(&event, &inner, &token_mints).unwrap();
+        assert_eq!(change.direction, LiquidityDirection::Remove);
+        assert_eq!((change.mint_a, change.mint_b), (coin_mint, pc_mint));
+        assert_eq!((change.amount_a, change.amount_b, change.lp_amount), (300, 700, 42));
+    }
+
+    #[test]
+    fn clmm_decrease_separates_collected_fees() {
+        let mut event = RaydiumClmmDecreaseLiquidityV2Event {
+            token_vault0: key(VAULT_A),
+            token_vault1: key(VAULT_B),
+            liquidity: 1_000_000,
+            ..Default::default()
+        };
+        // vault 转出的数量包含手续费
+        let inner = [transfer(VAULT_A, USER_A, 1_010), transfer(VAULT_B, USER_B, 2_020)];
+        let token_mints = TokenAccountMints::default();
+        // 没有 DecreaseLiquidityEvent 日志时无法区分本金与手续费
+        assert!(derive(&event, &inner, &token_mints).is_none());
+
+        event.decrease_amount0 = 1_000;
+        event.decrease_amount1 = 2_000;
+        event.fee_amount0 = 10;
+        event.fee_amount1 = 20;
+        let change = derive(&event, &inner, &token_mints).unwrap();
+        assert_eq!((change.amount_a, change.amount_b), (1_000, 2_000));
+        assert_eq!((change.fee_a, change.fee_b), (10, 20));
+        assert_eq!(change.lp_amount, 1_000_000);
+    }
+}
@@ -1,7 +1,34 @@
 pub mod account_event_parser;
+pub mod anchor_idl;
+pub mod balance_change;
 pub mod common_event_parser;
+pub mod dex_event;
 pub mod global_state;
+pub mod graduation;
+pub mod normalized_trade;
+pub mod parse_diagnostics;
+pub mod program_logs;
+pub mod json_schema;
+pub mod liquidity_change;
+pub mod proto_codec;
+pub mod protocol_registry;
+pub mod raw_instruction;
+pub mod router;
+pub mod token_launch;
 pub mod traits;
+pub mod transaction_context;
+pub use anchor_idl::{AnchorIdl, IdlValue};
+pub use balance_change::BalanceChangeEvent;
+pub use dex_event::DexEvent;
+pub use graduation::Graduation;
+pub use liquidity_change::{LiquidityChange, LiquidityDirection};
+pub use normalized_trade::{NormalizedTrade, TradeSide};
+pub use parse_diagnostics::{
+    ParseDiagnostics, ParseDiagnosticsSnapshot, UnparsedInstructionEvent, UnparsedReason,
+};
+pub use protocol_registry::{CustomProtocol, ProtocolRegistry};
+pub use raw_instruction::RawInstructionEvent;
+pub use token_launch::TokenLaunch;
 pub use traits::UnifiedEvent;
 
 pub mod event_parser;
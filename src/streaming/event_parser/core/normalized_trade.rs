@@ -0,0 +1,511 @@
+use serde::{Deserialize, Serialize};
+use solana_sdk::pubkey::Pubkey;
+
+use crate::{
+    impl_unified_event,
+    streaming::event_parser::{
+        common::{EventMetadata, EventType, ProtocolType, TokenAccountMints},
+        core::{balance_change::NATIVE_SOL_MINT, router},
+        protocols::{
+            axiom::AxiomPumpFunTradeEvent,
+            axiom2::AxiomPumpSwapBuyEvent,
+            bonk::{types::TradeDirection as BonkTradeDirection, BonkTradeEvent},
+            meteora_dammv2::MeteoraDAMMv2SwapEvent,
+            meteora_dbc::{types::TradeDirection, MeteoraDBCSwapEvent},
+            orca_whirlpool::{
+                OrcaWhirlpoolSwapEvent, OrcaWhirlpoolSwapV2Event, OrcaWhirlpoolTwoHopSwapEvent,
+            },
+            photon::{PhotonPumpFunTradeEvent, PhotonPumpSwapTradeEvent},
+            pumpfun::PumpFunTradeEvent,
+            pumpswap::{PumpSwapBuyEvent, PumpSwapSellEvent},
+            raydium_amm_v4::RaydiumAmmV4SwapEvent,
+            raydium_clmm::{RaydiumClmmSwapEvent, RaydiumClmmSwapV2Event},
+            raydium_cpmm::RaydiumCpmmSwapEvent,
+        },
+        UnifiedEvent,
+    },
+};
+
+/// USDC mint
+pub const USDC_MINT: Pubkey = solana_sdk::pubkey!("EPjFWdd5AufqSSqeM2qN1xzybapC8G4wEGGkZwyTDt1v");
+/// USDT mint
+pub const USDT_MINT: Pubkey = solana_sdk::pubkey!("Es9vMFrzaCERmJfrF4H2FYD4KCoNkY11McCe8BenwNYB");
+
+/// 计价币：SOL / USDC / USDT
+pub fn is_quote_mint(mint: &Pubkey) -> bool {
+    *mint == NATIVE_SOL_MINT || *mint == USDC_MINT || *mint == USDT_MINT
+}
+
+/// 各项手续费之和，溢出时取 u64::MAX，避免异常的事件数据导致 panic
+fn sum_fees(fees: &[u64]) -> u64 {
+    fees.iter().fold(0, |sum, fee| sum.saturating_add(*fee))
+}
+
+/// 相对计价币的交易方向
+#[derive(Copy, Clone, Debug, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub enum TradeSide {
+    /// 两侧都是或都不是计价币
+    #[default]
+    Unknown,
+    /// 计价币 -> 代币
+    Buy,
+    /// 代币 -> 计价币
+    Sell,
+}
+
+/// 跨 DEX 统一的交易事件，由各协议的交易事件派生
+///
+/// `metadata` 复制自原始事件（`event_type` 为 `NormalizedTrade`，`protocol` 不变）。
+/// 路由事件（Photon / Axiom）仅在未关联到底层 DEX 交易（`routed_trade` 为 None）时派生，
+/// 避免同一笔交易由路由事件和 DEX 事件各计一次。
+/// 输入输出的 mint 与数量优先取自 `metadata.swap_data`，缺失时回退到事件字段。
+#[derive(Clone, Debug, Default, PartialEq, Serialize, Deserialize)]
+pub struct NormalizedTrade {
+    pub metadata: EventMetadata,
+    /// 原始事件类型
+    pub source_event_type: EventType,
+    pub pool: Pubkey,
+    pub trader: Pubkey,
+    pub input_mint: Pubkey,
+    pub output_mint: Pubkey,
+    pub amount_in: u64,
+    pub amount_out: u64,
+    pub input_decimals: Option<u8>,
+    pub output_decimals: Option<u8>,
+    /// 事件中各项手续费之和，单位为 `fee_mint`（未知时为默认 Pubkey）
+    pub fee: u64,
+    pub fee_mint: Pubkey,
+    pub side: TradeSide,
+    /// 每个代币的计价币价格（已按精度换算），精度未知或方向未知时为 None
+    pub price: Option<f64>,
+}
+impl_unified_event!(NormalizedTrade,);
+
+/// 各协议交易事件中可直接读取的字段
+struct TradeFields<'a> {
+    metadata: &'a EventMetadata,
+    pool: Pubkey,
+    trader: Pubkey,
+    input_mint: Pubkey,
+    output_mint: Pubkey,
+    amount_in: u64,
+    amount_out: u64,
+    fee: u64,
+    fee_mint: Pubkey,
+}
+
+impl<'a> TradeFields<'a> {
+    #[allow(clippy::too_many_arguments)]
+    fn pumpfun(
+        metadata: &'a EventMetadata,
+        bonding_curve: Pubkey,
+        user: Pubkey,
+        mint: Pubkey,
+        is_buy: bool,
+        sol_amount: u64,
+        token_amount: u64,
+        fee: u64,
+    ) -> Self {
+        let (input_mint, output_mint, amount_in, amount_out) = if is_buy {
+            (NATIVE_SOL_MINT, mint, sol_amount, token_amount)
+        } else {
+            (mint, NATIVE_SOL_MINT, token_amount, sol_amount)
+        };
+        Self {
+            metadata,
+            pool: bonding_curve,
+            trader: user,
+            input_mint,
+            output_mint,
+            amount_in,
+            amount_out,
+            fee,
+            fee_mint: NATIVE_SOL_MINT,
+        }
+    }
+
+    fn from_event(event: &'a dyn UnifiedEvent) -> Option<Self> {
+        let any = event.as_any();
+        let fields = if let Some(e) = any.downcast_ref::<PumpFunTradeEvent>() {
+            // Photon 的 PumpFun 卖出同样以 PumpFunTradeEvent 输出，无法记录 routed_trade，
+            // 由其调用的 PumpFun 卖出事件派生
+            if e.metadata.protocol == ProtocolType::PhotonProtocol {
+                return None;
+            }
+            Self::pumpfun(
+                &e.metadata,
+                e.bonding_curve,
+                e.user,
+                e.mint,
+                e.is_buy,
+                e.sol_amount,
+                e.token_amount,
+                sum_fees(&[e.fee, e.creator_fee]),
+            )
+        } else if let Some(e) = any.downcast_ref::<PhotonPumpFunTradeEvent>() {
+            Self::pumpfun(
+                &e.metadata,
+                e.bonding_curve,
+                e.user,
+                e.mint,
+                e.is_buy,
+                e.sol_amount,
+                e.token_amount,
+                sum_fees(&[e.fee, e.creator_fee]),
+            )
+        } else if let Some(e) = any.downcast_ref::<AxiomPumpFunTradeEvent>() {
+            Self::pumpfun(
+                &e.metadata,
+                e.bonding_curve,
+                e.user,
+                e.mint,
+                e.is_buy,
+                e.sol_amount,
+                e.token_amount,
+                sum_fees(&[e.fee, e.creator_fee]),
+            )
+        } else if let Some(e) = any.downcast_ref::<PumpSwapBuyEvent>() {
+            Self {
+                metadata: &e.metadata,
+                pool: e.pool,
+                trader: e.user,
+                input_mint: e.quote_mint,
+                output_mint: e.base_mint,
+                amount_in: e.user_quote_amount_in,
+                amount_out: e.base_amount_out,
+                fee: sum_fees(&[e.lp_fee, e.protocol_fee, e.coin_creator_fee]),
+                fee_mint: e.quote_mint,
+            }
+        } else if let Some(e) = any.downcast_ref::<PumpSwapSellEvent>() {
+            Self {
+                metadata: &e.metadata,
+                pool: e.pool,
+                trader: e.user,
+                input_mint: e.base_mint,
+                output_mint: e.quote_mint,
+                amount_in: e.base_amount_in,
+                amount_out: e.user_quote_amount_out,
+                fee: sum_fees(&[e.lp_fee, e.protocol_fee, e.coin_creator_fee]),
+                fee_mint: e.quote_mint,
+            }
+        } else if let Some(e) = any.downcast_ref::<PhotonPumpSwapTradeEvent>() {
+            let (input_mint, output_mint, amount_in, amount_out) = if e.is_buy {
+                (e.quote_mint, e.base_mint, e.user_quote_amount_in, e.base_amount_out)
+            } else {
+                // 卖出事件不含实际到账的 quote 数量，由 swap_data 补全
+                (e.base_mint, e.quote_mint, e.base_amount_in, 0)
+            };
+            Self {
+                metadata: &e.metadata,
+                pool: e.pool,
+                trader: e.user,
+                input_mint,
+                output_mint,
+                amount_in,
+                amount_out,
+                fee: sum_fees(&[e.lp_fee, e.protocol_fee, e.coin_creator_fee]),
+                fee_mint: e.quote_mint,
+            }
+        } else if let Some(e) = any.downcast_ref::<AxiomPumpSwapBuyEvent>() {
+            Self {
+                metadata: &e.metadata,
+                pool: e.pool,
+                trader: e.user,
+                input_mint: e.quote_mint,
+                output_mint: e.base_mint,
+                amount_in: e.user_quote_amount_in,
+                amount_out: e.base_amount_out,
+                fee: sum_fees(&[e.lp_fee, e.protocol_fee, e.coin_creator_fee]),
+                fee_mint: e.quote_mint,
+            }
+        } else if let Some(e) = any.downcast_ref::<BonkTradeEvent>() {
+            let (input_mint, output_mint) = if e.trade_direction == BonkTradeDirection::Buy {
+                (e.quote_token_mint, e.base_token_mint)
+            } else {
+                (e.base_token_mint, e.quote_token_mint)
+            };
+            Self {
+                metadata: &e.metadata,
+                pool: e.pool_state,
+                trader: e.payer,
+                input_mint,
+                output_mint,
+                amount_in: e.amount_in,
+                amount_out: e.amount_out,
+                fee: sum_fees(&[e.protocol_fee, e.platform_fee, e.creator_fee, e.share_fee]),
+                fee_mint: e.quote_token_mint,
+            }
+        } else if let Some(e) = any.downcast_ref::<MeteoraDBCSwapEvent>() {
+            let (input_mint, output_mint) = if e.trade_direction == TradeDirection::Buy {
+                (e.quote_mint, e.base_mint)
+            } else {
+                (e.base_mint, e.quote_mint)
+            };
+            Self {
+                metadata: &e.metadata,
+                pool: e.pool,
+                trader: e.payer,
+                input_mint,
+                output_mint,
+                amount_in: e.actual_input_amount,
+                amount_out: e.output_amount,
+                fee: sum_fees(&[e.trading_fee, e.protocol_fee, e.referral_fee]),
+                fee_mint: e.quote_mint,
+            }
+        } else if let Some(e) = any.downcast_ref::<MeteoraDAMMv2SwapEvent>() {
+            let (input_mint, output_mint) = if e.trade_direction == TradeDirection::Buy {
+                (e.token_b_mint, e.token_a_mint)
+            } else {
+                (e.token_a_mint, e.token_b_mint)
+            };
+            Self {
+                metadata: &e.metadata,
+                pool: e.pool,
+                trader: e.payer,
+                input_mint,
+                output_mint,
+                amount_in: e.amount_in,
+                amount_out: e.output_amount,
+                fee: sum_fees(&[e.lp_fee, e.protocol_fee, e.partner_fee, e.referral_fee]),
+                // 收费币种取决于池子的 collect_fee_mode
+                fee_mint: Pubkey::default(),
+            }
+        } else if let Some(e) = any.downcast_ref::<RaydiumCpmmSwapEvent>() {
+            Self {
+                metadata: &e.metadata,
+                pool: e.pool_state,
+                trader: e.payer,
+                input_mint: e.input_token_mint,
+                output_mint: e.output_token_mint,
+                amount_in: e.amount_in,
+                amount_out: e.amount_out,
+                fee: 0,
+                fee_mint: Pubkey::default(),
+            }
+        } else if let Some(e) = any.downcast_ref::<RaydiumClmmSwapEvent>() {
+            Self {
+                metadata: &e.metadata,
+                pool: e.pool_state,
+                trader: e.payer,
+                input_mint: Pubkey::default(),
+                output_mint: Pubkey::default(),
+                amount_in: 0,
+                amount_out: 0,
+                fee: 0,
+                fee_mint: Pubkey::default(),
+            }
+        } else if let Some(e) = any.downcast_ref::<RaydiumClmmSwapV2Event>() {
+            Self {
+                metadata: &e.metadata,
+                pool: e.pool_state,
+                trader: e.payer,
+                input_mint: e.input_vault_mint,
+                output_mint: e.output_vault_mint,
+                amount_in: 0,
+                amount_out: 0,
+                fee: 0,
+                fee_mint: Pubkey::default(),
+            }
+        } else if let Some(e) = any.downcast_ref::<OrcaWhirlpoolSwapEvent>() {
+            Self {
+                metadata: &e.metadata,
+                pool: e.whirlpool,
+                trader: e.token_authority,
+                input_mint: Pubkey::default(),
+                output_mint: Pubkey::default(),
+                amount_in: 0,
+                amount_out: 0,
+                fee: 0,
+                fee_mint: Pubkey::default(),
+            }
+        } else if let Some(e) = any.downcast_ref::<OrcaWhirlpoolSwapV2Event>() {
+            let (input_mint, output_mint) = if e.a_to_b {
+                (e.token_mint_a, e.token_mint_b)
+            } else {
+                (e.token_mint_b, e.token_mint_a)
+            };
+            Self {
+                metadata: &e.metadata,
+                pool: e.whirlpool,
+                trader: e.token_authority,
+                input_mint,
+                output_mint,
+                amount_in: 0,
+                amount_out: 0,
+                fee: 0,
+                fee_mint: Pubkey::default(),
+            }
+        } else if let Some(e) = any.downcast_ref::<OrcaWhirlpoolTwoHopSwapEvent>() {
+            // 两跳交易记为第一个池子的交易，输入输出为整条路径的两端
+            Self {
+                metadata: &e.metadata,
+                pool: e.whirlpool_one,
+                trader: e.token_authority,
+                input_mint: Pubkey::default(),
+                output_mint: Pubkey::default(),
+                amount_in: 0,
+                amount_out: 0,
+                fee: 0,
+                fee_mint: Pubkey::default(),
+            }
+        } else {
+            let e = any.downcast_ref::<RaydiumAmmV4SwapEvent>()?;
+            Self {
+                metadata: &e.metadata,
+                pool: e.amm,
+                trader: e.user_source_owner,
+                input_mint: Pubkey::default(),
+                output_mint: Pubkey::default(),
+                amount_in: e.amount_in,
+                amount_out: e.amount_out,
+                fee: 0,
+                fee_mint: Pubkey::default(),
+            }
+        };
+        Some(fields)
+    }
+}
+
+impl NormalizedTrade {
+    /// 由交易事件派生，非交易事件及已关联底层交易的路由事件返回 None
+    ///
+    /// `token_mints` 用于查询 mint 精度以计算价格。
+    pub fn from_event(event: &dyn UnifiedEvent, token_mints: &TokenAccountMints) -> Option<Self> {
+        // 底层 DEX 事件会单独派生
+        if router::routed_trade(event).is_some() {
+            return None;
+        }
+        let mut fields = TradeFields::from_event(event)?;
+        if let Some(swap_data) = &fields.metadata.swap_data {
+            if swap_data.from_mint != Pubkey::default() {
+                fields.input_mint = swap_data.from_mint;
+            }
+            if swap_data.to_mint != Pubkey::default() {
+                fields.output_mint = swap_data.to_mint;
+            }
+            if swap_data.from_amount != 0 {
+                fields.amount_in = swap_data.from_amount;
+            }
+            if swap_data.to_amount != 0 {
+                fields.amount_out = swap_data.to_amount;
+            }
+        }
+
+        let side = match (is_quote_mint(&fields.input_mint), is_quote_mint(&fields.output_mint)) {
+            (true, false) => TradeSide::Buy,
+            (false, true) => TradeSide::Sell,
+            _ => TradeSide::Unknown,
+        };
+        let input_decimals = token_mints.decimals(&fields.input_mint);
+        let output_decimals = token_mints.decimals(&fields.output_mint);
+        let ui_amount = |amount: u64, decimals: Option<u8>| {
+            decimals.map(|decimals| amount as f64 / 10f64.powi(decimals as i32))
+        };
+        let amount_in = ui_amount(fields.amount_in, input_decimals);
+        let amount_out = ui_amount(fields.amount_out, output_decimals);
+        let (quote, base) = match side {
+            TradeSide::Buy => (amount_in, amount_out),
+            TradeSide::Sell => (amount_out, amount_in),
+            TradeSide::Unknown => (None, None),
+        };
+        let price = match (quote, base) {
+            (Some(quote), Some(base)) if base > 0.0 => Some(quote / base),
+            _ => None,
+        };
+
+        let mut metadata = fields.metadata.clone();
+        metadata.event_type = EventType::NormalizedTrade;
+        Some(Self {
+            metadata,
+            source_event_type: event.event_type(),
+            pool: fields.pool,
+            trader: fields.trader,
+            input_mint: fields.input_mint,
+            output_mint: fields.output_mint,
+            amount_in: fields.amount_in,
+            amount_out: fields.amount_out,
+            input_decimals,
+            output_decimals,
+            fee: fields.fee,
+            fee_mint: fields.fee_mint,
+            side,
+            price,
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::streaming::event_parser::common::InstructionIndex;
+
+    fn photon_buy(routed_trade: Option<InstructionIndex>) -> PhotonPumpFunTradeEvent {
+        PhotonPumpFunTradeEvent {
+            mint: Pubkey::new_unique(),
+            is_buy: true,
+            sol_amount: 1_000,
+            token_amount: 2_000,
+            routed_trade,
+            ..Default::default()
+        }
+    }
+
+    #[test]
+    fn routed_router_event_is_not_derived() {
+        let routed = InstructionIndex { outer_index: 3, inner_index: Some(1) };
+        let event = photon_buy(Some(routed));
+        assert!(NormalizedTrade::from_event(&event, &TokenAccountMints::default()).is_none());
+    }
+
+    #[test]
+    fn unlinked_router_event_is_derived() {
+        let event = photon_buy(None);
+        let trade = NormalizedTrade::from_event(&event, &TokenAccountMints::default())
+            .expect("router trade without an underlying DEX event");
+        assert_eq!((trade.input_mint, trade.output_mint), (NATIVE_SOL_MINT, event.mint));
+        assert_eq!((trade.amount_in, trade.amount_out), (1_000, 2_000));
+        assert_eq!(trade.side, TradeSide::Buy);
+    }
+
+    #[test]
+    fn dex_event_is_derived() {
+        let event = PumpFunTradeEvent {
+            mint: Pubkey::new_unique(),
+            is_buy: false,
+            sol_amount: 1_000,
+            token_amount: 2_000,
+            ..Default::default()
+        };
+        let trade =
+            NormalizedTrade::from_event(&event, &TokenAccountMints::default()).expect("dex trade");
+        assert_eq!((trade.input_mint, trade.output_mint), (event.mint, NATIVE_SOL_MINT));
+        assert_eq!(trade.side, TradeSide::Sell);
+    }
+
+    #[test]
+    fn photon_pumpfun_sell_is_not_derived() {
+        let mut event = PumpFunTradeEvent {
+            mint: Pubkey::new_unique(),
+            is_buy: false,
+            sol_amount: 1_000,
+            token_amount: 2_000,
+            ..Default::default()
+        };
+        event.metadata.protocol = ProtocolType::PhotonProtocol;
+        assert!(NormalizedTrade::from_event(&event, &TokenAccountMints::default()).is_none());
+    }
+
+    #[test]
+    fn fee_sum_saturates() {
+        let event = PumpSwapBuyEvent {
+            base_mint: Pubkey::new_unique(),
+            quote_mint: NATIVE_SOL_MINT,
+            lp_fee: u64::MAX,
+            protocol_fee: 1,
+            coin_creator_fee: 1,
+            ..Default::default()
+        };
+        let trade = NormalizedTrade::from_event(&event, &TokenAccountMints::default()).unwrap();
+        assert_eq!(trade.fee, u64::MAX);
+    }
+}
@@ -0,0 +1,368 @@
+// 解析诊断 - 统计未匹配的指令与解码失败，用于及早发现程序升级或新增指令
+use std::collections::BTreeMap;
+use std::sync::atomic::{AtomicU64, Ordering};
+
+use dashmap::DashMap;
+use serde::{Deserialize, Serialize};
+use solana_sdk::{pubkey::Pubkey, signature::Signature};
+
+use crate::{
+    impl_unified_event,
+    streaming::event_parser::common::{EventMetadata, EventType},
+};
+
+/// 未匹配指令记录的 discriminator 最大长度
+pub const MAX_DIAGNOSTIC_DISCRIMINATOR_LEN: usize = 8;
+/// 快照中每个程序默认保留的条目数
+pub const DEFAULT_DIAGNOSTICS_TOP_N: usize = 10;
+// 每类明细最多跟踪的条目数，超出后只计入程序总数，避免异常数据占用过多内存
+const MAX_TRACKED_ENTRIES: usize = 1024;
+
+/// 未能解析的原因
+#[derive(Copy, Clone, Debug, Default, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub enum UnparsedReason {
+    /// 没有与 discriminator 匹配的指令配置
+    #[default]
+    UnknownDiscriminator,
+    /// discriminator 已匹配，但指令数据解码失败
+    DecodeFailed,
+    /// discriminator 已匹配，但 CPI 事件数据解码失败
+    InnerDecodeFailed,
+}
+
+impl UnparsedReason {
+    /// 导出指标时使用的标签值
+    pub const fn label(self) -> &'static str {
+        match self {
+            UnparsedReason::UnknownDiscriminator => "unknown_discriminator",
+            UnparsedReason::DecodeFailed => "decode_failed",
+            UnparsedReason::InnerDecodeFailed => "inner_decode_failed",
+        }
+    }
+}
+
+/// 订阅程序中未能解析的指令，需在 `EventTypeFilter` 中显式包含 `EventType::UnparsedInstruction`
+///
+/// 只输出指令层面的失败（未知 discriminator 与指令解码失败），CPI 事件解码失败仅计入诊断统计。
+#[derive(Clone, Debug, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct UnparsedInstructionEvent {
+    pub metadata: EventMetadata,
+    pub reason: UnparsedReason,
+    /// 解码失败时为尝试解析的事件类型，未知 discriminator 时为 `EventType::Unknown`
+    pub attempted_event_type: EventType,
+    /// 指令数据前缀，最长 `MAX_DIAGNOSTIC_DISCRIMINATOR_LEN` 字节
+    pub discriminator: Vec<u8>,
+    pub data_len: u64,
+}
+impl_unified_event!(UnparsedInstructionEvent,);
+
+/// 未匹配的 discriminator 统计
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct UnmatchedDiscriminator {
+    /// 十六进制 discriminator 前缀
+    pub discriminator: String,
+    pub count: u64,
+    /// 首次出现时的指令数据长度
+    pub data_len: usize,
+    /// 首次出现的交易签名
+    pub sample_signature: String,
+}
+
+/// 解码失败统计，按事件类型与数据长度区分，便于发现布局变化
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct DecodeFailure {
+    pub event_type: String,
+    pub reason: UnparsedReason,
+    /// 去掉 discriminator 后的数据长度
+    pub data_len: usize,
+    pub count: u64,
+    pub sample_signature: String,
+}
+
+/// 单个程序的解析诊断
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct ProgramParseDiagnostics {
+    pub unmatched_instructions: u64,
+    pub decode_failures: u64,
+    /// 按次数降序
+    pub top_unmatched: Vec<UnmatchedDiscriminator>,
+    /// 按次数降序
+    pub top_decode_failures: Vec<DecodeFailure>,
+}
+
+/// 解析诊断快照，键为程序 ID
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct ParseDiagnosticsSnapshot {
+    pub programs: BTreeMap<String, ProgramParseDiagnostics>,
+}
+
+impl ParseDiagnosticsSnapshot {
+    pub fn is_empty(&self) -> bool {
+        self.programs.is_empty()
+    }
+}
+
+#[derive(Debug)]
+struct DiagnosticEntry {
+    count: AtomicU64,
+    data_len: usize,
+    sample_signature: Signature,
+}
+
+impl DiagnosticEntry {
+    fn new(data_len: usize, sample_signature: Signature) -> Self {
+        Self { count: AtomicU64::new(1), data_len, sample_signature }
+    }
+}
+
+#[derive(Debug, Default)]
+struct ProgramTotals {
+    unmatched_instructions: AtomicU64,
+    decode_failures: AtomicU64,
+}
+
+/// 未匹配指令的键，discriminator 以定长数组保存，记录已有条目时无需分配
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+struct UnmatchedKey {
+    program_id: Pubkey,
+    discriminator: [u8; MAX_DIAGNOSTIC_DISCRIMINATOR_LEN],
+    len: u8,
+}
+
+impl UnmatchedKey {
+    /// 超过 `MAX_DIAGNOSTIC_DISCRIMINATOR_LEN` 的部分被截断
+    fn new(program_id: Pubkey, discriminator: &[u8]) -> Self {
+        let len = discriminator.len().min(MAX_DIAGNOSTIC_DISCRIMINATOR_LEN);
+        let mut bytes = [0u8; MAX_DIAGNOSTIC_DISCRIMINATOR_LEN];
+        bytes[..len].copy_from_slice(&discriminator[..len]);
+        Self { program_id, discriminator: bytes, len: len as u8 }
+    }
+
+    fn discriminator(&self) -> &[u8] {
+        &self.discriminator[..self.len as usize]
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+struct DecodeFailureKey {
+    program_id: Pubkey,
+    event_type: EventType,
+    reason: UnparsedReason,
+    data_len: usize,
+}
+
+/// 解析诊断计数器，由 `EventParser` 持有，可通过 `MetricsManager` 导出
+#[derive(Debug, Default)]
+pub struct ParseDiagnostics {
+    totals: DashMap<Pubkey, ProgramTotals>,
+    unmatched: DashMap<UnmatchedKey, DiagnosticEntry>,
+    decode_failures: DashMap<DecodeFailureKey, DiagnosticEntry>,
+}
+
+impl ParseDiagnostics {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// 记录没有匹配到任何指令配置的指令，`discriminator` 最多保留
+    /// `MAX_DIAGNOSTIC_DISCRIMINATOR_LEN` 字节
+    pub fn record_unmatched(
+        &self,
+        program_id: Pubkey,
+        discriminator: &[u8],
+        data_len: usize,
+        signature: Signature,
+    ) {
+        self.totals
+            .entry(program_id)
+            .or_default()
+            .unmatched_instructions
+            .fetch_add(1, Ordering::Relaxed);
+        let key = UnmatchedKey::new(program_id, discriminator);
+        record_entry(&self.unmatched, key, data_len, signature);
+    }
+
+    /// 记录 discriminator 匹配但解码返回 None 的数据，`data_len` 不含 discriminator
+    pub fn record_decode_failure(
+        &self,
+        program_id: Pubkey,
+        event_type: &EventType,
+        reason: UnparsedReason,
+        data_len: usize,
+        signature: Signature,
+    ) {
+        self.totals.entry(program_id).or_default().decode_failures.fetch_add(1, Ordering::Relaxed);
+        let key = DecodeFailureKey { program_id, event_type: event_type.clone(), reason, data_len };
+        record_entry(&self.decode_failures, key, data_len, signature);
+    }
+
+    /// 获取快照，每个程序只保留次数最多的 `top_n` 条明细
+    pub fn snapshot(&self, top_n: usize) -> ParseDiagnosticsSnapshot {
+        let mut programs: BTreeMap<Pubkey, ProgramParseDiagnostics> = self
+            .totals
+            .iter()
+            .map(|entry| {
+                let diagnostics = ProgramParseDiagnostics {
+                    unmatched_instructions: entry.unmatched_instructions.load(Ordering::Relaxed),
+                    decode_failures: entry.decode_failures.load(Ordering::Relaxed),
+                    ..Default::default()
+                };
+                (*entry.key(), diagnostics)
+            })
+            .collect();
+        for entry in self.unmatched.iter() {
+            let (key, value) = entry.pair();
+            if let Some(program) = programs.get_mut(&key.program_id) {
+                program.top_unmatched.push(UnmatchedDiscriminator {
+                    discriminator: to_hex(key.discriminator()),
+                    count: value.count.load(Ordering::Relaxed),
+                    data_len: value.data_len,
+                    sample_signature: value.sample_signature.to_string(),
+                });
+            }
+        }
+        for entry in self.decode_failures.iter() {
+            let (key, value) = entry.pair();
+            if let Some(program) = programs.get_mut(&key.program_id) {
+                program.top_decode_failures.push(DecodeFailure {
+                    event_type: key.event_type.to_string(),
+                    reason: key.reason,
+                    data_len: key.data_len,
+                    count: value.count.load(Ordering::Relaxed),
+                    sample_signature: value.sample_signature.to_string(),
+                });
+            }
+        }
+        for program in programs.values_mut() {
+            program.top_unmatched.sort_by_key(|e| std::cmp::Reverse(e.count));
+            program.top_unmatched.truncate(top_n);
+            program.top_decode_failures.sort_by_key(|e| std::cmp::Reverse(e.count));
+            program.top_decode_failures.truncate(top_n);
+        }
+        ParseDiagnosticsSnapshot {
+            programs: programs
+                .into_iter()
+                .map(|(program_id, diagnostics)| (program_id.to_string(), diagnostics))
+                .collect(),
+        }
+    }
+
+    /// 清空所有计数
+    pub fn reset(&self) {
+        self.totals.clear();
+        self.unmatched.clear();
+        self.decode_failures.clear();
+    }
+}
+
+fn record_entry<K: std::hash::Hash + Eq>(
+    map: &DashMap<K, DiagnosticEntry>,
+    key: K,
+    data_len: usize,
+    signature: Signature,
+) {
+    if let Some(entry) = map.get(&key) {
+        entry.count.fetch_add(1, Ordering::Relaxed);
+        return;
+    }
+    if map.len() < MAX_TRACKED_ENTRIES {
+        map.entry(key)
+            .and_modify(|entry| {
+                entry.count.fetch_add(1, Ordering::Relaxed);
+            })
+            .or_insert_with(|| DiagnosticEntry::new(data_len, signature));
+    }
+}
+
+fn to_hex(bytes: &[u8]) -> String {
+    bytes.iter().map(|byte| format!("{:02x}", byte)).collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn program_snapshot(
+        diagnostics: &ParseDiagnostics,
+        program_id: &Pubkey,
+        top_n: usize,
+    ) -> ProgramParseDiagnostics {
+        diagnostics.snapshot(top_n).programs.remove(&program_id.to_string()).expect("program")
+    }
+
+    #[test]
+    fn snapshot_keeps_top_n_by_count() {
+        let diagnostics = ParseDiagnostics::new();
+        let program_id = Pubkey::new_unique();
+        let signature = Signature::default();
+        for (discriminator, count) in [([1u8], 2), ([2u8], 5), ([3u8], 1), ([4u8], 3)] {
+            for _ in 0..count {
+                diagnostics.record_unmatched(program_id, &discriminator, 16, signature);
+            }
+        }
+        for (data_len, count) in [(10, 1), (20, 4), (30, 2)] {
+            for _ in 0..count {
+                diagnostics.record_decode_failure(
+                    program_id,
+                    &EventType::PumpFunBuy,
+                    UnparsedReason::DecodeFailed,
+                    data_len,
+                    signature,
+                );
+            }
+        }
+
+        let program = program_snapshot(&diagnostics, &program_id, 2);
+        assert_eq!(program.unmatched_instructions, 11);
+        assert_eq!(program.decode_failures, 7);
+        let unmatched: Vec<_> =
+            program.top_unmatched.iter().map(|e| (e.discriminator.as_str(), e.count)).collect();
+        assert_eq!(unmatched, [("02", 5), ("04", 3)]);
+        let failures: Vec<_> =
+            program.top_decode_failures.iter().map(|e| (e.data_len, e.count)).collect();
+        assert_eq!(failures, [(20, 4), (30, 2)]);
+    }
+
+    #[test]
+    fn tracked_entries_are_capped() {
+        let diagnostics = ParseDiagnostics::new();
+        let program_id = Pubkey::new_unique();
+        let signature = Signature::default();
+        for index in 0..=MAX_TRACKED_ENTRIES as u16 {
+            diagnostics.record_unmatched(program_id, &index.to_le_bytes(), 8, signature);
+        }
+        // 超出上限的新条目只计入总数，已有条目仍继续计数
+        diagnostics.record_unmatched(program_id, &0u16.to_le_bytes(), 8, signature);
+
+        let program = program_snapshot(&diagnostics, &program_id, usize::MAX);
+        assert_eq!(program.unmatched_instructions, MAX_TRACKED_ENTRIES as u64 + 2);
+        assert_eq!(program.top_unmatched.len(), MAX_TRACKED_ENTRIES);
+        assert_eq!(program.top_unmatched[0].discriminator, "0000");
+        assert_eq!(program.top_unmatched[0].count, 2);
+        let untracked = to_hex(&(MAX_TRACKED_ENTRIES as u16).to_le_bytes());
+        assert!(program.top_unmatched.iter().all(|e| e.discriminator != untracked));
+    }
+
+    #[test]
+    fn reset_clears_all_counts() {
+        let diagnostics = ParseDiagnostics::new();
+        let program_id = Pubkey::new_unique();
+        diagnostics.record_unmatched(program_id, &[1, 2, 3], 8, Signature::default());
+        diagnostics.record_decode_failure(
+            program_id,
+            &EventType::PumpFunBuy,
+            UnparsedReason::InnerDecodeFailed,
+            4,
+            Signature::default(),
+        );
+        assert!(!diagnostics.snapshot(DEFAULT_DIAGNOSTICS_TOP_N).is_empty());
+
+        diagnostics.reset();
+        assert!(diagnostics.snapshot(DEFAULT_DIAGNOSTICS_TOP_N).is_empty());
+        // 重置后可重新开始计数
+        diagnostics.record_unmatched(program_id, &[1, 2, 3], 8, Signature::default());
+        let program = program_snapshot(&diagnostics, &program_id, DEFAULT_DIAGNOSTICS_TOP_N);
+        assert_eq!(program.unmatched_instructions, 1);
+        assert_eq!(program.top_unmatched[0].count, 1);
+    }
+}
@@ -0,0 +1,298 @@
+use std::str::FromStr;
+
+use base64::{engine::general_purpose::STANDARD, Engine};
+use solana_sdk::pubkey::Pubkey;
+
+const PROGRAM_DATA_PREFIX: &str = "Program data: ";
+const LOG_TRUNCATED: &str = "Log truncated";
+
+/// `Program data:` 日志（`sol_log_data`）及其所属指令
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ProgramDataLog {
+    /// 输出日志的程序
+    pub program_id: Pubkey,
+    /// 所属外层指令索引
+    pub outer_index: i64,
+    /// 所属内联指令索引，外层指令直接输出时为 None
+    pub inner_index: Option<i64>,
+    /// 调用深度，外层指令为 1
+    pub stack_height: u32,
+    /// 解码后的数据，多段数据按顺序拼接
+    pub data: Vec<u8>,
+}
+
+struct InvokeFrame {
+    program_id: Pubkey,
+    inner_index: Option<i64>,
+    stack_height: u32,
+}
+
+/// 按 invoke / success / failed 维护调用栈，将 `Program data:` 日志归属到对应指令
+///
+/// `instruction_programs` 为消息中各外层指令的程序 ID（按指令顺序）。预编译程序及部分原生程序
+/// 不输出 `invoke [1]` 日志，因此外层 `invoke` 按程序 ID 匹配到下一条对应指令，而非简单计数；
+/// 无法匹配时归属已不可靠，停止解析。
+///
+/// `inner_index` 与 `InnerInstructions.instructions` 的下标一致（按 CPI 调用顺序）。
+/// 仅解码 `should_decode` 返回 true 的程序的数据；遇到 `Log truncated` 后停止。
+pub fn parse_program_data_logs(
+    log_messages: &[String],
+    instruction_programs: &[Pubkey],
+    mut should_decode: impl FnMut(&Pubkey) -> bool,
+) -> Vec<ProgramDataLog> {
+    let mut logs = Vec::new();
+    let mut stack: Vec<InvokeFrame> = Vec::new();
+    let mut outer_index = -1_i64;
+    let mut inner_count = 0_i64;
+
+    for message in log_messages {
+        if message.starts_with(LOG_TRUNCATED) {
+            break;
+        }
+        if let Some(encoded) = message.strip_prefix(PROGRAM_DATA_PREFIX) {
+            let Some(frame) = stack.last() else {
+                continue;
+            };
+            if !should_decode(&frame.program_id) {
+                continue;
+            }
+            let mut data = Vec::new();
+            let decoded =
+                encoded.split_whitespace().all(|part| STANDARD.decode_vec(part, &mut data).is_ok());
+            if decoded && !data.is_empty() {
+                logs.push(ProgramDataLog {
+                    program_id: frame.program_id,
+                    outer_index,
+                    inner_index: frame.inner_index,
+                    stack_height: frame.stack_height,
+                    data,
+                });
+            }
+            continue;
+        }
+        let Some(rest) = message.strip_prefix("Program ") else {
+            continue;
+        };
+        let mut parts = rest.split_whitespace();
+        let (Some(program), Some(action)) = (parts.next(), parts.next()) else {
+            continue;
+        };
+        match action {
+            "invoke" => {
+                let Ok(program_id) = Pubkey::from_str(program) else {
+                    continue;
+                };
+                let stack_height = parts
+                    .next()
+                    .and_then(|depth| depth.trim_matches(|c| c == '[' || c == ']').parse().ok())
+                    .unwrap_or(stack.len() as u32 + 1);
+                let inner_index = if stack_height <= 1 {
+                    let start = (outer_index + 1) as usize;
+                    let Some(offset) = instruction_programs
+                        .get(start..)
+                        .and_then(|programs| programs.iter().position(|id| *id == program_id))
+                    else {
+                        break;
+                    };
+                    outer_index = (start + offset) as i64;
+                    inner_count = 0;
+                    stack.clear();
+                    None
+                } else {
+                    inner_count += 1;
+                    Some(inner_count - 1)
+                };
+                stack.push(InvokeFrame { program_id, inner_index, stack_height });
+            }
+            "success" | "failed:" => {
+                stack.pop();
+            }
+            _ => {}
+        }
+    }
+    logs
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const PUMPFUN: &str = "6EF8rrecthR5Dkzon8Nwu78hRvfCKubJ14M5uBEwF6P";
+    const PUMPSWAP: &str = "pAMMBay6oceH9fJKBRHGP5D4bD4sWpmSwMn52FMfXEA";
+    const TOKEN: &str = "TokenkegQfeZyiNwAJbNbGKPFXCWuBjf9Ss623VQ5DA";
+    const COMPUTE_BUDGET: &str = "ComputeBudget111111111111111111111111111111";
+
+    fn logs(lines: &[String], programs: &[&str]) -> Vec<ProgramDataLog> {
+        let programs: Vec<_> = programs.iter().map(|program| pubkey(program)).collect();
+        parse_program_data_logs(lines, &programs, |_| true)
+    }
+
+    fn invoke(program: &str, depth: u32) -> String {
+        format!("Program {program} invoke [{depth}]")
+    }
+
+    fn success(program: &str) -> String {
+        format!("Program {program} success")
+    }
+
+    fn consumed(program: &str) -> String {
+        format!("Program {program} consumed 12345 of 200000 compute units")
+    }
+
+    fn data(bytes: &[u8]) -> String {
+        format!("{PROGRAM_DATA_PREFIX}{}", STANDARD.encode(bytes))
+    }
+
+    fn pubkey(s: &str) -> Pubkey {
+        Pubkey::from_str(s).unwrap()
+    }
+
+    /// 外层 0：ComputeBudget；外层 1：PumpFun -> PumpSwap -> Token，随后 PumpFun 再调用 Token 与自身
+    fn nested_cpi_logs() -> Vec<String> {
+        vec![
+            invoke(COMPUTE_BUDGET, 1),
+            success(COMPUTE_BUDGET),
+            invoke(PUMPFUN, 1),
+            "Program log: Instruction: Buy".to_string(),
+            data(&[1]),
+            invoke(PUMPSWAP, 2),
+            "Program log: Instruction: Swap".to_string(),
+            invoke(TOKEN, 3),
+            "Program log: Instruction: Transfer".to_string(),
+            consumed(TOKEN),
+            success(TOKEN),
+            data(&[2]),
+            consumed(PUMPSWAP),
+            success(PUMPSWAP),
+            invoke(TOKEN, 2),
+            success(TOKEN),
+            invoke(PUMPFUN, 2),
+            data(&[3]),
+            success(PUMPFUN),
+            data(&[4]),
+            format!("Program return: {PUMPFUN} AQ=="),
+            consumed(PUMPFUN),
+            success(PUMPFUN),
+        ]
+    }
+
+    #[test]
+    fn nested_cpi_attribution() {
+        let parsed = logs(&nested_cpi_logs(), &[COMPUTE_BUDGET, PUMPFUN]);
+        let summary: Vec<_> = parsed
+            .iter()
+            .map(|log| {
+                (log.program_id, log.outer_index, log.inner_index, log.stack_height, log.data[0])
+            })
+            .collect();
+        assert_eq!(
+            summary,
+            vec![
+                (pubkey(PUMPFUN), 1, None, 1, 1),
+                // PumpSwap 是外层 1 的第 0 条内联指令，其 Token CPI 为第 1 条
+                (pubkey(PUMPSWAP), 1, Some(0), 2, 2),
+                // Token 为第 2 条，PumpFun self-CPI（emit_cpi）为第 3 条
+                (pubkey(PUMPFUN), 1, Some(3), 2, 3),
+                (pubkey(PUMPFUN), 1, None, 1, 4),
+            ]
+        );
+    }
+
+    #[test]
+    fn filter_and_multi_part_data() {
+        let lines = vec![
+            invoke(PUMPFUN, 1),
+            format!("{PROGRAM_DATA_PREFIX}AQ== AgM="),
+            invoke(PUMPSWAP, 2),
+            data(&[9]),
+            success(PUMPSWAP),
+            "Program data: not base64!".to_string(),
+            success(PUMPFUN),
+        ];
+        let parsed = parse_program_data_logs(&lines, &[pubkey(PUMPFUN)], |program| {
+            *program == pubkey(PUMPFUN)
+        });
+        assert_eq!(parsed.len(), 1);
+        assert_eq!(parsed[0].data, [1, 2, 3]);
+    }
+
+    #[test]
+    fn failed_inner_call_unwinds_stack() {
+        let lines = vec![
+            invoke(PUMPFUN, 1),
+            data(&[1]),
+            invoke(PUMPSWAP, 2),
+            data(&[2]),
+            invoke(TOKEN, 3),
+            "Program log: Error: insufficient funds".to_string(),
+            consumed(TOKEN),
+            format!("Program {TOKEN} failed: custom program error: 0x1"),
+            format!("Program {PUMPSWAP} failed: custom program error: 0x1"),
+            format!("Program {PUMPFUN} failed: custom program error: 0x1"),
+            // 失败后栈已清空，孤立的数据日志被忽略
+            data(&[3]),
+            invoke(PUMPSWAP, 1),
+            data(&[4]),
+            success(PUMPSWAP),
+        ];
+        let summary: Vec<_> = logs(&lines, &[PUMPFUN, PUMPSWAP])
+            .iter()
+            .map(|log| (log.program_id, log.outer_index, log.inner_index, log.data[0]))
+            .collect();
+        assert_eq!(
+            summary,
+            vec![
+                (pubkey(PUMPFUN), 0, None, 1),
+                (pubkey(PUMPSWAP), 0, Some(0), 2),
+                (pubkey(PUMPSWAP), 1, None, 4),
+            ]
+        );
+    }
+
+    #[test]
+    fn truncated_logs_stop_parsing() {
+        let mut lines = nested_cpi_logs();
+        // 在 PumpSwap 的数据之后截断
+        let cut = lines.iter().position(|line| *line == data(&[2])).unwrap() + 1;
+        lines.truncate(cut);
+        lines.push("Log truncated".to_string());
+        lines.push(data(&[4]));
+
+        let parsed = logs(&lines, &[COMPUTE_BUDGET, PUMPFUN]);
+        assert_eq!(parsed.iter().map(|log| log.data[0]).collect::<Vec<_>>(), [1, 2]);
+    }
+
+    #[test]
+    fn outer_index_skips_instructions_without_invoke_logs() {
+        const ED25519: &str = "Ed25519SigVerify111111111111111111111111111";
+        // 预编译程序（外层 0、2）不输出 invoke 日志
+        let lines = vec![
+            invoke(PUMPFUN, 1),
+            data(&[1]),
+            success(PUMPFUN),
+            invoke(PUMPSWAP, 1),
+            data(&[2]),
+            success(PUMPSWAP),
+        ];
+        let summary: Vec<_> = logs(&lines, &[ED25519, PUMPFUN, ED25519, PUMPSWAP])
+            .iter()
+            .map(|log| (log.program_id, log.outer_index, log.data[0]))
+            .collect();
+        assert_eq!(summary, vec![(pubkey(PUMPFUN), 1, 1), (pubkey(PUMPSWAP), 3, 2)]);
+    }
+
+    #[test]
+    fn unmatched_outer_invoke_stops_parsing() {
+        // 日志中的外层程序与指令列表不符时，后续归属不可靠
+        let lines = vec![
+            invoke(PUMPFUN, 1),
+            data(&[1]),
+            success(PUMPFUN),
+            invoke(PUMPSWAP, 1),
+            data(&[2]),
+            success(PUMPSWAP),
+        ];
+        let parsed = logs(&lines, &[PUMPFUN]);
+        assert_eq!(parsed.iter().map(|log| log.data[0]).collect::<Vec<_>>(), [1]);
+    }
+}
@@ -0,0 +1,3306 @@
+//! 事件 → protobuf 转换（与 `src/protos/events.proto` 一一对应）
+//!
+//! 新增事件结构体或字段时需同步更新 proto 与本文件，`protos::events` 由 build.rs 生成。
+
+use solana_sdk::{instruction::InstructionError, transaction::TransactionError};
+
+use crate::protos::events as pb;
+use crate::streaming::event_parser::{
+    common, core,
+    protocols::{
+        axiom, axiom2, block, bonk, jupiter_v6, meteora_dammv2, meteora_dbc, orca_whirlpool,
+        photon, pumpfun, pumpswap, raydium_amm_v4, raydium_clmm, raydium_cpmm,
+    },
+};
+
+impl From<&common::types::SwapData> for pb::SwapData {
+    fn from(v: &common::types::SwapData) -> Self {
+        Self {
+            from_mint: v.from_mint.to_bytes().to_vec(),
+            to_mint: v.to_mint.to_bytes().to_vec(),
+            from_amount: v.from_amount,
+            to_amount: v.to_amount,
+            description: v.description.as_ref().map(|x| x.to_string()),
+        }
+    }
+}
+
+impl From<&common::types::TransactionContext> for pb::TransactionContext {
+    fn from(v: &common::types::TransactionContext) -> Self {
+        Self {
+            success: v.success,
+            err: v.err.as_ref().map(pb::TransactionError::from),
+            fee: v.fee,
+            compute_units_consumed: v.compute_units_consumed,
+            compute_unit_limit: v.compute_unit_limit,
+            compute_unit_price: v.compute_unit_price,
+            fee_payer: v.fee_payer.to_bytes().to_vec(),
+            signers: v.signers.iter().map(|x| x.to_bytes().to_vec()).collect(),
+            recent_blockhash: v.recent_blockhash.to_bytes().to_vec(),
+        }
+    }
+}
+
+impl From<&TransactionError> for pb::TransactionError {
+    fn from(v: &TransactionError) -> Self {
+        use pb::transaction_error::Error;
+        let error = match v {
+            TransactionError::InstructionError(index, err) => {
+                Error::InstructionError(pb::InstructionError::from((*index, err)))
+            }
+            TransactionError::DuplicateInstruction(index) => {
+                Error::DuplicateInstruction(*index as u32)
+            }
+            TransactionError::InsufficientFundsForRent { account_index } => {
+                Error::InsufficientFundsForRent(*account_index as u32)
+            }
+            TransactionError::ProgramExecutionTemporarilyRestricted { account_index } => {
+                Error::ProgramExecutionTemporarilyRestricted(*account_index as u32)
+            }
+            // 其余变体不带数据，Debug 输出即变体名
+            err => Error::Kind(format!("{err:?}")),
+        };
+        Self { error: Some(error) }
+    }
+}
+
+impl From<(u8, &InstructionError)> for pb::InstructionError {
+    fn from((index, v): (u8, &InstructionError)) -> Self {
+        use pb::instruction_error::Error;
+        let error = match v {
+            InstructionError::Custom(code) => Error::Custom(*code),
+            InstructionError::BorshIoError(message) => Error::BorshIoError(message.clone()),
+            err => Error::Kind(format!("{err:?}")),
+        };
+        Self { index: index as u32, error: Some(error) }
+    }
+}
+
+/// 不带数据的变体按变体名还原
+fn unit_variant<T: serde::de::DeserializeOwned>(kind: &str) -> Option<T> {
+    serde_json::from_value(serde_json::Value::String(kind.to_string())).ok()
+}
+
+impl TryFrom<&pb::TransactionError> for TransactionError {
+    type Error = ();
+
+    fn try_from(v: &pb::TransactionError) -> Result<Self, ()> {
+        use pb::transaction_error::Error;
+        match v.error.as_ref().ok_or(())? {
+            Error::Kind(kind) => unit_variant(kind).ok_or(()),
+            Error::InstructionError(err) => {
+                let index = u8::try_from(err.index).map_err(|_| ())?;
+                Ok(Self::InstructionError(index, InstructionError::try_from(err)?))
+            }
+            Error::DuplicateInstruction(index) => {
+                Ok(Self::DuplicateInstruction(u8::try_from(*index).map_err(|_| ())?))
+            }
+            Error::InsufficientFundsForRent(account_index) => {
+                let account_index = u8::try_from(*account_index).map_err(|_| ())?;
+                Ok(Self::InsufficientFundsForRent { account_index })
+            }
+            Error::ProgramExecutionTemporarilyRestricted(account_index) => {
+                let account_index = u8::try_from(*account_index).map_err(|_| ())?;
+                Ok(Self::ProgramExecutionTemporarilyRestricted { account_index })
+            }
+        }
+    }
+}
+
+impl TryFrom<&pb::InstructionError> for InstructionError {
+    type Error = ();
+
+    fn try_from(v: &pb::InstructionError) -> Result<Self, ()> {
+        use pb::instruction_error::Error;
+        match v.error.as_ref().ok_or(())? {
+            Error::Kind(kind) => unit_variant(kind).ok_or(()),
+            Error::Custom(code) => Ok(Self::Custom(*code)),
+            Error::BorshIoError(message) => Ok(Self::BorshIoError(message.clone())),
+        }
+    }
+}
+
+impl From<&common::types::InstructionInvocation> for pb::InstructionInvocation {
+    fn from(v: &common::types::InstructionInvocation) -> Self {
+        Self {
+            stack_height: v.stack_height,
+            parent_program_id: v.parent_program_id.map(|x| x.to_bytes().to_vec()),
+            invoking_program_id: v.invoking_program_id.to_bytes().to_vec(),
+        }
+    }
+}
+
+impl From<&common::types::InstructionIndex> for pb::InstructionIndex {
+    fn from(v: &common::types::InstructionIndex) -> Self {
+        Self { outer_index: v.outer_index, inner_index: v.inner_index }
+    }
+}
+
+impl From<&common::types::RouterInfo> for pb::RouterInfo {
+    fn from(v: &common::types::RouterInfo) -> Self {
+        Self {
+            router: format!("{:?}", v.router),
+            router_program: v.router_program.to_bytes().to_vec(),
+            router_fee: v.router_fee,
+            fee_recipient: v.fee_recipient.map(|x| x.to_bytes().to_vec()),
+            referral: v.referral.map(|x| x.to_bytes().to_vec()),
+        }
+    }
+}
+
+impl From<&common::types::EventMetadata> for pb::EventMetadata {
+    fn from(v: &common::types::EventMetadata) -> Self {
+        Self {
+            signature: v.signature.as_ref().to_vec(),
+            slot: v.slot,
+            transaction_index: v.transaction_index,
+            block_time: v.block_time,
+            block_time_ms: v.block_time_ms,
+            recv_us: v.recv_us,
+            handle_us: v.handle_us,
+            protocol: format!("{:?}", v.protocol),
+            event_type: format!("{:?}", v.event_type),
+            program_id: v.program_id.to_bytes().to_vec(),
+            swap_data: v.swap_data.as_ref().map(pb::SwapData::from),
+            outer_index: v.outer_index,
+            inner_index: v.inner_index,
+            tx_context: v.tx_context.as_deref().map(pb::TransactionContext::from),
+            invocation: v.invocation.as_ref().map(pb::InstructionInvocation::from),
+            via_router: v.via_router.as_ref().map(pb::RouterInfo::from),
+        }
+    }
+}
+
+impl From<&pumpfun::PumpFunCreateTokenEvent> for pb::PumpFunCreateTokenEvent {
+    fn from(v: &pumpfun::PumpFunCreateTokenEvent) -> Self {
+        Self {
+            metadata: Some((&v.metadata).into()),
+            name: v.name.clone(),
+            symbol: v.symbol.clone(),
+            uri: v.uri.clone(),
+            mint: v.mint.to_bytes().to_vec(),
+            bonding_curve: v.bonding_curve.to_bytes().to_vec(),
+            user: v.user.to_bytes().to_vec(),
+            creator: v.creator.to_bytes().to_vec(),
+            timestamp: v.timestamp,
+            virtual_token_reserves: v.virtual_token_reserves,
+            virtual_sol_reserves: v.virtual_sol_reserves,
+            real_token_reserves: v.real_token_reserves,
+            token_total_supply: v.token_total_supply,
+            mint_authority: v.mint_authority.to_bytes().to_vec(),
+            associated_bonding_curve: v.associated_bonding_curve.to_bytes().to_vec(),
+            token_program: v.token_program.to_bytes().to_vec(),
+        }
+    }
+}
+
+impl From<&pumpfun::PumpFunTradeEvent> for pb::PumpFunTradeEvent {
+    fn from(v: &pumpfun::PumpFunTradeEvent) -> Self {
+        Self {
+            metadata: Some((&v.metadata).into()),
+            mint: v.mint.to_bytes().to_vec(),
+            sol_amount: v.sol_amount,
+            token_amount: v.token_amount,
+            is_buy: v.is_buy,
+            user: v.user.to_bytes().to_vec(),
+            timestamp: v.timestamp,
+            virtual_sol_reserves: v.virtual_sol_reserves,
+            virtual_token_reserves: v.virtual_token_reserves,
+            real_sol_reserves: v.real_sol_reserves,
+            real_token_reserves: v.real_token_reserves,
+            fee_recipient: v.fee_recipient.to_bytes().to_vec(),
+            fee_basis_points: v.fee_basis_points,
+            fee: v.fee,
+            creator: v.creator.to_bytes().to_vec(),
+            creator_fee_basis_points: v.creator_fee_basis_points,
+            creator_fee: v.creator_fee,
+            track_volume: v.track_volume,
+            total_unclaimed_tokens: v.total_unclaimed_tokens,
+            total_claimed_tokens: v.total_claimed_tokens,
+            current_sol_volume: v.current_sol_volume,
+            last_update_timestamp: v.last_update_timestamp,
+            max_sol_cost: v.max_sol_cost,
+            min_sol_output: v.min_sol_output,
+            amount: v.amount,
+            is_bot: v.is_bot,
+            is_dev_create_token_trade: v.is_dev_create_token_trade,
+            global: v.global.to_bytes().to_vec(),
+            bonding_curve: v.bonding_curve.to_bytes().to_vec(),
+            associated_bonding_curve: v.associated_bonding_curve.to_bytes().to_vec(),
+            associated_user: v.associated_user.to_bytes().to_vec(),
+            system_program: v.system_program.to_bytes().to_vec(),
+            token_program: v.token_program.to_bytes().to_vec(),
+            creator_vault: v.creator_vault.to_bytes().to_vec(),
+            event_authority: v.event_authority.to_bytes().to_vec(),
+            program: v.program.to_bytes().to_vec(),
+            global_volume_accumulator: v.global_volume_accumulator.to_bytes().to_vec(),
+            user_volume_accumulator: v.user_volume_accumulator.to_bytes().to_vec(),
+            fee_config: v.fee_config.to_bytes().to_vec(),
+            fee_program: v.fee_program.to_bytes().to_vec(),
+        }
+    }
+}
+
+impl From<&pumpfun::PumpFunMigrateEvent> for pb::PumpFunMigrateEvent {
+    fn from(v: &pumpfun::PumpFunMigrateEvent) -> Self {
+        Self {
+            metadata: Some((&v.metadata).into()),
+            user: v.user.to_bytes().to_vec(),
+            mint: v.mint.to_bytes().to_vec(),
+            mint_amount: v.mint_amount,
+            sol_amount: v.sol_amount,
+            pool_migration_fee: v.pool_migration_fee,
+            bonding_curve: v.bonding_curve.to_bytes().to_vec(),
+            timestamp: v.timestamp,
+            pool: v.pool.to_bytes().to_vec(),
+            global: v.global.to_bytes().to_vec(),
+            withdraw_authority: v.withdraw_authority.to_bytes().to_vec(),
+            associated_bonding_curve: v.associated_bonding_curve.to_bytes().to_vec(),
+            system_program: v.system_program.to_bytes().to_vec(),
+            token_program: v.token_program.to_bytes().to_vec(),
+            pump_amm: v.pump_amm.to_bytes().to_vec(),
+            pool_authority: v.pool_authority.to_bytes().to_vec(),
+            pool_authority_mint_account: v.pool_authority_mint_account.to_bytes().to_vec(),
+            pool_authority_wsol_account: v.pool_authority_wsol_account.to_bytes().to_vec(),
+            amm_global_config: v.amm_global_config.to_bytes().to_vec(),
+            wsol_mint: v.wsol_mint.to_bytes().to_vec(),
+            lp_mint: v.lp_mint.to_bytes().to_vec(),
+            user_pool_token_account: v.user_pool_token_account.to_bytes().to_vec(),
+            pool_base_token_account: v.pool_base_token_account.to_bytes().to_vec(),
+            pool_quote_token_account: v.pool_quote_token_account.to_bytes().to_vec(),
+            token_2022_program: v.token_2022_program.to_bytes().to_vec(),
+            associated_token_program: v.associated_token_program.to_bytes().to_vec(),
+            pump_amm_event_authority: v.pump_amm_event_authority.to_bytes().to_vec(),
+            event_authority: v.event_authority.to_bytes().to_vec(),
+            program: v.program.to_bytes().to_vec(),
+        }
+    }
+}
+
+impl From<&pumpfun::types::BondingCurve> for pb::PumpFunBondingCurve {
+    fn from(v: &pumpfun::types::BondingCurve) -> Self {
+        Self {
+            virtual_token_reserves: v.virtual_token_reserves,
+            virtual_sol_reserves: v.virtual_sol_reserves,
+            real_token_reserves: v.real_token_reserves,
+            real_sol_reserves: v.real_sol_reserves,
+            token_total_supply: v.token_total_supply,
+            complete: v.complete,
+            creator: v.creator.to_bytes().to_vec(),
+        }
+    }
+}
+
+impl From<&pumpfun::PumpFunBondingCurveAccountEvent> for pb::PumpFunBondingCurveAccountEvent {
+    fn from(v: &pumpfun::PumpFunBondingCurveAccountEvent) -> Self {
+        Self {
+            metadata: Some((&v.metadata).into()),
+            pubkey: v.pubkey.to_bytes().to_vec(),
+            executable: v.executable,
+            lamports: v.lamports,
+            owner: v.owner.to_bytes().to_vec(),
+            rent_epoch: v.rent_epoch,
+            bonding_curve: Some((&v.bonding_curve).into()),
+        }
+    }
+}
+
+impl From<&pumpfun::types::Global> for pb::PumpFunGlobal {
+    fn from(v: &pumpfun::types::Global) -> Self {
+        Self {
+            initialized: v.initialized,
+            authority: v.authority.to_bytes().to_vec(),
+            fee_recipient: v.fee_recipient.to_bytes().to_vec(),
+            initial_virtual_token_reserves: v.initial_virtual_token_reserves,
+            initial_virtual_sol_reserves: v.initial_virtual_sol_reserves,
+            initial_real_token_reserves: v.initial_real_token_reserves,
+            token_total_supply: v.token_total_supply,
+            fee_basis_points: v.fee_basis_points,
+            withdraw_authority: v.withdraw_authority.to_bytes().to_vec(),
+            enable_migrate: v.enable_migrate,
+            pool_migration_fee: v.pool_migration_fee,
+            creator_fee_basis_points: v.creator_fee_basis_points,
+            fee_recipients: v.fee_recipients.iter().map(|x| x.to_bytes().to_vec()).collect(),
+            set_creator_authority: v.set_creator_authority.to_bytes().to_vec(),
+            admin_set_creator_authority: v.admin_set_creator_authority.to_bytes().to_vec(),
+        }
+    }
+}
+
+impl From<&pumpfun::PumpFunGlobalAccountEvent> for pb::PumpFunGlobalAccountEvent {
+    fn from(v: &pumpfun::PumpFunGlobalAccountEvent) -> Self {
+        Self {
+            metadata: Some((&v.metadata).into()),
+            pubkey: v.pubkey.to_bytes().to_vec(),
+            executable: v.executable,
+            lamports: v.lamports,
+            owner: v.owner.to_bytes().to_vec(),
+            rent_epoch: v.rent_epoch,
+            global: Some((&v.global).into()),
+        }
+    }
+}
+
+impl From<&pumpswap::PumpSwapBuyEvent> for pb::PumpSwapBuyEvent {
+    fn from(v: &pumpswap::PumpSwapBuyEvent) -> Self {
+        Self {
+            metadata: Some((&v.metadata).into()),
+            timestamp: v.timestamp,
+            base_amount_out: v.base_amount_out,
+            max_quote_amount_in: v.max_quote_amount_in,
+            user_base_token_reserves: v.user_base_token_reserves,
+            user_quote_token_reserves: v.user_quote_token_reserves,
+            pool_base_token_reserves: v.pool_base_token_reserves,
+            pool_quote_token_reserves: v.pool_quote_token_reserves,
+            quote_amount_in: v.quote_amount_in,
+            lp_fee_basis_points: v.lp_fee_basis_points,
+            lp_fee: v.lp_fee,
+            protocol_fee_basis_points: v.protocol_fee_basis_points,
+            protocol_fee: v.protocol_fee,
+            quote_amount_in_with_lp_fee: v.quote_amount_in_with_lp_fee,
+            user_quote_amount_in: v.user_quote_amount_in,
+            pool: v.pool.to_bytes().to_vec(),
+            user: v.user.to_bytes().to_vec(),
+            user_base_token_account: v.user_base_token_account.to_bytes().to_vec(),
+            user_quote_token_account: v.user_quote_token_account.to_bytes().to_vec(),
+            protocol_fee_recipient: v.protocol_fee_recipient.to_bytes().to_vec(),
+            protocol_fee_recipient_token_account: v
+                .protocol_fee_recipient_token_account
+                .to_bytes()
+                .to_vec(),
+            coin_creator: v.coin_creator.to_bytes().to_vec(),
+            coin_creator_fee_basis_points: v.coin_creator_fee_basis_points,
+            coin_creator_fee: v.coin_creator_fee,
+            track_volume: v.track_volume,
+            total_unclaimed_tokens: v.total_unclaimed_tokens,
+            total_claimed_tokens: v.total_claimed_tokens,
+            current_sol_volume: v.current_sol_volume,
+            last_update_timestamp: v.last_update_timestamp,
+            base_mint: v.base_mint.to_bytes().to_vec(),
+            quote_mint: v.quote_mint.to_bytes().to_vec(),
+            pool_base_token_account: v.pool_base_token_account.to_bytes().to_vec(),
+            pool_quote_token_account: v.pool_quote_token_account.to_bytes().to_vec(),
+            coin_creator_vault_ata: v.coin_creator_vault_ata.to_bytes().to_vec(),
+            coin_creator_vault_authority: v.coin_creator_vault_authority.to_bytes().to_vec(),
+            base_token_program: v.base_token_program.to_bytes().to_vec(),
+            quote_token_program: v.quote_token_program.to_bytes().to_vec(),
+            global_volume_accumulator: v.global_volume_accumulator.to_bytes().to_vec(),
+            user_volume_accumulator: v.user_volume_accumulator.to_bytes().to_vec(),
+        }
+    }
+}
+
+impl From<&pumpswap::PumpSwapSellEvent> for pb::PumpSwapSellEvent {
+    fn from(v: &pumpswap::PumpSwapSellEvent) -> Self {
+        Self {
+            metadata: Some((&v.metadata).into()),
+            timestamp: v.timestamp,
+            base_amount_in: v.base_amount_in,
+            min_quote_amount_out: v.min_quote_amount_out,
+            user_base_token_reserves: v.user_base_token_reserves,
+            user_quote_token_reserves: v.user_quote_token_reserves,
+            pool_base_token_reserves: v.pool_base_token_reserves,
+            pool_quote_token_reserves: v.pool_quote_token_reserves,
+            quote_amount_out: v.quote_amount_out,
+            lp_fee_basis_points: v.lp_fee_basis_points,
+            lp_fee: v.lp_fee,
+            protocol_fee_basis_points: v.protocol_fee_basis_points,
+            protocol_fee: v.protocol_fee,
+            quote_amount_out_without_lp_fee: v.quote_amount_out_without_lp_fee,
+            user_quote_amount_out: v.user_quote_amount_out,
+            pool: v.pool.to_bytes().to_vec(),
+            user: v.user.to_bytes().to_vec(),
+            user_base_token_account: v.user_base_token_account.to_bytes().to_vec(),
+            user_quote_token_account: v.user_quote_token_account.to_bytes().to_vec(),
+            protocol_fee_recipient: v.protocol_fee_recipient.to_bytes().to_vec(),
+            protocol_fee_recipient_token_account: v
+                .protocol_fee_recipient_token_account
+                .to_bytes()
+                .to_vec(),
+            coin_creator: v.coin_creator.to_bytes().to_vec(),
+            coin_creator_fee_basis_points: v.coin_creator_fee_basis_points,
+            coin_creator_fee: v.coin_creator_fee,
+            base_mint: v.base_mint.to_bytes().to_vec(),
+            quote_mint: v.quote_mint.to_bytes().to_vec(),
+            pool_base_token_account: v.pool_base_token_account.to_bytes().to_vec(),
+            pool_quote_token_account: v.pool_quote_token_account.to_bytes().to_vec(),
+            coin_creator_vault_ata: v.coin_creator_vault_ata.to_bytes().to_vec(),
+            coin_creator_vault_authority: v.coin_creator_vault_authority.to_bytes().to_vec(),
+            base_token_program: v.base_token_program.to_bytes().to_vec(),
+            quote_token_program: v.quote_token_program.to_bytes().to_vec(),
+            global_volume_accumulator: v.global_volume_accumulator.to_bytes().to_vec(),
+            user_volume_accumulator: v.user_volume_accumulator.to_bytes().to_vec(),
+        }
+    }
+}
+
+impl From<&pumpswap::PumpSwapCreatePoolEvent> for pb::PumpSwapCreatePoolEvent {
+    fn from(v: &pumpswap::PumpSwapCreatePoolEvent) -> Self {
+        Self {
+            metadata: Some((&v.metadata).into()),
+            timestamp: v.timestamp,
+            index: u32::from(v.index),
+            creator: v.creator.to_bytes().to_vec(),
+            base_mint: v.base_mint.to_bytes().to_vec(),
+            quote_mint: v.quote_mint.to_bytes().to_vec(),
+            base_mint_decimals: u32::from(v.base_mint_decimals),
+            quote_mint_decimals: u32::from(v.quote_mint_decimals),
+            base_amount_in: v.base_amount_in,
+            quote_amount_in: v.quote_amount_in,
+            pool_base_amount: v.pool_base_amount,
+            pool_quote_amount: v.pool_quote_amount,
+            minimum_liquidity: v.minimum_liquidity,
+            initial_liquidity: v.initial_liquidity,
+            lp_token_amount_out: v.lp_token_amount_out,
+            pool_bump: u32::from(v.pool_bump),
+            pool: v.pool.to_bytes().to_vec(),
+            lp_mint: v.lp_mint.to_bytes().to_vec(),
+            user_base_token_account: v.user_base_token_account.to_bytes().to_vec(),
+            user_quote_token_account: v.user_quote_token_account.to_bytes().to_vec(),
+            coin_creator: v.coin_creator.to_bytes().to_vec(),
+            user_pool_token_account: v.user_pool_token_account.to_bytes().to_vec(),
+            pool_base_token_account: v.pool_base_token_account.to_bytes().to_vec(),
+            pool_quote_token_account: v.pool_quote_token_account.to_bytes().to_vec(),
+        }
+    }
+}
+
+impl From<&pumpswap::PumpSwapDepositEvent> for pb::PumpSwapDepositEvent {
+    fn from(v: &pumpswap::PumpSwapDepositEvent) -> Self {
+        Self {
+            metadata: Some((&v.metadata).into()),
+            timestamp: v.timestamp,
+            lp_token_amount_out: v.lp_token_amount_out,
+            max_base_amount_in: v.max_base_amount_in,
+            max_quote_amount_in: v.max_quote_amount_in,
+            user_base_token_reserves: v.user_base_token_reserves,
+            user_quote_token_reserves: v.user_quote_token_reserves,
+            pool_base_token_reserves: v.pool_base_token_reserves,
+            pool_quote_token_reserves: v.pool_quote_token_reserves,
+            base_amount_in: v.base_amount_in,
+            quote_amount_in: v.quote_amount_in,
+            lp_mint_supply: v.lp_mint_supply,
+            pool: v.pool.to_bytes().to_vec(),
+            user: v.user.to_bytes().to_vec(),
+            user_base_token_account: v.user_base_token_account.to_bytes().to_vec(),
+            user_quote_token_account: v.user_quote_token_account.to_bytes().to_vec(),
+            user_pool_token_account: v.user_pool_token_account.to_bytes().to_vec(),
+            base_mint: v.base_mint.to_bytes().to_vec(),
+            quote_mint: v.quote_mint.to_bytes().to_vec(),
+            pool_base_token_account: v.pool_base_token_account.to_bytes().to_vec(),
+            pool_quote_token_account: v.pool_quote_token_account.to_bytes().to_vec(),
+        }
+    }
+}
+
+impl From<&pumpswap::PumpSwapWithdrawEvent> for pb::PumpSwapWithdrawEvent {
+    fn from(v: &pumpswap::PumpSwapWithdrawEvent) -> Self {
+        Self {
+            metadata: Some((&v.metadata).into()),
+            timestamp: v.timestamp,
+            lp_token_amount_in: v.lp_token_amount_in,
+            min_base_amount_out: v.min_base_amount_out,
+            min_quote_amount_out: v.min_quote_amount_out,
+            user_base_token_reserves: v.user_base_token_reserves,
+            user_quote_token_reserves: v.user_quote_token_reserves,
+            pool_base_token_reserves: v.pool_base_token_reserves,
+            pool_quote_token_reserves: v.pool_quote_token_reserves,
+            base_amount_out: v.base_amount_out,
+            quote_amount_out: v.quote_amount_out,
+            lp_mint_supply: v.lp_mint_supply,
+            pool: v.pool.to_bytes().to_vec(),
+            user: v.user.to_bytes().to_vec(),
+            user_base_token_account: v.user_base_token_account.to_bytes().to_vec(),
+            user_quote_token_account: v.user_quote_token_account.to_bytes().to_vec(),
+            user_pool_token_account: v.user_pool_token_account.to_bytes().to_vec(),
+            base_mint: v.base_mint.to_bytes().to_vec(),
+            quote_mint: v.quote_mint.to_bytes().to_vec(),
+            pool_base_token_account: v.pool_base_token_account.to_bytes().to_vec(),
+            pool_quote_token_account: v.pool_quote_token_account.to_bytes().to_vec(),
+        }
+    }
+}
+
+impl From<&pumpswap::types::GlobalConfig> for pb::PumpSwapGlobalConfig {
+    fn from(v: &pumpswap::types::GlobalConfig) -> Self {
+        Self {
+            admin: v.admin.to_bytes().to_vec(),
+            lp_fee_basis_points: v.lp_fee_basis_points,
+            protocol_fee_basis_points: v.protocol_fee_basis_points,
+            disable_flags: u32::from(v.disable_flags),
+            protocol_fee_recipients: v
+                .protocol_fee_recipients
+                .iter()
+                .map(|x| x.to_bytes().to_vec())
+                .collect(),
+            coin_creator_fee_basis_points: v.coin_creator_fee_basis_points,
+            admin_set_coin_creator_authority: v
+                .admin_set_coin_creator_authority
+                .to_bytes()
+                .to_vec(),
+        }
+    }
+}
+
+impl From<&pumpswap::PumpSwapGlobalConfigAccountEvent> for pb::PumpSwapGlobalConfigAccountEvent {
+    fn from(v: &pumpswap::PumpSwapGlobalConfigAccountEvent) -> Self {
+        Self {
+            metadata: Some((&v.metadata).into()),
+            pubkey: v.pubkey.to_bytes().to_vec(),
+            executable: v.executable,
+            lamports: v.lamports,
+            owner: v.owner.to_bytes().to_vec(),
+            rent_epoch: v.rent_epoch,
+            global_config: Some((&v.global_config).into()),
+        }
+    }
+}
+
+impl From<&pumpswap::types::Pool> for pb::PumpSwapPool {
+    fn from(v: &pumpswap::types::Pool) -> Self {
+        Self {
+            pool_bump: u32::from(v.pool_bump),
+            index: u32::from(v.index),
+            creator: v.creator.to_bytes().to_vec(),
+            base_mint: v.base_mint.to_bytes().to_vec(),
+            quote_mint: v.quote_mint.to_bytes().to_vec(),
+            lp_mint: v.lp_mint.to_bytes().to_vec(),
+            pool_base_token_account: v.pool_base_token_account.to_bytes().to_vec(),
+            pool_quote_token_account: v.pool_quote_token_account.to_bytes().to_vec(),
+            lp_supply: v.lp_supply,
+            coin_creator: v.coin_creator.to_bytes().to_vec(),
+        }
+    }
+}
+
+impl From<&pumpswap::PumpSwapPoolAccountEvent> for pb::PumpSwapPoolAccountEvent {
+    fn from(v: &pumpswap::PumpSwapPoolAccountEvent) -> Self {
+        Self {
+            metadata: Some((&v.metadata).into()),
+            pubkey: v.pubkey.to_bytes().to_vec(),
+            executable: v.executable,
+            lamports: v.lamports,
+            owner: v.owner.to_bytes().to_vec(),
+            rent_epoch: v.rent_epoch,
+            pool: Some((&v.pool).into()),
+        }
+    }
+}
+
+impl From<&bonk::types::TradeDirection> for pb::BonkTradeDirection {
+    fn from(v: &bonk::types::TradeDirection) -> Self {
+        match v {
+            bonk::types::TradeDirection::Buy => pb::BonkTradeDirection::Buy,
+            bonk::types::TradeDirection::Sell => pb::BonkTradeDirection::Sell,
+        }
+    }
+}
+
+impl From<&bonk::types::PoolStatus> for pb::BonkPoolStatus {
+    fn from(v: &bonk::types::PoolStatus) -> Self {
+        match v {
+            bonk::types::PoolStatus::Fund => pb::BonkPoolStatus::Fund,
+            bonk::types::PoolStatus::Migrate => pb::BonkPoolStatus::Migrate,
+            bonk::types::PoolStatus::Trade => pb::BonkPoolStatus::Trade,
+        }
+    }
+}
+
+impl From<&bonk::BonkTradeEvent> for pb::BonkTradeEvent {
+    fn from(v: &bonk::BonkTradeEvent) -> Self {
+        Self {
+            metadata: Some((&v.metadata).into()),
+            pool_state: v.pool_state.to_bytes().to_vec(),
+            total_base_sell: v.total_base_sell,
+            virtual_base: v.virtual_base,
+            virtual_quote: v.virtual_quote,
+            real_base_before: v.real_base_before,
+            real_quote_before: v.real_quote_before,
+            real_base_after: v.real_base_after,
+            real_quote_after: v.real_quote_after,
+            amount_in: v.amount_in,
+            amount_out: v.amount_out,
+            protocol_fee: v.protocol_fee,
+            platform_fee: v.platform_fee,
+            creator_fee: v.creator_fee,
+            share_fee: v.share_fee,
+            trade_direction: pb::BonkTradeDirection::from(&v.trade_direction) as i32,
+            pool_status: pb::BonkPoolStatus::from(&v.pool_status) as i32,
+            exact_in: v.exact_in,
+            minimum_amount_out: v.minimum_amount_out,
+            maximum_amount_in: v.maximum_amount_in,
+            share_fee_rate: v.share_fee_rate,
+            payer: v.payer.to_bytes().to_vec(),
+            global_config: v.global_config.to_bytes().to_vec(),
+            platform_config: v.platform_config.to_bytes().to_vec(),
+            user_base_token: v.user_base_token.to_bytes().to_vec(),
+            user_quote_token: v.user_quote_token.to_bytes().to_vec(),
+            base_vault: v.base_vault.to_bytes().to_vec(),
+            quote_vault: v.quote_vault.to_bytes().to_vec(),
+            base_token_mint: v.base_token_mint.to_bytes().to_vec(),
+            quote_token_mint: v.quote_token_mint.to_bytes().to_vec(),
+            base_token_program: v.base_token_program.to_bytes().to_vec(),
+            quote_token_program: v.quote_token_program.to_bytes().to_vec(),
+            is_dev_create_token_trade: v.is_dev_create_token_trade,
+            is_bot: v.is_bot,
+            system_program: v.system_program.to_bytes().to_vec(),
+            platform_associated_account: v.platform_associated_account.to_bytes().to_vec(),
+            creator_associated_account: v.creator_associated_account.to_bytes().to_vec(),
+        }
+    }
+}
+
+impl From<&bonk::types::MintParams> for pb::BonkMintParams {
+    fn from(v: &bonk::types::MintParams) -> Self {
+        Self {
+            decimals: u32::from(v.decimals),
+            name: v.name.clone(),
+            symbol: v.symbol.clone(),
+            uri: v.uri.clone(),
+        }
+    }
+}
+
+impl From<&bonk::types::ConstantCurve> for pb::BonkConstantCurve {
+    fn from(v: &bonk::types::ConstantCurve) -> Self {
+        Self {
+            supply: v.supply,
+            total_base_sell: v.total_base_sell,
+            total_quote_fund_raising: v.total_quote_fund_raising,
+            migrate_type: u32::from(v.migrate_type),
+        }
+    }
+}
+
+impl From<&bonk::types::FixedCurve> for pb::BonkFixedCurve {
+    fn from(v: &bonk::types::FixedCurve) -> Self {
+        Self {
+            supply: v.supply,
+            total_quote_fund_raising: v.total_quote_fund_raising,
+            migrate_type: u32::from(v.migrate_type),
+        }
+    }
+}
+
+impl From<&bonk::types::LinearCurve> for pb::BonkLinearCurve {
+    fn from(v: &bonk::types::LinearCurve) -> Self {
+        Self {
+            supply: v.supply,
+            total_quote_fund_raising: v.total_quote_fund_raising,
+            migrate_type: u32::from(v.migrate_type),
+        }
+    }
+}
+
+impl From<&bonk::types::CurveParams> for pb::BonkCurveParams {
+    fn from(v: &bonk::types::CurveParams) -> Self {
+        let value = match v {
+            bonk::types::CurveParams::Constant { data } => {
+                pb::bonk_curve_params::Value::Constant(data.into())
+            }
+            bonk::types::CurveParams::Fixed { data } => {
+                pb::bonk_curve_params::Value::Fixed(data.into())
+            }
+            bonk::types::CurveParams::Linear { data } => {
+                pb::bonk_curve_params::Value::Linear(data.into())
+            }
+        };
+        Self { value: Some(value) }
+    }
+}
+
+impl From<&bonk::types::VestingParams> for pb::BonkVestingParams {
+    fn from(v: &bonk::types::VestingParams) -> Self {
+        Self {
+            total_locked_amount: v.total_locked_amount,
+            cliff_period: v.cliff_period,
+            unlock_period: v.unlock_period,
+        }
+    }
+}
+
+impl From<&bonk::types::AmmFeeOn> for pb::BonkAmmFeeOn {
+    fn from(v: &bonk::types::AmmFeeOn) -> Self {
+        match v {
+            bonk::types::AmmFeeOn::QuoteToken => pb::BonkAmmFeeOn::QuoteToken,
+            bonk::types::AmmFeeOn::BothToken => pb::BonkAmmFeeOn::BothToken,
+        }
+    }
+}
+
+impl From<&bonk::BonkPoolCreateEvent> for pb::BonkPoolCreateEvent {
+    fn from(v: &bonk::BonkPoolCreateEvent) -> Self {
+        Self {
+            metadata: Some((&v.metadata).into()),
+            pool_state: v.pool_state.to_bytes().to_vec(),
+            creator: v.creator.to_bytes().to_vec(),
+            config: v.config.to_bytes().to_vec(),
+            base_mint_param: Some((&v.base_mint_param).into()),
+            curve_param: Some((&v.curve_param).into()),
+            vesting_param: Some((&v.vesting_param).into()),
+            amm_fee_on: v.amm_fee_on.as_ref().map(|x| pb::BonkAmmFeeOn::from(x) as i32),
+            payer: v.payer.to_bytes().to_vec(),
+            base_mint: v.base_mint.to_bytes().to_vec(),
+            quote_mint: v.quote_mint.to_bytes().to_vec(),
+            base_vault: v.base_vault.to_bytes().to_vec(),
+            quote_vault: v.quote_vault.to_bytes().to_vec(),
+            global_config: v.global_config.to_bytes().to_vec(),
+            platform_config: v.platform_config.to_bytes().to_vec(),
+        }
+    }
+}
+
+impl From<&bonk::BonkMigrateToAmmEvent> for pb::BonkMigrateToAmmEvent {
+    fn from(v: &bonk::BonkMigrateToAmmEvent) -> Self {
+        Self {
+            metadata: Some((&v.metadata).into()),
+            base_lot_size: v.base_lot_size,
+            quote_lot_size: v.quote_lot_size,
+            market_vault_signer_nonce: u32::from(v.market_vault_signer_nonce),
+            payer: v.payer.to_bytes().to_vec(),
+            base_mint: v.base_mint.to_bytes().to_vec(),
+            quote_mint: v.quote_mint.to_bytes().to_vec(),
+            openbook_program: v.openbook_program.to_bytes().to_vec(),
+            market: v.market.to_bytes().to_vec(),
+            request_queue: v.request_queue.to_bytes().to_vec(),
+            event_queue: v.event_queue.to_bytes().to_vec(),
+            bids: v.bids.to_bytes().to_vec(),
+            asks: v.asks.to_bytes().to_vec(),
+            market_vault_signer: v.market_vault_signer.to_bytes().to_vec(),
+            market_base_vault: v.market_base_vault.to_bytes().to_vec(),
+            market_quote_vault: v.market_quote_vault.to_bytes().to_vec(),
+            amm_program: v.amm_program.to_bytes().to_vec(),
+            amm_pool: v.amm_pool.to_bytes().to_vec(),
+            amm_authority: v.amm_authority.to_bytes().to_vec(),
+            amm_open_orders: v.amm_open_orders.to_bytes().to_vec(),
+            amm_lp_mint: v.amm_lp_mint.to_bytes().to_vec(),
+            amm_base_vault: v.amm_base_vault.to_bytes().to_vec(),
+            amm_quote_vault: v.amm_quote_vault.to_bytes().to_vec(),
+            amm_target_orders: v.amm_target_orders.to_bytes().to_vec(),
+            amm_config: v.amm_config.to_bytes().to_vec(),
+            amm_create_fee_destination: v.amm_create_fee_destination.to_bytes().to_vec(),
+            authority: v.authority.to_bytes().to_vec(),
+            pool_state: v.pool_state.to_bytes().to_vec(),
+            global_config: v.global_config.to_bytes().to_vec(),
+            base_vault: v.base_vault.to_bytes().to_vec(),
+            quote_vault: v.quote_vault.to_bytes().to_vec(),
+            pool_lp_token: v.pool_lp_token.to_bytes().to_vec(),
+            spl_token_program: v.spl_token_program.to_bytes().to_vec(),
+            associated_token_program: v.associated_token_program.to_bytes().to_vec(),
+            system_program: v.system_program.to_bytes().to_vec(),
+            rent_program: v.rent_program.to_bytes().to_vec(),
+        }
+    }
+}
+
+impl From<&bonk::BonkMigrateToCpswapEvent> for pb::BonkMigrateToCpswapEvent {
+    fn from(v: &bonk::BonkMigrateToCpswapEvent) -> Self {
+        Self {
+            metadata: Some((&v.metadata).into()),
+            payer: v.payer.to_bytes().to_vec(),
+            base_mint: v.base_mint.to_bytes().to_vec(),
+            quote_mint: v.quote_mint.to_bytes().to_vec(),
+            platform_config: v.platform_config.to_bytes().to_vec(),
+            cpswap_program: v.cpswap_program.to_bytes().to_vec(),
+            cpswap_pool: v.cpswap_pool.to_bytes().to_vec(),
+            cpswap_authority: v.cpswap_authority.to_bytes().to_vec(),
+            cpswap_lp_mint: v.cpswap_lp_mint.to_bytes().to_vec(),
+            cpswap_base_vault: v.cpswap_base_vault.to_bytes().to_vec(),
+            cpswap_quote_vault: v.cpswap_quote_vault.to_bytes().to_vec(),
+            cpswap_config: v.cpswap_config.to_bytes().to_vec(),
+            cpswap_create_pool_fee: v.cpswap_create_pool_fee.to_bytes().to_vec(),
+            cpswap_observation: v.cpswap_observation.to_bytes().to_vec(),
+            lock_program: v.lock_program.to_bytes().to_vec(),
+            lock_authority: v.lock_authority.to_bytes().to_vec(),
+            lock_lp_vault: v.lock_lp_vault.to_bytes().to_vec(),
+            authority: v.authority.to_bytes().to_vec(),
+            pool_state: v.pool_state.to_bytes().to_vec(),
+            global_config: v.global_config.to_bytes().to_vec(),
+            base_vault: v.base_vault.to_bytes().to_vec(),
+            quote_vault: v.quote_vault.to_bytes().to_vec(),
+            pool_lp_token: v.pool_lp_token.to_bytes().to_vec(),
+            base_token_program: v.base_token_program.to_bytes().to_vec(),
+            quote_token_program: v.quote_token_program.to_bytes().to_vec(),
+            associated_token_program: v.associated_token_program.to_bytes().to_vec(),
+            system_program: v.system_program.to_bytes().to_vec(),
+            rent_program: v.rent_program.to_bytes().to_vec(),
+            metadata_program: v.metadata_program.to_bytes().to_vec(),
+            remaining_accounts: v
+                .remaining_accounts
+                .iter()
+                .map(|x| x.to_bytes().to_vec())
+                .collect(),
+        }
+    }
+}
+
+impl From<&bonk::types::VestingSchedule> for pb::BonkVestingSchedule {
+    fn from(v: &bonk::types::VestingSchedule) -> Self {
+        Self {
+            total_locked_amount: v.total_locked_amount,
+            cliff_period: v.cliff_period,
+            unlock_period: v.unlock_period,
+            start_time: v.start_time,
+            allocated_share_amount: v.allocated_share_amount,
+        }
+    }
+}
+
+impl From<&bonk::types::PoolState> for pb::BonkPoolState {
+    fn from(v: &bonk::types::PoolState) -> Self {
+        Self {
+            epoch: v.epoch,
+            auth_bump: u32::from(v.auth_bump),
+            status: u32::from(v.status),
+            base_decimals: u32::from(v.base_decimals),
+            quote_decimals: u32::from(v.quote_decimals),
+            migrate_type: u32::from(v.migrate_type),
+            supply: v.supply,
+            total_base_sell: v.total_base_sell,
+            virtual_base: v.virtual_base,
+            virtual_quote: v.virtual_quote,
+            real_base: v.real_base,
+            real_quote: v.real_quote,
+            total_quote_fund_raising: v.total_quote_fund_raising,
+            quote_protocol_fee: v.quote_protocol_fee,
+            platform_fee: v.platform_fee,
+            migrate_fee: v.migrate_fee,
+            vesting_schedule: Some((&v.vesting_schedule).into()),
+            global_config: v.global_config.to_bytes().to_vec(),
+            platform_config: v.platform_config.to_bytes().to_vec(),
+            base_mint: v.base_mint.to_bytes().to_vec(),
+            quote_mint: v.quote_mint.to_bytes().to_vec(),
+            base_vault: v.base_vault.to_bytes().to_vec(),
+            quote_vault: v.quote_vault.to_bytes().to_vec(),
+            creator: v.creator.to_bytes().to_vec(),
+            padding: v.padding.to_vec(),
+        }
+    }
+}
+
+impl From<&bonk::BonkPoolStateAccountEvent> for pb::BonkPoolStateAccountEvent {
+    fn from(v: &bonk::BonkPoolStateAccountEvent) -> Self {
+        Self {
+            metadata: Some((&v.metadata).into()),
+            pubkey: v.pubkey.to_bytes().to_vec(),
+            executable: v.executable,
+            lamports: v.lamports,
+            owner: v.owner.to_bytes().to_vec(),
+            rent_epoch: v.rent_epoch,
+            pool_state: Some((&v.pool_state).into()),
+        }
+    }
+}
+
+impl From<&bonk::types::GlobalConfig> for pb::BonkGlobalConfig {
+    fn from(v: &bonk::types::GlobalConfig) -> Self {
+        Self {
+            epoch: v.epoch,
+            curve_type: u32::from(v.curve_type),
+            index: u32::from(v.index),
+            migrate_fee: v.migrate_fee,
+            trade_fee_rate: v.trade_fee_rate,
+            max_share_fee_rate: v.max_share_fee_rate,
+            min_base_supply: v.min_base_supply,
+            max_lock_rate: v.max_lock_rate,
+            min_base_sell_rate: v.min_base_sell_rate,
+            min_base_migrate_rate: v.min_base_migrate_rate,
+            min_quote_fund_raising: v.min_quote_fund_raising,
+            quote_mint: v.quote_mint.to_bytes().to_vec(),
+            protocol_fee_owner: v.protocol_fee_owner.to_bytes().to_vec(),
+            migrate_fee_owner: v.migrate_fee_owner.to_bytes().to_vec(),
+            migrate_to_amm_wallet: v.migrate_to_amm_wallet.to_bytes().to_vec(),
+            migrate_to_cpswap_wallet: v.migrate_to_cpswap_wallet.to_bytes().to_vec(),
+            padding: v.padding.to_vec(),
+        }
+    }
+}
+
+impl From<&bonk::BonkGlobalConfigAccountEvent> for pb::BonkGlobalConfigAccountEvent {
+    fn from(v: &bonk::BonkGlobalConfigAccountEvent) -> Self {
+        Self {
+            metadata: Some((&v.metadata).into()),
+            pubkey: v.pubkey.to_bytes().to_vec(),
+            executable: v.executable,
+            lamports: v.lamports,
+            owner: v.owner.to_bytes().to_vec(),
+            rent_epoch: v.rent_epoch,
+            global_config: Some((&v.global_config).into()),
+        }
+    }
+}
+
+impl From<&bonk::types::PlatformConfig> for pb::BonkPlatformConfig {
+    fn from(v: &bonk::types::PlatformConfig) -> Self {
+        Self {
+            epoch: v.epoch,
+            platform_fee_wallet: v.platform_fee_wallet.to_bytes().to_vec(),
+            platform_nft_wallet: v.platform_nft_wallet.to_bytes().to_vec(),
+            platform_scale: v.platform_scale,
+            creator_scale: v.creator_scale,
+            burn_scale: v.burn_scale,
+            fee_rate: v.fee_rate,
+            name: v.name.clone(),
+            web: v.web.clone(),
+            img: v.img.clone(),
+            padding: v.padding.clone(),
+        }
+    }
+}
+
+impl From<&bonk::BonkPlatformConfigAccountEvent> for pb::BonkPlatformConfigAccountEvent {
+    fn from(v: &bonk::BonkPlatformConfigAccountEvent) -> Self {
+        Self {
+            metadata: Some((&v.metadata).into()),
+            pubkey: v.pubkey.to_bytes().to_vec(),
+            executable: v.executable,
+            lamports: v.lamports,
+            owner: v.owner.to_bytes().to_vec(),
+            rent_epoch: v.rent_epoch,
+            platform_config: Some((&v.platform_config).into()),
+        }
+    }
+}
+
+impl From<&raydium_cpmm::RaydiumCpmmSwapEvent> for pb::RaydiumCpmmSwapEvent {
+    fn from(v: &raydium_cpmm::RaydiumCpmmSwapEvent) -> Self {
+        Self {
+            metadata: Some((&v.metadata).into()),
+            amount_in: v.amount_in,
+            minimum_amount_out: v.minimum_amount_out,
+            max_amount_in: v.max_amount_in,
+            amount_out: v.amount_out,
+            payer: v.payer.to_bytes().to_vec(),
+            authority: v.authority.to_bytes().to_vec(),
+            amm_config: v.amm_config.to_bytes().to_vec(),
+            pool_state: v.pool_state.to_bytes().to_vec(),
+            input_token_account: v.input_token_account.to_bytes().to_vec(),
+            output_token_account: v.output_token_account.to_bytes().to_vec(),
+            input_vault: v.input_vault.to_bytes().to_vec(),
+            output_vault: v.output_vault.to_bytes().to_vec(),
+            input_token_program: v.input_token_program.to_bytes().to_vec(),
+            output_token_program: v.output_token_program.to_bytes().to_vec(),
+            input_token_mint: v.input_token_mint.to_bytes().to_vec(),
+            output_token_mint: v.output_token_mint.to_bytes().to_vec(),
+            observation_state: v.observation_state.to_bytes().to_vec(),
+        }
+    }
+}
+
+impl From<&raydium_cpmm::RaydiumCpmmDepositEvent> for pb::RaydiumCpmmDepositEvent {
+    fn from(v: &raydium_cpmm::RaydiumCpmmDepositEvent) -> Self {
+        Self {
+            metadata: Some((&v.metadata).into()),
+            lp_token_amount: v.lp_token_amount,
+            maximum_token0_amount: v.maximum_token0_amount,
+            maximum_token1_amount: v.maximum_token1_amount,
+            owner: v.owner.to_bytes().to_vec(),
+            authority: v.authority.to_bytes().to_vec(),
+            pool_state: v.pool_state.to_bytes().to_vec(),
+            owner_lp_token: v.owner_lp_token.to_bytes().to_vec(),
+            token0_account: v.token0_account.to_bytes().to_vec(),
+            token1_account: v.token1_account.to_bytes().to_vec(),
+            token0_vault: v.token0_vault.to_bytes().to_vec(),
+            token1_vault: v.token1_vault.to_bytes().to_vec(),
+            token_program: v.token_program.to_bytes().to_vec(),
+            token_program2022: v.token_program2022.to_bytes().to_vec(),
+            vault0_mint: v.vault0_mint.to_bytes().to_vec(),
+            vault1_mint: v.vault1_mint.to_bytes().to_vec(),
+            lp_mint: v.lp_mint.to_bytes().to_vec(),
+        }
+    }
+}
+
+impl From<&raydium_cpmm::RaydiumCpmmInitializeEvent> for pb::RaydiumCpmmInitializeEvent {
+    fn from(v: &raydium_cpmm::RaydiumCpmmInitializeEvent) -> Self {
+        Self {
+            metadata: Some((&v.metadata).into()),
+            init_amount0: v.init_amount0,
+            init_amount1: v.init_amount1,
+            open_time: v.open_time,
+            creator: v.creator.to_bytes().to_vec(),
+            amm_config: v.amm_config.to_bytes().to_vec(),
+            authority: v.authority.to_bytes().to_vec(),
+            pool_state: v.pool_state.to_bytes().to_vec(),
+            token0_mint: v.token0_mint.to_bytes().to_vec(),
+            token1_mint: v.token1_mint.to_bytes().to_vec(),
+            lp_mint: v.lp_mint.to_bytes().to_vec(),
+            creator_token0: v.creator_token0.to_bytes().to_vec(),
+            creator_token1: v.creator_token1.to_bytes().to_vec(),
+            creator_lp_token: v.creator_lp_token.to_bytes().to_vec(),
+            token0_vault: v.token0_vault.to_bytes().to_vec(),
+            token1_vault: v.token1_vault.to_bytes().to_vec(),
+            create_pool_fee: v.create_pool_fee.to_bytes().to_vec(),
+            observation_state: v.observation_state.to_bytes().to_vec(),
+            token_program: v.token_program.to_bytes().to_vec(),
+            token0_program: v.token0_program.to_bytes().to_vec(),
+            token1_program: v.token1_program.to_bytes().to_vec(),
+            associated_token_program: v.associated_token_program.to_bytes().to_vec(),
+            system_program: v.system_program.to_bytes().to_vec(),
+            rent: v.rent.to_bytes().to_vec(),
+        }
+    }
+}
+
+impl From<&raydium_cpmm::RaydiumCpmmWithdrawEvent> for pb::RaydiumCpmmWithdrawEvent {
+    fn from(v: &raydium_cpmm::RaydiumCpmmWithdrawEvent) -> Self {
+        Self {
+            metadata: Some((&v.metadata).into()),
+            lp_token_amount: v.lp_token_amount,
+            minimum_token0_amount: v.minimum_token0_amount,
+            minimum_token1_amount: v.minimum_token1_amount,
+            owner: v.owner.to_bytes().to_vec(),
+            authority: v.authority.to_bytes().to_vec(),
+            pool_state: v.pool_state.to_bytes().to_vec(),
+            owner_lp_token: v.owner_lp_token.to_bytes().to_vec(),
+            token0_account: v.token0_account.to_bytes().to_vec(),
+            token1_account: v.token1_account.to_bytes().to_vec(),
+            token0_vault: v.token0_vault.to_bytes().to_vec(),
+            token1_vault: v.token1_vault.to_bytes().to_vec(),
+            token_program: v.token_program.to_bytes().to_vec(),
+            token_program2022: v.token_program2022.to_bytes().to_vec(),
+            vault0_mint: v.vault0_mint.to_bytes().to_vec(),
+            vault1_mint: v.vault1_mint.to_bytes().to_vec(),
+            lp_mint: v.lp_mint.to_bytes().to_vec(),
+            memo_program: v.memo_program.to_bytes().to_vec(),
+        }
+    }
+}
+
+impl From<&raydium_cpmm::types::AmmConfig> for pb::RaydiumCpmmAmmConfig {
+    fn from(v: &raydium_cpmm::types::AmmConfig) -> Self {
+        Self {
+            bump: u32::from(v.bump),
+            disable_create_pool: v.disable_create_pool,
+            index: u32::from(v.index),
+            trade_fee_rate: v.trade_fee_rate,
+            protocol_fee_rate: v.protocol_fee_rate,
+            fund_fee_rate: v.fund_fee_rate,
+            create_pool_fee: v.create_pool_fee,
+            protocol_owner: v.protocol_owner.to_bytes().to_vec(),
+            fund_owner: v.fund_owner.to_bytes().to_vec(),
+            padding: v.padding.to_vec(),
+        }
+    }
+}
+
+impl From<&raydium_cpmm::RaydiumCpmmAmmConfigAccountEvent>
+    for pb::RaydiumCpmmAmmConfigAccountEvent
+{
+    fn from(v: &raydium_cpmm::RaydiumCpmmAmmConfigAccountEvent) -> Self {
+        Self {
+            metadata: Some((&v.metadata).into()),
+            pubkey: v.pubkey.to_bytes().to_vec(),
+            executable: v.executable,
+            lamports: v.lamports,
+            owner: v.owner.to_bytes().to_vec(),
+            rent_epoch: v.rent_epoch,
+            amm_config: Some((&v.amm_config).into()),
+        }
+    }
+}
+
+impl From<&raydium_cpmm::types::PoolState> for pb::RaydiumCpmmPoolState {
+    fn from(v: &raydium_cpmm::types::PoolState) -> Self {
+        Self {
+            amm_config: v.amm_config.to_bytes().to_vec(),
+            pool_creator: v.pool_creator.to_bytes().to_vec(),
+            token0_vault: v.token0_vault.to_bytes().to_vec(),
+            token1_vault: v.token1_vault.to_bytes().to_vec(),
+            lp_mint: v.lp_mint.to_bytes().to_vec(),
+            token0_mint: v.token0_mint.to_bytes().to_vec(),
+            token1_mint: v.token1_mint.to_bytes().to_vec(),
+            token0_program: v.token0_program.to_bytes().to_vec(),
+            token1_program: v.token1_program.to_bytes().to_vec(),
+            observation_key: v.observation_key.to_bytes().to_vec(),
+            auth_bump: u32::from(v.auth_bump),
+            status: u32::from(v.status),
+            lp_mint_decimals: u32::from(v.lp_mint_decimals),
+            mint0_decimals: u32::from(v.mint0_decimals),
+            mint1_decimals: u32::from(v.mint1_decimals),
+            lp_supply: v.lp_supply,
+            protocol_fees_token0: v.protocol_fees_token0,
+            protocol_fees_token1: v.protocol_fees_token1,
+            fund_fees_token0: v.fund_fees_token0,
+            fund_fees_token1: v.fund_fees_token1,
+            open_time: v.open_time,
+            recent_epoch: v.recent_epoch,
+            padding: v.padding.to_vec(),
+        }
+    }
+}
+
+impl From<&raydium_cpmm::RaydiumCpmmPoolStateAccountEvent>
+    for pb::RaydiumCpmmPoolStateAccountEvent
+{
+    fn from(v: &raydium_cpmm::RaydiumCpmmPoolStateAccountEvent) -> Self {
+        Self {
+            metadata: Some((&v.metadata).into()),
+            pubkey: v.pubkey.to_bytes().to_vec(),
+            executable: v.executable,
+            lamports: v.lamports,
+            owner: v.owner.to_bytes().to_vec(),
+            rent_epoch: v.rent_epoch,
+            pool_state: Some((&v.pool_state).into()),
+        }
+    }
+}
+
+impl From<&raydium_clmm::RaydiumClmmSwapEvent> for pb::RaydiumClmmSwapEvent {
+    fn from(v: &raydium_clmm::RaydiumClmmSwapEvent) -> Self {
+        Self {
+            metadata: Some((&v.metadata).into()),
+            amount: v.amount,
+            other_amount_threshold: v.other_amount_threshold,
+            sqrt_price_limit_x64: v.sqrt_price_limit_x64.to_string(),
+            is_base_input: v.is_base_input,
+            payer: v.payer.to_bytes().to_vec(),
+            amm_config: v.amm_config.to_bytes().to_vec(),
+            pool_state: v.pool_state.to_bytes().to_vec(),
+            input_token_account: v.input_token_account.to_bytes().to_vec(),
+            output_token_account: v.output_token_account.to_bytes().to_vec(),
+            input_vault: v.input_vault.to_bytes().to_vec(),
+            output_vault: v.output_vault.to_bytes().to_vec(),
+            observation_state: v.observation_state.to_bytes().to_vec(),
+            token_program: v.token_program.to_bytes().to_vec(),
+            tick_array: v.tick_array.to_bytes().to_vec(),
+            remaining_accounts: v
+                .remaining_accounts
+                .iter()
+                .map(|x| x.to_bytes().to_vec())
+                .collect(),
+        }
+    }
+}
+
+impl From<&raydium_clmm::RaydiumClmmSwapV2Event> for pb::RaydiumClmmSwapV2Event {
+    fn from(v: &raydium_clmm::RaydiumClmmSwapV2Event) -> Self {
+        Self {
+            metadata: Some((&v.metadata).into()),
+            amount: v.amount,
+            other_amount_threshold: v.other_amount_threshold,
+            sqrt_price_limit_x64: v.sqrt_price_limit_x64.to_string(),
+            is_base_input: v.is_base_input,
+            payer: v.payer.to_bytes().to_vec(),
+            amm_config: v.amm_config.to_bytes().to_vec(),
+            pool_state: v.pool_state.to_bytes().to_vec(),
+            input_token_account: v.input_token_account.to_bytes().to_vec(),
+            output_token_account: v.output_token_account.to_bytes().to_vec(),
+            input_vault: v.input_vault.to_bytes().to_vec(),
+            output_vault: v.output_vault.to_bytes().to_vec(),
+            observation_state: v.observation_state.to_bytes().to_vec(),
+            token_program: v.token_program.to_bytes().to_vec(),
+            token_program2022: v.token_program2022.to_bytes().to_vec(),
+            memo_program: v.memo_program.to_bytes().to_vec(),
+            input_vault_mint: v.input_vault_mint.to_bytes().to_vec(),
+            output_vault_mint: v.output_vault_mint.to_bytes().to_vec(),
+            remaining_accounts: v
+                .remaining_accounts
+                .iter()
+                .map(|x| x.to_bytes().to_vec())
+                .collect(),
+        }
+    }
+}
+
+impl From<&raydium_clmm::RaydiumClmmClosePositionEvent> for pb::RaydiumClmmClosePositionEvent {
+    fn from(v: &raydium_clmm::RaydiumClmmClosePositionEvent) -> Self {
+        Self {
+            metadata: Some((&v.metadata).into()),
+            nft_owner: v.nft_owner.to_bytes().to_vec(),
+            position_nft_mint: v.position_nft_mint.to_bytes().to_vec(),
+            position_nft_account: v.position_nft_account.to_bytes().to_vec(),
+            personal_position: v.personal_position.to_bytes().to_vec(),
+            system_program: v.system_program.to_bytes().to_vec(),
+            token_program: v.token_program.to_bytes().to_vec(),
+        }
+    }
+}
+
+impl From<&raydium_clmm::RaydiumClmmDecreaseLiquidityV2Event>
+    for pb::RaydiumClmmDecreaseLiquidityV2Event
+{
+    fn from(v: &raydium_clmm::RaydiumClmmDecreaseLiquidityV2Event) -> Self {
+        Self {
+            metadata: Some((&v.metadata).into()),
+            liquidity: v.liquidity.to_string(),
+            amount0_min: v.amount0_min,
+            amount1_min: v.amount1_min,
+            nft_owner: v.nft_owner.to_bytes().to_vec(),
+            nft_account: v.nft_account.to_bytes().to_vec(),
+            personal_position: v.personal_position.to_bytes().to_vec(),
+            pool_state: v.pool_state.to_bytes().to_vec(),
+            protocol_position: v.protocol_position.to_bytes().to_vec(),
+            token_vault0: v.token_vault0.to_bytes().to_vec(),
+            token_vault1: v.token_vault1.to_bytes().to_vec(),
+            tick_array_lower: v.tick_array_lower.to_bytes().to_vec(),
+            tick_array_upper: v.tick_array_upper.to_bytes().to_vec(),
+            recipient_token_account0: v.recipient_token_account0.to_bytes().to_vec(),
+            recipient_token_account1: v.recipient_token_account1.to_bytes().to_vec(),
+            token_program: v.token_program.to_bytes().to_vec(),
+            token_program2022: v.token_program2022.to_bytes().to_vec(),
+            memo_program: v.memo_program.to_bytes().to_vec(),
+            vault0_mint: v.vault0_mint.to_bytes().to_vec(),
+            vault1_mint: v.vault1_mint.to_bytes().to_vec(),
+            remaining_accounts: v
+                .remaining_accounts
+                .iter()
+                .map(|x| x.to_bytes().to_vec())
+                .collect(),
+            decrease_amount0: v.decrease_amount0,
+            decrease_amount1: v.decrease_amount1,
+            fee_amount0: v.fee_amount0,
+            fee_amount1: v.fee_amount1,
+            transfer_fee0: v.transfer_fee0,
+            transfer_fee1: v.transfer_fee1,
+        }
+    }
+}
+
+impl From<&raydium_clmm::RaydiumClmmCreatePoolEvent> for pb::RaydiumClmmCreatePoolEvent {
+    fn from(v: &raydium_clmm::RaydiumClmmCreatePoolEvent) -> Self {
+        Self {
+            metadata: Some((&v.metadata).into()),
+            sqrt_price_x64: v.sqrt_price_x64.to_string(),
+            open_time: v.open_time,
+            pool_creator: v.pool_creator.to_bytes().to_vec(),
+            amm_config: v.amm_config.to_bytes().to_vec(),
+            pool_state: v.pool_state.to_bytes().to_vec(),
+            token_mint0: v.token_mint0.to_bytes().to_vec(),
+            token_mint1: v.token_mint1.to_bytes().to_vec(),
+            token_vault0: v.token_vault0.to_bytes().to_vec(),
+            token_vault1: v.token_vault1.to_bytes().to_vec(),
+            observation_state: v.observation_state.to_bytes().to_vec(),
+            tick_array_bitmap: v.tick_array_bitmap.to_bytes().to_vec(),
+            token_program0: v.token_program0.to_bytes().to_vec(),
+            token_program1: v.token_program1.to_bytes().to_vec(),
+            system_program: v.system_program.to_bytes().to_vec(),
+            rent: v.rent.to_bytes().to_vec(),
+        }
+    }
+}
+
+impl From<&raydium_clmm::RaydiumClmmIncreaseLiquidityV2Event>
+    for pb::RaydiumClmmIncreaseLiquidityV2Event
+{
+    fn from(v: &raydium_clmm::RaydiumClmmIncreaseLiquidityV2Event) -> Self {
+        Self {
+            metadata: Some((&v.metadata).into()),
+            liquidity: v.liquidity.to_string(),
+            amount0_max: v.amount0_max,
+            amount1_max: v.amount1_max,
+            base_flag: v.base_flag,
+            nft_owner: v.nft_owner.to_bytes().to_vec(),
+            nft_account: v.nft_account.to_bytes().to_vec(),
+            pool_state: v.pool_state.to_bytes().to_vec(),
+            protocol_position: v.protocol_position.to_bytes().to_vec(),
+            personal_position: v.personal_position.to_bytes().to_vec(),
+            tick_array_lower: v.tick_array_lower.to_bytes().to_vec(),
+            tick_array_upper: v.tick_array_upper.to_bytes().to_vec(),
+            token_account0: v.token_account0.to_bytes().to_vec(),
+            token_account1: v.token_account1.to_bytes().to_vec(),
+            token_vault0: v.token_vault0.to_bytes().to_vec(),
+            token_vault1: v.token_vault1.to_bytes().to_vec(),
+            token_program: v.token_program.to_bytes().to_vec(),
+            token_program2022: v.token_program2022.to_bytes().to_vec(),
+            vault0_mint: v.vault0_mint.to_bytes().to_vec(),
+            vault1_mint: v.vault1_mint.to_bytes().to_vec(),
+        }
+    }
+}
+
+impl From<&raydium_clmm::RaydiumClmmOpenPositionWithToken22NftEvent>
+    for pb::RaydiumClmmOpenPositionWithToken22NftEvent
+{
+    fn from(v: &raydium_clmm::RaydiumClmmOpenPositionWithToken22NftEvent) -> Self {
+        Self {
+            metadata: Some((&v.metadata).into()),
+            tick_lower_index: v.tick_lower_index,
+            tick_upper_index: v.tick_upper_index,
+            tick_array_lower_start_index: v.tick_array_lower_start_index,
+            tick_array_upper_start_index: v.tick_array_upper_start_index,
+            liquidity: v.liquidity.to_string(),
+            amount0_max: v.amount0_max,
+            amount1_max: v.amount1_max,
+            with_metadata: v.with_metadata,
+            base_flag: v.base_flag,
+            payer: v.payer.to_bytes().to_vec(),
+            position_nft_owner: v.position_nft_owner.to_bytes().to_vec(),
+            position_nft_mint: v.position_nft_mint.to_bytes().to_vec(),
+            position_nft_account: v.position_nft_account.to_bytes().to_vec(),
+            pool_state: v.pool_state.to_bytes().to_vec(),
+            protocol_position: v.protocol_position.to_bytes().to_vec(),
+            tick_array_lower: v.tick_array_lower.to_bytes().to_vec(),
+            tick_array_upper: v.tick_array_upper.to_bytes().to_vec(),
+            personal_position: v.personal_position.to_bytes().to_vec(),
+            token_account0: v.token_account0.to_bytes().to_vec(),
+            token_account1: v.token_account1.to_bytes().to_vec(),
+            token_vault0: v.token_vault0.to_bytes().to_vec(),
+            token_vault1: v.token_vault1.to_bytes().to_vec(),
+            rent: v.rent.to_bytes().to_vec(),
+            system_program: v.system_program.to_bytes().to_vec(),
+            token_program: v.token_program.to_bytes().to_vec(),
+            associated_token_program: v.associated_token_program.to_bytes().to_vec(),
+            token_program2022: v.token_program2022.to_bytes().to_vec(),
+            vault0_mint: v.vault0_mint.to_bytes().to_vec(),
+            vault1_mint: v.vault1_mint.to_bytes().to_vec(),
+        }
+    }
+}
+
+impl From<&raydium_clmm::RaydiumClmmOpenPositionV2Event> for pb::RaydiumClmmOpenPositionV2Event {
+    fn from(v: &raydium_clmm::RaydiumClmmOpenPositionV2Event) -> Self {
+        Self {
+            metadata: Some((&v.metadata).into()),
+            tick_lower_index: v.tick_lower_index,
+            tick_upper_index: v.tick_upper_index,
+            tick_array_lower_start_index: v.tick_array_lower_start_index,
+            tick_array_upper_start_index: v.tick_array_upper_start_index,
+            liquidity: v.liquidity.to_string(),
+            amount0_max: v.amount0_max,
+            amount1_max: v.amount1_max,
+            with_metadata: v.with_metadata,
+            base_flag: v.base_flag,
+            payer: v.payer.to_bytes().to_vec(),
+            position_nft_owner: v.position_nft_owner.to_bytes().to_vec(),
+            position_nft_mint: v.position_nft_mint.to_bytes().to_vec(),
+            position_nft_account: v.position_nft_account.to_bytes().to_vec(),
+            metadata_account: v.metadata_account.to_bytes().to_vec(),
+            pool_state: v.pool_state.to_bytes().to_vec(),
+            protocol_position: v.protocol_position.to_bytes().to_vec(),
+            tick_array_lower: v.tick_array_lower.to_bytes().to_vec(),
+            tick_array_upper: v.tick_array_upper.to_bytes().to_vec(),
+            personal_position: v.personal_position.to_bytes().to_vec(),
+            token_account0: v.token_account0.to_bytes().to_vec(),
+            token_account1: v.token_account1.to_bytes().to_vec(),
+            token_vault0: v.token_vault0.to_bytes().to_vec(),
+            token_vault1: v.token_vault1.to_bytes().to_vec(),
+            rent: v.rent.to_bytes().to_vec(),
+            system_program: v.system_program.to_bytes().to_vec(),
+            token_program: v.token_program.to_bytes().to_vec(),
+            associated_token_program: v.associated_token_program.to_bytes().to_vec(),
+            metadata_program: v.metadata_program.to_bytes().to_vec(),
+            token_program2022: v.token_program2022.to_bytes().to_vec(),
+            vault0_mint: v.vault0_mint.to_bytes().to_vec(),
+            vault1_mint: v.vault1_mint.to_bytes().to_vec(),
+            remaining_accounts: v
+                .remaining_accounts
+                .iter()
+                .map(|x| x.to_bytes().to_vec())
+                .collect(),
+        }
+    }
+}
+
+impl From<&raydium_clmm::types::AmmConfig> for pb::RaydiumClmmAmmConfig {
+    fn from(v: &raydium_clmm::types::AmmConfig) -> Self {
+        Self {
+            bump: u32::from(v.bump),
+            index: u32::from(v.index),
+            owner: v.owner.to_bytes().to_vec(),
+            protocol_fee_rate: v.protocol_fee_rate,
+            trade_fee_rate: v.trade_fee_rate,
+            tick_spacing: u32::from(v.tick_spacing),
+            fund_fee_rate: v.fund_fee_rate,
+            padding_u32: v.padding_u32,
+            fund_owner: v.fund_owner.to_bytes().to_vec(),
+            padding: v.padding.to_vec(),
+        }
+    }
+}
+
+impl From<&raydium_clmm::RaydiumClmmAmmConfigAccountEvent>
+    for pb::RaydiumClmmAmmConfigAccountEvent
+{
+    fn from(v: &raydium_clmm::RaydiumClmmAmmConfigAccountEvent) -> Self {
+        Self {
+            metadata: Some((&v.metadata).into()),
+            pubkey: v.pubkey.to_bytes().to_vec(),
+            executable: v.executable,
+            lamports: v.lamports,
+            owner: v.owner.to_bytes().to_vec(),
+            rent_epoch: v.rent_epoch,
+            amm_config: Some((&v.amm_config).into()),
+        }
+    }
+}
+
+impl From<&raydium_clmm::types::RewardInfo> for pb::RaydiumClmmRewardInfo {
+    fn from(v: &raydium_clmm::types::RewardInfo) -> Self {
+        Self {
+            reward_state: u32::from(v.reward_state),
+            open_time: v.open_time,
+            end_time: v.end_time,
+            last_update_time: v.last_update_time,
+            emissions_per_second_x64: v.emissions_per_second_x64.to_string(),
+            reward_total_emissioned: v.reward_total_emissioned,
+            reward_claimed: v.reward_claimed,
+            token_mint: v.token_mint.to_bytes().to_vec(),
+            token_vault: v.token_vault.to_bytes().to_vec(),
+            authority: v.authority.to_bytes().to_vec(),
+            reward_growth_global_x64: v.reward_growth_global_x64.to_string(),
+        }
+    }
+}
+
+impl From<&raydium_clmm::types::PoolState> for pb::RaydiumClmmPoolState {
+    fn from(v: &raydium_clmm::types::PoolState) -> Self {
+        Self {
+            bump: v.bump.to_vec(),
+            amm_config: v.amm_config.to_bytes().to_vec(),
+            owner: v.owner.to_bytes().to_vec(),
+            token_mint0: v.token_mint0.to_bytes().to_vec(),
+            token_mint1: v.token_mint1.to_bytes().to_vec(),
+            token_vault0: v.token_vault0.to_bytes().to_vec(),
+            token_vault1: v.token_vault1.to_bytes().to_vec(),
+            observation_key: v.observation_key.to_bytes().to_vec(),
+            mint_decimals0: u32::from(v.mint_decimals0),
+            mint_decimals1: u32::from(v.mint_decimals1),
+            tick_spacing: u32::from(v.tick_spacing),
+            liquidity: v.liquidity.to_string(),
+            sqrt_price_x64: v.sqrt_price_x64.to_string(),
+            tick_current: v.tick_current,
+            padding3: u32::from(v.padding3),
+            padding4: u32::from(v.padding4),
+            fee_growth_global0_x64: v.fee_growth_global0_x64.to_string(),
+            fee_growth_global1_x64: v.fee_growth_global1_x64.to_string(),
+            protocol_fees_token0: v.protocol_fees_token0,
+            protocol_fees_token1: v.protocol_fees_token1,
+            swap_in_amount_token0: v.swap_in_amount_token0.to_string(),
+            swap_out_amount_token1: v.swap_out_amount_token1.to_string(),
+            swap_in_amount_token1: v.swap_in_amount_token1.to_string(),
+            swap_out_amount_token0: v.swap_out_amount_token0.to_string(),
+            status: u32::from(v.status),
+            padding: v.padding.to_vec(),
+            reward_infos: v.reward_infos.iter().map(pb::RaydiumClmmRewardInfo::from).collect(),
+            tick_array_bitmap: v.tick_array_bitmap.to_vec(),
+            total_fees_token0: v.total_fees_token0,
+            total_fees_claimed_token0: v.total_fees_claimed_token0,
+            total_fees_token1: v.total_fees_token1,
+            total_fees_claimed_token1: v.total_fees_claimed_token1,
+            fund_fees_token0: v.fund_fees_token0,
+            fund_fees_token1: v.fund_fees_token1,
+            open_time: v.open_time,
+            recent_epoch: v.recent_epoch,
+            padding1: v.padding1.to_vec(),
+            padding2: v.padding2.to_vec(),
+        }
+    }
+}
+
+impl From<&raydium_clmm::RaydiumClmmPoolStateAccountEvent>
+    for pb::RaydiumClmmPoolStateAccountEvent
+{
+    fn from(v: &raydium_clmm::RaydiumClmmPoolStateAccountEvent) -> Self {
+        Self {
+            metadata: Some((&v.metadata).into()),
+            pubkey: v.pubkey.to_bytes().to_vec(),
+            executable: v.executable,
+            lamports: v.lamports,
+            owner: v.owner.to_bytes().to_vec(),
+            rent_epoch: v.rent_epoch,
+            pool_state: Some((&v.pool_state).into()),
+        }
+    }
+}
+
+impl From<&raydium_clmm::types::TickState> for pb::RaydiumClmmTickState {
+    fn from(v: &raydium_clmm::types::TickState) -> Self {
+        Self {
+            tick: v.tick,
+            liquidity_net: v.liquidity_net.to_string(),
+            liquidity_gross: v.liquidity_gross.to_string(),
+            fee_growth_outside0_x64: v.fee_growth_outside0_x64.to_string(),
+            fee_growth_outside1_x64: v.fee_growth_outside1_x64.to_string(),
+            reward_growths_outside_x64: v
+                .reward_growths_outside_x64
+                .iter()
+                .map(|x| x.to_string())
+                .collect(),
+            padding: v.padding.to_vec(),
+        }
+    }
+}
+
+impl From<&raydium_clmm::types::TickArrayState> for pb::RaydiumClmmTickArrayState {
+    fn from(v: &raydium_clmm::types::TickArrayState) -> Self {
+        Self {
+            pool_id: v.pool_id.to_bytes().to_vec(),
+            start_tick_index: v.start_tick_index,
+            ticks: v.ticks.iter().map(pb::RaydiumClmmTickState::from).collect(),
+            initialized_tick_count: u32::from(v.initialized_tick_count),
+            recent_epoch: v.recent_epoch,
+            padding: v.padding.to_vec(),
+        }
+    }
+}
+
+impl From<&raydium_clmm::RaydiumClmmTickArrayStateAccountEvent>
+    for pb::RaydiumClmmTickArrayStateAccountEvent
+{
+    fn from(v: &raydium_clmm::RaydiumClmmTickArrayStateAccountEvent) -> Self {
+        Self {
+            metadata: Some((&v.metadata).into()),
+            pubkey: v.pubkey.to_bytes().to_vec(),
+            executable: v.executable,
+            lamports: v.lamports,
+            owner: v.owner.to_bytes().to_vec(),
+            rent_epoch: v.rent_epoch,
+            tick_array_state: Some((&v.tick_array_state).into()),
+        }
+    }
+}
+
+impl From<&raydium_amm_v4::RaydiumAmmV4SwapEvent> for pb::RaydiumAmmV4SwapEvent {
+    fn from(v: &raydium_amm_v4::RaydiumAmmV4SwapEvent) -> Self {
+        Self {
+            metadata: Some((&v.metadata).into()),
+            amount_in: v.amount_in,
+            minimum_amount_out: v.minimum_amount_out,
+            max_amount_in: v.max_amount_in,
+            amount_out: v.amount_out,
+            token_program: v.token_program.to_bytes().to_vec(),
+            amm: v.amm.to_bytes().to_vec(),
+            amm_authority: v.amm_authority.to_bytes().to_vec(),
+            amm_open_orders: v.amm_open_orders.to_bytes().to_vec(),
+            amm_target_orders: v.amm_target_orders.as_ref().map(|x| x.to_bytes().to_vec()),
+            pool_coin_token_account: v.pool_coin_token_account.to_bytes().to_vec(),
+            pool_pc_token_account: v.pool_pc_token_account.to_bytes().to_vec(),
+            serum_program: v.serum_program.to_bytes().to_vec(),
+            serum_market: v.serum_market.to_bytes().to_vec(),
+            serum_bids: v.serum_bids.to_bytes().to_vec(),
+            serum_asks: v.serum_asks.to_bytes().to_vec(),
+            serum_event_queue: v.serum_event_queue.to_bytes().to_vec(),
+            serum_coin_vault_account: v.serum_coin_vault_account.to_bytes().to_vec(),
+            serum_pc_vault_account: v.serum_pc_vault_account.to_bytes().to_vec(),
+            serum_vault_signer: v.serum_vault_signer.to_bytes().to_vec(),
+            user_source_token_account: v.user_source_token_account.to_bytes().to_vec(),
+            user_destination_token_account: v.user_destination_token_account.to_bytes().to_vec(),
+            user_source_owner: v.user_source_owner.to_bytes().to_vec(),
+        }
+    }
+}
+
+impl From<&raydium_amm_v4::RaydiumAmmV4DepositEvent> for pb::RaydiumAmmV4DepositEvent {
+    fn from(v: &raydium_amm_v4::RaydiumAmmV4DepositEvent) -> Self {
+        Self {
+            metadata: Some((&v.metadata).into()),
+            max_coin_amount: v.max_coin_amount,
+            max_pc_amount: v.max_pc_amount,
+            base_side: v.base_side,
+            token_program: v.token_program.to_bytes().to_vec(),
+            amm: v.amm.to_bytes().to_vec(),
+            amm_authority: v.amm_authority.to_bytes().to_vec(),
+            amm_open_orders: v.amm_open_orders.to_bytes().to_vec(),
+            amm_target_orders: v.amm_target_orders.to_bytes().to_vec(),
+            lp_mint_address: v.lp_mint_address.to_bytes().to_vec(),
+            pool_coin_token_account: v.pool_coin_token_account.to_bytes().to_vec(),
+            pool_pc_token_account: v.pool_pc_token_account.to_bytes().to_vec(),
+            serum_market: v.serum_market.to_bytes().to_vec(),
+            user_coin_token_account: v.user_coin_token_account.to_bytes().to_vec(),
+            user_pc_token_account: v.user_pc_token_account.to_bytes().to_vec(),
+            user_lp_token_account: v.user_lp_token_account.to_bytes().to_vec(),
+            user_owner: v.user_owner.to_bytes().to_vec(),
+            serum_event_queue: v.serum_event_queue.to_bytes().to_vec(),
+        }
+    }
+}
+
+impl From<&raydium_amm_v4::RaydiumAmmV4Initialize2Event> for pb::RaydiumAmmV4Initialize2Event {
+    fn from(v: &raydium_amm_v4::RaydiumAmmV4Initialize2Event) -> Self {
+        Self {
+            metadata: Some((&v.metadata).into()),
+            nonce: u32::from(v.nonce),
+            open_time: v.open_time,
+            init_pc_amount: v.init_pc_amount,
+            init_coin_amount: v.init_coin_amount,
+            token_program: v.token_program.to_bytes().to_vec(),
+            spl_associated_token_account: v.spl_associated_token_account.to_bytes().to_vec(),
+            system_program: v.system_program.to_bytes().to_vec(),
+            rent: v.rent.to_bytes().to_vec(),
+            amm: v.amm.to_bytes().to_vec(),
+            amm_authority: v.amm_authority.to_bytes().to_vec(),
+            amm_open_orders: v.amm_open_orders.to_bytes().to_vec(),
+            lp_mint: v.lp_mint.to_bytes().to_vec(),
+            coin_mint: v.coin_mint.to_bytes().to_vec(),
+            pc_mint: v.pc_mint.to_bytes().to_vec(),
+            pool_coin_token_account: v.pool_coin_token_account.to_bytes().to_vec(),
+            pool_pc_token_account: v.pool_pc_token_account.to_bytes().to_vec(),
+            pool_withdraw_queue: v.pool_withdraw_queue.to_bytes().to_vec(),
+            amm_target_orders: v.amm_target_orders.to_bytes().to_vec(),
+            pool_temp_lp: v.pool_temp_lp.to_bytes().to_vec(),
+            serum_program: v.serum_program.to_bytes().to_vec(),
+            serum_market: v.serum_market.to_bytes().to_vec(),
+            user_wallet: v.user_wallet.to_bytes().to_vec(),
+            user_token_coin: v.user_token_coin.to_bytes().to_vec(),
+            user_token_pc: v.user_token_pc.to_bytes().to_vec(),
+            user_lp_token_account: v.user_lp_token_account.to_bytes().to_vec(),
+        }
+    }
+}
+
+impl From<&raydium_amm_v4::RaydiumAmmV4WithdrawEvent> for pb::RaydiumAmmV4WithdrawEvent {
+    fn from(v: &raydium_amm_v4::RaydiumAmmV4WithdrawEvent) -> Self {
+        Self {
+            metadata: Some((&v.metadata).into()),
+            amount: v.amount,
+            token_program: v.token_program.to_bytes().to_vec(),
+            amm: v.amm.to_bytes().to_vec(),
+            amm_authority: v.amm_authority.to_bytes().to_vec(),
+            amm_open_orders: v.amm_open_orders.to_bytes().to_vec(),
+            amm_target_orders: v.amm_target_orders.to_bytes().to_vec(),
+            lp_mint_address: v.lp_mint_address.to_bytes().to_vec(),
+            pool_coin_token_account: v.pool_coin_token_account.to_bytes().to_vec(),
+            pool_pc_token_account: v.pool_pc_token_account.to_bytes().to_vec(),
+            pool_withdraw_queue: v.pool_withdraw_queue.to_bytes().to_vec(),
+            pool_temp_lp_token_account: v.pool_temp_lp_token_account.to_bytes().to_vec(),
+            serum_program: v.serum_program.to_bytes().to_vec(),
+            serum_market: v.serum_market.to_bytes().to_vec(),
+            serum_coin_vault_account: v.serum_coin_vault_account.to_bytes().to_vec(),
+            serum_pc_vault_account: v.serum_pc_vault_account.to_bytes().to_vec(),
+            serum_vault_signer: v.serum_vault_signer.to_bytes().to_vec(),
+            user_lp_token_account: v.user_lp_token_account.to_bytes().to_vec(),
+            user_coin_token_account: v.user_coin_token_account.to_bytes().to_vec(),
+            user_pc_token_account: v.user_pc_token_account.to_bytes().to_vec(),
+            user_owner: v.user_owner.to_bytes().to_vec(),
+            serum_event_queue: v.serum_event_queue.to_bytes().to_vec(),
+            serum_bids: v.serum_bids.to_bytes().to_vec(),
+            serum_asks: v.serum_asks.to_bytes().to_vec(),
+        }
+    }
+}
+
+impl From<&raydium_amm_v4::RaydiumAmmV4WithdrawPnlEvent> for pb::RaydiumAmmV4WithdrawPnlEvent {
+    fn from(v: &raydium_amm_v4::RaydiumAmmV4WithdrawPnlEvent) -> Self {
+        Self {
+            metadata: Some((&v.metadata).into()),
+            token_program: v.token_program.to_bytes().to_vec(),
+            amm: v.amm.to_bytes().to_vec(),
+            amm_config: v.amm_config.to_bytes().to_vec(),
+            amm_authority: v.amm_authority.to_bytes().to_vec(),
+            amm_open_orders: v.amm_open_orders.to_bytes().to_vec(),
+            pool_coin_token_account: v.pool_coin_token_account.to_bytes().to_vec(),
+            pool_pc_token_account: v.pool_pc_token_account.to_bytes().to_vec(),
+            coin_pnl_token_account: v.coin_pnl_token_account.to_bytes().to_vec(),
+            pc_pnl_token_account: v.pc_pnl_token_account.to_bytes().to_vec(),
+            pnl_owner_account: v.pnl_owner_account.to_bytes().to_vec(),
+            amm_target_orders: v.amm_target_orders.to_bytes().to_vec(),
+            serum_program: v.serum_program.to_bytes().to_vec(),
+            serum_market: v.serum_market.to_bytes().to_vec(),
+            serum_event_queue: v.serum_event_queue.to_bytes().to_vec(),
+            serum_coin_vault_account: v.serum_coin_vault_account.to_bytes().to_vec(),
+            serum_pc_vault_account: v.serum_pc_vault_account.to_bytes().to_vec(),
+            serum_vault_signer: v.serum_vault_signer.to_bytes().to_vec(),
+        }
+    }
+}
+
+impl From<&raydium_amm_v4::types::Fees> for pb::RaydiumAmmV4Fees {
+    fn from(v: &raydium_amm_v4::types::Fees) -> Self {
+        Self {
+            min_separate_numerator: v.min_separate_numerator,
+            min_separate_denominator: v.min_separate_denominator,
+            trade_fee_numerator: v.trade_fee_numerator,
+            trade_fee_denominator: v.trade_fee_denominator,
+            pnl_numerator: v.pnl_numerator,
+            pnl_denominator: v.pnl_denominator,
+            swap_fee_numerator: v.swap_fee_numerator,
+            swap_fee_denominator: v.swap_fee_denominator,
+        }
+    }
+}
+
+impl From<&raydium_amm_v4::types::OutPutData> for pb::RaydiumAmmV4OutPutData {
+    fn from(v: &raydium_amm_v4::types::OutPutData) -> Self {
+        Self {
+            need_take_pnl_coin: v.need_take_pnl_coin,
+            need_take_pnl_pc: v.need_take_pnl_pc,
+            total_pnl_pc: v.total_pnl_pc,
+            total_pnl_coin: v.total_pnl_coin,
+            pool_open_time: v.pool_open_time,
+            punish_pc_amount: v.punish_pc_amount,
+            punish_coin_amount: v.punish_coin_amount,
+            orderbook_to_init_time: v.orderbook_to_init_time,
+            swap_coin_in_amount: v.swap_coin_in_amount.to_string(),
+            swap_pc_out_amount: v.swap_pc_out_amount.to_string(),
+            swap_take_pc_fee: v.swap_take_pc_fee,
+            swap_pc_in_amount: v.swap_pc_in_amount.to_string(),
+            swap_coin_out_amount: v.swap_coin_out_amount.to_string(),
+            swap_take_coin_fee: v.swap_take_coin_fee,
+        }
+    }
+}
+
+impl From<&raydium_amm_v4::types::AmmInfo> for pb::RaydiumAmmV4AmmInfo {
+    fn from(v: &raydium_amm_v4::types::AmmInfo) -> Self {
+        Self {
+            status: v.status,
+            nonce: v.nonce,
+            order_num: v.order_num,
+            depth: v.depth,
+            coin_decimals: v.coin_decimals,
+            pc_decimals: v.pc_decimals,
+            state: v.state,
+            reset_flag: v.reset_flag,
+            min_size: v.min_size,
+            vol_max_cut_ratio: v.vol_max_cut_ratio,
+            amount_wave: v.amount_wave,
+            coin_lot_size: v.coin_lot_size,
+            pc_lot_size: v.pc_lot_size,
+            min_price_multiplier: v.min_price_multiplier,
+            max_price_multiplier: v.max_price_multiplier,
+            sys_decimal_value: v.sys_decimal_value,
+            fees: Some((&v.fees).into()),
+            out_put: Some((&v.out_put).into()),
+            token_coin: v.token_coin.to_bytes().to_vec(),
+            token_pc: v.token_pc.to_bytes().to_vec(),
+            coin_mint: v.coin_mint.to_bytes().to_vec(),
+            pc_mint: v.pc_mint.to_bytes().to_vec(),
+            lp_mint: v.lp_mint.to_bytes().to_vec(),
+            open_orders: v.open_orders.to_bytes().to_vec(),
+            market: v.market.to_bytes().to_vec(),
+            serum_dex: v.serum_dex.to_bytes().to_vec(),
+            target_orders: v.target_orders.to_bytes().to_vec(),
+            withdraw_queue: v.withdraw_queue.to_bytes().to_vec(),
+            token_temp_lp: v.token_temp_lp.to_bytes().to_vec(),
+            amm_owner: v.amm_owner.to_bytes().to_vec(),
+            lp_amount: v.lp_amount,
+            client_order_id: v.client_order_id,
+            padding: v.padding.to_vec(),
+        }
+    }
+}
+
+impl From<&raydium_amm_v4::RaydiumAmmV4AmmInfoAccountEvent>
+    for pb::RaydiumAmmV4AmmInfoAccountEvent
+{
+    fn from(v: &raydium_amm_v4::RaydiumAmmV4AmmInfoAccountEvent) -> Self {
+        Self {
+            metadata: Some((&v.metadata).into()),
+            pubkey: v.pubkey.to_bytes().to_vec(),
+            executable: v.executable,
+            lamports: v.lamports,
+            owner: v.owner.to_bytes().to_vec(),
+            rent_epoch: v.rent_epoch,
+            amm_info: Some((&v.amm_info).into()),
+        }
+    }
+}
+
+impl From<&photon::PhotonPumpFunTradeEvent> for pb::PhotonPumpFunTradeEvent {
+    fn from(v: &photon::PhotonPumpFunTradeEvent) -> Self {
+        Self {
+            metadata: Some((&v.metadata).into()),
+            mint: v.mint.to_bytes().to_vec(),
+            sol_amount: v.sol_amount,
+            token_amount: v.token_amount,
+            is_buy: v.is_buy,
+            user: v.user.to_bytes().to_vec(),
+            timestamp: v.timestamp,
+            virtual_sol_reserves: v.virtual_sol_reserves,
+            virtual_token_reserves: v.virtual_token_reserves,
+            real_sol_reserves: v.real_sol_reserves,
+            real_token_reserves: v.real_token_reserves,
+            fee_recipient: v.fee_recipient.to_bytes().to_vec(),
+            fee_basis_points: v.fee_basis_points,
+            fee: v.fee,
+            creator: v.creator.to_bytes().to_vec(),
+            creator_fee_basis_points: v.creator_fee_basis_points,
+            creator_fee: v.creator_fee,
+            track_volume: v.track_volume,
+            total_unclaimed_tokens: v.total_unclaimed_tokens,
+            total_claimed_tokens: v.total_claimed_tokens,
+            current_sol_volume: v.current_sol_volume,
+            last_update_timestamp: v.last_update_timestamp,
+            max_sol_cost: v.max_sol_cost,
+            min_sol_output: v.min_sol_output,
+            amount: v.amount,
+            is_bot: v.is_bot,
+            is_dev_create_token_trade: v.is_dev_create_token_trade,
+            global: v.global.to_bytes().to_vec(),
+            bonding_curve: v.bonding_curve.to_bytes().to_vec(),
+            associated_bonding_curve: v.associated_bonding_curve.to_bytes().to_vec(),
+            associated_user: v.associated_user.to_bytes().to_vec(),
+            system_program: v.system_program.to_bytes().to_vec(),
+            token_program: v.token_program.to_bytes().to_vec(),
+            creator_vault: v.creator_vault.to_bytes().to_vec(),
+            event_authority: v.event_authority.to_bytes().to_vec(),
+            program: v.program.to_bytes().to_vec(),
+            global_volume_accumulator: v.global_volume_accumulator.to_bytes().to_vec(),
+            user_volume_accumulator: v.user_volume_accumulator.to_bytes().to_vec(),
+            fee_config: v.fee_config.to_bytes().to_vec(),
+            fee_program: v.fee_program.to_bytes().to_vec(),
+            routed_trade: v.routed_trade.as_ref().map(pb::InstructionIndex::from),
+        }
+    }
+}
+
+impl From<&photon::PhotonPumpSwapTradeEvent> for pb::PhotonPumpSwapTradeEvent {
+    fn from(v: &photon::PhotonPumpSwapTradeEvent) -> Self {
+        Self {
+            metadata: Some((&v.metadata).into()),
+            timestamp: v.timestamp,
+            base_amount_out: v.base_amount_out,
+            max_quote_amount_in: v.max_quote_amount_in,
+            base_amount_in: v.base_amount_in,
+            min_quote_amount_out: v.min_quote_amount_out,
+            is_buy: v.is_buy,
+            user_base_token_reserves: v.user_base_token_reserves,
+            user_quote_token_reserves: v.user_quote_token_reserves,
+            pool_base_token_reserves: v.pool_base_token_reserves,
+            pool_quote_token_reserves: v.pool_quote_token_reserves,
+            quote_amount_in: v.quote_amount_in,
+            lp_fee_basis_points: v.lp_fee_basis_points,
+            lp_fee: v.lp_fee,
+            protocol_fee_basis_points: v.protocol_fee_basis_points,
+            protocol_fee: v.protocol_fee,
+            quote_amount_in_with_lp_fee: v.quote_amount_in_with_lp_fee,
+            user_quote_amount_in: v.user_quote_amount_in,
+            pool: v.pool.to_bytes().to_vec(),
+            user: v.user.to_bytes().to_vec(),
+            user_base_token_account: v.user_base_token_account.to_bytes().to_vec(),
+            user_quote_token_account: v.user_quote_token_account.to_bytes().to_vec(),
+            protocol_fee_recipient: v.protocol_fee_recipient.to_bytes().to_vec(),
+            protocol_fee_recipient_token_account: v
+                .protocol_fee_recipient_token_account
+                .to_bytes()
+                .to_vec(),
+            coin_creator: v.coin_creator.to_bytes().to_vec(),
+            coin_creator_fee_basis_points: v.coin_creator_fee_basis_points,
+            coin_creator_fee: v.coin_creator_fee,
+            base_mint: v.base_mint.to_bytes().to_vec(),
+            quote_mint: v.quote_mint.to_bytes().to_vec(),
+            pool_base_token_account: v.pool_base_token_account.to_bytes().to_vec(),
+            pool_quote_token_account: v.pool_quote_token_account.to_bytes().to_vec(),
+            coin_creator_vault_ata: v.coin_creator_vault_ata.to_bytes().to_vec(),
+            coin_creator_vault_authority: v.coin_creator_vault_authority.to_bytes().to_vec(),
+            base_token_program: v.base_token_program.to_bytes().to_vec(),
+            quote_token_program: v.quote_token_program.to_bytes().to_vec(),
+            global_volume_accumulator: v.global_volume_accumulator.to_bytes().to_vec(),
+            user_volume_accumulator: v.user_volume_accumulator.to_bytes().to_vec(),
+            routed_trade: v.routed_trade.as_ref().map(pb::InstructionIndex::from),
+        }
+    }
+}
+
+impl From<&axiom::AxiomPumpFunTradeEvent> for pb::AxiomPumpFunTradeEvent {
+    fn from(v: &axiom::AxiomPumpFunTradeEvent) -> Self {
+        Self {
+            metadata: Some((&v.metadata).into()),
+            mint: v.mint.to_bytes().to_vec(),
+            sol_amount: v.sol_amount,
+            token_amount: v.token_amount,
+            is_buy: v.is_buy,
+            user: v.user.to_bytes().to_vec(),
+            timestamp: v.timestamp,
+            virtual_sol_reserves: v.virtual_sol_reserves,
+            virtual_token_reserves: v.virtual_token_reserves,
+            real_sol_reserves: v.real_sol_reserves,
+            real_token_reserves: v.real_token_reserves,
+            fee_recipient: v.fee_recipient.to_bytes().to_vec(),
+            fee_basis_points: v.fee_basis_points,
+            fee: v.fee,
+            creator: v.creator.to_bytes().to_vec(),
+            creator_fee_basis_points: v.creator_fee_basis_points,
+            creator_fee: v.creator_fee,
+            track_volume: v.track_volume,
+            total_unclaimed_tokens: v.total_unclaimed_tokens,
+            total_claimed_tokens: v.total_claimed_tokens,
+            current_sol_volume: v.current_sol_volume,
+            last_update_timestamp: v.last_update_timestamp,
+            max_sol_cost: v.max_sol_cost,
+            min_sol_output: v.min_sol_output,
+            amount: v.amount,
+            is_bot: v.is_bot,
+            is_dev_create_token_trade: v.is_dev_create_token_trade,
+            global: v.global.to_bytes().to_vec(),
+            bonding_curve: v.bonding_curve.to_bytes().to_vec(),
+            associated_bonding_curve: v.associated_bonding_curve.to_bytes().to_vec(),
+            associated_user: v.associated_user.to_bytes().to_vec(),
+            system_program: v.system_program.to_bytes().to_vec(),
+            token_program: v.token_program.to_bytes().to_vec(),
+            creator_vault: v.creator_vault.to_bytes().to_vec(),
+            event_authority: v.event_authority.to_bytes().to_vec(),
+            program: v.program.to_bytes().to_vec(),
+            global_volume_accumulator: v.global_volume_accumulator.to_bytes().to_vec(),
+            user_volume_accumulator: v.user_volume_accumulator.to_bytes().to_vec(),
+            fee_config: v.fee_config.to_bytes().to_vec(),
+            fee_program: v.fee_program.to_bytes().to_vec(),
+            routed_trade: v.routed_trade.as_ref().map(pb::InstructionIndex::from),
+        }
+    }
+}
+
+impl From<&axiom2::AxiomPumpSwapBuyEvent> for pb::AxiomPumpSwapBuyEvent {
+    fn from(v: &axiom2::AxiomPumpSwapBuyEvent) -> Self {
+        Self {
+            metadata: Some((&v.metadata).into()),
+            timestamp: v.timestamp,
+            base_amount_out: v.base_amount_out,
+            max_quote_amount_in: v.max_quote_amount_in,
+            user_base_token_reserves: v.user_base_token_reserves,
+            user_quote_token_reserves: v.user_quote_token_reserves,
+            pool_base_token_reserves: v.pool_base_token_reserves,
+            pool_quote_token_reserves: v.pool_quote_token_reserves,
+            quote_amount_in: v.quote_amount_in,
+            lp_fee_basis_points: v.lp_fee_basis_points,
+            lp_fee: v.lp_fee,
+            protocol_fee_basis_points: v.protocol_fee_basis_points,
+            protocol_fee: v.protocol_fee,
+            quote_amount_in_with_lp_fee: v.quote_amount_in_with_lp_fee,
+            user_quote_amount_in: v.user_quote_amount_in,
+            pool: v.pool.to_bytes().to_vec(),
+            user: v.user.to_bytes().to_vec(),
+            user_base_token_account: v.user_base_token_account.to_bytes().to_vec(),
+            user_quote_token_account: v.user_quote_token_account.to_bytes().to_vec(),
+            protocol_fee_recipient: v.protocol_fee_recipient.to_bytes().to_vec(),
+            protocol_fee_recipient_token_account: v
+                .protocol_fee_recipient_token_account
+                .to_bytes()
+                .to_vec(),
+            coin_creator: v.coin_creator.to_bytes().to_vec(),
+            coin_creator_fee_basis_points: v.coin_creator_fee_basis_points,
+            coin_creator_fee: v.coin_creator_fee,
+            track_volume: v.track_volume,
+            total_unclaimed_tokens: v.total_unclaimed_tokens,
+            total_claimed_tokens: v.total_claimed_tokens,
+            current_sol_volume: v.current_sol_volume,
+            last_update_timestamp: v.last_update_timestamp,
+            base_mint: v.base_mint.to_bytes().to_vec(),
+            quote_mint: v.quote_mint.to_bytes().to_vec(),
+            pool_base_token_account: v.pool_base_token_account.to_bytes().to_vec(),
+            pool_quote_token_account: v.pool_quote_token_account.to_bytes().to_vec(),
+            coin_creator_vault_ata: v.coin_creator_vault_ata.to_bytes().to_vec(),
+            coin_creator_vault_authority: v.coin_creator_vault_authority.to_bytes().to_vec(),
+            base_token_program: v.base_token_program.to_bytes().to_vec(),
+            quote_token_program: v.quote_token_program.to_bytes().to_vec(),
+            global_volume_accumulator: v.global_volume_accumulator.to_bytes().to_vec(),
+            user_volume_accumulator: v.user_volume_accumulator.to_bytes().to_vec(),
+            routed_trade: v.routed_trade.as_ref().map(pb::InstructionIndex::from),
+        }
+    }
+}
+
+impl From<&meteora_dbc::types::TradeDirection> for pb::MeteoraDbcTradeDirection {
+    fn from(v: &meteora_dbc::types::TradeDirection) -> Self {
+        match v {
+            meteora_dbc::types::TradeDirection::Sell => pb::MeteoraDbcTradeDirection::Sell,
+            meteora_dbc::types::TradeDirection::Buy => pb::MeteoraDbcTradeDirection::Buy,
+        }
+    }
+}
+
+impl From<&meteora_dbc::MeteoraDBCSwapEvent> for pb::MeteoraDbcSwapEvent {
+    fn from(v: &meteora_dbc::MeteoraDBCSwapEvent) -> Self {
+        Self {
+            metadata: Some((&v.metadata).into()),
+            pool: v.pool.to_bytes().to_vec(),
+            config: v.config.to_bytes().to_vec(),
+            trade_direction: pb::MeteoraDbcTradeDirection::from(&v.trade_direction) as i32,
+            has_referral: u32::from(v.has_referral),
+            param_amount_in: v.param_amount_in,
+            param_minimum_amount_out: v.param_minimum_amount_out,
+            actual_input_amount: v.actual_input_amount,
+            output_amount: v.output_amount,
+            next_sqrt_price: v.next_sqrt_price.to_string(),
+            trading_fee: v.trading_fee,
+            protocol_fee: v.protocol_fee,
+            referral_fee: v.referral_fee,
+            amount_in: v.amount_in,
+            current_timestamp: v.current_timestamp,
+            payer: v.payer.to_bytes().to_vec(),
+            base_mint: v.base_mint.to_bytes().to_vec(),
+            quote_mint: v.quote_mint.to_bytes().to_vec(),
+            input_token_account: v.input_token_account.to_bytes().to_vec(),
+            output_token_account: v.output_token_account.to_bytes().to_vec(),
+            base_vault: v.base_vault.to_bytes().to_vec(),
+            quote_vault: v.quote_vault.to_bytes().to_vec(),
+            token_base_program: v.token_base_program.to_bytes().to_vec(),
+            token_quote_program: v.token_quote_program.to_bytes().to_vec(),
+            remaining_accounts: v
+                .remaining_accounts
+                .iter()
+                .map(|x| x.to_bytes().to_vec())
+                .collect(),
+        }
+    }
+}
+
+impl From<&meteora_dammv2::MeteoraDAMMv2SwapEvent> for pb::MeteoraDammV2SwapEvent {
+    fn from(v: &meteora_dammv2::MeteoraDAMMv2SwapEvent) -> Self {
+        Self {
+            metadata: Some((&v.metadata).into()),
+            pool: v.pool.to_bytes().to_vec(),
+            trade_direction: pb::MeteoraDbcTradeDirection::from(&v.trade_direction) as i32,
+            has_referral: u32::from(v.has_referral),
+            param_amount_in: v.param_amount_in,
+            param_minimum_amount_out: v.param_minimum_amount_out,
+            output_amount: v.output_amount,
+            next_sqrt_price: v.next_sqrt_price.to_string(),
+            lp_fee: v.lp_fee,
+            protocol_fee: v.protocol_fee,
+            partner_fee: v.partner_fee,
+            referral_fee: v.referral_fee,
+            amount_in: v.amount_in,
+            current_timestamp: v.current_timestamp,
+            payer: v.payer.to_bytes().to_vec(),
+            token_a_mint: v.token_a_mint.to_bytes().to_vec(),
+            token_b_mint: v.token_b_mint.to_bytes().to_vec(),
+            input_token_account: v.input_token_account.to_bytes().to_vec(),
+            output_token_account: v.output_token_account.to_bytes().to_vec(),
+            token_a_vault: v.token_a_vault.to_bytes().to_vec(),
+            token_b_vault: v.token_b_vault.to_bytes().to_vec(),
+            token_a_program: v.token_a_program.to_bytes().to_vec(),
+            token_b_program: v.token_b_program.to_bytes().to_vec(),
+            remaining_accounts: v
+                .remaining_accounts
+                .iter()
+                .map(|x| x.to_bytes().to_vec())
+                .collect(),
+        }
+    }
+}
+
+impl From<&core::account_event_parser::TokenAccountEvent> for pb::TokenAccountEvent {
+    fn from(v: &core::account_event_parser::TokenAccountEvent) -> Self {
+        Self {
+            metadata: Some((&v.metadata).into()),
+            pubkey: v.pubkey.to_bytes().to_vec(),
+            executable: v.executable,
+            lamports: v.lamports,
+            owner: v.owner.to_bytes().to_vec(),
+            rent_epoch: v.rent_epoch,
+            amount: v.amount,
+            token_owner: v.token_owner.to_bytes().to_vec(),
+        }
+    }
+}
+
+impl From<&core::account_event_parser::NonceAccountEvent> for pb::NonceAccountEvent {
+    fn from(v: &core::account_event_parser::NonceAccountEvent) -> Self {
+        Self {
+            metadata: Some((&v.metadata).into()),
+            pubkey: v.pubkey.to_bytes().to_vec(),
+            executable: v.executable,
+            lamports: v.lamports,
+            owner: v.owner.to_bytes().to_vec(),
+            rent_epoch: v.rent_epoch,
+            nonce: v.nonce.clone(),
+            authority: v.authority.clone(),
+        }
+    }
+}
+
+impl From<&core::account_event_parser::TokenInfoEvent> for pb::TokenInfoEvent {
+    fn from(v: &core::account_event_parser::TokenInfoEvent) -> Self {
+        Self {
+            metadata: Some((&v.metadata).into()),
+            pubkey: v.pubkey.to_bytes().to_vec(),
+            executable: v.executable,
+            lamports: v.lamports,
+            owner: v.owner.to_bytes().to_vec(),
+            rent_epoch: v.rent_epoch,
+            supply: v.supply,
+            decimals: u32::from(v.decimals),
+        }
+    }
+}
+
+impl From<&block::block_meta_event::BlockMetaEvent> for pb::BlockMetaEvent {
+    fn from(v: &block::block_meta_event::BlockMetaEvent) -> Self {
+        Self {
+            metadata: Some((&v.metadata).into()),
+            slot: v.slot,
+            block_hash: v.block_hash.clone(),
+        }
+    }
+}
+
+impl From<&core::balance_change::BalanceChangeEvent> for pb::BalanceChangeEvent {
+    fn from(v: &core::balance_change::BalanceChangeEvent) -> Self {
+        Self {
+            metadata: Some((&v.metadata).into()),
+            owner: v.owner.to_bytes().to_vec(),
+            mint: v.mint.to_bytes().to_vec(),
+            decimals: u32::from(v.decimals),
+            is_native: v.is_native,
+            pre_amount: v.pre_amount,
+            post_amount: v.post_amount,
+            delta: v.delta.to_string(),
+        }
+    }
+}
+
+impl From<&core::normalized_trade::TradeSide> for pb::TradeSide {
+    fn from(v: &core::normalized_trade::TradeSide) -> Self {
+        match v {
+            core::normalized_trade::TradeSide::Unknown => pb::TradeSide::Unknown,
+            core::normalized_trade::TradeSide::Buy => pb::TradeSide::Buy,
+            core::normalized_trade::TradeSide::Sell => pb::TradeSide::Sell,
+        }
+    }
+}
+
+impl From<&core::normalized_trade::NormalizedTrade> for pb::NormalizedTrade {
+    fn from(v: &core::normalized_trade::NormalizedTrade) -> Self {
+        Self {
+            metadata: Some((&v.metadata).into()),
+            source_event_type: format!("{:?}", v.source_event_type),
+            pool: v.pool.to_bytes().to_vec(),
+            trader: v.trader.to_bytes().to_vec(),
+            input_mint: v.input_mint.to_bytes().to_vec(),
+            output_mint: v.output_mint.to_bytes().to_vec(),
+            amount_in: v.amount_in,
+            amount_out: v.amount_out,
+            input_decimals: v.input_decimals.as_ref().map(|x| u32::from(*x)),
+            output_decimals: v.output_decimals.as_ref().map(|x| u32::from(*x)),
+            fee: v.fee,
+            fee_mint: v.fee_mint.to_bytes().to_vec(),
+            side: pb::TradeSide::from(&v.side) as i32,
+            price: v.price,
+        }
+    }
+}
+
+impl From<&meteora_dbc::MeteoraDBCInitializePoolEvent> for pb::MeteoraDbcInitializePoolEvent {
+    fn from(v: &meteora_dbc::MeteoraDBCInitializePoolEvent) -> Self {
+        Self {
+            metadata: Some((&v.metadata).into()),
+            pool: v.pool.to_bytes().to_vec(),
+            config: v.config.to_bytes().to_vec(),
+            creator: v.creator.to_bytes().to_vec(),
+            base_mint: v.base_mint.to_bytes().to_vec(),
+            pool_type: u32::from(v.pool_type),
+            activation_point: v.activation_point,
+            name: v.name.clone(),
+            symbol: v.symbol.clone(),
+            uri: v.uri.clone(),
+            payer: v.payer.to_bytes().to_vec(),
+            quote_mint: v.quote_mint.to_bytes().to_vec(),
+            base_vault: v.base_vault.to_bytes().to_vec(),
+            quote_vault: v.quote_vault.to_bytes().to_vec(),
+            token_program: v.token_program.to_bytes().to_vec(),
+            token_quote_program: v.token_quote_program.to_bytes().to_vec(),
+        }
+    }
+}
+
+impl From<&core::token_launch::TokenLaunch> for pb::TokenLaunch {
+    fn from(v: &core::token_launch::TokenLaunch) -> Self {
+        Self {
+            metadata: Some((&v.metadata).into()),
+            launchpad: format!("{:?}", v.launchpad),
+            source_event_type: format!("{:?}", v.source_event_type),
+            mint: v.mint.to_bytes().to_vec(),
+            creator: v.creator.to_bytes().to_vec(),
+            payer: v.payer.to_bytes().to_vec(),
+            pool: v.pool.to_bytes().to_vec(),
+            quote_mint: v.quote_mint.to_bytes().to_vec(),
+            name: v.name.clone(),
+            symbol: v.symbol.clone(),
+            uri: v.uri.clone(),
+            decimals: v.decimals.as_ref().map(|x| u32::from(*x)),
+            token_program: v.token_program.to_bytes().to_vec(),
+            is_token_2022: v.is_token_2022,
+            total_supply: v.total_supply,
+            virtual_base_reserves: v.virtual_base_reserves,
+            virtual_quote_reserves: v.virtual_quote_reserves,
+            real_base_reserves: v.real_base_reserves,
+            base_sell_amount: v.base_sell_amount,
+            quote_raise_target: v.quote_raise_target,
+            dev_buy_quote_amount: v.dev_buy_quote_amount,
+            dev_buy_base_amount: v.dev_buy_base_amount,
+        }
+    }
+}
+
+impl From<&meteora_dbc::MeteoraDBCMigrationDammV2Event> for pb::MeteoraDbcMigrationDammV2Event {
+    fn from(v: &meteora_dbc::MeteoraDBCMigrationDammV2Event) -> Self {
+        Self {
+            metadata: Some((&v.metadata).into()),
+            virtual_pool: v.virtual_pool.to_bytes().to_vec(),
+            migration_metadata: v.migration_metadata.to_bytes().to_vec(),
+            config: v.config.to_bytes().to_vec(),
+            pool: v.pool.to_bytes().to_vec(),
+            first_position_nft_mint: v.first_position_nft_mint.to_bytes().to_vec(),
+            first_position: v.first_position.to_bytes().to_vec(),
+            second_position_nft_mint: v.second_position_nft_mint.to_bytes().to_vec(),
+            second_position: v.second_position.to_bytes().to_vec(),
+            base_mint: v.base_mint.to_bytes().to_vec(),
+            quote_mint: v.quote_mint.to_bytes().to_vec(),
+            token_a_vault: v.token_a_vault.to_bytes().to_vec(),
+            token_b_vault: v.token_b_vault.to_bytes().to_vec(),
+            base_vault: v.base_vault.to_bytes().to_vec(),
+            quote_vault: v.quote_vault.to_bytes().to_vec(),
+            payer: v.payer.to_bytes().to_vec(),
+        }
+    }
+}
+
+impl From<&core::graduation::Graduation> for pb::Graduation {
+    fn from(v: &core::graduation::Graduation) -> Self {
+        Self {
+            metadata: Some((&v.metadata).into()),
+            source_event_type: format!("{:?}", v.source_event_type),
+            mint: v.mint.to_bytes().to_vec(),
+            quote_mint: v.quote_mint.to_bytes().to_vec(),
+            launchpad: format!("{:?}", v.launchpad),
+            source_pool: v.source_pool.to_bytes().to_vec(),
+            destination: format!("{:?}", v.destination),
+            pool: v.pool.to_bytes().to_vec(),
+            lp_mint: v.lp_mint.to_bytes().to_vec(),
+            base_amount: v.base_amount,
+            quote_amount: v.quote_amount,
+        }
+    }
+}
+
+impl From<&core::liquidity_change::LiquidityDirection> for pb::LiquidityDirection {
+    fn from(v: &core::liquidity_change::LiquidityDirection) -> Self {
+        match v {
+            core::liquidity_change::LiquidityDirection::Add => pb::LiquidityDirection::Add,
+            core::liquidity_change::LiquidityDirection::Remove => pb::LiquidityDirection::Remove,
+        }
+    }
+}
+
+impl From<&core::liquidity_change::LiquidityChange> for pb::LiquidityChange {
+    fn from(v: &core::liquidity_change::LiquidityChange) -> Self {
+        Self {
+            metadata: Some((&v.metadata).into()),
+            source_event_type: format!("{:?}", v.source_event_type),
+            pool: v.pool.to_bytes().to_vec(),
+            provider: v.provider.to_bytes().to_vec(),
+            direction: pb::LiquidityDirection::from(&v.direction) as i32,
+            mint_a: v.mint_a.to_bytes().to_vec(),
+            mint_b: v.mint_b.to_bytes().to_vec(),
+            amount_a: v.amount_a,
+            amount_b: v.amount_b,
+            lp_mint: v.lp_mint.to_bytes().to_vec(),
+            lp_amount: v.lp_amount.to_string(),
+            fee_a: v.fee_a,
+            fee_b: v.fee_b,
+        }
+    }
+}
+
+impl From<&core::parse_diagnostics::UnparsedReason> for pb::UnparsedReason {
+    fn from(v: &core::parse_diagnostics::UnparsedReason) -> Self {
+        match v {
+            core::parse_diagnostics::UnparsedReason::UnknownDiscriminator => {
+                pb::UnparsedReason::UnknownDiscriminator
+            }
+            core::parse_diagnostics::UnparsedReason::DecodeFailed => {
+                pb::UnparsedReason::DecodeFailed
+            }
+            core::parse_diagnostics::UnparsedReason::InnerDecodeFailed => {
+                pb::UnparsedReason::InnerDecodeFailed
+            }
+        }
+    }
+}
+
+impl From<&core::parse_diagnostics::UnparsedInstructionEvent> for pb::UnparsedInstructionEvent {
+    fn from(v: &core::parse_diagnostics::UnparsedInstructionEvent) -> Self {
+        Self {
+            metadata: Some((&v.metadata).into()),
+            reason: pb::UnparsedReason::from(&v.reason) as i32,
+            attempted_event_type: format!("{:?}", v.attempted_event_type),
+            discriminator: v.discriminator.clone(),
+            data_len: v.data_len,
+        }
+    }
+}
+
+impl From<&core::raw_instruction::RawInstructionEvent> for pb::RawInstructionEvent {
+    fn from(v: &core::raw_instruction::RawInstructionEvent) -> Self {
+        Self {
+            metadata: Some((&v.metadata).into()),
+            stack_height: v.stack_height,
+            accounts: v.accounts.iter().map(|x| x.to_bytes().to_vec()).collect(),
+            data: v.data.clone(),
+        }
+    }
+}
+
+impl From<&jupiter_v6::types::JupiterRouteKind> for pb::JupiterRouteKind {
+    fn from(v: &jupiter_v6::types::JupiterRouteKind) -> Self {
+        use jupiter_v6::types::JupiterRouteKind;
+        match v {
+            JupiterRouteKind::Route => pb::JupiterRouteKind::Route,
+            JupiterRouteKind::RouteWithTokenLedger => pb::JupiterRouteKind::RouteWithTokenLedger,
+            JupiterRouteKind::ExactOutRoute => pb::JupiterRouteKind::ExactOutRoute,
+            JupiterRouteKind::SharedAccountsRoute => pb::JupiterRouteKind::SharedAccountsRoute,
+            JupiterRouteKind::SharedAccountsRouteWithTokenLedger => {
+                pb::JupiterRouteKind::SharedAccountsRouteWithTokenLedger
+            }
+            JupiterRouteKind::SharedAccountsExactOutRoute => {
+                pb::JupiterRouteKind::SharedAccountsExactOutRoute
+            }
+        }
+    }
+}
+
+impl From<&jupiter_v6::types::JupiterSwapHop> for pb::JupiterSwapHop {
+    fn from(v: &jupiter_v6::types::JupiterSwapHop) -> Self {
+        Self {
+            amm: v.amm.to_bytes().to_vec(),
+            input_mint: v.input_mint.to_bytes().to_vec(),
+            input_amount: v.input_amount,
+            output_mint: v.output_mint.to_bytes().to_vec(),
+            output_amount: v.output_amount,
+        }
+    }
+}
+
+impl From<&jupiter_v6::JupiterRouteEvent> for pb::JupiterRouteEvent {
+    fn from(v: &jupiter_v6::JupiterRouteEvent) -> Self {
+        Self {
+            metadata: Some((&v.metadata).into()),
+            kind: pb::JupiterRouteKind::from(&v.kind) as i32,
+            user: v.user.to_bytes().to_vec(),
+            source_token_account: v.source_token_account.to_bytes().to_vec(),
+            destination_token_account: v.destination_token_account.to_bytes().to_vec(),
+            input_mint: v.input_mint.to_bytes().to_vec(),
+            output_mint: v.output_mint.to_bytes().to_vec(),
+            in_amount: v.in_amount,
+            out_amount: v.out_amount,
+            quoted_out_amount: v.quoted_out_amount,
+            quoted_in_amount: v.quoted_in_amount,
+            slippage_bps: u32::from(v.slippage_bps),
+            platform_fee_bps: u32::from(v.platform_fee_bps),
+            hops: v.hops.iter().map(pb::JupiterSwapHop::from).collect(),
+            platform_fee_account: v.platform_fee_account.map(|x| x.to_bytes().to_vec()),
+            platform_fee_mint: v.platform_fee_mint.map(|x| x.to_bytes().to_vec()),
+            platform_fee_amount: v.platform_fee_amount,
+        }
+    }
+}
+
+impl From<&jupiter_v6::JupiterSwapEvent> for pb::JupiterSwapEvent {
+    fn from(v: &jupiter_v6::JupiterSwapEvent) -> Self {
+        Self {
+            metadata: Some((&v.metadata).into()),
+            amm: v.amm.to_bytes().to_vec(),
+            input_mint: v.input_mint.to_bytes().to_vec(),
+            input_amount: v.input_amount,
+            output_mint: v.output_mint.to_bytes().to_vec(),
+            output_amount: v.output_amount,
+        }
+    }
+}
+
+impl From<&orca_whirlpool::OrcaWhirlpoolSwapEvent> for pb::OrcaWhirlpoolSwapEvent {
+    fn from(v: &orca_whirlpool::OrcaWhirlpoolSwapEvent) -> Self {
+        Self {
+            metadata: Some((&v.metadata).into()),
+            amount: v.amount,
+            other_amount_threshold: v.other_amount_threshold,
+            sqrt_price_limit: v.sqrt_price_limit.to_string(),
+            amount_specified_is_input: v.amount_specified_is_input,
+            a_to_b: v.a_to_b,
+            token_program: v.token_program.to_bytes().to_vec(),
+            token_authority: v.token_authority.to_bytes().to_vec(),
+            whirlpool: v.whirlpool.to_bytes().to_vec(),
+            token_owner_account_a: v.token_owner_account_a.to_bytes().to_vec(),
+            token_vault_a: v.token_vault_a.to_bytes().to_vec(),
+            token_owner_account_b: v.token_owner_account_b.to_bytes().to_vec(),
+            token_vault_b: v.token_vault_b.to_bytes().to_vec(),
+            tick_array0: v.tick_array0.to_bytes().to_vec(),
+            tick_array1: v.tick_array1.to_bytes().to_vec(),
+            tick_array2: v.tick_array2.to_bytes().to_vec(),
+            oracle: v.oracle.to_bytes().to_vec(),
+        }
+    }
+}
+
+impl From<&orca_whirlpool::OrcaWhirlpoolSwapV2Event> for pb::OrcaWhirlpoolSwapV2Event {
+    fn from(v: &orca_whirlpool::OrcaWhirlpoolSwapV2Event) -> Self {
+        Self {
+            metadata: Some((&v.metadata).into()),
+            amount: v.amount,
+            other_amount_threshold: v.other_amount_threshold,
+            sqrt_price_limit: v.sqrt_price_limit.to_string(),
+            amount_specified_is_input: v.amount_specified_is_input,
+            a_to_b: v.a_to_b,
+            token_program_a: v.token_program_a.to_bytes().to_vec(),
+            token_program_b: v.token_program_b.to_bytes().to_vec(),
+            memo_program: v.memo_program.to_bytes().to_vec(),
+            token_authority: v.token_authority.to_bytes().to_vec(),
+            whirlpool: v.whirlpool.to_bytes().to_vec(),
+            token_mint_a: v.token_mint_a.to_bytes().to_vec(),
+            token_mint_b: v.token_mint_b.to_bytes().to_vec(),
+            token_owner_account_a: v.token_owner_account_a.to_bytes().to_vec(),
+            token_vault_a: v.token_vault_a.to_bytes().to_vec(),
+            token_owner_account_b: v.token_owner_account_b.to_bytes().to_vec(),
+            token_vault_b: v.token_vault_b.to_bytes().to_vec(),
+            tick_array0: v.tick_array0.to_bytes().to_vec(),
+            tick_array1: v.tick_array1.to_bytes().to_vec(),
+            tick_array2: v.tick_array2.to_bytes().to_vec(),
+            oracle: v.oracle.to_bytes().to_vec(),
+            remaining_accounts: v
+                .remaining_accounts
+                .iter()
+                .map(|x| x.to_bytes().to_vec())
+                .collect(),
+        }
+    }
+}
+
+impl From<&orca_whirlpool::OrcaWhirlpoolTwoHopSwapEvent> for pb::OrcaWhirlpoolTwoHopSwapEvent {
+    fn from(v: &orca_whirlpool::OrcaWhirlpoolTwoHopSwapEvent) -> Self {
+        Self {
+            metadata: Some((&v.metadata).into()),
+            amount: v.amount,
+            other_amount_threshold: v.other_amount_threshold,
+            amount_specified_is_input: v.amount_specified_is_input,
+            a_to_b_one: v.a_to_b_one,
+            a_to_b_two: v.a_to_b_two,
+            sqrt_price_limit_one: v.sqrt_price_limit_one.to_string(),
+            sqrt_price_limit_two: v.sqrt_price_limit_two.to_string(),
+            token_program: v.token_program.to_bytes().to_vec(),
+            token_authority: v.token_authority.to_bytes().to_vec(),
+            whirlpool_one: v.whirlpool_one.to_bytes().to_vec(),
+            whirlpool_two: v.whirlpool_two.to_bytes().to_vec(),
+            token_owner_account_one_a: v.token_owner_account_one_a.to_bytes().to_vec(),
+            token_vault_one_a: v.token_vault_one_a.to_bytes().to_vec(),
+            token_owner_account_one_b: v.token_owner_account_one_b.to_bytes().to_vec(),
+            token_vault_one_b: v.token_vault_one_b.to_bytes().to_vec(),
+            token_owner_account_two_a: v.token_owner_account_two_a.to_bytes().to_vec(),
+            token_vault_two_a: v.token_vault_two_a.to_bytes().to_vec(),
+            token_owner_account_two_b: v.token_owner_account_two_b.to_bytes().to_vec(),
+            token_vault_two_b: v.token_vault_two_b.to_bytes().to_vec(),
+            tick_array_one0: v.tick_array_one0.to_bytes().to_vec(),
+            tick_array_one1: v.tick_array_one1.to_bytes().to_vec(),
+            tick_array_one2: v.tick_array_one2.to_bytes().to_vec(),
+            tick_array_two0: v.tick_array_two0.to_bytes().to_vec(),
+            tick_array_two1: v.tick_array_two1.to_bytes().to_vec(),
+            tick_array_two2: v.tick_array_two2.to_bytes().to_vec(),
+            oracle_one: v.oracle_one.to_bytes().to_vec(),
+            oracle_two: v.oracle_two.to_bytes().to_vec(),
+        }
+    }
+}
+
+impl From<&orca_whirlpool::OrcaWhirlpoolIncreaseLiquidityEvent>
+    for pb::OrcaWhirlpoolIncreaseLiquidityEvent
+{
+    fn from(v: &orca_whirlpool::OrcaWhirlpoolIncreaseLiquidityEvent) -> Self {
+        Self {
+            metadata: Some((&v.metadata).into()),
+            liquidity_amount: v.liquidity_amount.to_string(),
+            token_max_a: v.token_max_a,
+            token_max_b: v.token_max_b,
+            whirlpool: v.whirlpool.to_bytes().to_vec(),
+            token_program: v.token_program.to_bytes().to_vec(),
+            position_authority: v.position_authority.to_bytes().to_vec(),
+            position: v.position.to_bytes().to_vec(),
+            position_token_account: v.position_token_account.to_bytes().to_vec(),
+            token_owner_account_a: v.token_owner_account_a.to_bytes().to_vec(),
+            token_owner_account_b: v.token_owner_account_b.to_bytes().to_vec(),
+            token_vault_a: v.token_vault_a.to_bytes().to_vec(),
+            token_vault_b: v.token_vault_b.to_bytes().to_vec(),
+            tick_array_lower: v.tick_array_lower.to_bytes().to_vec(),
+            tick_array_upper: v.tick_array_upper.to_bytes().to_vec(),
+        }
+    }
+}
+
+impl From<&orca_whirlpool::OrcaWhirlpoolDecreaseLiquidityEvent>
+    for pb::OrcaWhirlpoolDecreaseLiquidityEvent
+{
+    fn from(v: &orca_whirlpool::OrcaWhirlpoolDecreaseLiquidityEvent) -> Self {
+        Self {
+            metadata: Some((&v.metadata).into()),
+            liquidity_amount: v.liquidity_amount.to_string(),
+            token_min_a: v.token_min_a,
+            token_min_b: v.token_min_b,
+            whirlpool: v.whirlpool.to_bytes().to_vec(),
+            token_program: v.token_program.to_bytes().to_vec(),
+            position_authority: v.position_authority.to_bytes().to_vec(),
+            position: v.position.to_bytes().to_vec(),
+            position_token_account: v.position_token_account.to_bytes().to_vec(),
+            token_owner_account_a: v.token_owner_account_a.to_bytes().to_vec(),
+            token_owner_account_b: v.token_owner_account_b.to_bytes().to_vec(),
+            token_vault_a: v.token_vault_a.to_bytes().to_vec(),
+            token_vault_b: v.token_vault_b.to_bytes().to_vec(),
+            tick_array_lower: v.tick_array_lower.to_bytes().to_vec(),
+            tick_array_upper: v.tick_array_upper.to_bytes().to_vec(),
+        }
+    }
+}
+
+impl From<&orca_whirlpool::OrcaWhirlpoolInitializePoolEvent>
+    for pb::OrcaWhirlpoolInitializePoolEvent
+{
+    fn from(v: &orca_whirlpool::OrcaWhirlpoolInitializePoolEvent) -> Self {
+        Self {
+            metadata: Some((&v.metadata).into()),
+            whirlpool_bump: u32::from(v.whirlpool_bump),
+            tick_spacing: u32::from(v.tick_spacing),
+            initial_sqrt_price: v.initial_sqrt_price.to_string(),
+            whirlpools_config: v.whirlpools_config.to_bytes().to_vec(),
+            token_mint_a: v.token_mint_a.to_bytes().to_vec(),
+            token_mint_b: v.token_mint_b.to_bytes().to_vec(),
+            funder: v.funder.to_bytes().to_vec(),
+            whirlpool: v.whirlpool.to_bytes().to_vec(),
+            token_vault_a: v.token_vault_a.to_bytes().to_vec(),
+            token_vault_b: v.token_vault_b.to_bytes().to_vec(),
+            fee_tier: v.fee_tier.to_bytes().to_vec(),
+            token_program: v.token_program.to_bytes().to_vec(),
+            system_program: v.system_program.to_bytes().to_vec(),
+            rent: v.rent.to_bytes().to_vec(),
+        }
+    }
+}
+
+impl From<&orca_whirlpool::OrcaWhirlpoolOpenPositionEvent> for pb::OrcaWhirlpoolOpenPositionEvent {
+    fn from(v: &orca_whirlpool::OrcaWhirlpoolOpenPositionEvent) -> Self {
+        Self {
+            metadata: Some((&v.metadata).into()),
+            position_bump: u32::from(v.position_bump),
+            tick_lower_index: v.tick_lower_index,
+            tick_upper_index: v.tick_upper_index,
+            funder: v.funder.to_bytes().to_vec(),
+            owner: v.owner.to_bytes().to_vec(),
+            position: v.position.to_bytes().to_vec(),
+            position_mint: v.position_mint.to_bytes().to_vec(),
+            position_token_account: v.position_token_account.to_bytes().to_vec(),
+            whirlpool: v.whirlpool.to_bytes().to_vec(),
+            token_program: v.token_program.to_bytes().to_vec(),
+            system_program: v.system_program.to_bytes().to_vec(),
+            rent: v.rent.to_bytes().to_vec(),
+            associated_token_program: v.associated_token_program.to_bytes().to_vec(),
+        }
+    }
+}
+
+impl From<&orca_whirlpool::types::WhirlpoolRewardInfo> for pb::OrcaWhirlpoolRewardInfo {
+    fn from(v: &orca_whirlpool::types::WhirlpoolRewardInfo) -> Self {
+        Self {
+            mint: v.mint.to_bytes().to_vec(),
+            vault: v.vault.to_bytes().to_vec(),
+            authority: v.authority.to_bytes().to_vec(),
+            emissions_per_second_x64: v.emissions_per_second_x64.to_string(),
+            growth_global_x64: v.growth_global_x64.to_string(),
+        }
+    }
+}
+
+impl From<&orca_whirlpool::types::Whirlpool> for pb::OrcaWhirlpool {
+    fn from(v: &orca_whirlpool::types::Whirlpool) -> Self {
+        Self {
+            whirlpools_config: v.whirlpools_config.to_bytes().to_vec(),
+            whirlpool_bump: v.whirlpool_bump.to_vec(),
+            tick_spacing: u32::from(v.tick_spacing),
+            fee_tier_index_seed: v.fee_tier_index_seed.to_vec(),
+            fee_rate: u32::from(v.fee_rate),
+            protocol_fee_rate: u32::from(v.protocol_fee_rate),
+            liquidity: v.liquidity.to_string(),
+            sqrt_price: v.sqrt_price.to_string(),
+            tick_current_index: v.tick_current_index,
+            protocol_fee_owed_a: v.protocol_fee_owed_a,
+            protocol_fee_owed_b: v.protocol_fee_owed_b,
+            token_mint_a: v.token_mint_a.to_bytes().to_vec(),
+            token_vault_a: v.token_vault_a.to_bytes().to_vec(),
+            fee_growth_global_a: v.fee_growth_global_a.to_string(),
+            token_mint_b: v.token_mint_b.to_bytes().to_vec(),
+            token_vault_b: v.token_vault_b.to_bytes().to_vec(),
+            fee_growth_global_b: v.fee_growth_global_b.to_string(),
+            reward_last_updated_timestamp: v.reward_last_updated_timestamp,
+            reward_infos: v.reward_infos.iter().map(pb::OrcaWhirlpoolRewardInfo::from).collect(),
+        }
+    }
+}
+
+impl From<&orca_whirlpool::OrcaWhirlpoolPoolAccountEvent> for pb::OrcaWhirlpoolPoolAccountEvent {
+    fn from(v: &orca_whirlpool::OrcaWhirlpoolPoolAccountEvent) -> Self {
+        Self {
+            metadata: Some((&v.metadata).into()),
+            pubkey: v.pubkey.to_bytes().to_vec(),
+            executable: v.executable,
+            lamports: v.lamports,
+            owner: v.owner.to_bytes().to_vec(),
+            rent_epoch: v.rent_epoch,
+            whirlpool: Some((&v.whirlpool).into()),
+        }
+    }
+}
+
+impl From<&orca_whirlpool::types::Tick> for pb::OrcaWhirlpoolTick {
+    fn from(v: &orca_whirlpool::types::Tick) -> Self {
+        Self {
+            initialized: v.initialized,
+            liquidity_net: v.liquidity_net.to_string(),
+            liquidity_gross: v.liquidity_gross.to_string(),
+            fee_growth_outside_a: v.fee_growth_outside_a.to_string(),
+            fee_growth_outside_b: v.fee_growth_outside_b.to_string(),
+            reward_growths_outside: v
+                .reward_growths_outside
+                .iter()
+                .map(|x| x.to_string())
+                .collect(),
+        }
+    }
+}
+
+impl From<&orca_whirlpool::types::TickArray> for pb::OrcaWhirlpoolTickArray {
+    fn from(v: &orca_whirlpool::types::TickArray) -> Self {
+        Self {
+            start_tick_index: v.start_tick_index,
+            ticks: v.ticks.iter().map(pb::OrcaWhirlpoolTick::from).collect(),
+            whirlpool: v.whirlpool.to_bytes().to_vec(),
+        }
+    }
+}
+
+impl From<&orca_whirlpool::OrcaWhirlpoolTickArrayAccountEvent>
+    for pb::OrcaWhirlpoolTickArrayAccountEvent
+{
+    fn from(v: &orca_whirlpool::OrcaWhirlpoolTickArrayAccountEvent) -> Self {
+        Self {
+            metadata: Some((&v.metadata).into()),
+            pubkey: v.pubkey.to_bytes().to_vec(),
+            executable: v.executable,
+            lamports: v.lamports,
+            owner: v.owner.to_bytes().to_vec(),
+            rent_epoch: v.rent_epoch,
+            tick_array: Some((&v.tick_array).into()),
+        }
+    }
+}
+
+impl From<&pumpfun::PumpFunCreateTokenEvent> for pb::Event {
+    fn from(v: &pumpfun::PumpFunCreateTokenEvent) -> Self {
+        Self { event: Some(pb::event::Event::PumpFunCreateTokenEvent(v.into())) }
+    }
+}
+
+impl From<&pumpfun::PumpFunTradeEvent> for pb::Event {
+    fn from(v: &pumpfun::PumpFunTradeEvent) -> Self {
+        Self { event: Some(pb::event::Event::PumpFunTradeEvent(v.into())) }
+    }
+}
+
+impl From<&pumpfun::PumpFunMigrateEvent> for pb::Event {
+    fn from(v: &pumpfun::PumpFunMigrateEvent) -> Self {
+        Self { event: Some(pb::event::Event::PumpFunMigrateEvent(v.into())) }
+    }
+}
+
+impl From<&pumpfun::PumpFunBondingCurveAccountEvent> for pb::Event {
+    fn from(v: &pumpfun::PumpFunBondingCurveAccountEvent) -> Self {
+        Self { event: Some(pb::event::Event::PumpFunBondingCurveAccountEvent(v.into())) }
+    }
+}
+
+impl From<&pumpfun::PumpFunGlobalAccountEvent> for pb::Event {
+    fn from(v: &pumpfun::PumpFunGlobalAccountEvent) -> Self {
+        Self { event: Some(pb::event::Event::PumpFunGlobalAccountEvent(v.into())) }
+    }
+}
+
+impl From<&pumpswap::PumpSwapBuyEvent> for pb::Event {
+    fn from(v: &pumpswap::PumpSwapBuyEvent) -> Self {
+        Self { event: Some(pb::event::Event::PumpSwapBuyEvent(v.into())) }
+    }
+}
+
+impl From<&pumpswap::PumpSwapSellEvent> for pb::Event {
+    fn from(v: &pumpswap::PumpSwapSellEvent) -> Self {
+        Self { event: Some(pb::event::Event::PumpSwapSellEvent(v.into())) }
+    }
+}
+
+impl From<&pumpswap::PumpSwapCreatePoolEvent> for pb::Event {
+    fn from(v: &pumpswap::PumpSwapCreatePoolEvent) -> Self {
+        Self { event: Some(pb::event::Event::PumpSwapCreatePoolEvent(v.into())) }
+    }
+}
+
+impl From<&pumpswap::PumpSwapDepositEvent> for pb::Event {
+    fn from(v: &pumpswap::PumpSwapDepositEvent) -> Self {
+        Self { event: Some(pb::event::Event::PumpSwapDepositEvent(v.into())) }
+    }
+}
+
+impl From<&pumpswap::PumpSwapWithdrawEvent> for pb::Event {
+    fn from(v: &pumpswap::PumpSwapWithdrawEvent) -> Self {
+        Self { event: Some(pb::event::Event::PumpSwapWithdrawEvent(v.into())) }
+    }
+}
+
+impl From<&pumpswap::PumpSwapGlobalConfigAccountEvent> for pb::Event {
+    fn from(v: &pumpswap::PumpSwapGlobalConfigAccountEvent) -> Self {
+        Self { event: Some(pb::event::Event::PumpSwapGlobalConfigAccountEvent(v.into())) }
+    }
+}
+
+impl From<&pumpswap::PumpSwapPoolAccountEvent> for pb::Event {
+    fn from(v: &pumpswap::PumpSwapPoolAccountEvent) -> Self {
+        Self { event: Some(pb::event::Event::PumpSwapPoolAccountEvent(v.into())) }
+    }
+}
+
+impl From<&bonk::BonkTradeEvent> for pb::Event {
+    fn from(v: &bonk::BonkTradeEvent) -> Self {
+        Self { event: Some(pb::event::Event::BonkTradeEvent(v.into())) }
+    }
+}
+
+impl From<&bonk::BonkPoolCreateEvent> for pb::Event {
+    fn from(v: &bonk::BonkPoolCreateEvent) -> Self {
+        Self { event: Some(pb::event::Event::BonkPoolCreateEvent(v.into())) }
+    }
+}
+
+impl From<&bonk::BonkMigrateToAmmEvent> for pb::Event {
+    fn from(v: &bonk::BonkMigrateToAmmEvent) -> Self {
+        Self { event: Some(pb::event::Event::BonkMigrateToAmmEvent(v.into())) }
+    }
+}
+
+impl From<&bonk::BonkMigrateToCpswapEvent> for pb::Event {
+    fn from(v: &bonk::BonkMigrateToCpswapEvent) -> Self {
+        Self { event: Some(pb::event::Event::BonkMigrateToCpswapEvent(v.into())) }
+    }
+}
+
+impl From<&bonk::BonkPoolStateAccountEvent> for pb::Event {
+    fn from(v: &bonk::BonkPoolStateAccountEvent) -> Self {
+        Self { event: Some(pb::event::Event::BonkPoolStateAccountEvent(v.into())) }
+    }
+}
+
+impl From<&bonk::BonkGlobalConfigAccountEvent> for pb::Event {
+    fn from(v: &bonk::BonkGlobalConfigAccountEvent) -> Self {
+        Self { event: Some(pb::event::Event::BonkGlobalConfigAccountEvent(v.into())) }
+    }
+}
+
+impl From<&bonk::BonkPlatformConfigAccountEvent> for pb::Event {
+    fn from(v: &bonk::BonkPlatformConfigAccountEvent) -> Self {
+        Self { event: Some(pb::event::Event::BonkPlatformConfigAccountEvent(v.into())) }
+    }
+}
+
+impl From<&raydium_cpmm::RaydiumCpmmSwapEvent> for pb::Event {
+    fn from(v: &raydium_cpmm::RaydiumCpmmSwapEvent) -> Self {
+        Self { event: Some(pb::event::Event::RaydiumCpmmSwapEvent(v.into())) }
+    }
+}
+
+impl From<&raydium_cpmm::RaydiumCpmmDepositEvent> for pb::Event {
+    fn from(v: &raydium_cpmm::RaydiumCpmmDepositEvent) -> Self {
+        Self { event: Some(pb::event::Event::RaydiumCpmmDepositEvent(v.into())) }
+    }
+}
+
+impl From<&raydium_cpmm::RaydiumCpmmInitializeEvent> for pb::Event {
+    fn from(v: &raydium_cpmm::RaydiumCpmmInitializeEvent) -> Self {
+        Self { event: Some(pb::event::Event::RaydiumCpmmInitializeEvent(v.into())) }
+    }
+}
+
+impl From<&raydium_cpmm::RaydiumCpmmWithdrawEvent> for pb::Event {
+    fn from(v: &raydium_cpmm::RaydiumCpmmWithdrawEvent) -> Self {
+        Self { event: Some(pb::event::Event::RaydiumCpmmWithdrawEvent(v.into())) }
+    }
+}
+
+impl From<&raydium_cpmm::RaydiumCpmmAmmConfigAccountEvent> for pb::Event {
+    fn from(v: &raydium_cpmm::RaydiumCpmmAmmConfigAccountEvent) -> Self {
+        Self { event: Some(pb::event::Event::RaydiumCpmmAmmConfigAccountEvent(v.into())) }
+    }
+}
+
+impl From<&raydium_cpmm::RaydiumCpmmPoolStateAccountEvent> for pb::Event {
+    fn from(v: &raydium_cpmm::RaydiumCpmmPoolStateAccountEvent) -> Self {
+        Self { event: Some(pb::event::Event::RaydiumCpmmPoolStateAccountEvent(v.into())) }
+    }
+}
+
+impl From<&raydium_clmm::RaydiumClmmSwapEvent> for pb::Event {
+    fn from(v: &raydium_clmm::RaydiumClmmSwapEvent) -> Self {
+        Self { event: Some(pb::event::Event::RaydiumClmmSwapEvent(v.into())) }
+    }
+}
+
+impl From<&raydium_clmm::RaydiumClmmSwapV2Event> for pb::Event {
+    fn from(v: &raydium_clmm::RaydiumClmmSwapV2Event) -> Self {
+        Self { event: Some(pb::event::Event::RaydiumClmmSwapV2Event(v.into())) }
+    }
+}
+
+impl From<&raydium_clmm::RaydiumClmmClosePositionEvent> for pb::Event {
+    fn from(v: &raydium_clmm::RaydiumClmmClosePositionEvent) -> Self {
+        Self { event: Some(pb::event::Event::RaydiumClmmClosePositionEvent(v.into())) }
+    }
+}
+
+impl From<&raydium_clmm::RaydiumClmmDecreaseLiquidityV2Event> for pb::Event {
+    fn from(v: &raydium_clmm::RaydiumClmmDecreaseLiquidityV2Event) -> Self {
+        Self { event: Some(pb::event::Event::RaydiumClmmDecreaseLiquidityV2Event(v.into())) }
+    }
+}
+
+impl From<&raydium_clmm::RaydiumClmmCreatePoolEvent> for pb::Event {
+    fn from(v: &raydium_clmm::RaydiumClmmCreatePoolEvent) -> Self {
+        Self { event: Some(pb::event::Event::RaydiumClmmCreatePoolEvent(v.into())) }
+    }
+}
+
+impl From<&raydium_clmm::RaydiumClmmIncreaseLiquidityV2Event> for pb::Event {
+    fn from(v: &raydium_clmm::RaydiumClmmIncreaseLiquidityV2Event) -> Self {
+        Self { event: Some(pb::event::Event::RaydiumClmmIncreaseLiquidityV2Event(v.into())) }
+    }
+}
+
+impl From<&raydium_clmm::RaydiumClmmOpenPositionWithToken22NftEvent> for pb::Event {
+    fn from(v: &raydium_clmm::RaydiumClmmOpenPositionWithToken22NftEvent) -> Self {
+        Self { event: Some(pb::event::Event::RaydiumClmmOpenPositionWithToken22NftEvent(v.into())) }
+    }
+}
+
+impl From<&raydium_clmm::RaydiumClmmOpenPositionV2Event> for pb::Event {
+    fn from(v: &raydium_clmm::RaydiumClmmOpenPositionV2Event) -> Self {
+        Self { event: Some(pb::event::Event::RaydiumClmmOpenPositionV2Event(v.into())) }
+    }
+}
+
+impl From<&raydium_clmm::RaydiumClmmAmmConfigAccountEvent> for pb::Event {
+    fn from(v: &raydium_clmm::RaydiumClmmAmmConfigAccountEvent) -> Self {
+        Self { event: Some(pb::event::Event::RaydiumClmmAmmConfigAccountEvent(v.into())) }
+    }
+}
+
+impl From<&raydium_clmm::RaydiumClmmPoolStateAccountEvent> for pb::Event {
+    fn from(v: &raydium_clmm::RaydiumClmmPoolStateAccountEvent) -> Self {
+        Self { event: Some(pb::event::Event::RaydiumClmmPoolStateAccountEvent(v.into())) }
+    }
+}
+
+impl From<&raydium_clmm::RaydiumClmmTickArrayStateAccountEvent> for pb::Event {
+    fn from(v: &raydium_clmm::RaydiumClmmTickArrayStateAccountEvent) -> Self {
+        Self { event: Some(pb::event::Event::RaydiumClmmTickArrayStateAccountEvent(v.into())) }
+    }
+}
+
+impl From<&raydium_amm_v4::RaydiumAmmV4SwapEvent> for pb::Event {
+    fn from(v: &raydium_amm_v4::RaydiumAmmV4SwapEvent) -> Self {
+        Self { event: Some(pb::event::Event::RaydiumAmmV4SwapEvent(v.into())) }
+    }
+}
+
+impl From<&raydium_amm_v4::RaydiumAmmV4DepositEvent> for pb::Event {
+    fn from(v: &raydium_amm_v4::RaydiumAmmV4DepositEvent) -> Self {
+        Self { event: Some(pb::event::Event::RaydiumAmmV4DepositEvent(v.into())) }
+    }
+}
+
+impl From<&raydium_amm_v4::RaydiumAmmV4Initialize2Event> for pb::Event {
+    fn from(v: &raydium_amm_v4::RaydiumAmmV4Initialize2Event) -> Self {
+        Self { event: Some(pb::event::Event::RaydiumAmmV4Initialize2Event(v.into())) }
+    }
+}
+
+impl From<&raydium_amm_v4::RaydiumAmmV4WithdrawEvent> for pb::Event {
+    fn from(v: &raydium_amm_v4::RaydiumAmmV4WithdrawEvent) -> Self {
+        Self { event: Some(pb::event::Event::RaydiumAmmV4WithdrawEvent(v.into())) }
+    }
+}
+
+impl From<&raydium_amm_v4::RaydiumAmmV4WithdrawPnlEvent> for pb::Event {
+    fn from(v: &raydium_amm_v4::RaydiumAmmV4WithdrawPnlEvent) -> Self {
+        Self { event: Some(pb::event::Event::RaydiumAmmV4WithdrawPnlEvent(v.into())) }
+    }
+}
+
+impl From<&raydium_amm_v4::RaydiumAmmV4AmmInfoAccountEvent> for pb::Event {
+    fn from(v: &raydium_amm_v4::RaydiumAmmV4AmmInfoAccountEvent) -> Self {
+        Self { event: Some(pb::event::Event::RaydiumAmmV4AmmInfoAccountEvent(v.into())) }
+    }
+}
+
+impl From<&photon::PhotonPumpFunTradeEvent> for pb::Event {
+    fn from(v: &photon::PhotonPumpFunTradeEvent) -> Self {
+        Self { event: Some(pb::event::Event::PhotonPumpFunTradeEvent(v.into())) }
+    }
+}
+
+impl From<&photon::PhotonPumpSwapTradeEvent> for pb::Event {
+    fn from(v: &photon::PhotonPumpSwapTradeEvent) -> Self {
+        Self { event: Some(pb::event::Event::PhotonPumpSwapTradeEvent(v.into())) }
+    }
+}
+
+impl From<&axiom::AxiomPumpFunTradeEvent> for pb::Event {
+    fn from(v: &axiom::AxiomPumpFunTradeEvent) -> Self {
+        Self { event: Some(pb::event::Event::AxiomPumpFunTradeEvent(v.into())) }
+    }
+}
+
+impl From<&axiom2::AxiomPumpSwapBuyEvent> for pb::Event {
+    fn from(v: &axiom2::AxiomPumpSwapBuyEvent) -> Self {
+        Self { event: Some(pb::event::Event::AxiomPumpSwapBuyEvent(v.into())) }
+    }
+}
+
+impl From<&meteora_dbc::MeteoraDBCSwapEvent> for pb::Event {
+    fn from(v: &meteora_dbc::MeteoraDBCSwapEvent) -> Self {
+        Self { event: Some(pb::event::Event::MeteoraDbcSwapEvent(v.into())) }
+    }
+}
+
+impl From<&meteora_dammv2::MeteoraDAMMv2SwapEvent> for pb::Event {
+    fn from(v: &meteora_dammv2::MeteoraDAMMv2SwapEvent) -> Self {
+        Self { event: Some(pb::event::Event::MeteoraDammV2SwapEvent(v.into())) }
+    }
+}
+
+impl From<&core::account_event_parser::TokenAccountEvent> for pb::Event {
+    fn from(v: &core::account_event_parser::TokenAccountEvent) -> Self {
+        Self { event: Some(pb::event::Event::TokenAccountEvent(v.into())) }
+    }
+}
+
+impl From<&core::account_event_parser::NonceAccountEvent> for pb::Event {
+    fn from(v: &core::account_event_parser::NonceAccountEvent) -> Self {
+        Self { event: Some(pb::event::Event::NonceAccountEvent(v.into())) }
+    }
+}
+
+impl From<&core::account_event_parser::TokenInfoEvent> for pb::Event {
+    fn from(v: &core::account_event_parser::TokenInfoEvent) -> Self {
+        Self { event: Some(pb::event::Event::TokenInfoEvent(v.into())) }
+    }
+}
+
+impl From<&block::block_meta_event::BlockMetaEvent> for pb::Event {
+    fn from(v: &block::block_meta_event::BlockMetaEvent) -> Self {
+        Self { event: Some(pb::event::Event::BlockMetaEvent(v.into())) }
+    }
+}
+
+impl From<&core::balance_change::BalanceChangeEvent> for pb::Event {
+    fn from(v: &core::balance_change::BalanceChangeEvent) -> Self {
+        Self { event: Some(pb::event::Event::BalanceChangeEvent(v.into())) }
+    }
+}
+
+impl From<&core::normalized_trade::NormalizedTrade> for pb::Event {
+    fn from(v: &core::normalized_trade::NormalizedTrade) -> Self {
+        Self { event: Some(pb::event::Event::NormalizedTrade(v.into())) }
+    }
+}
+
+impl From<&meteora_dbc::MeteoraDBCInitializePoolEvent> for pb::Event {
+    fn from(v: &meteora_dbc::MeteoraDBCInitializePoolEvent) -> Self {
+        Self { event: Some(pb::event::Event::MeteoraDbcInitializePoolEvent(v.into())) }
+    }
+}
+
+impl From<&core::token_launch::TokenLaunch> for pb::Event {
+    fn from(v: &core::token_launch::TokenLaunch) -> Self {
+        Self { event: Some(pb::event::Event::TokenLaunch(v.into())) }
+    }
+}
+
+impl From<&meteora_dbc::MeteoraDBCMigrationDammV2Event> for pb::Event {
+    fn from(v: &meteora_dbc::MeteoraDBCMigrationDammV2Event) -> Self {
+        Self { event: Some(pb::event::Event::MeteoraDbcMigrationDammV2Event(v.into())) }
+    }
+}
+
+impl From<&core::graduation::Graduation> for pb::Event {
+    fn from(v: &core::graduation::Graduation) -> Self {
+        Self { event: Some(pb::event::Event::Graduation(v.into())) }
+    }
+}
+
+impl From<&core::liquidity_change::LiquidityChange> for pb::Event {
+    fn from(v: &core::liquidity_change::LiquidityChange) -> Self {
+        Self { event: Some(pb::event::Event::LiquidityChange(v.into())) }
+    }
+}
+
+impl From<&core::parse_diagnostics::UnparsedInstructionEvent> for pb::Event {
+    fn from(v: &core::parse_diagnostics::UnparsedInstructionEvent) -> Self {
+        Self { event: Some(pb::event::Event::UnparsedInstructionEvent(v.into())) }
+    }
+}
+
+impl From<&core::raw_instruction::RawInstructionEvent> for pb::Event {
+    fn from(v: &core::raw_instruction::RawInstructionEvent) -> Self {
+        Self { event: Some(pb::event::Event::RawInstructionEvent(v.into())) }
+    }
+}
+
+impl From<&jupiter_v6::JupiterRouteEvent> for pb::Event {
+    fn from(v: &jupiter_v6::JupiterRouteEvent) -> Self {
+        Self { event: Some(pb::event::Event::JupiterRouteEvent(v.into())) }
+    }
+}
+
+impl From<&jupiter_v6::JupiterSwapEvent> for pb::Event {
+    fn from(v: &jupiter_v6::JupiterSwapEvent) -> Self {
+        Self { event: Some(pb::event::Event::JupiterSwapEvent(v.into())) }
+    }
+}
+
+impl From<&orca_whirlpool::OrcaWhirlpoolSwapEvent> for pb::Event {
+    fn from(v: &orca_whirlpool::OrcaWhirlpoolSwapEvent) -> Self {
+        Self { event: Some(pb::event::Event::OrcaWhirlpoolSwapEvent(v.into())) }
+    }
+}
+
+impl From<&orca_whirlpool::OrcaWhirlpoolSwapV2Event> for pb::Event {
+    fn from(v: &orca_whirlpool::OrcaWhirlpoolSwapV2Event) -> Self {
+        Self { event: Some(pb::event::Event::OrcaWhirlpoolSwapV2Event(v.into())) }
+    }
+}
+
+impl From<&orca_whirlpool::OrcaWhirlpoolTwoHopSwapEvent> for pb::Event {
+    fn from(v: &orca_whirlpool::OrcaWhirlpoolTwoHopSwapEvent) -> Self {
+        Self { event: Some(pb::event::Event::OrcaWhirlpoolTwoHopSwapEvent(v.into())) }
+    }
+}
+
+impl From<&orca_whirlpool::OrcaWhirlpoolIncreaseLiquidityEvent> for pb::Event {
+    fn from(v: &orca_whirlpool::OrcaWhirlpoolIncreaseLiquidityEvent) -> Self {
+        Self { event: Some(pb::event::Event::OrcaWhirlpoolIncreaseLiquidityEvent(v.into())) }
+    }
+}
+
+impl From<&orca_whirlpool::OrcaWhirlpoolDecreaseLiquidityEvent> for pb::Event {
+    fn from(v: &orca_whirlpool::OrcaWhirlpoolDecreaseLiquidityEvent) -> Self {
+        Self { event: Some(pb::event::Event::OrcaWhirlpoolDecreaseLiquidityEvent(v.into())) }
+    }
+}
+
+impl From<&orca_whirlpool::OrcaWhirlpoolInitializePoolEvent> for pb::Event {
+    fn from(v: &orca_whirlpool::OrcaWhirlpoolInitializePoolEvent) -> Self {
+        Self { event: Some(pb::event::Event::OrcaWhirlpoolInitializePoolEvent(v.into())) }
+    }
+}
+
+impl From<&orca_whirlpool::OrcaWhirlpoolOpenPositionEvent> for pb::Event {
+    fn from(v: &orca_whirlpool::OrcaWhirlpoolOpenPositionEvent) -> Self {
+        Self { event: Some(pb::event::Event::OrcaWhirlpoolOpenPositionEvent(v.into())) }
+    }
+}
+
+impl From<&orca_whirlpool::OrcaWhirlpoolPoolAccountEvent> for pb::Event {
+    fn from(v: &orca_whirlpool::OrcaWhirlpoolPoolAccountEvent) -> Self {
+        Self { event: Some(pb::event::Event::OrcaWhirlpoolPoolAccountEvent(v.into())) }
+    }
+}
+
+impl From<&orca_whirlpool::OrcaWhirlpoolTickArrayAccountEvent> for pb::Event {
+    fn from(v: &orca_whirlpool::OrcaWhirlpoolTickArrayAccountEvent) -> Self {
+        Self { event: Some(pb::event::Event::OrcaWhirlpoolTickArrayAccountEvent(v.into())) }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::collections::HashSet;
+
+    use std::sync::Arc;
+
+    use prost::Message;
+
+    use solana_sdk::{
+        hash::Hash, instruction::InstructionError, pubkey::Pubkey, signature::Signature,
+        transaction::TransactionError,
+    };
+
+    use super::pb;
+    use crate::streaming::event_parser::{
+        common::{
+            EventMetadata, EventType, InstructionInvocation, ProtocolType, RouterInfo, SwapData,
+            TransactionContext,
+        },
+        core::dex_event::DexEvent,
+        protocols::{bonk, pumpfun},
+        UnifiedEvent,
+    };
+
+    fn roundtrip(event: &pb::Event) -> pb::Event {
+        pb::Event::decode(event.encode_to_vec().as_slice()).expect("decode")
+    }
+
+    #[test]
+    fn every_oneof_arm_roundtrips() {
+        let mut arms = HashSet::new();
+        for event in DexEvent::all_defaults() {
+            let name = event.name();
+            let encoded = event.to_proto().unwrap_or_else(|| panic!("{name} has no protobuf arm"));
+            assert_eq!(roundtrip(&encoded), encoded, "{name} does not survive encode/decode");
+            let arm = std::mem::discriminant(encoded.event.as_ref().expect("oneof set"));
+            assert!(arms.insert(arm), "{name} shares a oneof arm with another event");
+        }
+    }
+
+    /// events.proto 中 `Event` oneof 的全部字段编号
+    fn proto_event_tags() -> Vec<u32> {
+        let proto = include_str!("../../../protos/events.proto");
+        let start = proto.find("oneof event {").expect("Event oneof");
+        let end = start + proto[start..].find('}').expect("end of oneof");
+        proto[start..end]
+            .lines()
+            .filter_map(|line| line.trim().strip_suffix(';')?.rsplit_once('='))
+            .map(|(_, tag)| tag.trim().parse().expect("oneof tag"))
+            .collect()
+    }
+
+    #[test]
+    fn every_proto_oneof_arm_has_an_event() {
+        assert_eq!(
+            proto_event_tags().len(),
+            DexEvent::all_defaults().len(),
+            "events.proto and DexEvent differ"
+        );
+    }
+
+    #[test]
+    fn transaction_error_roundtrips() {
+        let errors = [
+            TransactionError::AccountInUse,
+            TransactionError::InstructionError(2, InstructionError::Custom(6001)),
+            TransactionError::InstructionError(0, InstructionError::InvalidAccountData),
+            TransactionError::InstructionError(1, InstructionError::BorshIoError("eof".into())),
+            TransactionError::DuplicateInstruction(4),
+            TransactionError::InsufficientFundsForRent { account_index: 3 },
+            TransactionError::ProgramExecutionTemporarilyRestricted { account_index: 5 },
+        ];
+        for err in errors {
+            let encoded = pb::TransactionError::from(&err);
+            let decoded = pb::TransactionError::decode(encoded.encode_to_vec().as_slice())
+                .expect("decode");
+            assert_eq!(TransactionError::try_from(&decoded), Ok(err));
+        }
+
+        let encoded = pb::TransactionError::from(&TransactionError::InstructionError(
+            2,
+            InstructionError::Custom(6001),
+        ));
+        let Some(pb::transaction_error::Error::InstructionError(err)) = encoded.error else {
+            panic!("wrong oneof arm");
+        };
+        assert_eq!(err.index, 2);
+        assert_eq!(err.error, Some(pb::instruction_error::Error::Custom(6001)));
+    }
+
+    fn sample_metadata(event_type: EventType, protocol: ProtocolType) -> EventMetadata {
+        let mut metadata = EventMetadata::new(
+            Signature::from([7u8; 64]),
+            321,
+            1_700_000_000,
+            1_700_000_000_123,
+            protocol,
+            event_type,
+            Pubkey::new_from_array([1; 32]),
+            4,
+            Some(2),
+            99,
+            Some(12),
+        );
+        metadata.set_swap_data(SwapData {
+            from_mint: Pubkey::new_from_array([2; 32]),
+            to_mint: Pubkey::new_from_array([3; 32]),
+            from_amount: 1_000,
+            to_amount: 2_000,
+            description: Some("sample".into()),
+        });
+        metadata.set_tx_context(Arc::new(TransactionContext {
+            success: Some(true),
+            err: None,
+            fee: Some(5_000),
+            compute_units_consumed: Some(80_000),
+            compute_unit_limit: Some(200_000),
+            compute_unit_price: Some(10),
+            fee_payer: Pubkey::new_from_array([4; 32]),
+            signers: vec![Pubkey::new_from_array([4; 32])],
+            recent_blockhash: Hash::new_from_array([5; 32]),
+        }));
+        metadata.set_invocation(InstructionInvocation {
+            stack_height: Some(2),
+            parent_program_id: Some(Pubkey::new_from_array([6; 32])),
+            invoking_program_id: Pubkey::new_from_array([6; 32]),
+        });
+        metadata.set_via_router(RouterInfo {
+            router: ProtocolType::PhotonProtocol,
+            router_program: Pubkey::new_from_array([6; 32]),
+            router_fee: 10_000,
+            fee_recipient: Some(Pubkey::new_from_array([8; 32])),
+            referral: None,
+        });
+        metadata
+    }
+
+    #[test]
+    fn populated_trade_event_roundtrips() {
+        let event = pumpfun::PumpFunTradeEvent {
+            metadata: sample_metadata(EventType::PumpFunBuy, ProtocolType::PumpFun),
+            mint: Pubkey::new_from_array([9; 32]),
+            sol_amount: 1_000,
+            token_amount: 2_000,
+            is_buy: true,
+            user: Pubkey::new_from_array([10; 32]),
+            fee: 30,
+            creator_fee: 5,
+            ..Default::default()
+        };
+        let encoded = event.to_proto().expect("builtin event");
+        let decoded = roundtrip(&encoded);
+        assert_eq!(decoded, encoded);
+
+        let Some(pb::event::Event::PumpFunTradeEvent(trade)) = decoded.event else {
+            panic!("wrong oneof arm");
+        };
+        assert_eq!(trade.mint, event.mint.to_bytes().to_vec());
+        assert_eq!((trade.sol_amount, trade.token_amount, trade.is_buy), (1_000, 2_000, true));
+        let metadata = trade.metadata.expect("metadata");
+        assert_eq!(metadata.signature, event.metadata.signature.as_ref().to_vec());
+        assert_eq!(metadata.event_type, "PumpFunBuy");
+        assert_eq!(metadata.protocol, "PumpFun");
+        assert_eq!((metadata.outer_index, metadata.inner_index), (4, Some(2)));
+        assert_eq!(metadata.swap_data.expect("swap_data").description.as_deref(), Some("sample"));
+        assert_eq!(metadata.tx_context.expect("tx_context").fee, Some(5_000));
+        assert_eq!(metadata.invocation.expect("invocation").stack_height, Some(2));
+        let via_router = metadata.via_router.expect("via_router");
+        assert_eq!((via_router.router.as_str(), via_router.router_fee), ("PhotonProtocol", 10_000));
+        assert_eq!(via_router.referral, None);
+    }
+
+    #[test]
+    fn nested_oneof_roundtrips() {
+        let event = bonk::BonkPoolCreateEvent {
+            metadata: sample_metadata(EventType::BonkInitialize, ProtocolType::Bonk),
+            curve_param: bonk::types::CurveParams::Linear {
+                data: bonk::types::LinearCurve {
+                    supply: 1_000_000,
+                    total_quote_fund_raising: 85,
+                    migrate_type: 1,
+                },
+            },
+            ..Default::default()
+        };
+        let encoded = event.to_proto().expect("builtin event");
+        let decoded = roundtrip(&encoded);
+        assert_eq!(decoded, encoded);
+
+        let Some(pb::event::Event::BonkPoolCreateEvent(pool)) = decoded.event else {
+            panic!("wrong oneof arm");
+        };
+        let curve = pool.curve_param.and_then(|curve| curve.value);
+        let Some(pb::bonk_curve_params::Value::Linear(linear)) = curve else {
+            panic!("wrong curve arm");
+        };
+        assert_eq!(
+            (linear.supply, linear.total_quote_fund_raising, linear.migrate_type),
+            (1_000_000, 85, 1)
+        );
+    }
+}
@@ -0,0 +1,355 @@
+use std::{fmt, sync::Arc};
+
+use solana_sdk::pubkey::Pubkey;
+
+use crate::streaming::{
+    event_parser::{
+        common::{EventMetadata, EventType, ProtocolType},
+        core::{event_parser::GenericEventParseConfig, traits::UnifiedEvent},
+    },
+    grpc::AccountPretty,
+};
+
+/// 内联指令事件解析闭包
+pub type InnerInstructionParseFn =
+    Arc<dyn Fn(&[u8], EventMetadata) -> Option<Box<dyn UnifiedEvent>> + Send + Sync>;
+
+/// 指令事件解析闭包
+pub type InstructionParseFn =
+    Arc<dyn Fn(&[u8], &[Pubkey], EventMetadata) -> Option<Box<dyn UnifiedEvent>> + Send + Sync>;
+
+/// 账户事件解析闭包
+pub type AccountParseFn =
+    Arc<dyn Fn(&AccountPretty, EventMetadata) -> Option<Box<dyn UnifiedEvent>> + Send + Sync>;
+
+/// 运行时事件解析配置
+///
+/// 与 `GenericEventParseConfig` 字段一致，但解析器为闭包、discriminator 为自有数据。
+/// 内置协议的配置在构建 `EventParser` 时会转换为此类型。
+#[derive(Clone)]
+pub struct EventParseConfig {
+    pub program_id: Pubkey,
+    pub protocol_type: ProtocolType,
+    pub inner_instruction_discriminator: Vec<u8>,
+    pub instruction_discriminator: Vec<u8>,
+    pub event_type: EventType,
+    pub inner_instruction_parser: Option<InnerInstructionParseFn>,
+    pub instruction_parser: Option<InstructionParseFn>,
+    pub requires_inner_instruction: bool,
+}
+
+impl fmt::Debug for EventParseConfig {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("EventParseConfig")
+            .field("program_id", &self.program_id)
+            .field("protocol_type", &self.protocol_type)
+            .field("inner_instruction_discriminator", &self.inner_instruction_discriminator)
+            .field("instruction_discriminator", &self.instruction_discriminator)
+            .field("event_type", &self.event_type)
+            .field("inner_instruction_parser", &self.inner_instruction_parser.is_some())
+            .field("instruction_parser", &self.instruction_parser.is_some())
+            .field("requires_inner_instruction", &self.requires_inner_instruction)
+            .finish()
+    }
+}
+
+impl From<&GenericEventParseConfig> for EventParseConfig {
+    fn from(config: &GenericEventParseConfig) -> Self {
+        Self {
+            program_id: config.program_id,
+            protocol_type: config.protocol_type.clone(),
+            inner_instruction_discriminator: config.inner_instruction_discriminator.to_vec(),
+            instruction_discriminator: config.instruction_discriminator.to_vec(),
+            event_type: config.event_type.clone(),
+            inner_instruction_parser: config
+                .inner_instruction_parser
+                .map(|parser| Arc::new(parser) as InnerInstructionParseFn),
+            instruction_parser: config
+                .instruction_parser
+                .map(|parser| Arc::new(parser) as InstructionParseFn),
+            requires_inner_instruction: config.requires_inner_instruction,
+        }
+    }
+}
+
+/// 运行时账户解析配置
+#[derive(Clone)]
+pub struct AccountParseConfig {
+    pub program_id: Pubkey,
+    pub protocol_type: ProtocolType,
+    pub event_type: EventType,
+    pub account_discriminator: Vec<u8>,
+    pub account_parser: AccountParseFn,
+}
+
+impl fmt::Debug for AccountParseConfig {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("AccountParseConfig")
+            .field("program_id", &self.program_id)
+            .field("protocol_type", &self.protocol_type)
+            .field("event_type", &self.event_type)
+            .field("account_discriminator", &self.account_discriminator)
+            .finish()
+    }
+}
+
+/// 外部注册的协议
+#[derive(Debug, Clone)]
+pub struct CustomProtocol {
+    pub name: String,
+    pub program_id: Pubkey,
+    pub event_configs: Vec<EventParseConfig>,
+    pub account_configs: Vec<AccountParseConfig>,
+}
+
+impl CustomProtocol {
+    pub fn new(name: impl Into<String>, program_id: Pubkey) -> Self {
+        Self { name: name.into(), program_id, event_configs: vec![], account_configs: vec![] }
+    }
+
+    /// `ProtocolType::Custom(name)`
+    pub fn protocol_type(&self) -> ProtocolType {
+        ProtocolType::Custom(self.name.clone())
+    }
+
+    /// 注册指令事件解析器
+    ///
+    /// `instruction_parser` 接收去掉 discriminator 后的指令数据与指令账户；
+    /// `inner_instruction_parser` 接收跳过 16 字节（event-cpi tag + discriminator）后的 CPI 日志数据。
+    pub fn with_event(mut self, config: EventParseConfig) -> Self {
+        self.event_configs.push(config);
+        self
+    }
+
+    /// 注册仅依赖指令数据的解析器
+    pub fn with_instruction_parser<F>(
+        mut self,
+        event_type: EventType,
+        instruction_discriminator: &[u8],
+        parser: F,
+    ) -> Self
+    where
+        F: Fn(&[u8], &[Pubkey], EventMetadata) -> Option<Box<dyn UnifiedEvent>>
+            + Send
+            + Sync
+            + 'static,
+    {
+        self.event_configs.push(EventParseConfig {
+            program_id: self.program_id,
+            protocol_type: self.protocol_type(),
+            inner_instruction_discriminator: vec![],
+            instruction_discriminator: instruction_discriminator.to_vec(),
+            event_type,
+            inner_instruction_parser: None,
+            instruction_parser: Some(Arc::new(parser)),
+            requires_inner_instruction: false,
+        });
+        self
+    }
+
+    /// 注册账户解析器，按 owner == program_id 且 discriminator 前缀匹配
+    pub fn with_account_parser<F>(
+        mut self,
+        event_type: EventType,
+        account_discriminator: &[u8],
+        parser: F,
+    ) -> Self
+    where
+        F: Fn(&AccountPretty, EventMetadata) -> Option<Box<dyn UnifiedEvent>>
+            + Send
+            + Sync
+            + 'static,
+    {
+        self.account_configs.push(AccountParseConfig {
+            program_id: self.program_id,
+            protocol_type: self.protocol_type(),
+            event_type,
+            account_discriminator: account_discriminator.to_vec(),
+            account_parser: Arc::new(parser),
+        });
+        self
+    }
+}
+
+/// 自定义协议注册表
+///
+/// 构建 `EventParser`（或通过 `StreamClientConfig::protocol_registry`）时传入，
+/// 注册的协议与内置 `Protocol` 一同解析，无需修改本 crate。
+#[derive(Debug, Clone, Default)]
+pub struct ProtocolRegistry {
+    protocols: Vec<CustomProtocol>,
+}
+
+impl ProtocolRegistry {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// 注册协议；同一 program_id 重复注册时替换旧配置
+    pub fn register(&mut self, protocol: CustomProtocol) -> &mut Self {
+        self.protocols.retain(|p| p.program_id != protocol.program_id);
+        self.protocols.push(protocol);
+        self
+    }
+
+    pub fn protocols(&self) -> &[CustomProtocol] {
+        &self.protocols
+    }
+
+    pub fn program_ids(&self) -> Vec<Pubkey> {
+        self.protocols.iter().map(|p| p.program_id).collect()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.protocols.is_empty()
+    }
+
+    /// 所有协议的账户解析配置
+    pub fn account_configs(&self) -> impl Iterator<Item = &AccountParseConfig> {
+        self.protocols.iter().flat_map(|p| p.account_configs.iter())
+    }
+
+    /// 是否为已注册账户解析器输出的事件类型，用于区分账户订阅与交易订阅
+    pub fn is_account_event_type(&self, event_type: &EventType) -> bool {
+        self.account_configs().any(|config| config.event_type == *event_type)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use parking_lot::Mutex;
+    use serde::Serialize;
+    use solana_sdk::{
+        instruction::{AccountMeta, Instruction},
+        message::{Message, VersionedMessage},
+        signature::Signature,
+        transaction::VersionedTransaction,
+    };
+
+    use super::*;
+    use crate::{
+        impl_unified_event,
+        streaming::event_parser::{
+            common::filter::EventTypeFilter, core::event_parser::EventParser,
+        },
+    };
+
+    #[derive(Clone, Debug, Default, Serialize)]
+    struct MemoEvent {
+        metadata: EventMetadata,
+        memo: String,
+    }
+    impl_unified_event!(custom MemoEvent,);
+
+    fn memo_type() -> EventType {
+        EventType::Custom("Memo".to_string())
+    }
+
+    fn memo_account_type() -> EventType {
+        EventType::Custom("MemoAccount".to_string())
+    }
+
+    fn memo_protocol(program_id: Pubkey) -> CustomProtocol {
+        CustomProtocol::new("Memo", program_id)
+            .with_instruction_parser(memo_type(), &[1], |data, _accounts, metadata| {
+                let memo = std::str::from_utf8(data).ok()?.to_string();
+                Some(Box::new(MemoEvent { metadata, memo }))
+            })
+            .with_account_parser(memo_account_type(), &[2], |_account, _metadata| None)
+    }
+
+    #[test]
+    fn register_replaces_same_program_id() {
+        let program_id = Pubkey::new_unique();
+        let mut registry = ProtocolRegistry::new();
+        registry.register(memo_protocol(program_id));
+        registry.register(CustomProtocol::new("Memo v2", program_id));
+        registry.register(memo_protocol(Pubkey::new_unique()));
+
+        assert_eq!(registry.protocols().len(), 2);
+        assert_eq!(registry.protocols()[0].name, "Memo v2");
+        assert_eq!(registry.program_ids()[0], program_id);
+        assert_eq!(registry.protocols()[1].protocol_type(), ProtocolType::Custom("Memo".into()));
+    }
+
+    #[test]
+    fn registered_account_types_are_account_events() {
+        let mut registry = ProtocolRegistry::new();
+        registry.register(memo_protocol(Pubkey::new_unique()));
+        assert!(registry.is_account_event_type(&memo_account_type()));
+        assert!(!registry.is_account_event_type(&memo_type()));
+
+        let filter = EventTypeFilter { include: vec![memo_account_type()] };
+        assert!(filter.include_account_event(Some(&registry)));
+        assert!(!filter.include_transaction_event(Some(&registry)));
+        // 没有注册表时无法识别自定义账户事件
+        assert!(!filter.include_account_event(None));
+
+        let filter = EventTypeFilter { include: vec![memo_type()] };
+        assert!(!filter.include_account_event(Some(&registry)));
+        assert!(filter.include_transaction_event(Some(&registry)));
+    }
+
+    /// 解析一笔只含一条 memo 指令的交易，返回解析出的事件数与回调收到的事件
+    async fn parse_memo(
+        parser: &EventParser,
+        program_id: Pubkey,
+    ) -> (usize, Vec<Box<dyn UnifiedEvent>>) {
+        let payer = Pubkey::new_unique();
+        let instruction =
+            Instruction::new_with_bytes(program_id, b"\x01gm", vec![AccountMeta::new(payer, true)]);
+        let transaction = VersionedTransaction {
+            signatures: vec![Signature::default()],
+            message: VersionedMessage::Legacy(Message::new(&[instruction], Some(&payer))),
+        };
+        let events = Arc::new(Mutex::new(Vec::new()));
+        let sink = events.clone();
+        let parsed = parser
+            .parse_versioned_transaction_owned(
+                transaction,
+                Signature::default(),
+                Some(1),
+                None,
+                0,
+                None,
+                None,
+                &[],
+                Arc::new(move |event| sink.lock().push(event)),
+                None,
+            )
+            .await
+            .expect("parse");
+        let events = std::mem::take(&mut *events.lock());
+        (parsed, events)
+    }
+
+    #[tokio::test]
+    async fn parser_decodes_registered_instruction() {
+        let program_id = Pubkey::new_unique();
+        let mut registry = ProtocolRegistry::new();
+        registry.register(memo_protocol(program_id));
+        let parser = EventParser::new_with_registry(vec![], None, Some(&registry));
+
+        let (parsed, events) = parse_memo(&parser, program_id).await;
+        assert_eq!(parsed, 1);
+        assert_eq!(events.len(), 1);
+        let memo = events[0].as_any().downcast_ref::<MemoEvent>().expect("memo event");
+        assert_eq!(memo.memo, "gm");
+        assert_eq!(memo.metadata.protocol, ProtocolType::Custom("Memo".into()));
+        assert_eq!(memo.metadata.event_type, memo_type());
+    }
+
+    #[tokio::test]
+    async fn filtered_instruction_counts_as_parsed() {
+        let program_id = Pubkey::new_unique();
+        let mut registry = ProtocolRegistry::new();
+        registry.register(memo_protocol(program_id));
+        let filter = EventTypeFilter { include: vec![memo_account_type()] };
+        let parser = EventParser::new_with_registry(vec![], Some(filter), Some(&registry));
+
+        // 被过滤的事件不输出，但仍计入解析出的事件数
+        let (parsed, events) = parse_memo(&parser, program_id).await;
+        assert_eq!(parsed, 1);
+        assert!(events.is_empty());
+    }
+}
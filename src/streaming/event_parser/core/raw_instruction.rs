@@ -0,0 +1,20 @@
+// 原始指令透传 - 订阅协议中本库未建模的指令，交由调用方自行解码
+use serde::{Deserialize, Serialize};
+use solana_sdk::pubkey::Pubkey;
+
+use crate::{impl_unified_event, streaming::event_parser::common::EventMetadata};
+
+/// 订阅协议中没有任何指令配置匹配的原始指令，需在 `EventTypeFilter` 中显式包含 `EventType::RawInstruction`
+///
+/// 程序 ID、外层与内层索引见 `metadata`。程序自身的 Anchor event CPI 不会输出。
+#[derive(Clone, Debug, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct RawInstructionEvent {
+    pub metadata: EventMetadata,
+    /// 调用栈高度，外层指令为 1；旧版本节点未提供时为 None
+    pub stack_height: Option<u32>,
+    /// 按指令账户顺序解析后的账户公钥
+    pub accounts: Vec<Pubkey>,
+    /// 完整指令数据，包含 discriminator
+    pub data: Vec<u8>,
+}
+impl_unified_event!(RawInstructionEvent,);
@@ -0,0 +1,420 @@
+// 路由归属 - 关联 Photon / Axiom / Jupiter 路由事件与其调用的底层交易事件
+use solana_sdk::pubkey::Pubkey;
+use spl_associated_token_account::get_associated_token_address;
+
+use crate::streaming::event_parser::{
+    common::{
+        direct_sol_transfers, InnerInstructionView, InstructionIndex, InstructionInvocation,
+        ProtocolType, RouterInfo, TokenAccountMints,
+    },
+    core::balance_change::NATIVE_SOL_MINT,
+    protocols::{
+        axiom::{
+            parser::{AXIOM_1_PROGRAM_ID, AXIOM_1_PROGRAM_VAR_1_ID},
+            AxiomPumpFunTradeEvent,
+        },
+        axiom2::{
+            parser::{AXIOM_2_PROGRAM_ID, AXIOM_2_PROGRAM_VAR_1_ID},
+            AxiomPumpSwapBuyEvent,
+        },
+        jupiter_v6::parser::JUPITER_V6_PROGRAM_ID,
+        photon::{parser::PHOTON_PROGRAM_ID, PhotonPumpFunTradeEvent, PhotonPumpSwapTradeEvent},
+        pumpfun::{discriminators as pumpfun_discriminators, parser::PUMPFUN_PROGRAM_ID},
+        pumpswap::{discriminators as pumpswap_discriminators, parser::PUMPSWAP_PROGRAM_ID},
+    },
+    UnifiedEvent,
+};
+
+/// 已知路由程序对应的协议，其他程序返回 None
+pub fn router_protocol(program_id: &Pubkey) -> Option<ProtocolType> {
+    if *program_id == PHOTON_PROGRAM_ID {
+        Some(ProtocolType::PhotonProtocol)
+    } else if *program_id == AXIOM_1_PROGRAM_ID || *program_id == AXIOM_1_PROGRAM_VAR_1_ID {
+        Some(ProtocolType::AxiomTrading1)
+    } else if *program_id == AXIOM_2_PROGRAM_ID || *program_id == AXIOM_2_PROGRAM_VAR_1_ID {
+        Some(ProtocolType::AxiomTrading2)
+    } else if *program_id == JUPITER_V6_PROGRAM_ID {
+        Some(ProtocolType::JupiterV6)
+    } else {
+        None
+    }
+}
+
+impl RouterInfo {
+    /// 由路由外层指令的内联指令构建，路由直接发起的 SOL 转账视为路由手续费
+    ///
+    /// 转入 token 账户（`token_mints` 中的账户）或签名者自身账户的转账是 SOL 包装等
+    /// 用户自己的资金移动，不计入手续费。
+    /// Jupiter 路由直接发起的转账是 SOL 包装与租金而非手续费，因此 `router_fee` 为 0；
+    /// 平台费见 `JupiterRouteEvent`。
+    pub fn from_inner_instructions<I: InnerInstructionView>(
+        router: ProtocolType,
+        router_program: Pubkey,
+        inner_instructions: &[I],
+        accounts: &[Pubkey],
+        token_mints: &TokenAccountMints,
+        signers: &[Pubkey],
+    ) -> Self {
+        if router == ProtocolType::JupiterV6 {
+            return Self { router, router_program, ..Default::default() };
+        }
+        let mut transfers = direct_sol_transfers(inner_instructions, accounts);
+        transfers.retain(|(recipient, _)| !is_user_account(recipient, token_mints, signers));
+        let fee_recipient = transfers.first().map(|(recipient, _)| *recipient);
+        Self {
+            router,
+            router_program,
+            router_fee: transfers.iter().map(|(_, lamports)| lamports).sum(),
+            fee_recipient,
+            referral: transfers
+                .iter()
+                .map(|(recipient, _)| *recipient)
+                .find(|recipient| Some(*recipient) != fee_recipient),
+        }
+    }
+}
+
+/// 签名者自身、其 WSOL ATA 或交易中出现的 token 账户
+fn is_user_account(account: &Pubkey, token_mints: &TokenAccountMints, signers: &[Pubkey]) -> bool {
+    token_mints.get(account).is_some()
+        || signers.iter().any(|signer| {
+            signer == account || get_associated_token_address(signer, &NATIVE_SOL_MINT) == *account
+        })
+}
+
+/// 关联路由与底层交易：路由事件记录底层交易指令位置，路由发起的事件记录路由信息
+///
+/// `inner_instructions` 为事件所属外层指令的全部内联指令。
+pub fn attach_router<I: InnerInstructionView>(
+    event: &mut dyn UnifiedEvent,
+    invocation: &InstructionInvocation,
+    outer_index: i64,
+    inner_instructions: &[I],
+    accounts: &[Pubkey],
+    token_mints: &TokenAccountMints,
+    signers: &[Pubkey],
+) {
+    let router_program = invocation.invoking_program_id;
+    let Some(router) = router_protocol(&router_program) else {
+        return;
+    };
+    if invocation.is_top_level() {
+        if let Some(routed_trade) =
+            find_routed_trade(&*event, outer_index, inner_instructions, accounts)
+        {
+            set_routed_trade(event, routed_trade);
+        }
+    } else {
+        event.set_via_router(RouterInfo::from_inner_instructions(
+            router,
+            router_program,
+            inner_instructions,
+            accounts,
+            token_mints,
+            signers,
+        ));
+    }
+}
+
+/// 路由事件对应的底层买卖指令：程序、指令 discriminator，以及指令中 `account_index` 处的账户
+struct RoutedTradeKey {
+    program_id: Pubkey,
+    discriminator: &'static [u8],
+    account_index: usize,
+    account: Pubkey,
+}
+
+impl RoutedTradeKey {
+    /// PumpFun 买卖指令的第 3 个账户为 mint
+    fn pumpfun(mint: Pubkey, is_buy: bool) -> Self {
+        let discriminator =
+            if is_buy { pumpfun_discriminators::BUY_IX } else { pumpfun_discriminators::SELL_IX };
+        Self { program_id: PUMPFUN_PROGRAM_ID, discriminator, account_index: 2, account: mint }
+    }
+
+    /// PumpSwap 买卖指令的第 1 个账户为池子
+    fn pumpswap(pool: Pubkey, is_buy: bool) -> Self {
+        let discriminator =
+            if is_buy { pumpswap_discriminators::BUY_IX } else { pumpswap_discriminators::SELL_IX };
+        Self { program_id: PUMPSWAP_PROGRAM_ID, discriminator, account_index: 0, account: pool }
+    }
+
+    fn from_event(event: &dyn UnifiedEvent) -> Option<Self> {
+        let any = event.as_any();
+        if let Some(e) = any.downcast_ref::<PhotonPumpFunTradeEvent>() {
+            Some(Self::pumpfun(e.mint, e.is_buy))
+        } else if let Some(e) = any.downcast_ref::<PhotonPumpSwapTradeEvent>() {
+            Some(Self::pumpswap(e.pool, e.is_buy))
+        } else if let Some(e) = any.downcast_ref::<AxiomPumpFunTradeEvent>() {
+            Some(Self::pumpfun(e.mint, e.is_buy))
+        } else {
+            any.downcast_ref::<AxiomPumpSwapBuyEvent>().map(|e| Self::pumpswap(e.pool, true))
+        }
+    }
+
+    fn matches<I: InnerInstructionView>(&self, instruction: &I, accounts: &[Pubkey]) -> bool {
+        if accounts.get(instruction.program_id_index()) != Some(&self.program_id) {
+            return false;
+        }
+        instruction.data().starts_with(self.discriminator)
+            && instruction
+                .accounts()
+                .get(self.account_index)
+                .and_then(|index| accounts.get(*index as usize))
+                == Some(&self.account)
+    }
+}
+
+/// 路由外层指令中与路由事件方向、mint（PumpFun）或池子（PumpSwap）一致的第一条买卖指令
+///
+/// 同一路由指令对同一 mint / 池子发起多笔同方向交易时，均关联到其中第一笔。
+/// 仅 Photon / Axiom 路由事件有 `routed_trade`；
+/// Jupiter 路由的各跳记录在 `JupiterRouteEvent::hops`。
+fn find_routed_trade<I: InnerInstructionView>(
+    event: &dyn UnifiedEvent,
+    outer_index: i64,
+    inner_instructions: &[I],
+    accounts: &[Pubkey],
+) -> Option<InstructionIndex> {
+    let key = RoutedTradeKey::from_event(event)?;
+    inner_instructions
+        .iter()
+        .position(|instruction| key.matches(instruction, accounts))
+        .map(|inner_index| InstructionIndex { outer_index, inner_index: Some(inner_index as i64) })
+}
+
+/// 路由事件关联的底层交易指令位置，非路由事件或未找到底层交易时为 None
+pub fn routed_trade(event: &dyn UnifiedEvent) -> Option<InstructionIndex> {
+    let any = event.as_any();
+    if let Some(e) = any.downcast_ref::<PhotonPumpFunTradeEvent>() {
+        e.routed_trade
+    } else if let Some(e) = any.downcast_ref::<PhotonPumpSwapTradeEvent>() {
+        e.routed_trade
+    } else if let Some(e) = any.downcast_ref::<AxiomPumpFunTradeEvent>() {
+        e.routed_trade
+    } else if let Some(e) = any.downcast_ref::<AxiomPumpSwapBuyEvent>() {
+        e.routed_trade
+    } else {
+        None
+    }
+}
+
+/// 为路由事件设置底层交易指令位置，非路由事件不做处理
+fn set_routed_trade(event: &mut dyn UnifiedEvent, routed_trade: InstructionIndex) {
+    let any = event.as_any_mut();
+    if let Some(e) = any.downcast_mut::<PhotonPumpFunTradeEvent>() {
+        e.routed_trade = Some(routed_trade);
+    } else if let Some(e) = any.downcast_mut::<PhotonPumpSwapTradeEvent>() {
+        e.routed_trade = Some(routed_trade);
+    } else if let Some(e) = any.downcast_mut::<AxiomPumpFunTradeEvent>() {
+        e.routed_trade = Some(routed_trade);
+    } else if let Some(e) = any.downcast_mut::<AxiomPumpSwapBuyEvent>() {
+        e.routed_trade = Some(routed_trade);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use solana_sdk::instruction::CompiledInstruction;
+    use solana_transaction_status::InnerInstruction;
+    use yellowstone_grpc_proto::prelude::{TokenBalance, TransactionStatusMeta, UiTokenAmount};
+
+    use super::*;
+
+    /// 账户 `from` -> `to` 的 System 转账，System Program 位于账户列表末尾
+    fn sol_transfer(from: u8, to: u8, lamports: u64, accounts: &[Pubkey]) -> InnerInstruction {
+        let mut data = 2u32.to_le_bytes().to_vec();
+        data.extend_from_slice(&lamports.to_le_bytes());
+        InnerInstruction {
+            instruction: CompiledInstruction {
+                program_id_index: (accounts.len() - 1) as u8,
+                accounts: vec![from, to],
+                data,
+            },
+            stack_height: Some(2),
+        }
+    }
+
+    /// 账户：[用户, 手续费账户, System Program]
+    fn fee_transfer(lamports: u64) -> (Vec<InnerInstruction>, Vec<Pubkey>) {
+        let accounts =
+            vec![Pubkey::new_unique(), Pubkey::new_unique(), solana_sdk::system_program::ID];
+        (vec![sol_transfer(0, 1, lamports, &accounts)], accounts)
+    }
+
+    #[test]
+    fn photon_direct_transfer_is_router_fee() {
+        let (inner, accounts) = fee_transfer(5_000);
+        let info = RouterInfo::from_inner_instructions(
+            ProtocolType::PhotonProtocol,
+            PHOTON_PROGRAM_ID,
+            &inner,
+            &accounts,
+            &TokenAccountMints::default(),
+            &accounts[..1],
+        );
+        assert_eq!(info.router_fee, 5_000);
+        assert_eq!(info.fee_recipient, Some(accounts[1]));
+    }
+
+    #[test]
+    fn jupiter_direct_transfer_is_not_router_fee() {
+        let (inner, accounts) = fee_transfer(2_039_280);
+        let info = RouterInfo::from_inner_instructions(
+            ProtocolType::JupiterV6,
+            JUPITER_V6_PROGRAM_ID,
+            &inner,
+            &accounts,
+            &TokenAccountMints::default(),
+            &accounts[..1],
+        );
+        assert_eq!(info.router_fee, 0);
+        assert_eq!(info.fee_recipient, None);
+        assert_eq!(info.router_program, JUPITER_V6_PROGRAM_ID);
+    }
+
+    #[test]
+    fn wsol_wrap_is_not_router_fee() {
+        // 账户：[用户, 手续费账户, 用户 WSOL ATA, System Program]
+        let user = Pubkey::new_unique();
+        let accounts = vec![
+            user,
+            Pubkey::new_unique(),
+            get_associated_token_address(&user, &NATIVE_SOL_MINT),
+            solana_sdk::system_program::ID,
+        ];
+        let inner = vec![
+            sol_transfer(0, 2, 1_000_000_000, &accounts),
+            sol_transfer(0, 1, 10_000_000, &accounts),
+        ];
+        let info = RouterInfo::from_inner_instructions(
+            ProtocolType::PhotonProtocol,
+            PHOTON_PROGRAM_ID,
+            &inner,
+            &accounts,
+            &TokenAccountMints::default(),
+            &accounts[..1],
+        );
+        assert_eq!(info.router_fee, 10_000_000);
+        assert_eq!(info.fee_recipient, Some(accounts[1]));
+        assert_eq!(info.referral, None);
+    }
+
+    #[test]
+    fn transfer_to_token_account_is_not_router_fee() {
+        // 账户：[用户, 临时 WSOL 账户, 手续费账户, 推荐人, System Program]
+        let accounts = vec![
+            Pubkey::new_unique(),
+            Pubkey::new_unique(),
+            Pubkey::new_unique(),
+            Pubkey::new_unique(),
+            solana_sdk::system_program::ID,
+        ];
+        let meta = TransactionStatusMeta {
+            post_token_balances: vec![TokenBalance {
+                account_index: 1,
+                mint: NATIVE_SOL_MINT.to_string(),
+                ui_token_amount: Some(UiTokenAmount {
+                    amount: "1000000000".to_string(),
+                    decimals: 9,
+                    ..Default::default()
+                }),
+                ..Default::default()
+            }],
+            ..Default::default()
+        };
+        let token_mints = TokenAccountMints::from_grpc_meta(&meta, &accounts);
+        let inner = vec![
+            sol_transfer(0, 1, 1_000_000_000, &accounts),
+            sol_transfer(0, 2, 8_000_000, &accounts),
+            sol_transfer(0, 3, 2_000_000, &accounts),
+        ];
+        let info = RouterInfo::from_inner_instructions(
+            ProtocolType::AxiomTrading2,
+            AXIOM_2_PROGRAM_ID,
+            &inner,
+            &accounts,
+            &token_mints,
+            &accounts[..1],
+        );
+        assert_eq!(info.router_fee, 10_000_000);
+        assert_eq!(info.fee_recipient, Some(accounts[2]));
+        assert_eq!(info.referral, Some(accounts[3]));
+    }
+
+    /// PumpFun 买卖指令，指令账户第 3 个为 `mint`（`accounts` 中的下标）
+    fn pumpfun_trade(discriminator: &[u8], mint: u8) -> InnerInstruction {
+        InnerInstruction {
+            instruction: CompiledInstruction {
+                program_id_index: 0,
+                accounts: vec![3, 3, mint, 3],
+                data: discriminator.to_vec(),
+            },
+            stack_height: Some(2),
+        }
+    }
+
+    /// 作为 Photon 外层指令（`outer_index` 为 4）的路由事件关联底层交易
+    fn routed_event(event: &mut dyn UnifiedEvent, inner: &[InnerInstruction], accounts: &[Pubkey]) {
+        let invocation = InstructionInvocation {
+            stack_height: Some(1),
+            parent_program_id: None,
+            invoking_program_id: PHOTON_PROGRAM_ID,
+        };
+        attach_router(event, &invocation, 4, inner, accounts, &TokenAccountMints::default(), &[]);
+    }
+
+    #[test]
+    fn routed_trade_matches_router_event_mint() {
+        // 账户：[PumpFun, mint A, mint B, 用户]
+        let accounts = vec![
+            PUMPFUN_PROGRAM_ID,
+            Pubkey::new_unique(),
+            Pubkey::new_unique(),
+            Pubkey::new_unique(),
+        ];
+        let inner = vec![
+            pumpfun_trade(pumpfun_discriminators::BUY_IX, 1),
+            pumpfun_trade(pumpfun_discriminators::BUY_IX, 2),
+        ];
+        let mut event =
+            PhotonPumpFunTradeEvent { mint: accounts[2], is_buy: true, ..Default::default() };
+        routed_event(&mut event, &inner, &accounts);
+        assert_eq!(
+            event.routed_trade,
+            Some(InstructionIndex { outer_index: 4, inner_index: Some(1) })
+        );
+
+        let mut event = PhotonPumpFunTradeEvent {
+            mint: Pubkey::new_unique(),
+            is_buy: true,
+            ..Default::default()
+        };
+        routed_event(&mut event, &inner, &accounts);
+        assert_eq!(event.routed_trade, None);
+    }
+
+    #[test]
+    fn routed_trade_matches_direction_and_links_first_duplicate() {
+        // 账户：[PumpFun, mint A, mint B, 用户]
+        let accounts = vec![
+            PUMPFUN_PROGRAM_ID,
+            Pubkey::new_unique(),
+            Pubkey::new_unique(),
+            Pubkey::new_unique(),
+        ];
+        let inner = vec![
+            pumpfun_trade(pumpfun_discriminators::SELL_IX, 1),
+            pumpfun_trade(pumpfun_discriminators::BUY_IX, 1),
+            pumpfun_trade(pumpfun_discriminators::BUY_IX, 1),
+        ];
+        let mut event =
+            AxiomPumpFunTradeEvent { mint: accounts[1], is_buy: true, ..Default::default() };
+        routed_event(&mut event, &inner, &accounts);
+        // 同 mint 同方向的多笔交易关联到第一笔
+        assert_eq!(
+            event.routed_trade,
+            Some(InstructionIndex { outer_index: 4, inner_index: Some(1) })
+        );
+    }
+}
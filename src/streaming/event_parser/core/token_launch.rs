@@ -0,0 +1,212 @@
+use serde::{Deserialize, Serialize};
+use solana_sdk::pubkey::Pubkey;
+
+use crate::{
+    impl_unified_event,
+    streaming::event_parser::{
+        common::{EventMetadata, EventType, ProtocolType, TokenAccountMints},
+        core::{balance_change::NATIVE_SOL_MINT, normalized_trade::NormalizedTrade},
+        protocols::{
+            bonk::{BonkPoolCreateEvent, CurveParams},
+            meteora_dbc::MeteoraDBCInitializePoolEvent,
+            pumpfun::PumpFunCreateTokenEvent,
+        },
+        UnifiedEvent,
+    },
+};
+
+/// PumpFun 代币固定为 6 位精度
+const PUMPFUN_TOKEN_DECIMALS: u8 = 6;
+
+/// 跨发射平台统一的新代币发射事件，由各平台的创建事件派生
+///
+/// 在同一交易的指令事件之后输出，以便关联创建者在同一交易中的首次买入（dev buy）。
+/// 曲线参数取决于平台，未提供的字段为 0。
+#[derive(Clone, Debug, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct TokenLaunch {
+    pub metadata: EventMetadata,
+    pub launchpad: ProtocolType,
+    /// 原始事件类型
+    pub source_event_type: EventType,
+    pub mint: Pubkey,
+    pub creator: Pubkey,
+    /// 支付创建费用的签名者
+    pub payer: Pubkey,
+    /// bonding curve / 池子地址
+    pub pool: Pubkey,
+    pub quote_mint: Pubkey,
+    pub name: String,
+    pub symbol: String,
+    pub uri: String,
+    pub decimals: Option<u8>,
+    pub token_program: Pubkey,
+    pub is_token_2022: bool,
+    pub total_supply: u64,
+    pub virtual_base_reserves: u64,
+    pub virtual_quote_reserves: u64,
+    pub real_base_reserves: u64,
+    /// 曲线上可卖出的代币数量
+    pub base_sell_amount: u64,
+    /// 毕业所需募集的计价币数量
+    pub quote_raise_target: u64,
+    /// 创建者在同一交易中买入花费的计价币数量
+    pub dev_buy_quote_amount: u64,
+    /// 创建者在同一交易中买入获得的代币数量
+    pub dev_buy_base_amount: u64,
+}
+impl_unified_event!(TokenLaunch,);
+
+impl TokenLaunch {
+    /// 由发射平台的创建事件派生，其他事件返回 None
+    pub fn from_event(event: &dyn UnifiedEvent) -> Option<Self> {
+        let any = event.as_any();
+        let (metadata, launch) = if let Some(e) = any.downcast_ref::<PumpFunCreateTokenEvent>() {
+            // 仅有日志事件时没有指令账户，按 create 指令的 SPL Token 处理
+            let token_program = if e.token_program == Pubkey::default() {
+                spl_token::ID
+            } else {
+                e.token_program
+            };
+            let launch = Self {
+                launchpad: ProtocolType::PumpFun,
+                mint: e.mint,
+                creator: if e.creator == Pubkey::default() { e.user } else { e.creator },
+                payer: e.user,
+                pool: e.bonding_curve,
+                quote_mint: NATIVE_SOL_MINT,
+                name: e.name.clone(),
+                symbol: e.symbol.clone(),
+                uri: e.uri.clone(),
+                decimals: Some(PUMPFUN_TOKEN_DECIMALS),
+                token_program,
+                is_token_2022: token_program == spl_token_2022::ID,
+                total_supply: e.token_total_supply,
+                virtual_base_reserves: e.virtual_token_reserves,
+                virtual_quote_reserves: e.virtual_sol_reserves,
+                real_base_reserves: e.real_token_reserves,
+                ..Default::default()
+            };
+            (&e.metadata, launch)
+        } else if let Some(e) = any.downcast_ref::<BonkPoolCreateEvent>() {
+            let is_token_2022 = e.metadata.event_type == EventType::BonkInitializeWithToken2022;
+            let (total_supply, base_sell_amount, quote_raise_target) = match &e.curve_param {
+                CurveParams::Constant { data } => {
+                    (data.supply, data.total_base_sell, data.total_quote_fund_raising)
+                }
+                CurveParams::Fixed { data } => (data.supply, 0, data.total_quote_fund_raising),
+                CurveParams::Linear { data } => (data.supply, 0, data.total_quote_fund_raising),
+            };
+            let launch = Self {
+                launchpad: ProtocolType::Bonk,
+                mint: e.base_mint,
+                creator: e.creator,
+                payer: e.payer,
+                pool: e.pool_state,
+                quote_mint: e.quote_mint,
+                name: e.base_mint_param.name.clone(),
+                symbol: e.base_mint_param.symbol.clone(),
+                uri: e.base_mint_param.uri.clone(),
+                decimals: Some(e.base_mint_param.decimals),
+                token_program: if is_token_2022 { spl_token_2022::ID } else { spl_token::ID },
+                is_token_2022,
+                total_supply,
+                base_sell_amount,
+                quote_raise_target,
+                ..Default::default()
+            };
+            (&e.metadata, launch)
+        } else {
+            // 曲线参数保存在 config 账户中，指令里没有
+            let e = any.downcast_ref::<MeteoraDBCInitializePoolEvent>()?;
+            let launch = Self {
+                launchpad: ProtocolType::MeteoraDBC,
+                mint: e.base_mint,
+                creator: e.creator,
+                payer: e.payer,
+                pool: e.pool,
+                quote_mint: e.quote_mint,
+                name: e.name.clone(),
+                symbol: e.symbol.clone(),
+                uri: e.uri.clone(),
+                token_program: e.token_program,
+                is_token_2022: e.token_program == spl_token_2022::ID,
+                ..Default::default()
+            };
+            (&e.metadata, launch)
+        };
+        let mut metadata = metadata.clone();
+        metadata.event_type = EventType::TokenLaunch;
+        Some(Self { metadata, source_event_type: event.event_type(), ..launch })
+    }
+
+    /// 若 `event` 是创建者买入本代币的交易，则累加到 dev buy 数量
+    ///
+    /// 经 `NormalizedTrade` 判断交易，已关联底层交易的路由事件不会重复累加。
+    pub fn link_dev_buy(&mut self, event: &dyn UnifiedEvent, token_mints: &TokenAccountMints) {
+        let Some(trade) = NormalizedTrade::from_event(event, token_mints) else {
+            return;
+        };
+        if trade.input_mint != self.quote_mint
+            || trade.output_mint != self.mint
+            || (trade.trader != self.creator && trade.trader != self.payer)
+        {
+            return;
+        }
+        self.dev_buy_quote_amount = self.dev_buy_quote_amount.saturating_add(trade.amount_in);
+        self.dev_buy_base_amount = self.dev_buy_base_amount.saturating_add(trade.amount_out);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::streaming::event_parser::{
+        common::InstructionIndex,
+        protocols::{photon::PhotonPumpFunTradeEvent, pumpfun::PumpFunTradeEvent},
+    };
+
+    #[test]
+    fn routed_dev_buy_is_counted_once() {
+        let creator = Pubkey::new_unique();
+        let create = PumpFunCreateTokenEvent {
+            mint: Pubkey::new_unique(),
+            user: creator,
+            creator,
+            ..Default::default()
+        };
+        let mut launch = TokenLaunch::from_event(&create).expect("launch");
+        let dex_buy = PumpFunTradeEvent {
+            mint: create.mint,
+            user: creator,
+            is_buy: true,
+            sol_amount: 1_000,
+            token_amount: 2_000,
+            ..Default::default()
+        };
+        let router_buy = PhotonPumpFunTradeEvent {
+            mint: create.mint,
+            user: creator,
+            is_buy: true,
+            sol_amount: 1_000,
+            token_amount: 2_000,
+            routed_trade: Some(InstructionIndex { outer_index: 1, inner_index: Some(0) }),
+            ..Default::default()
+        };
+
+        let token_mints = TokenAccountMints::default();
+        launch.link_dev_buy(&router_buy, &token_mints);
+        launch.link_dev_buy(&dex_buy, &token_mints);
+        assert_eq!((launch.dev_buy_quote_amount, launch.dev_buy_base_amount), (1_000, 2_000));
+    }
+
+    #[test]
+    fn pumpfun_token_program_from_instruction() {
+        let legacy = PumpFunCreateTokenEvent { mint: Pubkey::new_unique(), ..Default::default() };
+        let launch = TokenLaunch::from_event(&legacy).expect("launch");
+        assert_eq!((launch.token_program, launch.is_token_2022), (spl_token::ID, false));
+
+        let create_v2 = PumpFunCreateTokenEvent { token_program: spl_token_2022::ID, ..legacy };
+        let launch = TokenLaunch::from_event(&create_v2).expect("launch");
+        assert_eq!((launch.token_program, launch.is_token_2022), (spl_token_2022::ID, true));
+    }
+}
@@ -1,13 +1,25 @@
 use crate::streaming::event_parser::common::EventType;
+use crate::streaming::event_parser::common::InstructionInvocation;
+use crate::streaming::event_parser::common::ProtocolType;
+use crate::streaming::event_parser::common::RouterInfo;
 use crate::streaming::event_parser::common::SwapData;
+use crate::streaming::event_parser::common::TransactionContext;
+use crate::streaming::event_parser::core::dex_event::DexEvent;
+use crate::streaming::event_parser::core::json_schema;
 use solana_sdk::signature::Signature;
 use std::fmt::Debug;
+use std::sync::Arc;
 
 /// Unified Event Interface - All protocol events must implement this trait
 pub trait UnifiedEvent: Debug + Send + Sync {
     /// Get event type
     fn event_type(&self) -> EventType;
 
+    /// Get the protocol that produced the event
+    fn protocol(&self) -> ProtocolType {
+        ProtocolType::Common
+    }
+
     /// Get transaction signature
     fn signature(&self) -> &Signature;
 
@@ -43,12 +55,50 @@ pub trait UnifiedEvent: Debug + Send + Sync {
     /// swap_data is parsed
     fn swap_data_is_parsed(&self) -> bool;
 
+    /// Set transaction-level context (optional implementation)
+    fn set_tx_context(&mut self, _tx_context: Arc<TransactionContext>) {}
+
+    /// Set the CPI invocation info of the source instruction (optional implementation)
+    fn set_invocation(&mut self, _invocation: InstructionInvocation) {}
+
+    /// Get the CPI invocation info, `None` for account events and external events
+    fn invocation(&self) -> Option<&InstructionInvocation> {
+        None
+    }
+
+    /// Set the router that invoked the source instruction (optional implementation)
+    fn set_via_router(&mut self, _via_router: RouterInfo) {}
+
+    /// Whether the event comes from a failed transaction
+    fn is_failed(&self) -> bool {
+        false
+    }
+
     /// Get index
     fn outer_index(&self) -> i64;
     fn inner_index(&self) -> Option<i64>;
 
     /// Get transaction index in slot
     fn transaction_index(&self) -> Option<u64>;
+
+    /// Convert into the typed DexEvent enum (built-in events override this via `impl_unified_event!`)
+    fn into_dex_event(self: Box<Self>) -> DexEvent {
+        DexEvent::Custom(self.clone_boxed())
+    }
+
+    /// Serialize into the stable JSON schema (see `core::json_schema`)
+    ///
+    /// External events without `impl_unified_event!` only carry trait-level metadata and `data: null`
+    fn to_json(&self) -> serde_json::Result<serde_json::Value> {
+        json_schema::unified_event_to_json(self)
+    }
+
+    /// Convert into the protobuf message defined in `src/protos/events.proto`
+    ///
+    /// Returns `None` for external events that have no protobuf schema
+    fn to_proto(&self) -> Option<crate::protos::events::Event> {
+        None
+    }
 }
 
 // 为Box<dyn UnifiedEvent>实现Clone
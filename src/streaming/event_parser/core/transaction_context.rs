@@ -0,0 +1,101 @@
+use solana_sdk::{
+    hash::Hash,
+    pubkey::Pubkey,
+    transaction::{TransactionError, VersionedTransaction},
+};
+use solana_transaction_status::{option_serializer::OptionSerializer, UiTransactionStatusMeta};
+use yellowstone_grpc_proto::prelude::{Message, TransactionStatusMeta};
+
+use crate::streaming::event_parser::common::TransactionContext;
+
+/// ComputeBudget 程序ID
+pub const COMPUTE_BUDGET_PROGRAM_ID: Pubkey =
+    solana_sdk::pubkey!("ComputeBudget111111111111111111111111111111");
+
+// ComputeBudgetInstruction 的 borsh 枚举序号
+const SET_COMPUTE_UNIT_LIMIT: u8 = 2;
+const SET_COMPUTE_UNIT_PRICE: u8 = 3;
+
+impl TransactionContext {
+    /// 从 gRPC 交易构建，`accounts` 为包含 ALT 地址的完整账户列表
+    pub fn from_grpc_transaction(
+        message: &Message,
+        meta: Option<&TransactionStatusMeta>,
+        accounts: &[Pubkey],
+    ) -> Self {
+        let num_signers = message.header.as_ref().map_or(0, |h| h.num_required_signatures as usize);
+        let (compute_unit_limit, compute_unit_price) = parse_compute_budget(
+            message
+                .instructions
+                .iter()
+                .map(|ix| (accounts.get(ix.program_id_index as usize), ix.data.as_slice())),
+        );
+        Self {
+            success: meta.map(|meta| meta.err.is_none()),
+            err: meta
+                .and_then(|meta| meta.err.as_ref())
+                .and_then(|err| bincode::deserialize::<TransactionError>(&err.err).ok()),
+            fee: meta.map(|meta| meta.fee),
+            compute_units_consumed: meta.and_then(|meta| meta.compute_units_consumed),
+            compute_unit_limit,
+            compute_unit_price,
+            fee_payer: accounts.first().copied().unwrap_or_default(),
+            signers: accounts.iter().take(num_signers).copied().collect(),
+            recent_blockhash: <[u8; 32]>::try_from(message.recent_blockhash.as_slice())
+                .map(Hash::new_from_array)
+                .unwrap_or_default(),
+        }
+    }
+
+    /// 从 VersionedTransaction 构建，没有 meta 时（如 ShredStream）交易状态与手续费为 None
+    pub fn from_versioned_transaction(
+        transaction: &VersionedTransaction,
+        meta: Option<&UiTransactionStatusMeta>,
+    ) -> Self {
+        let account_keys = transaction.message.static_account_keys();
+        let num_signers = transaction.message.header().num_required_signatures as usize;
+        let (compute_unit_limit, compute_unit_price) =
+            parse_compute_budget(transaction.message.instructions().iter().map(|ix| {
+                (account_keys.get(ix.program_id_index as usize), ix.data.as_slice())
+            }));
+        Self {
+            success: meta.map(|meta| meta.err.is_none()),
+            err: meta.and_then(|meta| meta.err.clone()),
+            fee: meta.map(|meta| meta.fee),
+            compute_units_consumed: meta.and_then(|meta| match meta.compute_units_consumed {
+                OptionSerializer::Some(units) => Some(units),
+                _ => None,
+            }),
+            compute_unit_limit,
+            compute_unit_price,
+            fee_payer: account_keys.first().copied().unwrap_or_default(),
+            signers: account_keys.iter().take(num_signers).copied().collect(),
+            recent_blockhash: *transaction.message.recent_blockhash(),
+        }
+    }
+}
+
+/// 从外层指令中解析 ComputeBudget 的 CU limit 与 CU price
+fn parse_compute_budget<'a>(
+    instructions: impl Iterator<Item = (Option<&'a Pubkey>, &'a [u8])>,
+) -> (Option<u32>, Option<u64>) {
+    let mut compute_unit_limit = None;
+    let mut compute_unit_price = None;
+    for (program_id, data) in instructions {
+        if program_id != Some(&COMPUTE_BUDGET_PROGRAM_ID) {
+            continue;
+        }
+        match data.split_first() {
+            Some((&SET_COMPUTE_UNIT_LIMIT, rest)) => {
+                compute_unit_limit =
+                    rest.get(..4).and_then(|b| b.try_into().ok()).map(u32::from_le_bytes);
+            }
+            Some((&SET_COMPUTE_UNIT_PRICE, rest)) => {
+                compute_unit_price =
+                    rest.get(..8).and_then(|b| b.try_into().ok()).map(u64::from_le_bytes);
+            }
+            _ => {}
+        }
+    }
+    (compute_unit_limit, compute_unit_price)
+}
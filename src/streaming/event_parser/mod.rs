@@ -2,6 +2,9 @@ pub mod common;
 pub mod core;
 pub mod protocols;
 
+pub use core::anchor_idl::{AnchorIdl, IdlValue};
+pub use core::dex_event::DexEvent;
+pub use core::protocol_registry::{CustomProtocol, ProtocolRegistry};
 pub use core::traits::UnifiedEvent;
 pub use protocols::types::Protocol;
 
@@ -1,5 +1,5 @@
 use crate::impl_unified_event;
-use crate::streaming::event_parser::common::EventMetadata;
+use crate::streaming::event_parser::common::{EventMetadata, InstructionIndex};
 use borsh::BorshDeserialize;
 use serde::{Deserialize, Serialize};
 use solana_sdk::pubkey::Pubkey;
@@ -74,6 +74,9 @@ pub struct AxiomPumpFunTradeEvent {
     pub fee_config: Pubkey,
     #[borsh(skip)]
     pub fee_program: Pubkey,
+    /// 路由调用的底层 PumpFun / PumpSwap 交易指令位置，未找到时为 None
+    #[borsh(skip)]
+    pub routed_trade: Option<InstructionIndex>,
 }
 
 impl_unified_event!(
@@ -2,7 +2,7 @@ use borsh::BorshDeserialize;
 use serde::{Deserialize, Serialize};
 use solana_sdk::pubkey::Pubkey;
 
-use crate::streaming::event_parser::common::EventMetadata;
+use crate::streaming::event_parser::common::{EventMetadata, InstructionIndex};
 use crate::impl_unified_event;
 
 /// 买入事件
@@ -58,6 +58,9 @@ pub struct AxiomPumpSwapBuyEvent {
     pub global_volume_accumulator: Pubkey,
     #[borsh(skip)]
     pub user_volume_accumulator: Pubkey,
+    /// 路由调用的底层 PumpFun / PumpSwap 交易指令位置，未找到时为 None
+    #[borsh(skip)]
+    pub routed_trade: Option<InstructionIndex>,
 }
 
 // 使用宏生成UnifiedEvent实现，指定需要合并的字段
@@ -0,0 +1,71 @@
+use borsh::BorshDeserialize;
+use serde::{Deserialize, Serialize};
+use solana_sdk::pubkey::Pubkey;
+
+use crate::impl_unified_event;
+use crate::streaming::event_parser::common::EventMetadata;
+use crate::streaming::event_parser::protocols::jupiter_v6::types::{
+    JupiterRouteKind, JupiterSwapHop,
+};
+
+/// 路由聚合事件，单跳明细由路由指令下的 `SwapEvent` CPI 补全
+#[derive(Clone, Debug, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct JupiterRouteEvent {
+    pub metadata: EventMetadata,
+    pub kind: JupiterRouteKind,
+    pub user: Pubkey,
+    pub source_token_account: Pubkey,
+    pub destination_token_account: Pubkey,
+    /// 非 shared accounts 路由的指令账户中不含输入代币，由首跳补全
+    pub input_mint: Pubkey,
+    pub output_mint: Pubkey,
+    /// 实际输入数量，为所有以 `input_mint` 为输入的单跳之和
+    pub in_amount: u64,
+    /// 实际输出数量，为所有以 `output_mint` 为输出的单跳之和
+    pub out_amount: u64,
+    /// exact-in 路由的报价输出数量
+    pub quoted_out_amount: u64,
+    /// exact-out 路由的报价输入数量
+    pub quoted_in_amount: u64,
+    pub slippage_bps: u16,
+    pub platform_fee_bps: u8,
+    pub hops: Vec<JupiterSwapHop>,
+    pub platform_fee_account: Option<Pubkey>,
+    pub platform_fee_mint: Option<Pubkey>,
+    pub platform_fee_amount: u64,
+}
+
+impl_unified_event!(JupiterRouteEvent,);
+
+/// 单跳交易，对应路由指令下的一次 `SwapEvent` CPI
+#[derive(Clone, Debug, Default, PartialEq, Eq, Serialize, Deserialize, BorshDeserialize)]
+pub struct JupiterSwapEvent {
+    #[borsh(skip)]
+    pub metadata: EventMetadata,
+    /// 该跳调用的 AMM 程序
+    pub amm: Pubkey,
+    pub input_mint: Pubkey,
+    pub input_amount: u64,
+    pub output_mint: Pubkey,
+    pub output_amount: u64,
+}
+
+impl_unified_event!(JupiterSwapEvent,);
+
+/// 事件鉴别器常量
+pub mod discriminators {
+    // 事件鉴别器，含 Anchor event CPI 前缀
+    pub const SWAP_EVENT: &[u8] =
+        &[228, 69, 165, 46, 81, 203, 154, 29, 64, 198, 205, 232, 38, 8, 113, 226];
+    pub const FEE_EVENT: &[u8] =
+        &[228, 69, 165, 46, 81, 203, 154, 29, 73, 79, 78, 127, 184, 213, 13, 220];
+
+    // 指令鉴别器
+    pub const ROUTE: &[u8] = &[229, 23, 203, 151, 122, 227, 173, 42];
+    pub const ROUTE_WITH_TOKEN_LEDGER: &[u8] = &[150, 86, 71, 116, 167, 93, 14, 104];
+    pub const EXACT_OUT_ROUTE: &[u8] = &[208, 51, 239, 151, 123, 43, 237, 92];
+    pub const SHARED_ACCOUNTS_ROUTE: &[u8] = &[193, 32, 155, 51, 65, 214, 156, 129];
+    pub const SHARED_ACCOUNTS_ROUTE_WITH_TOKEN_LEDGER: &[u8] =
+        &[230, 121, 143, 80, 119, 159, 106, 170];
+    pub const SHARED_ACCOUNTS_EXACT_OUT_ROUTE: &[u8] = &[176, 209, 105, 168, 154, 125, 69, 62];
+}
@@ -0,0 +1,369 @@
+use solana_sdk::pubkey::Pubkey;
+
+use crate::streaming::event_parser::{
+    common::{
+        read_u16_le, read_u64_le, read_u8_le, EventMetadata, EventType, InnerInstructionView,
+        ProtocolType, SwapData,
+    },
+    core::event_parser::GenericEventParseConfig,
+    protocols::jupiter_v6::{
+        discriminators,
+        types::{JupiterFee, JupiterRouteKind, JupiterSwapHop},
+        JupiterRouteEvent, JupiterSwapEvent,
+    },
+    UnifiedEvent,
+};
+
+/// Jupiter v6 聚合器程序ID
+pub const JUPITER_V6_PROGRAM_ID: Pubkey =
+    solana_sdk::pubkey!("JUP6LkbZbjS1jKKwapdHNy74zcZ3tLUZoi5QNyVTaV4");
+
+/// 路由参数位于指令数据末尾，`route_plan` 为变长数据，因此从末尾读取：
+/// 数量 + 报价数量(u64 * 2) + slippage_bps(u16) + platform_fee_bps(u8)
+const ROUTE_ARGS_LEN: usize = 8 + 8 + 2 + 1;
+/// token ledger 变体的输入数量取自 token ledger 账户，参数中没有输入数量
+const TOKEN_LEDGER_ROUTE_ARGS_LEN: usize = 8 + 2 + 1;
+
+pub const CONFIGS: &[GenericEventParseConfig] = &[
+    GenericEventParseConfig {
+        program_id: JUPITER_V6_PROGRAM_ID,
+        protocol_type: ProtocolType::JupiterV6,
+        inner_instruction_discriminator: &[],
+        instruction_discriminator: discriminators::ROUTE,
+        event_type: EventType::JupiterRoute,
+        inner_instruction_parser: None,
+        instruction_parser: Some(parse_route_instruction),
+        requires_inner_instruction: false,
+    },
+    GenericEventParseConfig {
+        program_id: JUPITER_V6_PROGRAM_ID,
+        protocol_type: ProtocolType::JupiterV6,
+        inner_instruction_discriminator: &[],
+        instruction_discriminator: discriminators::ROUTE_WITH_TOKEN_LEDGER,
+        event_type: EventType::JupiterRoute,
+        inner_instruction_parser: None,
+        instruction_parser: Some(parse_route_with_token_ledger_instruction),
+        requires_inner_instruction: false,
+    },
+    GenericEventParseConfig {
+        program_id: JUPITER_V6_PROGRAM_ID,
+        protocol_type: ProtocolType::JupiterV6,
+        inner_instruction_discriminator: &[],
+        instruction_discriminator: discriminators::EXACT_OUT_ROUTE,
+        event_type: EventType::JupiterRoute,
+        inner_instruction_parser: None,
+        instruction_parser: Some(parse_exact_out_route_instruction),
+        requires_inner_instruction: false,
+    },
+    GenericEventParseConfig {
+        program_id: JUPITER_V6_PROGRAM_ID,
+        protocol_type: ProtocolType::JupiterV6,
+        inner_instruction_discriminator: &[],
+        instruction_discriminator: discriminators::SHARED_ACCOUNTS_ROUTE,
+        event_type: EventType::JupiterRoute,
+        inner_instruction_parser: None,
+        instruction_parser: Some(parse_shared_accounts_route_instruction),
+        requires_inner_instruction: false,
+    },
+    GenericEventParseConfig {
+        program_id: JUPITER_V6_PROGRAM_ID,
+        protocol_type: ProtocolType::JupiterV6,
+        inner_instruction_discriminator: &[],
+        instruction_discriminator: discriminators::SHARED_ACCOUNTS_ROUTE_WITH_TOKEN_LEDGER,
+        event_type: EventType::JupiterRoute,
+        inner_instruction_parser: None,
+        instruction_parser: Some(parse_shared_accounts_route_with_token_ledger_instruction),
+        requires_inner_instruction: false,
+    },
+    GenericEventParseConfig {
+        program_id: JUPITER_V6_PROGRAM_ID,
+        protocol_type: ProtocolType::JupiterV6,
+        inner_instruction_discriminator: &[],
+        instruction_discriminator: discriminators::SHARED_ACCOUNTS_EXACT_OUT_ROUTE,
+        event_type: EventType::JupiterRoute,
+        inner_instruction_parser: None,
+        instruction_parser: Some(parse_shared_accounts_exact_out_route_instruction),
+        requires_inner_instruction: false,
+    },
+    // 每一跳的 SwapEvent 为程序对自身的 event CPI，按指令解析
+    GenericEventParseConfig {
+        program_id: JUPITER_V6_PROGRAM_ID,
+        protocol_type: ProtocolType::JupiterV6,
+        inner_instruction_discriminator: &[],
+        instruction_discriminator: discriminators::SWAP_EVENT,
+        event_type: EventType::JupiterSwap,
+        inner_instruction_parser: None,
+        instruction_parser: Some(parse_swap_event_instruction),
+        requires_inner_instruction: false,
+    },
+];
+
+/// 从指令数据末尾读取路由参数：(数量, 报价数量, slippage_bps, platform_fee_bps)
+fn read_route_args(data: &[u8]) -> Option<(u64, u64, u16, u8)> {
+    let offset = data.len().checked_sub(ROUTE_ARGS_LEN)?;
+    Some((
+        read_u64_le(data, offset)?,
+        read_u64_le(data, offset + 8)?,
+        read_u16_le(data, offset + 16)?,
+        read_u8_le(data, offset + 18)?,
+    ))
+}
+
+/// 从 token ledger 变体的指令数据末尾读取路由参数：(报价数量, slippage_bps, platform_fee_bps)
+fn read_token_ledger_route_args(data: &[u8]) -> Option<(u64, u16, u8)> {
+    let offset = data.len().checked_sub(TOKEN_LEDGER_ROUTE_ARGS_LEN)?;
+    Some((
+        read_u64_le(data, offset)?,
+        read_u16_le(data, offset + 8)?,
+        read_u8_le(data, offset + 10)?,
+    ))
+}
+
+/// 可选账户未传入时为 Jupiter 程序本身
+fn optional_account(account: Pubkey) -> Option<Pubkey> {
+    (account != JUPITER_V6_PROGRAM_ID).then_some(account)
+}
+
+/// 解析 route 指令
+fn parse_route_instruction(
+    data: &[u8],
+    accounts: &[Pubkey],
+    metadata: EventMetadata,
+) -> Option<Box<dyn UnifiedEvent>> {
+    if accounts.len() < 7 {
+        return None;
+    }
+    let (in_amount, quoted_out_amount, slippage_bps, platform_fee_bps) = read_route_args(data)?;
+    Some(Box::new(JupiterRouteEvent {
+        metadata,
+        kind: JupiterRouteKind::Route,
+        user: accounts[1],
+        source_token_account: accounts[2],
+        destination_token_account: optional_account(accounts[4]).unwrap_or(accounts[3]),
+        output_mint: accounts[5],
+        in_amount,
+        quoted_out_amount,
+        slippage_bps,
+        platform_fee_bps,
+        platform_fee_account: optional_account(accounts[6]),
+        ..Default::default()
+    }))
+}
+
+/// 解析 route_with_token_ledger 指令
+fn parse_route_with_token_ledger_instruction(
+    data: &[u8],
+    accounts: &[Pubkey],
+    metadata: EventMetadata,
+) -> Option<Box<dyn UnifiedEvent>> {
+    if accounts.len() < 8 {
+        return None;
+    }
+    let (quoted_out_amount, slippage_bps, platform_fee_bps) = read_token_ledger_route_args(data)?;
+    Some(Box::new(JupiterRouteEvent {
+        metadata,
+        kind: JupiterRouteKind::RouteWithTokenLedger,
+        user: accounts[1],
+        source_token_account: accounts[2],
+        destination_token_account: optional_account(accounts[4]).unwrap_or(accounts[3]),
+        output_mint: accounts[5],
+        quoted_out_amount,
+        slippage_bps,
+        platform_fee_bps,
+        platform_fee_account: optional_account(accounts[6]),
+        ..Default::default()
+    }))
+}
+
+/// 解析 exact_out_route 指令
+fn parse_exact_out_route_instruction(
+    data: &[u8],
+    accounts: &[Pubkey],
+    metadata: EventMetadata,
+) -> Option<Box<dyn UnifiedEvent>> {
+    if accounts.len() < 8 {
+        return None;
+    }
+    let (out_amount, quoted_in_amount, slippage_bps, platform_fee_bps) = read_route_args(data)?;
+    Some(Box::new(JupiterRouteEvent {
+        metadata,
+        kind: JupiterRouteKind::ExactOutRoute,
+        user: accounts[1],
+        source_token_account: accounts[2],
+        destination_token_account: optional_account(accounts[4]).unwrap_or(accounts[3]),
+        input_mint: accounts[5],
+        output_mint: accounts[6],
+        out_amount,
+        quoted_in_amount,
+        slippage_bps,
+        platform_fee_bps,
+        platform_fee_account: optional_account(accounts[7]),
+        ..Default::default()
+    }))
+}
+
+/// shared accounts 路由的公共账户布局
+fn shared_accounts_route_event(
+    kind: JupiterRouteKind,
+    accounts: &[Pubkey],
+    metadata: EventMetadata,
+) -> Option<JupiterRouteEvent> {
+    if accounts.len() < 10 {
+        return None;
+    }
+    Some(JupiterRouteEvent {
+        metadata,
+        kind,
+        user: accounts[2],
+        source_token_account: accounts[3],
+        destination_token_account: accounts[6],
+        input_mint: accounts[7],
+        output_mint: accounts[8],
+        platform_fee_account: optional_account(accounts[9]),
+        ..Default::default()
+    })
+}
+
+/// 解析 shared_accounts_route 指令
+fn parse_shared_accounts_route_instruction(
+    data: &[u8],
+    accounts: &[Pubkey],
+    metadata: EventMetadata,
+) -> Option<Box<dyn UnifiedEvent>> {
+    let (in_amount, quoted_out_amount, slippage_bps, platform_fee_bps) = read_route_args(data)?;
+    let event =
+        shared_accounts_route_event(JupiterRouteKind::SharedAccountsRoute, accounts, metadata)?;
+    Some(Box::new(JupiterRouteEvent {
+        in_amount,
+        quoted_out_amount,
+        slippage_bps,
+        platform_fee_bps,
+        ..event
+    }))
+}
+
+/// 解析 shared_accounts_route_with_token_ledger 指令
+fn parse_shared_accounts_route_with_token_ledger_instruction(
+    data: &[u8],
+    accounts: &[Pubkey],
+    metadata: EventMetadata,
+) -> Option<Box<dyn UnifiedEvent>> {
+    let (quoted_out_amount, slippage_bps, platform_fee_bps) = read_token_ledger_route_args(data)?;
+    let event = shared_accounts_route_event(
+        JupiterRouteKind::SharedAccountsRouteWithTokenLedger,
+        accounts,
+        metadata,
+    )?;
+    Some(Box::new(JupiterRouteEvent { quoted_out_amount, slippage_bps, platform_fee_bps, ..event }))
+}
+
+/// 解析 shared_accounts_exact_out_route 指令
+fn parse_shared_accounts_exact_out_route_instruction(
+    data: &[u8],
+    accounts: &[Pubkey],
+    metadata: EventMetadata,
+) -> Option<Box<dyn UnifiedEvent>> {
+    let (out_amount, quoted_in_amount, slippage_bps, platform_fee_bps) = read_route_args(data)?;
+    let event = shared_accounts_route_event(
+        JupiterRouteKind::SharedAccountsExactOutRoute,
+        accounts,
+        metadata,
+    )?;
+    Some(Box::new(JupiterRouteEvent {
+        out_amount,
+        quoted_in_amount,
+        slippage_bps,
+        platform_fee_bps,
+        ..event
+    }))
+}
+
+/// 解析单跳 SwapEvent
+fn parse_swap_event_instruction(
+    data: &[u8],
+    _accounts: &[Pubkey],
+    metadata: EventMetadata,
+) -> Option<Box<dyn UnifiedEvent>> {
+    let event = borsh::from_slice::<JupiterSwapEvent>(data).ok()?;
+    let swap_data = SwapData {
+        from_mint: event.input_mint,
+        to_mint: event.output_mint,
+        from_amount: event.input_amount,
+        to_amount: event.output_amount,
+        description: None,
+    };
+    let mut event = JupiterSwapEvent { metadata, ..event };
+    event.metadata.set_swap_data(swap_data);
+    Some(Box::new(event))
+}
+
+/// 由路由指令 CPI 子树中的 `SwapEvent` / `FeeEvent` 补全路由事件的单跳明细、实际数量与平台手续费，
+/// 非路由事件不做处理
+///
+/// `inner_index` 为路由指令所在内联指令的下标，外层指令为 None；缺少 `stack_height` 时扫描至外层指令结束。
+pub fn complete_route<I: InnerInstructionView>(
+    event: &mut dyn UnifiedEvent,
+    inner_instructions: &[I],
+    inner_index: Option<i64>,
+    accounts: &[Pubkey],
+) {
+    let Some(route) = event.as_any_mut().downcast_mut::<JupiterRouteEvent>() else {
+        return;
+    };
+    let (start, parent_height) = match inner_index {
+        None => (0, Some(1)),
+        Some(index) => (
+            index as usize + 1,
+            inner_instructions.get(index as usize).and_then(InnerInstructionView::stack_height),
+        ),
+    };
+    for instruction in inner_instructions.iter().skip(start) {
+        if let (Some(parent), Some(height)) = (parent_height, instruction.stack_height()) {
+            if height <= parent {
+                break;
+            }
+        }
+        if accounts.get(instruction.program_id_index()) != Some(&JUPITER_V6_PROGRAM_ID) {
+            continue;
+        }
+        let data = instruction.data();
+        if let Some(hop) = data.strip_prefix(discriminators::SWAP_EVENT) {
+            route.hops.extend(borsh::from_slice::<JupiterSwapHop>(hop).ok());
+        } else if let Some(fee) = data.strip_prefix(discriminators::FEE_EVENT) {
+            if let Ok(fee) = borsh::from_slice::<JupiterFee>(fee) {
+                route.platform_fee_account = Some(fee.account);
+                route.platform_fee_mint = Some(fee.mint);
+                route.platform_fee_amount = fee.amount;
+            }
+        }
+    }
+    let (Some(first), Some(last)) = (route.hops.first(), route.hops.last()) else {
+        return;
+    };
+    if route.input_mint == Pubkey::default() {
+        route.input_mint = first.input_mint;
+    }
+    if route.output_mint == Pubkey::default() {
+        route.output_mint = last.output_mint;
+    }
+    // 拆单路由中多跳共享输入或输出代币，实际数量取各跳之和
+    route.in_amount = route
+        .hops
+        .iter()
+        .filter(|hop| hop.input_mint == route.input_mint)
+        .map(|hop| hop.input_amount)
+        .sum();
+    route.out_amount = route
+        .hops
+        .iter()
+        .filter(|hop| hop.output_mint == route.output_mint)
+        .map(|hop| hop.output_amount)
+        .sum();
+    route.metadata.set_swap_data(SwapData {
+        from_mint: route.input_mint,
+        to_mint: route.output_mint,
+        from_amount: route.in_amount,
+        to_amount: route.out_amount,
+        description: None,
+    });
+}
@@ -0,0 +1,44 @@
+use borsh_derive::BorshDeserialize;
+use serde::{Deserialize, Serialize};
+use solana_sdk::pubkey::Pubkey;
+
+/// 路由指令类型
+#[derive(Copy, Clone, Debug, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub enum JupiterRouteKind {
+    #[default]
+    Route,
+    RouteWithTokenLedger,
+    ExactOutRoute,
+    SharedAccountsRoute,
+    SharedAccountsRouteWithTokenLedger,
+    SharedAccountsExactOutRoute,
+}
+
+impl JupiterRouteKind {
+    /// 是否为指定输出数量的路由
+    pub fn is_exact_out(&self) -> bool {
+        matches!(
+            self,
+            JupiterRouteKind::ExactOutRoute | JupiterRouteKind::SharedAccountsExactOutRoute
+        )
+    }
+}
+
+/// 路由中的单跳交易，对应一次 `SwapEvent` CPI
+#[derive(Clone, Debug, Default, PartialEq, Eq, Serialize, Deserialize, BorshDeserialize)]
+pub struct JupiterSwapHop {
+    /// 该跳调用的 AMM 程序
+    pub amm: Pubkey,
+    pub input_mint: Pubkey,
+    pub input_amount: u64,
+    pub output_mint: Pubkey,
+    pub output_amount: u64,
+}
+
+/// 平台手续费，对应 `FeeEvent` CPI
+#[derive(Clone, Debug, Default, PartialEq, Eq, Serialize, Deserialize, BorshDeserialize)]
+pub struct JupiterFee {
+    pub account: Pubkey,
+    pub mint: Pubkey,
+    pub amount: u64,
+}
@@ -64,13 +64,84 @@ impl_unified_event!(
     current_timestamp
 );
 
+/// 创建虚拟池（发射新代币）
+///
+/// `name` / `symbol` / `uri` 及账户字段来自指令，其余字段来自 `EvtInitializePool` 事件。
+#[derive(Clone, Debug, Default, PartialEq, Eq, Serialize, Deserialize, BorshDeserialize)]
+pub struct MeteoraDBCInitializePoolEvent {
+    #[borsh(skip)]
+    pub metadata: EventMetadata,
+    pub pool: Pubkey,
+    pub config: Pubkey,
+    pub creator: Pubkey,
+    pub base_mint: Pubkey,
+    pub pool_type: u8,
+    pub activation_point: u64,
+    #[borsh(skip)]
+    pub name: String,
+    #[borsh(skip)]
+    pub symbol: String,
+    #[borsh(skip)]
+    pub uri: String,
+    #[borsh(skip)]
+    pub payer: Pubkey,
+    #[borsh(skip)]
+    pub quote_mint: Pubkey,
+    #[borsh(skip)]
+    pub base_vault: Pubkey,
+    #[borsh(skip)]
+    pub quote_vault: Pubkey,
+    #[borsh(skip)]
+    pub token_program: Pubkey,
+    #[borsh(skip)]
+    pub token_quote_program: Pubkey,
+}
+
+impl_unified_event!(
+    MeteoraDBCInitializePoolEvent,
+    pool,
+    config,
+    creator,
+    base_mint,
+    pool_type,
+    activation_point
+);
+
+/// 毕业迁移到 DAMM v2
+#[derive(Clone, Debug, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct MeteoraDBCMigrationDammV2Event {
+    pub metadata: EventMetadata,
+    pub virtual_pool: Pubkey,
+    pub migration_metadata: Pubkey,
+    pub config: Pubkey,
+    /// DAMM v2 池子
+    pub pool: Pubkey,
+    pub first_position_nft_mint: Pubkey,
+    pub first_position: Pubkey,
+    pub second_position_nft_mint: Pubkey,
+    pub second_position: Pubkey,
+    pub base_mint: Pubkey,
+    pub quote_mint: Pubkey,
+    pub token_a_vault: Pubkey,
+    pub token_b_vault: Pubkey,
+    pub base_vault: Pubkey,
+    pub quote_vault: Pubkey,
+    pub payer: Pubkey,
+}
+
+impl_unified_event!(MeteoraDBCMigrationDammV2Event,);
+
 /// 事件鉴别器常量
 pub mod discriminators {
 
     // pub const TRADE_EVENT: &str = "0xe445a52e51cb9a1d1b3c15d58aaabb93";
 
     pub const TRADE_EVENT: &[u8] = &[228, 69, 165, 46, 81, 203, 154, 29, 27, 60, 21, 213, 138, 170, 187, 147];
+    pub const INITIALIZE_POOL_EVENT: &[u8] = &[228, 69, 165, 46, 81, 203, 154, 29, 228, 50, 246, 85, 203, 66, 134, 37];
     // 指令鉴别器
     pub const SWAP: &[u8] = &[248, 198, 158, 145, 225, 117, 135, 200];
     pub const SWAP2: &[u8] = &[65, 75, 63, 76, 235, 91, 91, 136];
+    pub const INITIALIZE_VIRTUAL_POOL_WITH_SPL_TOKEN: &[u8] = &[140, 85, 215, 176, 102, 54, 104, 79];
+    pub const INITIALIZE_VIRTUAL_POOL_WITH_TOKEN2022: &[u8] = &[169, 118, 51, 78, 145, 110, 220, 155];
+    pub const MIGRATION_DAMM_V2: &[u8] = &[156, 169, 230, 103, 53, 228, 80, 64];
 }
@@ -7,7 +7,9 @@ use spl_associated_token_account::get_associated_token_address;
 use crate::streaming::event_parser::common::{read_u64_le, EventMetadata, EventType, ProtocolType};
 use crate::streaming::event_parser::core::event_parser::GenericEventParseConfig;
 use crate::streaming::event_parser::protocols::meteora_dbc::discriminators;
-use crate::streaming::event_parser::protocols::meteora_dbc::events::MeteoraDBCSwapEvent;
+use crate::streaming::event_parser::protocols::meteora_dbc::events::{
+    MeteoraDBCInitializePoolEvent, MeteoraDBCMigrationDammV2Event, MeteoraDBCSwapEvent,
+};
 use crate::streaming::event_parser::protocols::meteora_dbc::types::TradeDirection;
 use crate::streaming::event_parser::protocols::pumpfun::PumpFunTradeEvent;
 use crate::streaming::event_parser::UnifiedEvent;
@@ -38,6 +40,36 @@ pub const CONFIGS: &[GenericEventParseConfig] = &[
         instruction_parser: Some(parse_swap_instruction),
         requires_inner_instruction: false,
     },
+    GenericEventParseConfig {
+        program_id: METEORA_DBC_PROGRAM_ID,
+        protocol_type: ProtocolType::MeteoraDBC,
+        inner_instruction_discriminator: discriminators::INITIALIZE_POOL_EVENT,
+        instruction_discriminator: discriminators::INITIALIZE_VIRTUAL_POOL_WITH_SPL_TOKEN,
+        event_type: EventType::MeteoraDBCInitializeVirtualPoolWithSplToken,
+        inner_instruction_parser: Some(parse_initialize_pool_inner_instruction),
+        instruction_parser: Some(parse_initialize_virtual_pool_with_spl_token_instruction),
+        requires_inner_instruction: false,
+    },
+    GenericEventParseConfig {
+        program_id: METEORA_DBC_PROGRAM_ID,
+        protocol_type: ProtocolType::MeteoraDBC,
+        inner_instruction_discriminator: discriminators::INITIALIZE_POOL_EVENT,
+        instruction_discriminator: discriminators::INITIALIZE_VIRTUAL_POOL_WITH_TOKEN2022,
+        event_type: EventType::MeteoraDBCInitializeVirtualPoolWithToken2022,
+        inner_instruction_parser: Some(parse_initialize_pool_inner_instruction),
+        instruction_parser: Some(parse_initialize_virtual_pool_with_token2022_instruction),
+        requires_inner_instruction: false,
+    },
+    GenericEventParseConfig {
+        program_id: METEORA_DBC_PROGRAM_ID,
+        protocol_type: ProtocolType::MeteoraDBC,
+        inner_instruction_discriminator: &[],
+        instruction_discriminator: discriminators::MIGRATION_DAMM_V2,
+        event_type: EventType::MeteoraDBCMigrationDammV2,
+        inner_instruction_parser: None,
+        instruction_parser: Some(parse_migration_damm_v2_instruction),
+        requires_inner_instruction: false,
+    },
 ];
 
 fn parse_trade_inner_instruction(
@@ -103,4 +135,112 @@ fn parse_swap_instruction(
         remaining_accounts: vec![],
         ..Default::default()
     }))
-}
\ No newline at end of file
+}
+
+fn parse_initialize_pool_inner_instruction(
+    data: &[u8],
+    metadata: EventMetadata,
+) -> Option<Box<dyn UnifiedEvent>> {
+    let event = borsh::from_slice::<MeteoraDBCInitializePoolEvent>(data).ok()?;
+    Some(Box::new(MeteoraDBCInitializePoolEvent { metadata, ..event }))
+}
+
+/// 解析 `InitializePoolParameters { name, symbol, uri }`
+fn parse_initialize_pool_params(data: &[u8]) -> Option<(String, String, String)> {
+    let mut offset = 0;
+    let mut read_string = || {
+        let len = u32::from_le_bytes(data.get(offset..offset + 4)?.try_into().ok()?) as usize;
+        offset += 4;
+        let value = String::from_utf8_lossy(data.get(offset..offset + len)?).to_string();
+        offset += len;
+        Some(value)
+    };
+    Some((read_string()?, read_string()?, read_string()?))
+}
+
+/// 解析创建虚拟池指令（SPL Token）
+fn parse_initialize_virtual_pool_with_spl_token_instruction(
+    data: &[u8],
+    accounts: &[Pubkey],
+    metadata: EventMetadata,
+) -> Option<Box<dyn UnifiedEvent>> {
+    if accounts.len() < 16 {
+        return None;
+    }
+    let (name, symbol, uri) = parse_initialize_pool_params(data)?;
+    Some(Box::new(MeteoraDBCInitializePoolEvent {
+        metadata,
+        config: accounts[0],
+        creator: accounts[2],
+        base_mint: accounts[3],
+        quote_mint: accounts[4],
+        pool: accounts[5],
+        base_vault: accounts[6],
+        quote_vault: accounts[7],
+        payer: accounts[10],
+        token_quote_program: accounts[11],
+        token_program: accounts[12],
+        name,
+        symbol,
+        uri,
+        ..Default::default()
+    }))
+}
+
+/// 解析创建虚拟池指令（Token-2022）
+fn parse_initialize_virtual_pool_with_token2022_instruction(
+    data: &[u8],
+    accounts: &[Pubkey],
+    metadata: EventMetadata,
+) -> Option<Box<dyn UnifiedEvent>> {
+    if accounts.len() < 14 {
+        return None;
+    }
+    let (name, symbol, uri) = parse_initialize_pool_params(data)?;
+    Some(Box::new(MeteoraDBCInitializePoolEvent {
+        metadata,
+        config: accounts[0],
+        creator: accounts[2],
+        base_mint: accounts[3],
+        quote_mint: accounts[4],
+        pool: accounts[5],
+        base_vault: accounts[6],
+        quote_vault: accounts[7],
+        payer: accounts[8],
+        token_quote_program: accounts[9],
+        token_program: accounts[10],
+        name,
+        symbol,
+        uri,
+        ..Default::default()
+    }))
+}
+
+/// 解析迁移到 DAMM v2 指令
+fn parse_migration_damm_v2_instruction(
+    _data: &[u8],
+    accounts: &[Pubkey],
+    metadata: EventMetadata,
+) -> Option<Box<dyn UnifiedEvent>> {
+    if accounts.len() < 20 {
+        return None;
+    }
+    Some(Box::new(MeteoraDBCMigrationDammV2Event {
+        metadata,
+        virtual_pool: accounts[0],
+        migration_metadata: accounts[1],
+        config: accounts[2],
+        pool: accounts[4],
+        first_position_nft_mint: accounts[5],
+        first_position: accounts[7],
+        second_position_nft_mint: accounts[8],
+        second_position: accounts[10],
+        base_mint: accounts[13],
+        quote_mint: accounts[14],
+        token_a_vault: accounts[15],
+        token_b_vault: accounts[16],
+        base_vault: accounts[17],
+        quote_vault: accounts[18],
+        payer: accounts[19],
+    }))
+}
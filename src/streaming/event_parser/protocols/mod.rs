@@ -11,6 +11,8 @@ pub mod axiom2;
 pub mod photon;
 pub mod meteora_dbc;
 pub mod meteora_dammv2;
+pub mod jupiter_v6;
+pub mod orca_whirlpool;
 
 pub use block::block_meta_event::BlockMetaEvent;
 pub use types::Protocol;
@@ -0,0 +1,215 @@
+use crate::impl_unified_event;
+use crate::streaming::event_parser::common::EventMetadata;
+use crate::streaming::event_parser::protocols::orca_whirlpool::types::{TickArray, Whirlpool};
+use serde::{Deserialize, Serialize};
+use solana_sdk::pubkey::Pubkey;
+
+/// 交易
+#[derive(Clone, Debug, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct OrcaWhirlpoolSwapEvent {
+    pub metadata: EventMetadata,
+    pub amount: u64,
+    pub other_amount_threshold: u64,
+    pub sqrt_price_limit: u128,
+    pub amount_specified_is_input: bool,
+    pub a_to_b: bool,
+    pub token_program: Pubkey,
+    pub token_authority: Pubkey,
+    pub whirlpool: Pubkey,
+    pub token_owner_account_a: Pubkey,
+    pub token_vault_a: Pubkey,
+    pub token_owner_account_b: Pubkey,
+    pub token_vault_b: Pubkey,
+    pub tick_array0: Pubkey,
+    pub tick_array1: Pubkey,
+    pub tick_array2: Pubkey,
+    pub oracle: Pubkey,
+}
+impl_unified_event!(OrcaWhirlpoolSwapEvent,);
+
+/// 交易v2
+#[derive(Clone, Debug, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct OrcaWhirlpoolSwapV2Event {
+    pub metadata: EventMetadata,
+    pub amount: u64,
+    pub other_amount_threshold: u64,
+    pub sqrt_price_limit: u128,
+    pub amount_specified_is_input: bool,
+    pub a_to_b: bool,
+    pub token_program_a: Pubkey,
+    pub token_program_b: Pubkey,
+    pub memo_program: Pubkey,
+    pub token_authority: Pubkey,
+    pub whirlpool: Pubkey,
+    pub token_mint_a: Pubkey,
+    pub token_mint_b: Pubkey,
+    pub token_owner_account_a: Pubkey,
+    pub token_vault_a: Pubkey,
+    pub token_owner_account_b: Pubkey,
+    pub token_vault_b: Pubkey,
+    pub tick_array0: Pubkey,
+    pub tick_array1: Pubkey,
+    pub tick_array2: Pubkey,
+    pub oracle: Pubkey,
+    pub remaining_accounts: Vec<Pubkey>,
+}
+impl_unified_event!(OrcaWhirlpoolSwapV2Event,);
+
+/// 两跳交易
+#[derive(Clone, Debug, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct OrcaWhirlpoolTwoHopSwapEvent {
+    pub metadata: EventMetadata,
+    pub amount: u64,
+    pub other_amount_threshold: u64,
+    pub amount_specified_is_input: bool,
+    pub a_to_b_one: bool,
+    pub a_to_b_two: bool,
+    pub sqrt_price_limit_one: u128,
+    pub sqrt_price_limit_two: u128,
+    pub token_program: Pubkey,
+    pub token_authority: Pubkey,
+    pub whirlpool_one: Pubkey,
+    pub whirlpool_two: Pubkey,
+    pub token_owner_account_one_a: Pubkey,
+    pub token_vault_one_a: Pubkey,
+    pub token_owner_account_one_b: Pubkey,
+    pub token_vault_one_b: Pubkey,
+    pub token_owner_account_two_a: Pubkey,
+    pub token_vault_two_a: Pubkey,
+    pub token_owner_account_two_b: Pubkey,
+    pub token_vault_two_b: Pubkey,
+    pub tick_array_one0: Pubkey,
+    pub tick_array_one1: Pubkey,
+    pub tick_array_one2: Pubkey,
+    pub tick_array_two0: Pubkey,
+    pub tick_array_two1: Pubkey,
+    pub tick_array_two2: Pubkey,
+    pub oracle_one: Pubkey,
+    pub oracle_two: Pubkey,
+}
+impl_unified_event!(OrcaWhirlpoolTwoHopSwapEvent,);
+
+/// 增加流动性
+#[derive(Clone, Debug, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct OrcaWhirlpoolIncreaseLiquidityEvent {
+    pub metadata: EventMetadata,
+    pub liquidity_amount: u128,
+    pub token_max_a: u64,
+    pub token_max_b: u64,
+    pub whirlpool: Pubkey,
+    pub token_program: Pubkey,
+    pub position_authority: Pubkey,
+    pub position: Pubkey,
+    pub position_token_account: Pubkey,
+    pub token_owner_account_a: Pubkey,
+    pub token_owner_account_b: Pubkey,
+    pub token_vault_a: Pubkey,
+    pub token_vault_b: Pubkey,
+    pub tick_array_lower: Pubkey,
+    pub tick_array_upper: Pubkey,
+}
+impl_unified_event!(OrcaWhirlpoolIncreaseLiquidityEvent,);
+
+/// 减少流动性
+#[derive(Clone, Debug, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct OrcaWhirlpoolDecreaseLiquidityEvent {
+    pub metadata: EventMetadata,
+    pub liquidity_amount: u128,
+    pub token_min_a: u64,
+    pub token_min_b: u64,
+    pub whirlpool: Pubkey,
+    pub token_program: Pubkey,
+    pub position_authority: Pubkey,
+    pub position: Pubkey,
+    pub position_token_account: Pubkey,
+    pub token_owner_account_a: Pubkey,
+    pub token_owner_account_b: Pubkey,
+    pub token_vault_a: Pubkey,
+    pub token_vault_b: Pubkey,
+    pub tick_array_lower: Pubkey,
+    pub tick_array_upper: Pubkey,
+}
+impl_unified_event!(OrcaWhirlpoolDecreaseLiquidityEvent,);
+
+/// 创建池
+#[derive(Clone, Debug, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct OrcaWhirlpoolInitializePoolEvent {
+    pub metadata: EventMetadata,
+    pub whirlpool_bump: u8,
+    pub tick_spacing: u16,
+    pub initial_sqrt_price: u128,
+    pub whirlpools_config: Pubkey,
+    pub token_mint_a: Pubkey,
+    pub token_mint_b: Pubkey,
+    pub funder: Pubkey,
+    pub whirlpool: Pubkey,
+    pub token_vault_a: Pubkey,
+    pub token_vault_b: Pubkey,
+    pub fee_tier: Pubkey,
+    pub token_program: Pubkey,
+    pub system_program: Pubkey,
+    pub rent: Pubkey,
+}
+impl_unified_event!(OrcaWhirlpoolInitializePoolEvent,);
+
+/// 打开仓位
+#[derive(Clone, Debug, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct OrcaWhirlpoolOpenPositionEvent {
+    pub metadata: EventMetadata,
+    pub position_bump: u8,
+    pub tick_lower_index: i32,
+    pub tick_upper_index: i32,
+    pub funder: Pubkey,
+    pub owner: Pubkey,
+    pub position: Pubkey,
+    pub position_mint: Pubkey,
+    pub position_token_account: Pubkey,
+    pub whirlpool: Pubkey,
+    pub token_program: Pubkey,
+    pub system_program: Pubkey,
+    pub rent: Pubkey,
+    pub associated_token_program: Pubkey,
+}
+impl_unified_event!(OrcaWhirlpoolOpenPositionEvent,);
+
+/// 池状态
+#[derive(Clone, Debug, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct OrcaWhirlpoolPoolAccountEvent {
+    pub metadata: EventMetadata,
+    pub pubkey: Pubkey,
+    pub executable: bool,
+    pub lamports: u64,
+    pub owner: Pubkey,
+    pub rent_epoch: u64,
+    pub whirlpool: Whirlpool,
+}
+impl_unified_event!(OrcaWhirlpoolPoolAccountEvent,);
+
+/// Tick 数组
+#[derive(Clone, Debug, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct OrcaWhirlpoolTickArrayAccountEvent {
+    pub metadata: EventMetadata,
+    pub pubkey: Pubkey,
+    pub executable: bool,
+    pub lamports: u64,
+    pub owner: Pubkey,
+    pub rent_epoch: u64,
+    pub tick_array: TickArray,
+}
+impl_unified_event!(OrcaWhirlpoolTickArrayAccountEvent,);
+
+/// 事件鉴别器常量
+pub mod discriminators {
+    // 指令鉴别器
+    pub const SWAP: &[u8] = &[248, 198, 158, 145, 225, 117, 135, 200];
+    pub const SWAP_V2: &[u8] = &[43, 4, 237, 11, 26, 201, 30, 98];
+    pub const TWO_HOP_SWAP: &[u8] = &[195, 96, 237, 108, 68, 162, 219, 230];
+    pub const INCREASE_LIQUIDITY: &[u8] = &[46, 156, 243, 118, 13, 205, 251, 178];
+    pub const DECREASE_LIQUIDITY: &[u8] = &[160, 38, 208, 111, 104, 91, 44, 1];
+    pub const INITIALIZE_POOL: &[u8] = &[95, 180, 10, 172, 84, 174, 232, 40];
+    pub const OPEN_POSITION: &[u8] = &[135, 128, 47, 77, 15, 152, 240, 49];
+
+    // 账号鉴别器
+    pub const WHIRLPOOL: &[u8] = &[63, 149, 209, 12, 225, 128, 99, 9];
+    pub const TICK_ARRAY: &[u8] = &[69, 97, 189, 190, 110, 7, 66, 187];
+}
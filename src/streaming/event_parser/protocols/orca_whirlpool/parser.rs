@@ -0,0 +1,311 @@
+use crate::streaming::event_parser::{
+    common::{
+        read_i32_le, read_u128_le, read_u16_le, read_u64_le, read_u8_le, EventMetadata, EventType,
+        ProtocolType,
+    },
+    core::event_parser::GenericEventParseConfig,
+    protocols::orca_whirlpool::{
+        discriminators, OrcaWhirlpoolDecreaseLiquidityEvent, OrcaWhirlpoolIncreaseLiquidityEvent,
+        OrcaWhirlpoolInitializePoolEvent, OrcaWhirlpoolOpenPositionEvent, OrcaWhirlpoolSwapEvent,
+        OrcaWhirlpoolSwapV2Event, OrcaWhirlpoolTwoHopSwapEvent,
+    },
+    UnifiedEvent,
+};
+use solana_sdk::pubkey::Pubkey;
+
+/// Orca Whirlpool程序ID
+pub const ORCA_WHIRLPOOL_PROGRAM_ID: Pubkey =
+    solana_sdk::pubkey!("whirLbMiicVdio4qvUfM5KAg6Ct8VwpYzGff3uctyCc");
+
+// 配置所有事件类型
+pub const CONFIGS: &[GenericEventParseConfig] = &[
+    GenericEventParseConfig {
+        program_id: ORCA_WHIRLPOOL_PROGRAM_ID,
+        protocol_type: ProtocolType::OrcaWhirlpool,
+        inner_instruction_discriminator: &[],
+        instruction_discriminator: discriminators::SWAP,
+        event_type: EventType::OrcaWhirlpoolSwap,
+        inner_instruction_parser: None,
+        instruction_parser: Some(parse_swap_instruction),
+        requires_inner_instruction: false,
+    },
+    GenericEventParseConfig {
+        program_id: ORCA_WHIRLPOOL_PROGRAM_ID,
+        protocol_type: ProtocolType::OrcaWhirlpool,
+        inner_instruction_discriminator: &[],
+        instruction_discriminator: discriminators::SWAP_V2,
+        event_type: EventType::OrcaWhirlpoolSwapV2,
+        inner_instruction_parser: None,
+        instruction_parser: Some(parse_swap_v2_instruction),
+        requires_inner_instruction: false,
+    },
+    GenericEventParseConfig {
+        program_id: ORCA_WHIRLPOOL_PROGRAM_ID,
+        protocol_type: ProtocolType::OrcaWhirlpool,
+        inner_instruction_discriminator: &[],
+        instruction_discriminator: discriminators::TWO_HOP_SWAP,
+        event_type: EventType::OrcaWhirlpoolTwoHopSwap,
+        inner_instruction_parser: None,
+        instruction_parser: Some(parse_two_hop_swap_instruction),
+        requires_inner_instruction: false,
+    },
+    GenericEventParseConfig {
+        program_id: ORCA_WHIRLPOOL_PROGRAM_ID,
+        protocol_type: ProtocolType::OrcaWhirlpool,
+        inner_instruction_discriminator: &[],
+        instruction_discriminator: discriminators::INCREASE_LIQUIDITY,
+        event_type: EventType::OrcaWhirlpoolIncreaseLiquidity,
+        inner_instruction_parser: None,
+        instruction_parser: Some(parse_increase_liquidity_instruction),
+        requires_inner_instruction: false,
+    },
+    GenericEventParseConfig {
+        program_id: ORCA_WHIRLPOOL_PROGRAM_ID,
+        protocol_type: ProtocolType::OrcaWhirlpool,
+        inner_instruction_discriminator: &[],
+        instruction_discriminator: discriminators::DECREASE_LIQUIDITY,
+        event_type: EventType::OrcaWhirlpoolDecreaseLiquidity,
+        inner_instruction_parser: None,
+        instruction_parser: Some(parse_decrease_liquidity_instruction),
+        requires_inner_instruction: false,
+    },
+    GenericEventParseConfig {
+        program_id: ORCA_WHIRLPOOL_PROGRAM_ID,
+        protocol_type: ProtocolType::OrcaWhirlpool,
+        inner_instruction_discriminator: &[],
+        instruction_discriminator: discriminators::INITIALIZE_POOL,
+        event_type: EventType::OrcaWhirlpoolInitializePool,
+        inner_instruction_parser: None,
+        instruction_parser: Some(parse_initialize_pool_instruction),
+        requires_inner_instruction: false,
+    },
+    GenericEventParseConfig {
+        program_id: ORCA_WHIRLPOOL_PROGRAM_ID,
+        protocol_type: ProtocolType::OrcaWhirlpool,
+        inner_instruction_discriminator: &[],
+        instruction_discriminator: discriminators::OPEN_POSITION,
+        event_type: EventType::OrcaWhirlpoolOpenPosition,
+        inner_instruction_parser: None,
+        instruction_parser: Some(parse_open_position_instruction),
+        requires_inner_instruction: false,
+    },
+];
+
+/// 解析交易指令事件
+fn parse_swap_instruction(
+    data: &[u8],
+    accounts: &[Pubkey],
+    metadata: EventMetadata,
+) -> Option<Box<dyn UnifiedEvent>> {
+    if data.len() < 34 || accounts.len() < 11 {
+        return None;
+    }
+    Some(Box::new(OrcaWhirlpoolSwapEvent {
+        metadata,
+        amount: read_u64_le(data, 0)?,
+        other_amount_threshold: read_u64_le(data, 8)?,
+        sqrt_price_limit: read_u128_le(data, 16)?,
+        amount_specified_is_input: read_u8_le(data, 32)? == 1,
+        a_to_b: read_u8_le(data, 33)? == 1,
+        token_program: accounts[0],
+        token_authority: accounts[1],
+        whirlpool: accounts[2],
+        token_owner_account_a: accounts[3],
+        token_vault_a: accounts[4],
+        token_owner_account_b: accounts[5],
+        token_vault_b: accounts[6],
+        tick_array0: accounts[7],
+        tick_array1: accounts[8],
+        tick_array2: accounts[9],
+        oracle: accounts[10],
+    }))
+}
+
+/// 解析交易v2指令事件
+///
+/// 末尾的 `remaining_accounts_info` 为可选变长参数，不解析，对应账户见 `remaining_accounts`。
+fn parse_swap_v2_instruction(
+    data: &[u8],
+    accounts: &[Pubkey],
+    metadata: EventMetadata,
+) -> Option<Box<dyn UnifiedEvent>> {
+    if data.len() < 34 || accounts.len() < 15 {
+        return None;
+    }
+    Some(Box::new(OrcaWhirlpoolSwapV2Event {
+        metadata,
+        amount: read_u64_le(data, 0)?,
+        other_amount_threshold: read_u64_le(data, 8)?,
+        sqrt_price_limit: read_u128_le(data, 16)?,
+        amount_specified_is_input: read_u8_le(data, 32)? == 1,
+        a_to_b: read_u8_le(data, 33)? == 1,
+        token_program_a: accounts[0],
+        token_program_b: accounts[1],
+        memo_program: accounts[2],
+        token_authority: accounts[3],
+        whirlpool: accounts[4],
+        token_mint_a: accounts[5],
+        token_mint_b: accounts[6],
+        token_owner_account_a: accounts[7],
+        token_vault_a: accounts[8],
+        token_owner_account_b: accounts[9],
+        token_vault_b: accounts[10],
+        tick_array0: accounts[11],
+        tick_array1: accounts[12],
+        tick_array2: accounts[13],
+        oracle: accounts[14],
+        remaining_accounts: accounts[15..].to_vec(),
+    }))
+}
+
+/// 解析两跳交易指令事件
+fn parse_two_hop_swap_instruction(
+    data: &[u8],
+    accounts: &[Pubkey],
+    metadata: EventMetadata,
+) -> Option<Box<dyn UnifiedEvent>> {
+    if data.len() < 51 || accounts.len() < 20 {
+        return None;
+    }
+    Some(Box::new(OrcaWhirlpoolTwoHopSwapEvent {
+        metadata,
+        amount: read_u64_le(data, 0)?,
+        other_amount_threshold: read_u64_le(data, 8)?,
+        amount_specified_is_input: read_u8_le(data, 16)? == 1,
+        a_to_b_one: read_u8_le(data, 17)? == 1,
+        a_to_b_two: read_u8_le(data, 18)? == 1,
+        sqrt_price_limit_one: read_u128_le(data, 19)?,
+        sqrt_price_limit_two: read_u128_le(data, 35)?,
+        token_program: accounts[0],
+        token_authority: accounts[1],
+        whirlpool_one: accounts[2],
+        whirlpool_two: accounts[3],
+        token_owner_account_one_a: accounts[4],
+        token_vault_one_a: accounts[5],
+        token_owner_account_one_b: accounts[6],
+        token_vault_one_b: accounts[7],
+        token_owner_account_two_a: accounts[8],
+        token_vault_two_a: accounts[9],
+        token_owner_account_two_b: accounts[10],
+        token_vault_two_b: accounts[11],
+        tick_array_one0: accounts[12],
+        tick_array_one1: accounts[13],
+        tick_array_one2: accounts[14],
+        tick_array_two0: accounts[15],
+        tick_array_two1: accounts[16],
+        tick_array_two2: accounts[17],
+        oracle_one: accounts[18],
+        oracle_two: accounts[19],
+    }))
+}
+
+/// 解析增加流动性指令事件
+fn parse_increase_liquidity_instruction(
+    data: &[u8],
+    accounts: &[Pubkey],
+    metadata: EventMetadata,
+) -> Option<Box<dyn UnifiedEvent>> {
+    if data.len() < 32 || accounts.len() < 11 {
+        return None;
+    }
+    Some(Box::new(OrcaWhirlpoolIncreaseLiquidityEvent {
+        metadata,
+        liquidity_amount: read_u128_le(data, 0)?,
+        token_max_a: read_u64_le(data, 16)?,
+        token_max_b: read_u64_le(data, 24)?,
+        whirlpool: accounts[0],
+        token_program: accounts[1],
+        position_authority: accounts[2],
+        position: accounts[3],
+        position_token_account: accounts[4],
+        token_owner_account_a: accounts[5],
+        token_owner_account_b: accounts[6],
+        token_vault_a: accounts[7],
+        token_vault_b: accounts[8],
+        tick_array_lower: accounts[9],
+        tick_array_upper: accounts[10],
+    }))
+}
+
+/// 解析减少流动性指令事件
+fn parse_decrease_liquidity_instruction(
+    data: &[u8],
+    accounts: &[Pubkey],
+    metadata: EventMetadata,
+) -> Option<Box<dyn UnifiedEvent>> {
+    if data.len() < 32 || accounts.len() < 11 {
+        return None;
+    }
+    Some(Box::new(OrcaWhirlpoolDecreaseLiquidityEvent {
+        metadata,
+        liquidity_amount: read_u128_le(data, 0)?,
+        token_min_a: read_u64_le(data, 16)?,
+        token_min_b: read_u64_le(data, 24)?,
+        whirlpool: accounts[0],
+        token_program: accounts[1],
+        position_authority: accounts[2],
+        position: accounts[3],
+        position_token_account: accounts[4],
+        token_owner_account_a: accounts[5],
+        token_owner_account_b: accounts[6],
+        token_vault_a: accounts[7],
+        token_vault_b: accounts[8],
+        tick_array_lower: accounts[9],
+        tick_array_upper: accounts[10],
+    }))
+}
+
+/// 解析创建池指令事件
+fn parse_initialize_pool_instruction(
+    data: &[u8],
+    accounts: &[Pubkey],
+    metadata: EventMetadata,
+) -> Option<Box<dyn UnifiedEvent>> {
+    if data.len() < 19 || accounts.len() < 11 {
+        return None;
+    }
+    Some(Box::new(OrcaWhirlpoolInitializePoolEvent {
+        metadata,
+        whirlpool_bump: read_u8_le(data, 0)?,
+        tick_spacing: read_u16_le(data, 1)?,
+        initial_sqrt_price: read_u128_le(data, 3)?,
+        whirlpools_config: accounts[0],
+        token_mint_a: accounts[1],
+        token_mint_b: accounts[2],
+        funder: accounts[3],
+        whirlpool: accounts[4],
+        token_vault_a: accounts[5],
+        token_vault_b: accounts[6],
+        fee_tier: accounts[7],
+        token_program: accounts[8],
+        system_program: accounts[9],
+        rent: accounts[10],
+    }))
+}
+
+/// 解析打开仓位指令事件
+fn parse_open_position_instruction(
+    data: &[u8],
+    accounts: &[Pubkey],
+    metadata: EventMetadata,
+) -> Option<Box<dyn UnifiedEvent>> {
+    if data.len() < 9 || accounts.len() < 10 {
+        return None;
+    }
+    Some(Box::new(OrcaWhirlpoolOpenPositionEvent {
+        metadata,
+        position_bump: read_u8_le(data, 0)?,
+        tick_lower_index: read_i32_le(data, 1)?,
+        tick_upper_index: read_i32_le(data, 5)?,
+        funder: accounts[0],
+        owner: accounts[1],
+        position: accounts[2],
+        position_mint: accounts[3],
+        position_token_account: accounts[4],
+        whirlpool: accounts[5],
+        token_program: accounts[6],
+        system_program: accounts[7],
+        rent: accounts[8],
+        associated_token_program: accounts[9],
+    }))
+}
@@ -0,0 +1,134 @@
+use borsh::BorshDeserialize;
+use serde::{Deserialize, Serialize};
+use solana_sdk::pubkey::Pubkey;
+
+use crate::streaming::{
+    event_parser::{
+        common::EventMetadata,
+        protocols::orca_whirlpool::{
+            OrcaWhirlpoolPoolAccountEvent, OrcaWhirlpoolTickArrayAccountEvent,
+        },
+        UnifiedEvent,
+    },
+    grpc::AccountPretty,
+};
+
+#[derive(Clone, Debug, Default, PartialEq, Eq, Serialize, Deserialize, BorshDeserialize)]
+pub struct WhirlpoolRewardInfo {
+    pub mint: Pubkey,
+    pub vault: Pubkey,
+    pub authority: Pubkey,
+    pub emissions_per_second_x64: u128,
+    pub growth_global_x64: u128,
+}
+
+#[derive(Clone, Debug, Default, PartialEq, Eq, Serialize, Deserialize, BorshDeserialize)]
+pub struct Whirlpool {
+    pub whirlpools_config: Pubkey,
+    pub whirlpool_bump: [u8; 1],
+    pub tick_spacing: u16,
+    pub fee_tier_index_seed: [u8; 2],
+    pub fee_rate: u16,
+    pub protocol_fee_rate: u16,
+    pub liquidity: u128,
+    pub sqrt_price: u128,
+    pub tick_current_index: i32,
+    pub protocol_fee_owed_a: u64,
+    pub protocol_fee_owed_b: u64,
+    pub token_mint_a: Pubkey,
+    pub token_vault_a: Pubkey,
+    pub fee_growth_global_a: u128,
+    pub token_mint_b: Pubkey,
+    pub token_vault_b: Pubkey,
+    pub fee_growth_global_b: u128,
+    pub reward_last_updated_timestamp: u64,
+    pub reward_infos: [WhirlpoolRewardInfo; 3],
+}
+
+pub const WHIRLPOOL_SIZE: usize = 645;
+
+pub fn whirlpool_decode(data: &[u8]) -> Option<Whirlpool> {
+    if data.len() < WHIRLPOOL_SIZE {
+        return None;
+    }
+    borsh::from_slice::<Whirlpool>(&data[..WHIRLPOOL_SIZE]).ok()
+}
+
+pub fn whirlpool_parser(
+    account: &AccountPretty,
+    metadata: EventMetadata,
+) -> Option<Box<dyn UnifiedEvent>> {
+    if account.data.len() < WHIRLPOOL_SIZE + 8 {
+        return None;
+    }
+    let whirlpool = whirlpool_decode(&account.data[8..WHIRLPOOL_SIZE + 8])?;
+    Some(Box::new(OrcaWhirlpoolPoolAccountEvent {
+        metadata,
+        pubkey: account.pubkey,
+        executable: account.executable,
+        lamports: account.lamports,
+        owner: account.owner,
+        rent_epoch: account.rent_epoch,
+        whirlpool,
+    }))
+}
+
+#[derive(Clone, Debug, Default, PartialEq, Eq, Serialize, Deserialize, BorshDeserialize)]
+pub struct Tick {
+    pub initialized: bool,
+    pub liquidity_net: i128,
+    pub liquidity_gross: u128,
+    pub fee_growth_outside_a: u128,
+    pub fee_growth_outside_b: u128,
+    pub reward_growths_outside: [u128; 3],
+}
+
+/// 每个 TickArray 包含的 tick 数量
+pub const TICK_ARRAY_SIZE: usize = 88;
+
+/// 固定大小的 TickArray；动态 TickArray 账户鉴别器不同，不在此解析
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize, BorshDeserialize)]
+pub struct TickArray {
+    pub start_tick_index: i32,
+    #[serde(with = "serde_big_array::BigArray")]
+    pub ticks: [Tick; TICK_ARRAY_SIZE],
+    pub whirlpool: Pubkey,
+}
+
+impl Default for TickArray {
+    fn default() -> Self {
+        Self {
+            start_tick_index: 0,
+            ticks: core::array::from_fn(|_| Tick::default()),
+            whirlpool: Pubkey::default(),
+        }
+    }
+}
+
+pub const TICK_ARRAY_ACCOUNT_SIZE: usize = 9980;
+
+pub fn tick_array_decode(data: &[u8]) -> Option<TickArray> {
+    if data.len() < TICK_ARRAY_ACCOUNT_SIZE {
+        return None;
+    }
+    borsh::from_slice::<TickArray>(&data[..TICK_ARRAY_ACCOUNT_SIZE]).ok()
+}
+
+pub fn tick_array_parser(
+    account: &AccountPretty,
+    metadata: EventMetadata,
+) -> Option<Box<dyn UnifiedEvent>> {
+    if account.data.len() < TICK_ARRAY_ACCOUNT_SIZE + 8 {
+        return None;
+    }
+    let tick_array = tick_array_decode(&account.data[8..TICK_ARRAY_ACCOUNT_SIZE + 8])?;
+    Some(Box::new(OrcaWhirlpoolTickArrayAccountEvent {
+        metadata,
+        pubkey: account.pubkey,
+        executable: account.executable,
+        lamports: account.lamports,
+        owner: account.owner,
+        rent_epoch: account.rent_epoch,
+        tick_array,
+    }))
+}
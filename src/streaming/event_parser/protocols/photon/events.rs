@@ -1,5 +1,5 @@
 use crate::impl_unified_event;
-use crate::streaming::event_parser::common::EventMetadata;
+use crate::streaming::event_parser::common::{EventMetadata, InstructionIndex};
 use crate::streaming::event_parser::protocols::bonk::types::{
     CurveParams, MintParams, PoolStatus, TradeDirection, VestingParams,
 };
@@ -77,6 +77,9 @@ pub struct PhotonPumpFunTradeEvent {
     pub fee_config: Pubkey,
     #[borsh(skip)]
     pub fee_program: Pubkey,
+    /// 路由调用的底层 PumpFun / PumpSwap 交易指令位置，未找到时为 None
+    #[borsh(skip)]
+    pub routed_trade: Option<InstructionIndex>,
 }
 
 impl_unified_event!(
@@ -149,6 +152,9 @@ pub struct PhotonPumpSwapTradeEvent {
     pub global_volume_accumulator: Pubkey,
     #[borsh(skip)]
     pub user_volume_accumulator: Pubkey,
+    /// 路由调用的底层 PumpFun / PumpSwap 交易指令位置，未找到时为 None
+    #[borsh(skip)]
+    pub routed_trade: Option<InstructionIndex>,
 }
 
 // 使用宏生成UnifiedEvent实现，指定需要合并的字段
@@ -26,6 +26,9 @@ pub struct PumpFunCreateTokenEvent {
     pub mint_authority: Pubkey,
     #[borsh(skip)]
     pub associated_bonding_curve: Pubkey,
+    /// 代币程序，来自指令账户（create_v2 为 Token-2022）
+    #[borsh(skip)]
+    pub token_program: Pubkey,
 }
 
 pub const PUMPFUN_CREATE_TOKEN_EVENT_LOG_SIZE: usize = 257;
@@ -271,6 +274,7 @@ pub mod discriminators {
 
     // 指令鉴别器
     pub const CREATE_TOKEN_IX: &[u8] = &[24, 30, 200, 40, 5, 28, 7, 119];
+    pub const CREATE_TOKEN_V2_IX: &[u8] = &[214, 144, 76, 236, 95, 139, 49, 180];
     pub const BUY_IX: &[u8] = &[102, 6, 61, 18, 1, 218, 235, 234];
     pub const SELL_IX: &[u8] = &[51, 230, 133, 164, 1, 127, 131, 173];
     pub const MIGRATE_IX: &[u8] = &[155, 234, 231, 146, 236, 158, 162, 30];
@@ -26,6 +26,16 @@ pub const CONFIGS: &[GenericEventParseConfig] = &[
         instruction_parser: Some(parse_create_token_instruction),
         requires_inner_instruction: false,
     },
+    GenericEventParseConfig {
+        program_id: PUMPFUN_PROGRAM_ID,
+        protocol_type: ProtocolType::PumpFun,
+        inner_instruction_discriminator: discriminators::CREATE_TOKEN_EVENT,
+        instruction_discriminator: discriminators::CREATE_TOKEN_V2_IX,
+        event_type: EventType::PumpFunCreateToken,
+        inner_instruction_parser: Some(parse_create_token_inner_instruction),
+        instruction_parser: Some(parse_create_token_v2_instruction),
+        requires_inner_instruction: false,
+    },
     GenericEventParseConfig {
         program_id: PUMPFUN_PROGRAM_ID,
         protocol_type: ProtocolType::PumpFun,
@@ -95,13 +105,12 @@ fn parse_trade_inner_instruction(
     }
 }
 
-/// 解析创建代币指令事件
-fn parse_create_token_instruction(
+/// 解析 create / create_v2 的指令参数（name、symbol、uri、creator）
+fn parse_create_token_args(
     data: &[u8],
-    accounts: &[Pubkey],
     metadata: EventMetadata,
-) -> Option<Box<dyn UnifiedEvent>> {
-    if data.len() < 16 || accounts.len() < 11 {
+) -> Option<PumpFunCreateTokenEvent> {
+    if data.len() < 16 {
         return None;
     }
     let mut offset = 0;
@@ -141,18 +150,53 @@ fn parse_create_token_instruction(
         Pubkey::default()
     };
 
-    Some(Box::new(PumpFunCreateTokenEvent {
+    Some(PumpFunCreateTokenEvent {
         metadata,
         name: name.to_string(),
         symbol: symbol.to_string(),
         uri: uri.to_string(),
         creator,
+        ..Default::default()
+    })
+}
+
+/// 解析创建代币指令事件
+fn parse_create_token_instruction(
+    data: &[u8],
+    accounts: &[Pubkey],
+    metadata: EventMetadata,
+) -> Option<Box<dyn UnifiedEvent>> {
+    if accounts.len() < 11 {
+        return None;
+    }
+    Some(Box::new(PumpFunCreateTokenEvent {
         mint: accounts[0],
         mint_authority: accounts[1],
         bonding_curve: accounts[2],
         associated_bonding_curve: accounts[3],
         user: accounts[7],
-        ..Default::default()
+        token_program: accounts[9],
+        ..parse_create_token_args(data, metadata)?
+    }))
+}
+
+/// 解析 create_v2 指令事件，代币使用 Token-2022
+fn parse_create_token_v2_instruction(
+    data: &[u8],
+    accounts: &[Pubkey],
+    metadata: EventMetadata,
+) -> Option<Box<dyn UnifiedEvent>> {
+    if accounts.len() < 8 {
+        return None;
+    }
+    Some(Box::new(PumpFunCreateTokenEvent {
+        mint: accounts[0],
+        mint_authority: accounts[1],
+        bonding_curve: accounts[2],
+        associated_bonding_curve: accounts[3],
+        user: accounts[5],
+        token_program: accounts[7],
+        ..parse_create_token_args(data, metadata)?
     }))
 }
 
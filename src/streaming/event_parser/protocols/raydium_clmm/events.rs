@@ -91,8 +91,26 @@ pub struct RaydiumClmmDecreaseLiquidityV2Event {
     pub vault0_mint: Pubkey,
     pub vault1_mint: Pubkey,
     pub remaining_accounts: Vec<Pubkey>,
+    /// 以下字段来自 `DecreaseLiquidityEvent` 日志，没有日志时为 0
+    /// 移除的流动性对应的代币数量，不含手续费
+    pub decrease_amount0: u64,
+    pub decrease_amount1: u64,
+    /// 同时领取的仓位手续费
+    pub fee_amount0: u64,
+    pub fee_amount1: u64,
+    /// Token-2022 转账费
+    pub transfer_fee0: u64,
+    pub transfer_fee1: u64,
 }
-impl_unified_event!(RaydiumClmmDecreaseLiquidityV2Event,);
+impl_unified_event!(
+    RaydiumClmmDecreaseLiquidityV2Event,
+    decrease_amount0,
+    decrease_amount1,
+    fee_amount0,
+    fee_amount1,
+    transfer_fee0,
+    transfer_fee1
+);
 
 /// 创建池
 #[derive(Clone, Debug, Default, PartialEq, Eq, Serialize, Deserialize)]
@@ -260,6 +278,9 @@ impl_unified_event!(RaydiumClmmTickArrayStateAccountEvent,);
 
 /// 事件鉴别器常量
 pub mod discriminators {
+    // 事件鉴别器（emit! 输出到 `Program data:` 日志）
+    pub const DECREASE_LIQUIDITY_EVENT: &[u8] = &[58, 222, 86, 58, 68, 50, 85, 56];
+
     // 指令鉴别器
     pub const SWAP: &[u8] = &[248, 198, 158, 145, 225, 117, 135, 200];
     pub const SWAP_V2: &[u8] = &[43, 4, 237, 11, 26, 201, 30, 98];
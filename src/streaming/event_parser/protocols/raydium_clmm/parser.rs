@@ -53,10 +53,10 @@ pub const CONFIGS: &[GenericEventParseConfig] = &[
     GenericEventParseConfig {
         program_id: RAYDIUM_CLMM_PROGRAM_ID,
         protocol_type: ProtocolType::RaydiumClmm,
-        inner_instruction_discriminator: &[],
+        inner_instruction_discriminator: discriminators::DECREASE_LIQUIDITY_EVENT,
         instruction_discriminator: discriminators::DECREASE_LIQUIDITY_V2,
         event_type: EventType::RaydiumClmmDecreaseLiquidityV2,
-        inner_instruction_parser: None,
+        inner_instruction_parser: Some(parse_decrease_liquidity_inner_instruction),
         instruction_parser: Some(parse_decrease_liquidity_v2_instruction),
         requires_inner_instruction: false,
     },
@@ -253,6 +253,24 @@ fn parse_create_pool_instruction(
     }))
 }
 
+/// 解析减少流动性日志事件（DecreaseLiquidityEvent），仅取数量字段
+fn parse_decrease_liquidity_inner_instruction(
+    data: &[u8],
+    metadata: EventMetadata,
+) -> Option<Box<dyn UnifiedEvent>> {
+    // position_nft_mint(32) + liquidity(16) 之后依次为数量、手续费、reward_amounts([u64; 3])、转账费
+    Some(Box::new(RaydiumClmmDecreaseLiquidityV2Event {
+        metadata,
+        decrease_amount0: read_u64_le(data, 48)?,
+        decrease_amount1: read_u64_le(data, 56)?,
+        fee_amount0: read_u64_le(data, 64)?,
+        fee_amount1: read_u64_le(data, 72)?,
+        transfer_fee0: read_u64_le(data, 104)?,
+        transfer_fee1: read_u64_le(data, 112)?,
+        ..Default::default()
+    }))
+}
+
 /// 解析减少流动性v2指令事件
 fn parse_decrease_liquidity_v2_instruction(
     data: &[u8],
@@ -284,6 +302,7 @@ fn parse_decrease_liquidity_v2_instruction(
         vault0_mint: accounts[14],
         vault1_mint: accounts[15],
         remaining_accounts: accounts[16..].to_vec(),
+        ..Default::default()
     }))
 }
 
@@ -10,6 +10,8 @@ use crate::streaming::event_parser::protocols::axiom::parser::{AXIOM_1_PROGRAM_I
 use crate::streaming::event_parser::protocols::meteora_dammv2::parser::METEORA_DAMM_V2_PROGRAM_ID;
 use crate::streaming::event_parser::protocols::meteora_dbc::parser::METEORA_DBC_PROGRAM_ID;
 use crate::streaming::event_parser::protocols::photon::parser::PHOTON_PROGRAM_ID;
+use crate::streaming::event_parser::protocols::jupiter_v6::parser::JUPITER_V6_PROGRAM_ID;
+use crate::streaming::event_parser::protocols::orca_whirlpool::parser::ORCA_WHIRLPOOL_PROGRAM_ID;
 
 /// 支持的协议
 #[derive(Debug, Clone, PartialEq, Eq, Hash)]
@@ -27,6 +29,8 @@ pub enum Protocol {
     AxiomProgram2,
     AxiomProgram1Var1,
     AxiomProgram2Var1,
+    JupiterV6,
+    OrcaWhirlpool,
 }
 
 impl Protocol {
@@ -45,7 +49,8 @@ impl Protocol {
             Protocol::AxiomProgram2 => vec![AXIOM_2_PROGRAM_ID],
             Protocol::AxiomProgram1Var1 => vec![AXIOM_1_PROGRAM_VAR_1_ID],
             Protocol::AxiomProgram2Var1 => vec![AXIOM_2_PROGRAM_VAR_1_ID],
-
+            Protocol::JupiterV6 => vec![JUPITER_V6_PROGRAM_ID],
+            Protocol::OrcaWhirlpool => vec![ORCA_WHIRLPOOL_PROGRAM_ID],
         }
     }
 }
@@ -66,6 +71,8 @@ impl std::fmt::Display for Protocol {
             Protocol::AxiomProgram2 => write!(f, "AxiomTradingProgram2"),
             Protocol::AxiomProgram1Var1 => write!(f, "AxiomTradingProgram1Var1"),
             Protocol::AxiomProgram2Var1 => write!(f, "AxiomTradingProgram2Var1"),
+            Protocol::JupiterV6 => write!(f, "JupiterV6"),
+            Protocol::OrcaWhirlpool => write!(f, "OrcaWhirlpool"),
         }
     }
 }
@@ -88,6 +95,8 @@ impl std::str::FromStr for Protocol {
             "axiomprogram2" => Ok(Protocol::AxiomProgram2),
             "axiomprogram1var1" => Ok(Protocol::AxiomProgram1Var1),
             "axiomprogram2var1" => Ok(Protocol::AxiomProgram2Var1),
+            "jupiterv6" => Ok(Protocol::JupiterV6),
+            "orcawhirlpool" => Ok(Protocol::OrcaWhirlpool),
             _ => Err(anyhow!("Unsupported protocol: {}", s)),
         }
     }
@@ -12,6 +12,6 @@ pub use types::*;
 
 // 从公用模块重新导出
 pub use crate::streaming::common::{
-    BackpressureConfig, BackpressureStrategy, ConnectionConfig, MetricsManager, PerformanceMetrics,
-    StreamClientConfig as ClientConfig,
+    BackpressureConfig, BackpressureStrategy, ConnectionConfig, FailedTransactionMode,
+    MetricsManager, PerformanceMetrics, StreamClientConfig as ClientConfig,
 };
@@ -83,7 +83,8 @@ impl SubscriptionManager {
         account_filter: Vec<AccountFilter>,
         event_type_filter: Option<&EventTypeFilter>,
     ) -> Option<AccountsFilterMap> {
-        if event_type_filter.is_some() && !event_type_filter.unwrap().include_account_event() {
+        let registry = self.config.protocol_registry.as_deref();
+        if event_type_filter.is_some_and(|filter| !filter.include_account_event(registry)) {
             return None;
         }
         if account_filter.len() == 0 {
@@ -110,7 +111,8 @@ impl SubscriptionManager {
         transaction_filter: Vec<TransactionFilter>,
         event_type_filter: Option<&EventTypeFilter>,
     ) -> Option<TransactionsFilterMap> {
-        if event_type_filter.is_some() && !event_type_filter.unwrap().include_transaction_event() {
+        let registry = self.config.protocol_registry.as_deref();
+        if event_type_filter.is_some_and(|filter| !filter.include_transaction_event(registry)) {
             return None;
         }
         let mut transactions = HashMap::new();
@@ -119,7 +121,7 @@ impl SubscriptionManager {
                 "client".to_string(),
                 SubscribeRequestFilterTransactions {
                     vote: Some(false),
-                    failed: Some(false),
+                    failed: self.config.failed_transactions.subscribe_filter(),
                     signature: None,
                     account_include: tf.account_include,
                     account_exclude: tf.account_exclude,
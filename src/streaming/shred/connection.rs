@@ -30,7 +30,8 @@ impl ShredStreamGrpc {
         let shredstream_client = ShredstreamProxyClient::connect(endpoint.clone()).await?;
         let metrics = Arc::new(RwLock::new(PerformanceMetrics::new()));
 
-        let metrics_manager = MetricsManager::new(config.enable_metrics, "ShredStream".to_string());
+        let metrics_manager = MetricsManager::new(config.enable_metrics, "ShredStream".to_string())
+            .with_report_config(config.metrics_report.clone());
 
         Ok(Self {
             shredstream_client: Arc::new(shredstream_client),
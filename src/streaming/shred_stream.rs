@@ -8,7 +8,7 @@ use crate::protos::shredstream::SubscribeEntriesRequest;
 use crate::streaming::common::{EventProcessor, SubscriptionHandle};
 use crate::streaming::event_parser::common::filter::EventTypeFilter;
 use crate::streaming::event_parser::common::high_performance_clock::get_high_perf_clock;
-use crate::streaming::event_parser::{Protocol, UnifiedEvent};
+use crate::streaming::event_parser::{DexEvent, Protocol, UnifiedEvent};
 use crate::streaming::shred::pool::factory;
 use solana_entry::entry::Entry;
 
@@ -99,4 +99,31 @@ impl ShredStreamGrpc {
 
         Ok(())
     }
+
+    /// 订阅ShredStream事件，回调接收强类型的 `DexEvent`
+    pub async fn shredstream_subscribe_dex_events<F, FP>(
+        &self,
+        protocols: Vec<Protocol>,
+        bot_wallet: Option<Pubkey>,
+        event_type_filter: Option<EventTypeFilter>,
+        callback: F,
+        tx_pack_callback: Option<FP>,
+    ) -> AnyResult<()>
+    where
+        F: Fn(DexEvent) + Send + Sync + 'static,
+        FP: Fn(Vec<DexEvent>) + Send + Sync + 'static,
+    {
+        self.shredstream_subscribe(
+            protocols,
+            bot_wallet,
+            event_type_filter,
+            move |event: Box<dyn UnifiedEvent>| callback(event.into_dex_event()),
+            tx_pack_callback.map(|cb| {
+                move |events: Vec<Box<dyn UnifiedEvent>>| {
+                    cb(events.into_iter().map(|event| event.into_dex_event()).collect())
+                }
+            }),
+        )
+        .await
+    }
 }
@@ -4,7 +4,7 @@ use crate::streaming::common::{
     EventProcessor, MetricsManager, PerformanceMetrics, StreamClientConfig, SubscriptionHandle,
 };
 use crate::streaming::event_parser::common::filter::EventTypeFilter;
-use crate::streaming::event_parser::{Protocol, UnifiedEvent};
+use crate::streaming::event_parser::{DexEvent, Protocol, UnifiedEvent};
 use crate::streaming::grpc::pool::factory;
 use crate::streaming::grpc::{EventPretty, SubscriptionManager};
 use anyhow::anyhow;
@@ -75,7 +75,8 @@ impl YellowstoneGrpc {
             metrics.clone(),
             config.enable_metrics,
             "YellowstoneGrpc".to_string(),
-        );
+        )
+        .with_report_config(config.metrics_report.clone());
         let event_processor = EventProcessor::new(metrics_manager.clone(), config.clone());
 
         Ok(Self {
@@ -313,6 +314,36 @@ impl YellowstoneGrpc {
         Ok(())
     }
 
+    /// Typed event subscription, delivers `DexEvent` instead of `Box<dyn UnifiedEvent>`
+    ///
+    /// Parameters are identical to [`Self::subscribe_events_immediate`]; the callback can
+    /// exhaustively `match` on the event enum instead of chaining `match_event!` downcasts.
+    #[allow(clippy::too_many_arguments)]
+    pub async fn subscribe_dex_events_immediate<F>(
+        &self,
+        protocols: Vec<Protocol>,
+        bot_wallet: Option<Pubkey>,
+        transaction_filter: Vec<TransactionFilter>,
+        account_filter: Vec<AccountFilter>,
+        event_type_filter: Option<EventTypeFilter>,
+        commitment: Option<CommitmentLevel>,
+        callback: F,
+    ) -> AnyResult<()>
+    where
+        F: Fn(DexEvent) + Send + Sync + 'static,
+    {
+        self.subscribe_events_immediate(
+            protocols,
+            bot_wallet,
+            transaction_filter,
+            account_filter,
+            event_type_filter,
+            commitment,
+            move |event: Box<dyn UnifiedEvent>| callback(event.into_dex_event()),
+        )
+        .await
+    }
+
     /// Update subscription filters at runtime without reconnection
     ///
     /// # Parameters